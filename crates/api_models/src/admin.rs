@@ -1,6 +1,7 @@
 use common_utils::{
     crypto::{Encryptable, OptionalEncryptableName},
     pii,
+    webhook_signature::WebhookSignatureAlgorithm,
 };
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -43,10 +44,9 @@ pub struct UserCreate {
 pub struct UserJwt {
     pub merchant_id: String,
     pub email: String,
-    pub exp: u64
+    pub exp: u64,
 }
 
-
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
 pub struct UserResponse {
     /// Full name of the user
@@ -545,6 +545,11 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The format in which outgoing webhooks are sent. Merchants migrating from Stripe can use
+    /// `stripe` to keep their existing webhook consumers working
+    #[schema(value_type = Option<OutgoingWebhookFormat>, example = "stripe")]
+    pub webhook_format: Option<api_enums::OutgoingWebhookFormat>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -652,6 +657,9 @@ pub struct MerchantConnectorCreate {
     pub profile_id: Option<String>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Fees charged by the connector, used by least cost routing to rank connectors
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -661,6 +669,105 @@ pub struct MerchantConnectorWebhookDetails {
     pub merchant_secret: Secret<String>,
     #[schema(value_type = String, example = "12345678900987654321")]
     pub additional_secret: Option<Secret<String>>,
+    /// Reject the incoming webhooks of the connector whose source could not be verified, instead
+    /// of fetching the status from the connector
+    #[schema(default = false, example = true)]
+    pub enforce_source_verification: Option<bool>,
+}
+
+/// Fees charged by a connector for processing payments
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeSchedule {
+    /// Fee rules of the connector, the most specific rule matching a payment is applied and the
+    /// first one wins when several rules are equally specific
+    pub rules: Vec<ConnectorFeeRule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeRule {
+    /// Payment method type the rule applies to, applies to all payment method types if not set
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// Card network the rule applies to, applies to all card networks if not set
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// Whether the rule applies to domestic or cross border payments, applies to both if not set
+    pub region: Option<ConnectorFeeRegion>,
+    /// Currency the rule applies to, applies to all currencies if not set
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// Fixed fee charged per payment, in the lowest denomination of the payment currency
+    #[serde(default)]
+    #[schema(example = 30)]
+    pub fixed_fee: i64,
+    /// Fee charged as a percentage of the payment amount
+    #[serde(default)]
+    #[schema(example = 2.9)]
+    pub percentage: f64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorFeeRegion {
+    /// The card was issued in the business country of the payment
+    Domestic,
+    /// The card was issued outside the business country of the payment
+    CrossBorder,
+}
+
+impl ConnectorFeeSchedule {
+    /// Returns the fee for a payment of `amount`, or `None` if no rule matches the payment
+    pub fn fee_for(
+        &self,
+        amount: i64,
+        currency: api_enums::Currency,
+        payment_method_type: Option<api_enums::PaymentMethodType>,
+        card_network: Option<&api_enums::CardNetwork>,
+        region: Option<ConnectorFeeRegion>,
+    ) -> Option<f64> {
+        self.rules
+            .iter()
+            .filter(|rule| {
+                rule.currency.map_or(true, |c| c == currency)
+                    && rule
+                        .payment_method_type
+                        .map_or(true, |pmt| Some(pmt) == payment_method_type)
+                    && rule
+                        .card_network
+                        .as_ref()
+                        .map_or(true, |network| Some(network) == card_network)
+                    && rule.region.map_or(true, |r| Some(r) == region)
+            })
+            .fold(None, |best: Option<(usize, &ConnectorFeeRule)>, rule| {
+                let specificity = rule.specificity();
+                match best {
+                    Some((best_specificity, _)) if best_specificity >= specificity => best,
+                    _ => Some((specificity, rule)),
+                }
+            })
+            .map(|(_, rule)| rule.fee_for(amount))
+    }
+}
+
+impl ConnectorFeeRule {
+    fn specificity(&self) -> usize {
+        [
+            self.payment_method_type.is_some(),
+            self.card_network.is_some(),
+            self.region.is_some(),
+            self.currency.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_set| *is_set)
+        .count()
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn fee_for(&self, amount: i64) -> f64 {
+        self.fixed_fee as f64 + (amount as f64) * self.percentage / 100.0
+    }
 }
 
 /// Response of creating a new Merchant Connector for the merchant account."
@@ -757,6 +864,9 @@ pub struct MerchantConnectorResponse {
     pub applepay_verified_domains: Option<Vec<String>>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Fees charged by the connector, used by least cost routing to rank connectors
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -831,6 +941,9 @@ pub struct MerchantConnectorUpdate {
     pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
 
     pub pm_auth_config: Option<serde_json::Value>,
+
+    /// Fees charged by the connector, used by least cost routing to rank connectors
+    pub fee_schedule: Option<ConnectorFeeSchedule>,
 }
 
 ///Details of FrmConfigs are mentioned here... it should be passed in payment connector create api call, and stored in merchant_connector_table
@@ -1164,3 +1277,44 @@ pub struct BusinessProfileUpdate {
     /// Verified applepay domains for a particular profile
     pub applepay_verified_domains: Option<Vec<String>>,
 }
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookSigningKeyRotateRequest {
+    /// The algorithm used by the new signing key, either `hmac_sha256` or `ed25519`
+    #[schema(value_type = String, example = "hmac_sha256")]
+    pub algorithm: WebhookSignatureAlgorithm,
+
+    /// Number of seconds for which the current signing key keeps signing webhooks along with
+    /// the new key, defaults to a day. A value of 0 revokes the current key immediately.
+    #[schema(example = 86400)]
+    pub previous_key_expiry_in_secs: Option<u32>,
+}
+
+#[derive(Clone, Debug, ToSchema, Serialize)]
+pub struct WebhookSigningKeyResponse {
+    /// The identifier of the signing key
+    #[schema(example = "whk_abcdefghijklmnopqrst")]
+    pub key_id: String,
+
+    /// The algorithm used by the signing key
+    #[schema(value_type = String, example = "hmac_sha256")]
+    pub algorithm: WebhookSignatureAlgorithm,
+
+    /// The shared secret of a HMAC-SHA256 key, returned only when the key is created
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+
+    /// The hex encoded public key of an Ed25519 key, used for verifying the signatures
+    pub public_key: Option<String>,
+
+    /// The time at which the key was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-11-22T06:45:12.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time after which the key stops signing webhooks
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-11-23T06:45:12.000Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CircuitState {
+    /// The connector receives traffic as usual
+    Closed,
+    /// Routing skips the connector because too many of the recent calls to it failed
+    Open,
+    /// The connector was open long enough, a single probe payment is let through to check if it
+    /// has recovered
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorHealthQuery {
+    /// Also report the health of the merchant connector accounts of this merchant
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectorHealthResponse {
+    /// Health of the connectors across all merchants, only connectors which were called recently
+    /// or whose circuit is not closed are listed
+    pub connectors: Vec<ConnectorHealth>,
+    /// Health of the merchant connector accounts of the merchant in the query
+    pub merchant_connector_accounts: Vec<ConnectorHealth>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectorHealth {
+    pub connector: String,
+    pub merchant_connector_id: Option<String>,
+    pub circuit_state: CircuitState,
+    /// Time at which the circuit was last opened, if it is not closed
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opened_at: Option<time::PrimitiveDateTime>,
+    /// Calls made to the connector in the current health window
+    pub total_calls: u64,
+    /// Calls which failed with a 5xx response, timed out or could not reach the connector
+    pub failed_calls: u64,
+    pub failure_rate: Option<f64>,
+    pub average_latency_ms: Option<u64>,
+}
@@ -3,6 +3,7 @@ pub mod admin;
 pub mod api_keys;
 pub mod bank_accounts;
 pub mod cards_info;
+pub mod connector_health;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
@@ -16,7 +17,10 @@ pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod surcharge_decision_configs;
 pub mod verifications;
+pub mod webhook_endpoints;
 pub mod webhooks;
@@ -685,6 +685,12 @@ pub struct CustomerPaymentMethod {
     #[schema(example = "7ebf443f-a050-4067-84e5-e6f6d4800aef")]
     pub payment_token: String,
 
+    /// The unique identifier of the payment method, only returned when the payment methods are
+    /// listed with the API key.
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIy")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_id: Option<String>,
+
     /// The unique identifier of the customer.
     #[schema(example = "cus_meowerunwiuwiwqw")]
     pub customer_id: String,
@@ -2109,6 +2109,27 @@ pub struct PaymentsResponse {
 
     /// Identifier of the connector ( merchant connector account ) which was chosen to make the payment
     pub merchant_connector_id: Option<String>,
+
+    /// The fees charged by the connector for this payment, when reported by the connector
+    pub fee_details: Option<ConnectorFeeDetails>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorFeeDetails {
+    /// The fee charged by the connector for processing the transaction, in the lowest denomination of the settlement currency
+    #[schema(example = 59)]
+    pub processing_fee: Option<i64>,
+    /// The interchange and scheme fees passed through by the connector, in the lowest denomination of the settlement currency
+    #[schema(example = 20)]
+    pub interchange_fee: Option<i64>,
+    /// The markup charged by the connector for currency conversion, in the lowest denomination of the settlement currency
+    pub fx_markup: Option<i64>,
+    /// The amount settled to the merchant after deducting all fees, in the lowest denomination of the settlement currency
+    #[schema(example = 6461)]
+    pub net_amount: Option<i64>,
+    /// The currency in which the connector settles the transaction
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub settlement_currency: Option<api_enums::Currency>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -2232,6 +2253,51 @@ pub struct PaymentListFilters {
     pub authentication_type: Vec<enums::AuthenticationType>,
 }
 
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentsRoutingTraceRequest {
+    /// The attempt whose routing trace is returned, defaults to the active attempt of the payment
+    pub attempt_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsRoutingTraceResponse {
+    pub payment_id: String,
+    pub attempt_id: String,
+    /// The connector the attempt was routed to
+    pub connector: Option<String>,
+    /// `None` if the attempt was not routed, for example when the connector was already decided
+    /// by a mandate or by the request
+    pub routing_trace: Option<crate::routing::RoutingTrace>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ConnectorFeeAggregateResponse {
+    /// The number of aggregates included in the response
+    pub count: usize,
+    /// The fees aggregated per connector, day and settlement currency
+    pub data: Vec<ConnectorFeeAggregate>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+pub struct ConnectorFeeAggregate {
+    /// The connector which processed the payments
+    pub connector: String,
+    /// The day on which the payments were created, in the `YYYY-MM-DD` format
+    pub date: String,
+    /// The currency in which the fees are reported
+    pub settlement_currency: Option<api_enums::Currency>,
+    /// The number of payments for which the connector reported fees
+    pub payment_count: i64,
+    /// The sum of the processing fees
+    pub processing_fee: i64,
+    /// The sum of the interchange and scheme fees
+    pub interchange_fee: i64,
+    /// The sum of the currency conversion markups
+    pub fx_markup: i64,
+    /// The sum of the net amounts settled to the merchant
+    pub net_amount: i64,
+}
+
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
 pub struct TimeRange {
     /// The start time to filter payments list or to get list of filters. To get list of filters start time is needed to be passed
@@ -7,7 +7,7 @@ use masking::Secret;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::{admin, enums as api_enums, payments};
+use crate::{admin, enums as api_enums, payments, refunds::TimeRange};
 
 #[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
 pub enum PayoutRequest {
@@ -157,6 +157,8 @@ pub struct PayoutCreateRequest {
 pub enum PayoutMethodData {
     Card(Card),
     Bank(Bank),
+    Wallet(Wallet),
+    InstantTransfer(InstantTransfer),
 }
 
 impl Default for PayoutMethodData {
@@ -262,6 +264,72 @@ pub struct SepaBankTransfer {
     pub bic: Option<Secret<String>>,
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Wallet {
+    Paypal(Paypal),
+    Venmo(Venmo),
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct Paypal {
+    /// Email linked with the PayPal account
+    #[schema(value_type = String, example = "john.doe@example.com")]
+    pub email: Email,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct Venmo {
+    /// Mobile number linked to the Venmo account
+    #[schema(value_type = String, example = "16608213349")]
+    pub telephone_number: Secret<String>,
+}
+
+/// Bank transfers settled over an instant payment rail. The rail is picked from the account
+/// details passed, the same way as for regular bank payouts.
+#[derive(Eq, PartialEq, Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(untagged)]
+pub enum InstantTransfer {
+    /// SEPA Instant Credit Transfer, for EUR payouts within the SEPA zone
+    SepaInstant(SepaBankTransfer),
+    /// Faster Payments, for GBP payouts within the UK
+    FasterPayments(BacsBankTransfer),
+    /// RTP (Real-Time Payments network), for USD payouts within the US
+    Rtp(AchBankTransfer),
+}
+
+impl InstantTransfer {
+    /// The only currency that can be settled over the rail
+    pub fn get_rail_currency(&self) -> api_enums::Currency {
+        match self {
+            Self::SepaInstant(_) => api_enums::Currency::EUR,
+            Self::FasterPayments(_) => api_enums::Currency::GBP,
+            Self::Rtp(_) => api_enums::Currency::USD,
+        }
+    }
+}
+
+impl From<InstantTransfer> for Bank {
+    fn from(instant_transfer: InstantTransfer) -> Self {
+        match instant_transfer {
+            InstantTransfer::SepaInstant(b) => Self::Sepa(b),
+            InstantTransfer::FasterPayments(b) => Self::Bacs(b),
+            InstantTransfer::Rtp(b) => Self::Ach(b),
+        }
+    }
+}
+
+impl PayoutMethodData {
+    pub fn get_payout_type(&self) -> api_enums::PayoutType {
+        match self {
+            Self::Card(_) => api_enums::PayoutType::Card,
+            Self::Bank(_) => api_enums::PayoutType::Bank,
+            Self::Wallet(_) => api_enums::PayoutType::Wallet,
+            Self::InstantTransfer(_) => api_enums::PayoutType::InstantTransfer,
+        }
+    }
+}
+
 #[derive(Debug, ToSchema, Clone, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PayoutCreateResponse {
@@ -420,3 +488,58 @@ pub struct PayoutActionRequest {
     )]
     pub payout_id: String,
 }
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutListRequest {
+    /// The identifier for the payout
+    pub payout_id: Option<String>,
+    /// The identifier for the customer
+    pub customer_id: Option<String>,
+    /// The identifier for business profile
+    pub profile_id: Option<String>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+    /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
+    #[serde(flatten)]
+    pub time_range: Option<TimeRange>,
+    /// The list of connectors to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<api_enums::PayoutConnectors>>,
+    /// The list of currencies to filter payouts list
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currency: Option<Vec<api_enums::Currency>>,
+    /// The list of payout statuses to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutStatus>>)]
+    pub status: Option<Vec<api_enums::PayoutStatus>>,
+    /// The list of payout types to filter payouts list
+    #[schema(value_type = Option<Vec<PayoutType>>)]
+    pub payout_type: Option<Vec<api_enums::PayoutType>>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PayoutListResponse {
+    /// The number of payouts included in the list
+    pub count: usize,
+    /// The total number of payouts in the list
+    pub total_count: i64,
+    /// The List of payout response objects
+    pub data: Vec<PayoutCreateResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, ToSchema)]
+pub struct PayoutListFilters {
+    /// The list of available connector filters
+    pub connector: Vec<String>,
+    /// The list of available currency filters
+    #[schema(value_type = Vec<Currency>)]
+    pub currency: Vec<api_enums::Currency>,
+    /// The list of available payout status filters
+    #[schema(value_type = Vec<PayoutStatus>)]
+    pub status: Vec<api_enums::PayoutStatus>,
+    /// The list of available payout type filters
+    #[schema(value_type = Vec<PayoutType>)]
+    pub payout_type: Vec<api_enums::PayoutType>,
+}
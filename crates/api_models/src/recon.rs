@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{
+    enums::{Connector, ReconOutcome, ReconReportStatus, ReconTransactionType},
+    refunds::TimeRange,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportCreateRequest {
+    /// The connector which generated the settlement report
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: Connector,
+    /// The id of the settlement report uploaded through the files API with purpose `settlement_report`.
+    /// Either this or `connector_report_id` must be passed
+    #[schema(example = "file_AZ2ZJ7Vx7kx2PdYlwZVo")]
+    pub file_id: Option<String>,
+    /// The id of the settlement report file at the connector, such as the file generated by a
+    /// Stripe report run. The report is fetched from the connector using the credentials of
+    /// `merchant_connector_id`
+    #[schema(example = "file_1OB0Hq2eZvKYlo2C1kbhpQ0p")]
+    pub connector_report_id: Option<String>,
+    /// The merchant connector account used to fetch the report from the connector
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<String>,
+    /// The period in which the payments settled in the report were created. Successful payments
+    /// processed through the connector in this period which are absent from the report are
+    /// flagged as missing from the report. Defaults to the period spanned by the payments found in
+    /// the report
+    pub settlement_period: Option<TimeRange>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconReportResponse {
+    /// The identifier for the reconciliation report
+    pub report_id: String,
+    /// The connector which generated the settlement report
+    pub connector: String,
+    /// The id of the settlement report file
+    pub file_id: Option<String>,
+    /// Status of the reconciliation run
+    #[schema(value_type = ReconReportStatus)]
+    pub status: ReconReportStatus,
+    /// Number of settlement report lines that were reconciled
+    pub total_records: i32,
+    /// Number of lines which matched the stored transaction
+    pub matched_records: i32,
+    /// Number of lines which were missing or did not match the stored transaction
+    pub unmatched_records: i32,
+    /// Reason for the failure, if the reconciliation run failed
+    pub error_message: Option<String>,
+    /// Matched and unmatched counts for every settlement batch in the report
+    pub settlement_batches: Option<Vec<SettlementBatchSummary>>,
+    /// Time at which the report was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the report was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, Serialize, ToSchema)]
+pub struct SettlementBatchSummary {
+    /// The settlement batch or payout reference sent by the connector
+    pub settlement_batch_id: Option<String>,
+    /// Number of lines in the settlement batch
+    pub total_records: i64,
+    /// Number of lines which matched the stored transaction
+    pub matched_records: i64,
+    /// Number of lines which were missing or did not match the stored transaction
+    pub unmatched_records: i64,
+    /// Number of lines for which no transaction was found
+    pub missing_records: i64,
+    /// Number of settled payments which are missing from the settlement report
+    pub missing_from_report: i64,
+    /// Number of lines with a currency mismatch
+    pub currency_mismatches: i64,
+    /// Number of lines with an amount mismatch
+    pub amount_mismatches: i64,
+    /// Number of lines with a status mismatch
+    pub status_mismatches: i64,
+    /// Number of lines with a fee mismatch
+    pub fee_mismatches: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconReportListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconReportListResponse {
+    /// The number of reports included in the list
+    pub size: usize,
+    /// The list of reconciliation reports
+    pub data: Vec<ReconReportResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReconRecordListConstraints {
+    /// Only return the records with this outcome
+    #[schema(value_type = Option<ReconOutcome>)]
+    pub outcome: Option<ReconOutcome>,
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconRecordResponse {
+    /// The identifier for the reconciliation record
+    pub record_id: String,
+    /// The identifier for the reconciliation report
+    pub report_id: String,
+    /// The settlement batch or payout reference sent by the connector
+    pub settlement_batch_id: Option<String>,
+    /// The kind of transaction the settlement line refers to
+    #[schema(value_type = ReconTransactionType)]
+    pub transaction_type: ReconTransactionType,
+    /// The connector reference of the transaction
+    pub connector_transaction_id: String,
+    /// The payment, refund or dispute id of the matched transaction
+    pub reference_id: Option<String>,
+    /// Amount reported by the connector, in the lowest denomination of the currency. Not set for
+    /// transactions which are missing from the settlement report
+    pub reported_amount: Option<i64>,
+    /// Currency reported by the connector
+    pub reported_currency: Option<String>,
+    /// Fee reported by the connector, in the lowest denomination of the currency
+    pub reported_fee: Option<i64>,
+    /// Net amount reported by the connector, in the lowest denomination of the currency
+    pub reported_net_amount: Option<i64>,
+    /// Status or record type reported by the connector
+    pub reported_status: Option<String>,
+    /// Amount of the stored transaction
+    pub expected_amount: Option<i64>,
+    /// Currency of the stored transaction
+    pub expected_currency: Option<String>,
+    /// Outcome of matching the settlement line
+    #[schema(value_type = ReconOutcome)]
+    pub outcome: ReconOutcome,
+    /// Every mismatch found for the settlement line
+    pub mismatches: Vec<ReconMismatch>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReconMismatch {
+    /// The kind of mismatch
+    #[schema(value_type = ReconOutcome)]
+    pub outcome: ReconOutcome,
+    /// Value stored for the transaction
+    pub expected: Option<String>,
+    /// Value reported by the connector
+    pub reported: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ReconRecordListResponse {
+    /// The number of records included in the list
+    pub size: usize,
+    /// The list of reconciliation records
+    pub data: Vec<ReconRecordResponse>,
+}
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{admin, enums};
+use crate::{admin, enums, payments};
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -125,6 +125,26 @@ pub struct RefundResponse {
     #[schema(example = "stripe")]
     pub connector: String,
     pub profile_id: Option<String>,
+    /// The fees charged by the connector for this refund, when reported by the connector
+    pub fee_details: Option<payments::ConnectorFeeDetails>,
+    /// The method through which the refund is paid back to the customer
+    #[schema(value_type = RefundMethod, example = "original_payment_method")]
+    pub refund_method: enums::RefundMethod,
+    /// The identifier of the payout through which the refund is paid, when the refund through the
+    /// connector failed and was paid out to a saved payment method of the customer instead
+    pub payout_id: Option<String>,
+}
+
+/// Merchant level configuration for paying out refunds which failed at the connector to a saved
+/// payment method of the customer. It is stored against the `refund_payout_fallback_{merchant_id}`
+/// config key, and the fallback is disabled when the config is not present.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct RefundPayoutFallbackConfig {
+    /// The connector error codes for which the refund is considered as failed permanently. Only
+    /// refunds which failed with one of these codes are paid out, so the fallback is disabled
+    /// when no codes are configured.
+    #[serde(default)]
+    pub error_codes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::enums::{self, RoutableConnectors};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
 pub enum ConnectorSelection {
     Priority(Vec<RoutableConnectorChoice>),
@@ -38,6 +38,10 @@ pub struct RoutingConfigRequest {
     pub description: Option<String>,
     pub algorithm: Option<RoutingAlgorithm>,
     pub profile_id: Option<String>,
+    /// The author of this version of the algorithm
+    pub created_by: Option<String>,
+    /// What changed in this version of the algorithm
+    pub comment: Option<String>,
 }
 
 #[cfg(feature = "business_profile_routing")]
@@ -55,6 +59,76 @@ pub struct RoutingRetrieveLinkQuery {
     pub profile_id: Option<String>,
 }
 
+/// The timezone in which the time based keys (hour_of_day, day_of_week and day_of_month) of the
+/// merchant's routing rules are evaluated
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingTimezoneConfig {
+    /// An IANA timezone name, such as `Asia/Kolkata` or `UTC`
+    pub timezone: String,
+}
+
+/// Explains how the connectors for a payment attempt were chosen
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RoutingTrace {
+    /// The routing algorithm that was evaluated, `None` if the fallback configuration was used
+    pub algorithm_id: Option<String>,
+    /// The arm of the canary rollout the payment was routed with, if a rollout was in progress
+    pub routing_arm: Option<enums::RoutingArm>,
+    /// Outcome of every rule and condition, for advanced routing algorithms. This is not recorded
+    /// while routing a payment, it is computed from `input` when the trace is retrieved
+    pub evaluation: Option<euclid::backend::trace::ProgramTrace>,
+    /// Input the advanced routing algorithm was evaluated with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<euclid::backend::inputs::BackendInput>,
+    /// Connectors chosen by the routing algorithm, before the eligibility analysis
+    pub selection: Vec<RoutableConnectorChoice>,
+    /// Outcome of the eligibility analysis for every connector that was considered
+    pub eligibility: Vec<ConnectorEligibilityTrace>,
+    /// Cost of every connector, for least cost routing algorithms
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub connector_costs: Vec<ConnectorCostTrace>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorCostTrace {
+    pub connector: RoutableConnectorChoice,
+    /// Fee the connector charges for the payment, `None` if its fee schedule has no matching rule
+    pub fee: Option<f64>,
+    /// Fee adjusted by the authorization rate of the connector, which the connectors are ranked by
+    pub effective_cost: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorEligibilityTrace {
+    pub connector: RoutableConnectorChoice,
+    pub stage: EligibilityStage,
+    /// Why the connector was removed, `None` if it was eligible
+    pub ineligibility_reason: Option<IneligibilityReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EligibilityStage {
+    /// Connectors chosen by the routing algorithm
+    Selection,
+    /// Connectors from the fallback configuration of the merchant
+    Fallback,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "details", rename_all = "snake_case")]
+pub enum IneligibilityReason {
+    /// The connector is not configured for the merchant
+    NotConfigured,
+    /// The connector is not one of the eligible connectors sent in the request
+    NotInEligibleConnectors,
+    /// The payment does not satisfy the constraints configured for the connector, such as its
+    /// accepted currencies, countries or amount limits
+    UnsatisfiedConstraints(serde_json::Value),
+    /// The circuit of the connector is open, as too many of the recent calls to it failed
+    CircuitOpen,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingRetrieveResponse {
     pub algorithm: Option<MerchantRoutingAlgorithm>,
@@ -77,6 +151,10 @@ pub struct MerchantRoutingAlgorithm {
     pub algorithm: RoutingAlgorithm,
     pub created_at: i64,
     pub modified_at: i64,
+    #[serde(default = "default_routing_algorithm_version")]
+    pub version: i32,
+    pub created_by: Option<String>,
+    pub comment: Option<String>,
 }
 
 impl EuclidDirFilter for ConnectorSelection {
@@ -108,6 +186,13 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::VoucherType,
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::DayOfMonth,
+        DirKeyKind::IssuerCardType,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IsDomestic,
     ];
 }
 
@@ -148,7 +233,7 @@ impl EuclidAnalysable for ConnectorSelection {
     }
 }
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorVolumeSplit {
     pub connector: RoutableConnectorChoice,
     pub split: u8,
@@ -387,6 +472,7 @@ pub enum RoutingAlgorithmKind {
     Priority,
     VolumeSplit,
     Advanced,
+    LeastCost,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -401,6 +487,62 @@ pub enum RoutingAlgorithm {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(euclid::frontend::ast::Program<ConnectorSelection>),
+    LeastCost(LeastCostRoutingConfig),
+}
+
+/// Ranks the connectors by the fee they charge for a payment, as configured in the fee schedule
+/// of their merchant connector account
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LeastCostRoutingConfig {
+    pub connectors: Vec<LeastCostConnector>,
+    /// How much the authorization rate of a connector weighs against its fee, between 0 (rank by
+    /// fee alone) and 1 (rank by fee per successful authorization)
+    #[serde(default)]
+    pub authorization_rate_weight: f64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LeastCostConnector {
+    pub connector: RoutableConnectorChoice,
+    /// Expected share of payments the connector authorizes, between 0 and 1
+    pub authorization_rate: Option<f64>,
+}
+
+impl LeastCostRoutingConfig {
+    fn validate(&self) -> Result<(), error_stack::Report<ParsingError>> {
+        if self.connectors.is_empty() {
+            return Err(ParsingError::StructParseFailure(
+                "Connectors list can't be empty for Least cost Algorithm",
+            ))
+            .into_report();
+        }
+        if !(0.0..=1.0).contains(&self.authorization_rate_weight) {
+            return Err(ParsingError::StructParseFailure(
+                "Authorization rate weight must be between 0 and 1",
+            ))
+            .into_report();
+        }
+        let has_invalid_rate = self
+            .connectors
+            .iter()
+            .filter_map(|connector| connector.authorization_rate)
+            .any(|rate| !(rate > 0.0 && rate <= 1.0));
+        if has_invalid_rate {
+            return Err(ParsingError::StructParseFailure(
+                "Authorization rate must be greater than 0 and at most 1",
+            ))
+            .into_report();
+        }
+        Ok(())
+    }
+
+    /// Connectors of the algorithm in their configured order
+    pub fn get_connectors(&self) -> Vec<RoutableConnectorChoice> {
+        self.connectors
+            .iter()
+            .map(|connector| connector.connector.clone())
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -410,6 +552,7 @@ pub enum RoutingAlgorithmSerde {
     Priority(Vec<RoutableConnectorChoice>),
     VolumeSplit(Vec<ConnectorVolumeSplit>),
     Advanced(euclid::frontend::ast::Program<ConnectorSelection>),
+    LeastCost(LeastCostRoutingConfig),
 }
 
 impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
@@ -429,6 +572,7 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
                 ))
                 .into_report()?
             }
+            RoutingAlgorithmSerde::LeastCost(config) => config.validate()?,
             _ => {}
         };
         Ok(match value {
@@ -436,6 +580,7 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
             RoutingAlgorithmSerde::Priority(i) => Self::Priority(i),
             RoutingAlgorithmSerde::VolumeSplit(i) => Self::VolumeSplit(i),
             RoutingAlgorithmSerde::Advanced(i) => Self::Advanced(i),
+            RoutingAlgorithmSerde::LeastCost(i) => Self::LeastCost(i),
         })
     }
 }
@@ -537,6 +682,7 @@ impl RoutingAlgorithm {
             Self::Priority(_) => RoutingAlgorithmKind::Priority,
             Self::VolumeSplit(_) => RoutingAlgorithmKind::VolumeSplit,
             Self::Advanced(_) => RoutingAlgorithmKind::Advanced,
+            Self::LeastCost(_) => RoutingAlgorithmKind::LeastCost,
         }
     }
 }
@@ -547,11 +693,21 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// A candidate algorithm that is being rolled out to a part of the payments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canary: Option<RoutingCanaryConfig>,
 }
 
 impl RoutingAlgorithmRef {
+    /// Activates the given algorithm for all payments, which ends any canary rollout
     pub fn update_algorithm_id(&mut self, new_id: String) {
         self.algorithm_id = Some(new_id);
+        self.canary = None;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
+    pub fn update_canary(&mut self, canary: Option<RoutingCanaryConfig>) {
+        self.canary = canary;
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
@@ -577,6 +733,26 @@ pub struct RoutingDictionaryRecord {
     pub description: String,
     pub created_at: i64,
     pub modified_at: i64,
+    /// Algorithms with the same name are versions of one another, numbered from 1
+    #[serde(default = "default_routing_algorithm_version")]
+    pub version: i32,
+    pub created_by: Option<String>,
+    pub comment: Option<String>,
+}
+
+/// Records created before algorithms were versioned are the first version of their name
+fn default_routing_algorithm_version() -> i32 {
+    1
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingConfigCreateResponse {
+    #[serde(flatten)]
+    pub record: RoutingDictionaryRecord,
+    /// Problems found with the way the rules of an advanced algorithm interact with one another,
+    /// such as rules that are shadowed by earlier rules and can never be selected
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub analysis_warnings: Vec<euclid::dssa::types::AnalysisWarning>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -592,3 +768,145 @@ pub enum RoutingKind {
     Config(RoutingDictionary),
     RoutingAlgorithm(Vec<RoutingDictionaryRecord>),
 }
+
+/// Structural changes between two routing algorithms
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum RoutingAlgorithmChanges {
+    Unchanged,
+    /// The algorithms are not both advanced, so the whole algorithm is reported
+    Replaced {
+        from: RoutingAlgorithm,
+        to: RoutingAlgorithm,
+    },
+    Program(ast::diff::ProgramDiff<ConnectorSelection>),
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingAlgorithmDiffResponse {
+    pub from: RoutingDictionaryRecord,
+    pub to: RoutingDictionaryRecord,
+    pub changes: RoutingAlgorithmChanges,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationScheduleRequest {
+    /// When the algorithm should be activated, must be in the future
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    pub created_by: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationScheduleResponse {
+    pub algorithm_id: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub activate_at: time::PrimitiveDateTime,
+    /// The id of the scheduler task that will activate the algorithm
+    pub task_id: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RoutingRollbackRequest {
+    pub profile_id: Option<String>,
+    pub created_by: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingActivationAuditQuery {
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+    pub profile_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingActivationAuditRecord {
+    pub audit_id: String,
+    pub profile_id: Option<String>,
+    /// The algorithm active after the change, `None` if routing was deactivated
+    pub algorithm_id: Option<String>,
+    /// The algorithm active before the change
+    pub previous_algorithm_id: Option<String>,
+    pub action: enums::RoutingActivationAction,
+    pub created_by: Option<String>,
+    pub created_at: i64,
+}
+
+/// A candidate routing algorithm that is used for a percentage of the payments, while the active
+/// algorithm keeps routing the rest
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingCanaryConfig {
+    pub algorithm_id: String,
+    /// Percentage of the payments routed with the candidate algorithm
+    pub percentage: u8,
+    pub stickiness: RoutingCanaryStickiness,
+    pub started_at: i64,
+}
+
+/// Decides which payments are consistently routed with the same arm of a canary rollout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingCanaryStickiness {
+    /// Every payment of a customer uses the same arm. Payments without a customer are bucketed
+    /// by their payment id.
+    #[default]
+    Customer,
+    /// Every attempt of a payment uses the same arm
+    Payment,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingCanaryStartRequest {
+    /// Percentage of the payments to route with the candidate algorithm, between 1 and 99.
+    /// Sending a new percentage for the algorithm already in rollout adjusts the rollout.
+    pub percentage: u8,
+    #[serde(default)]
+    pub stickiness: RoutingCanaryStickiness,
+    pub created_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct RoutingCanaryActionRequest {
+    pub profile_id: Option<String>,
+    pub created_by: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingCanaryQuery {
+    pub profile_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingCanaryStatusResponse {
+    pub active_algorithm_id: String,
+    pub canary: RoutingCanaryConfig,
+    pub arms: Vec<RoutingArmStats>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingArmStats {
+    pub arm: enums::RoutingArm,
+    pub algorithm_id: String,
+    #[serde(flatten)]
+    pub totals: RoutingArmCounts,
+    /// Counts per UTC day since the rollout started, oldest day first
+    pub daily: Vec<RoutingArmDailyStats>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RoutingArmDailyStats {
+    /// The day, in the `YYYY-MM-DD` format
+    pub date: String,
+    #[serde(flatten)]
+    pub counts: RoutingArmCounts,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct RoutingArmCounts {
+    pub total_attempts: u64,
+    pub authorized_attempts: u64,
+    pub declined_attempts: u64,
+    /// Authorized attempts out of the attempts that were authorized or declined, `None` if no
+    /// attempt has reached either outcome yet
+    pub authorization_rate: Option<f64>,
+}
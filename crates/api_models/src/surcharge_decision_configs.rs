@@ -0,0 +1,69 @@
+use common_utils::{consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH, types::Percentage};
+use euclid::frontend::{
+    ast::Program,
+    dir::{DirKeyKind, EuclidDirFilter},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::payment_methods::Surcharge;
+
+/// The surcharge to be applied when a surcharge rule matches
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SurchargeDetailsOutput {
+    pub surcharge: Surcharge,
+    pub tax_on_surcharge: Option<Percentage<SURCHARGE_PERCENTAGE_PRECISION_LENGTH>>,
+}
+
+/// The output of a surcharge rule, no surcharge is applied when `surcharge_details` is absent
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SurchargeDecisionConfigs {
+    pub surcharge_details: Option<SurchargeDetailsOutput>,
+}
+
+impl EuclidDirFilter for SurchargeDecisionConfigs {
+    const ALLOWED: &'static [DirKeyKind] = &[
+        DirKeyKind::PaymentMethod,
+        DirKeyKind::CardNetwork,
+        DirKeyKind::CardType,
+        DirKeyKind::PayLaterType,
+        DirKeyKind::WalletType,
+        DirKeyKind::UpiType,
+        DirKeyKind::BankRedirectType,
+        DirKeyKind::BankDebitType,
+        DirKeyKind::BankTransferType,
+        DirKeyKind::CryptoType,
+        DirKeyKind::RewardType,
+        DirKeyKind::VoucherType,
+        DirKeyKind::CardRedirectType,
+        DirKeyKind::MetaData,
+        DirKeyKind::PaymentAmount,
+        DirKeyKind::PaymentCurrency,
+        DirKeyKind::BillingCountry,
+    ];
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MerchantSurchargeConfigs {
+    /// Whether the surcharge and tax breakup screen should be shown to the customer
+    pub show_surcharge_breakup_screen: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SurchargeDecisionManagerRecord {
+    pub name: String,
+    pub merchant_surcharge_configs: MerchantSurchargeConfigs,
+    pub algorithm: Program<SurchargeDecisionConfigs>,
+    pub created_at: i64,
+    pub modified_at: i64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SurchargeDecisionManagerRequest {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub merchant_surcharge_configs: MerchantSurchargeConfigs,
+    pub algorithm: Option<Program<SurchargeDecisionConfigs>>,
+}
+
+pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
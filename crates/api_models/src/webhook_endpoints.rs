@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::{EventType, WebhookDeliveryStatus};
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The url to which the webhooks are sent
+    #[schema(value_type = String, example = "https://merchant.com/webhooks/refunds")]
+    pub url: url::Url,
+    /// A description of the endpoint
+    #[schema(max_length = 256, example = "Refunds service")]
+    pub description: Option<String>,
+    /// The event types sent to the endpoint, every event type is sent when not provided
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<EventType>>,
+    /// Custom headers added to every webhook sent to the endpoint
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"x-service-token": "token"}))]
+    pub headers: Option<HashMap<String, Secret<String>>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The url to which the webhooks are sent
+    #[schema(value_type = Option<String>, example = "https://merchant.com/webhooks/refunds")]
+    pub url: Option<url::Url>,
+    /// A description of the endpoint
+    #[schema(max_length = 256, example = "Refunds service")]
+    pub description: Option<String>,
+    /// The event types sent to the endpoint
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["refund_succeeded", "refund_failed"]))]
+    pub enabled_events: Option<Vec<EventType>>,
+    /// Custom headers added to every webhook sent to the endpoint, replaces the existing headers
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"x-service-token": "token"}))]
+    pub headers: Option<HashMap<String, Secret<String>>>,
+    /// Webhooks are not sent to a disabled endpoint
+    #[schema(example = false)]
+    pub disabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier for the webhook endpoint
+    #[schema(example = "whe_abcdefghijklmnopqrst")]
+    pub endpoint_id: String,
+    /// The identifier for the business profile
+    pub profile_id: String,
+    /// The url to which the webhooks are sent
+    pub url: String,
+    /// A description of the endpoint
+    pub description: Option<String>,
+    /// The event types sent to the endpoint, every event type is sent when this is not set
+    #[schema(value_type = Option<Vec<EventType>>)]
+    pub enabled_events: Option<Vec<EventType>>,
+    /// The names of the custom headers added to every webhook sent to the endpoint
+    pub headers: Vec<String>,
+    /// The secret with which the webhooks sent to the endpoint are signed, returned only when
+    /// the endpoint is created
+    #[schema(value_type = Option<String>)]
+    pub secret: Option<Secret<String>>,
+    /// Webhooks are not sent to a disabled endpoint
+    pub disabled: bool,
+    /// Time at which the endpoint was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the endpoint was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookDeliveryListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryResponse {
+    /// The identifier for the delivery
+    pub delivery_id: String,
+    /// The identifier for the event that was delivered
+    pub event_id: String,
+    /// The type of the event that was delivered
+    #[schema(value_type = EventType)]
+    pub event_type: EventType,
+    /// The identifier for the webhook endpoint
+    pub endpoint_id: String,
+    /// Status of the delivery to the endpoint
+    #[schema(value_type = WebhookDeliveryStatus)]
+    pub status: WebhookDeliveryStatus,
+    /// HTTP status code with which the endpoint responded
+    pub response_status_code: Option<i16>,
+    /// Reason for the failure, if the delivery failed
+    pub error_message: Option<String>,
+    /// Time at which the delivery was attempted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the delivery status was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryListResponse {
+    /// The number of deliveries included in the list
+    pub size: usize,
+    /// The list of deliveries
+    pub data: Vec<WebhookDeliveryResponse>,
+}
@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+#[cfg(feature = "payouts")]
+use crate::payouts;
 use crate::{disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
@@ -31,6 +33,13 @@ pub enum IncomingWebhookEvent {
     MandateActive,
     MandateRevoked,
     EndpointVerification,
+    PayoutSuccess,
+    PayoutFailure,
+    PayoutProcessing,
+    PayoutCancelled,
+    PayoutCreated,
+    PayoutExpired,
+    PayoutReversal,
 }
 
 pub enum WebhookFlow {
@@ -41,6 +50,7 @@ pub enum WebhookFlow {
     ReturnResponse,
     BankTransfer,
     Mandate,
+    Payout,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -64,6 +74,10 @@ pub enum WebhookResponseTracker {
         mandate_id: String,
         status: common_enums::MandateStatus,
     },
+    Payout {
+        payout_id: String,
+        status: common_enums::PayoutStatus,
+    },
     NoEffect,
 }
 
@@ -73,7 +87,7 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_string()),
-            Self::NoEffect | Self::Mandate { .. } => None,
+            Self::NoEffect | Self::Mandate { .. } | Self::Payout { .. } => None,
         }
     }
 }
@@ -104,6 +118,13 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::EndpointVerification => Self::ReturnResponse,
             IncomingWebhookEvent::SourceChargeable
             | IncomingWebhookEvent::SourceTransactionCreated => Self::BankTransfer,
+            IncomingWebhookEvent::PayoutSuccess
+            | IncomingWebhookEvent::PayoutFailure
+            | IncomingWebhookEvent::PayoutProcessing
+            | IncomingWebhookEvent::PayoutCancelled
+            | IncomingWebhookEvent::PayoutCreated
+            | IncomingWebhookEvent::PayoutExpired
+            | IncomingWebhookEvent::PayoutReversal => Self::Payout,
         }
     }
 }
@@ -122,11 +143,18 @@ pub enum MandateIdType {
     ConnectorMandateId(String),
 }
 
+#[derive(Clone)]
+pub enum PayoutIdType {
+    PayoutId(String),
+    ConnectorPayoutId(String),
+}
+
 #[derive(Clone)]
 pub enum ObjectReferenceId {
     PaymentId(payments::PaymentIdType),
     RefundId(RefundIdType),
     MandateId(MandateIdType),
+    PayoutId(PayoutIdType),
 }
 
 pub struct IncomingWebhookDetails {
@@ -165,6 +193,9 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse)]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = PayoutCreateResponse)]
+    PayoutDetails(Box<payouts::PayoutCreateResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -172,3 +203,60 @@ pub struct ConnectorWebhookSecrets {
     pub secret: Vec<u8>,
     pub additional_secret: Option<masking::Secret<String>>,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IncomingWebhookListConstraints {
+    /// Limit on the number of objects to return
+    pub limit: Option<i64>,
+    /// The starting point within a list of objects
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookResponse {
+    /// The identifier for the stored incoming webhook
+    #[schema(example = "whin_abcdefghijklmnopqrst")]
+    pub webhook_id: String,
+    /// The connector name or merchant connector account id on which the webhook was received
+    #[schema(example = "stripe")]
+    pub connector_name_or_mca_id: String,
+    /// The name of the connector which sent the webhook
+    #[schema(example = "stripe")]
+    pub connector_name: Option<String>,
+    /// The headers of the webhook, with the values of the sensitive headers masked
+    #[schema(value_type = Object)]
+    pub headers: serde_json::Value,
+    /// The body of the webhook with the sensitive fields masked, as text if the body is neither
+    /// JSON nor form encoded, not set if the webhook has no body
+    #[schema(value_type = Option<Object>)]
+    pub body: Option<serde_json::Value>,
+    /// Outcome of the source verification of the webhook when it was received
+    #[schema(value_type = WebhookSourceVerificationStatus)]
+    pub source_verification_status: api_enums::WebhookSourceVerificationStatus,
+    /// The processing status of the webhook
+    #[schema(value_type = IncomingWebhookStatus)]
+    pub status: api_enums::IncomingWebhookStatus,
+    /// Reason for the failure, if the processing of the webhook failed
+    pub error_message: Option<String>,
+    /// The payment which the webhook was processed for
+    pub payment_id: Option<String>,
+    /// The refund which the webhook was processed for
+    pub refund_id: Option<String>,
+    /// The dispute which the webhook was processed for
+    pub dispute_id: Option<String>,
+    /// Time at which the webhook was received
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// Time at which the webhook was last processed
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct IncomingWebhookListResponse {
+    /// The number of webhooks included in the list
+    pub size: usize,
+    /// The list of webhooks
+    pub data: Vec<IncomingWebhookResponse>,
+}
@@ -807,6 +807,12 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    PayoutSuccess,
+    PayoutFailed,
+    PayoutProcessing,
+    PayoutCancelled,
+    PayoutExpired,
+    PayoutReversed,
 }
 
 // TODO: This decision about using KV mode or not,
@@ -1045,6 +1051,7 @@ pub enum PaymentMethodType {
     Trustly,
     Twint,
     UpiCollect,
+    Venmo,
     Vipps,
     Walley,
     WeChatPay,
@@ -1139,6 +1146,33 @@ pub enum RefundStatus {
     TransactionFailure,
 }
 
+/// The method through which a refund is paid back to the customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    strum::Display,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundMethod {
+    /// The refund is sent to the connector which processed the payment
+    #[default]
+    OriginalPaymentMethod,
+    /// The refund is paid out to a saved payment method of the customer, after the refund through
+    /// the connector failed
+    Payout,
+}
+
 /// The status of the mandate, which indicates whether it can be used to initiate a payment
 #[derive(
     Clone,
@@ -1701,6 +1735,8 @@ pub enum PayoutStatus {
     RequiresCreation,
     RequiresPayoutMethodData,
     RequiresFulfillment,
+    Expired,
+    Reversed,
 }
 
 #[derive(
@@ -1723,6 +1759,8 @@ pub enum PayoutType {
     #[default]
     Card,
     Bank,
+    Wallet,
+    InstantTransfer,
 }
 
 #[derive(
@@ -1848,8 +1886,220 @@ pub enum ReconStatus {
     Active,
     Disabled,
 }
+
+/// Lifecycle of a reconciliation run over a single settlement report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconReportStatus {
+    #[default]
+    Processing,
+    Completed,
+    Failed,
+}
+
+/// The kind of transaction a settlement report line refers to
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconTransactionType {
+    Payment,
+    Refund,
+    Dispute,
+}
+
+/// Result of matching a settlement report line against the stored transaction
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReconOutcome {
+    Matched,
+    MissingRecord,
+    MissingFromReport,
+    CurrencyMismatch,
+    AmountMismatch,
+    StatusMismatch,
+    FeeMismatch,
+}
+
+/// The change made to the active routing algorithm, as recorded in the activation audit trail
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingActivationAction {
+    Activate,
+    Deactivate,
+    Rollback,
+    ScheduledActivate,
+    CanaryStart,
+    CanaryPromote,
+    CanaryAbort,
+}
+
+/// The arm of a canary rollout of a routing algorithm that a payment was routed with
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingArm {
+    /// The algorithm that was active when the rollout started
+    Active,
+    /// The candidate algorithm being rolled out
+    Canary,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ApplePayFlow {
     Simplified,
     Manual,
 }
+
+/// Delivery status of an outgoing webhook to a single webhook endpoint
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    #[default]
+    Pending,
+    Delivered,
+    Failed,
+}
+
+/// The outcome of verifying the source of an incoming webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookSourceVerificationStatus {
+    /// The signature of the webhook was verified with the merchant secret
+    Verified,
+    /// The signature of the webhook could not be verified
+    Failed,
+    /// The connector does not support source verification of its webhooks
+    NotSupported,
+}
+
+impl WebhookSourceVerificationStatus {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified)
+    }
+}
+
+/// Processing status of a stored incoming webhook
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IncomingWebhookStatus {
+    /// The webhook has been received and is being processed
+    #[default]
+    Received,
+    /// The webhook was processed successfully
+    Processed,
+    /// The processing of the webhook failed
+    Failed,
+}
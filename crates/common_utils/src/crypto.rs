@@ -10,7 +10,7 @@ use masking::{ExposeInterface, Secret};
 use md5;
 use ring::{
     aead::{self, BoundKey, OpeningKey, SealingKey, UnboundKey},
-    hmac,
+    hmac, signature,
 };
 
 use crate::{
@@ -242,6 +242,64 @@ impl VerifySignature for HmacSha512 {
     }
 }
 
+/// Represents the Ed25519 signature algorithm.
+/// Messages are signed with a PKCS#8 (v2) encoded key pair and verified with the raw
+/// 32 byte public key
+#[derive(Debug)]
+pub struct Ed25519;
+
+impl Ed25519 {
+    /// Generates a new key pair, returning the PKCS#8 encoded key pair and the public key
+    pub fn generate_key_pair() -> CustomResult<(Vec<u8>, Vec<u8>), errors::CryptoError> {
+        let pkcs8_key_pair =
+            signature::Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+                .map_err(|_| errors::CryptoError::EncodingFailed)
+                .into_report()
+                .attach_printable("Failed to generate Ed25519 key pair")?;
+        let public_key = Self::get_public_key(pkcs8_key_pair.as_ref())?;
+
+        Ok((pkcs8_key_pair.as_ref().to_vec(), public_key))
+    }
+
+    /// Extracts the public key from a PKCS#8 encoded key pair
+    pub fn get_public_key(pkcs8_key_pair: &[u8]) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key_pair = signature::Ed25519KeyPair::from_pkcs8(pkcs8_key_pair)
+            .map_err(|_| errors::CryptoError::DecodingFailed)
+            .into_report()
+            .attach_printable("Invalid Ed25519 key pair")?;
+
+        Ok(signature::KeyPair::public_key(&key_pair).as_ref().to_vec())
+    }
+}
+
+impl SignMessage for Ed25519 {
+    fn sign_message(
+        &self,
+        secret: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key_pair = signature::Ed25519KeyPair::from_pkcs8(secret)
+            .map_err(|_| errors::CryptoError::MessageSigningFailed)
+            .into_report()
+            .attach_printable("Invalid Ed25519 key pair")?;
+
+        Ok(key_pair.sign(msg).as_ref().to_vec())
+    }
+}
+
+impl VerifySignature for Ed25519 {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        let public_key = signature::UnparsedPublicKey::new(&signature::ED25519, secret);
+
+        Ok(public_key.verify(msg, signature).is_ok())
+    }
+}
+
 /// Represents the GCM-AES-256 algorithm
 #[derive(Debug)]
 pub struct GcmAes256;
@@ -511,7 +569,6 @@ where
     }
 }
 
-
 impl<T: Clone> PartialEq for Encryptable<T>
 where
     T: PartialEq,
@@ -555,6 +612,31 @@ mod crypto_tests {
         assert_eq!(signature, right_signature);
     }
 
+    #[test]
+    fn test_ed25519_sign_and_verify_message() {
+        let (key_pair, public_key) = super::Ed25519::generate_key_pair().expect("Key pair");
+        let (_, other_public_key) = super::Ed25519::generate_key_pair().expect("Other key pair");
+        let message = r#"{"type":"payment_intent"}"#.as_bytes();
+
+        let signature = super::Ed25519
+            .sign_message(&key_pair, message)
+            .expect("Signed message");
+
+        assert_eq!(
+            super::Ed25519::get_public_key(&key_pair).expect("Public key"),
+            public_key
+        );
+        assert!(super::Ed25519
+            .verify_signature(&public_key, &signature, message)
+            .expect("Right signature verification result"));
+        assert!(!super::Ed25519
+            .verify_signature(&other_public_key, &signature, message)
+            .expect("Wrong key verification result"));
+        assert!(!super::Ed25519
+            .verify_signature(&public_key, &signature, "tampered".as_bytes())
+            .expect("Tampered message verification result"));
+    }
+
     #[test]
     fn test_hmac_sha256_verify_signature() {
         let right_signature =
@@ -68,6 +68,20 @@ pub enum CryptoError {
     SignatureVerificationFailed,
 }
 
+/// Errors while verifying the signature of a webhook
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum WebhookSignatureError {
+    /// The signature header could not be parsed
+    #[error("Invalid webhook signature header")]
+    InvalidSignatureHeader,
+    /// The webhook was signed too long ago, or too far in the future
+    #[error("Webhook timestamp is outside the allowed tolerance")]
+    TimestampOutsideTolerance,
+    /// None of the signatures match any of the verification keys
+    #[error("Webhook signature does not match")]
+    SignatureMismatch,
+}
+
 /// Errors for Qr code handling
 #[derive(Debug, thiserror::Error)]
 pub enum QrCodeError {
@@ -17,6 +17,7 @@ pub mod signals;
 pub mod static_cache;
 pub mod types;
 pub mod validation;
+pub mod webhook_signature;
 
 /// Date-time utilities.
 pub mod date_time {
@@ -42,6 +42,12 @@ pub struct Request {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub form_data: Option<reqwest::multipart::Form>,
+    /// Name of the connector the request is sent to, used for picking the http client
+    /// settings and for tagging outbound metrics
+    pub connector: Option<String>,
+    /// Whether the request is idempotent and may be sent again when it fails to get a response,
+    /// only such requests are retried
+    pub retry_allowed: bool,
 }
 
 impl Request {
@@ -55,6 +61,8 @@ impl Request {
             certificate: None,
             certificate_key: None,
             form_data: None,
+            connector: None,
+            retry_allowed: false,
         }
     }
 
@@ -85,6 +93,14 @@ impl Request {
     pub fn set_form_data(&mut self, form_data: reqwest::multipart::Form) {
         self.form_data = Some(form_data);
     }
+
+    pub fn set_connector(&mut self, connector: String) {
+        self.connector = Some(connector);
+    }
+
+    pub fn allow_retries(&mut self) {
+        self.retry_allowed = true;
+    }
 }
 
 #[derive(Debug)]
@@ -174,6 +190,8 @@ impl RequestBuilder {
             certificate: self.certificate,
             certificate_key: self.certificate_key,
             form_data: self.form_data,
+            connector: None,
+            retry_allowed: false,
         }
     }
 }
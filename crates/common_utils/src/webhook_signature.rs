@@ -0,0 +1,346 @@
+//! Versioned signatures of outgoing webhooks
+//!
+//! The signature header carries the time at which the webhook was signed and one signature for
+//! every active signing key, for example `t=1700000000,v1=5257a869e7,v1_ed25519=4f0d3c2a91`.
+//! Each signature is computed over the timestamp and the raw request body joined by a `.`, which
+//! allows receivers to reject webhooks that are replayed later. Holding two keys at a time lets
+//! merchants rotate their secrets without dropping webhooks.
+
+use std::fmt;
+
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{
+    crypto::{self, SignMessage, VerifySignature},
+    date_time,
+    errors::{self, CustomResult},
+};
+
+const TIMESTAMP_KEY: &str = "t";
+
+/// The header carrying the versioned signature of outgoing webhooks
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature-Versioned";
+
+/// Default number of seconds by which the signature timestamp may differ from the current time
+pub const DEFAULT_TIMESTAMP_TOLERANCE_SECS: i64 = 300;
+
+/// Algorithms available for signing outgoing webhooks
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookSignatureAlgorithm {
+    /// HMAC-SHA256 using a secret shared with the merchant
+    HmacSha256,
+    /// Ed25519, the merchant verifies the signature using the public key
+    Ed25519,
+}
+
+impl WebhookSignatureAlgorithm {
+    /// The versioned scheme under which the signatures of the algorithm appear in the header
+    pub fn scheme(self) -> &'static str {
+        match self {
+            Self::HmacSha256 => "v1",
+            Self::Ed25519 => "v1_ed25519",
+        }
+    }
+
+    fn from_scheme(scheme: &str) -> Option<Self> {
+        [Self::HmacSha256, Self::Ed25519]
+            .into_iter()
+            .find(|algorithm| algorithm.scheme() == scheme)
+    }
+
+    fn sign(self, secret: &[u8], message: &[u8]) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        match self {
+            Self::HmacSha256 => crypto::HmacSha256.sign_message(secret, message),
+            Self::Ed25519 => crypto::Ed25519.sign_message(secret, message),
+        }
+    }
+
+    fn verify(
+        self,
+        verification_key: &[u8],
+        signature: &[u8],
+        message: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        match self {
+            Self::HmacSha256 => {
+                crypto::HmacSha256.verify_signature(verification_key, signature, message)
+            }
+            Self::Ed25519 => crypto::Ed25519.verify_signature(verification_key, signature, message),
+        }
+    }
+}
+
+/// The parsed value of a webhook signature header
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WebhookSignatureHeader {
+    /// UNIX timestamp at which the webhook was signed
+    pub timestamp: i64,
+    /// The signatures along with the algorithm used to compute them
+    pub signatures: Vec<(WebhookSignatureAlgorithm, Vec<u8>)>,
+}
+
+impl WebhookSignatureHeader {
+    /// Signs the payload with every signing key. The signing key is the shared secret for
+    /// HMAC-SHA256 and the PKCS#8 encoded key pair for Ed25519.
+    pub fn sign<'a>(
+        payload: &[u8],
+        timestamp: i64,
+        signing_keys: impl IntoIterator<Item = (WebhookSignatureAlgorithm, &'a [u8])>,
+    ) -> CustomResult<Self, errors::CryptoError> {
+        let message = get_signed_message(timestamp, payload);
+        let signatures = signing_keys
+            .into_iter()
+            .map(|(algorithm, signing_key)| {
+                algorithm
+                    .sign(signing_key, &message)
+                    .map(|signature| (algorithm, signature))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            timestamp,
+            signatures,
+        })
+    }
+
+    /// Parses the header value, signatures with unknown schemes are skipped so that newer
+    /// schemes can be added without breaking existing receivers
+    pub fn parse(header: &str) -> CustomResult<Self, errors::WebhookSignatureError> {
+        let mut timestamp = None;
+        let mut signatures = Vec::new();
+
+        for element in header.split(',') {
+            let (key, value) = element
+                .trim()
+                .split_once('=')
+                .ok_or(errors::WebhookSignatureError::InvalidSignatureHeader)
+                .into_report()
+                .attach_printable("Signature header element is not a key value pair")?;
+
+            if key == TIMESTAMP_KEY {
+                timestamp = Some(
+                    value
+                        .parse::<i64>()
+                        .into_report()
+                        .change_context(errors::WebhookSignatureError::InvalidSignatureHeader)
+                        .attach_printable("Signature timestamp is not a number")?,
+                );
+            } else if let Some(algorithm) = WebhookSignatureAlgorithm::from_scheme(key) {
+                let signature = hex::decode(value)
+                    .into_report()
+                    .change_context(errors::WebhookSignatureError::InvalidSignatureHeader)
+                    .attach_printable("Signature is not hex encoded")?;
+                signatures.push((algorithm, signature));
+            }
+        }
+
+        Ok(Self {
+            timestamp: timestamp
+                .ok_or(errors::WebhookSignatureError::InvalidSignatureHeader)
+                .into_report()
+                .attach_printable("Signature header is missing the timestamp")?,
+            signatures,
+        })
+    }
+
+    /// Verifies the payload against the signatures, succeeding if any signature matches any
+    /// of the verification keys. The verification key is the shared secret for HMAC-SHA256
+    /// and the raw public key for Ed25519.
+    pub fn verify(
+        &self,
+        payload: &[u8],
+        verification_keys: &[(WebhookSignatureAlgorithm, &[u8])],
+        tolerance_secs: i64,
+        current_timestamp: i64,
+    ) -> CustomResult<(), errors::WebhookSignatureError> {
+        if (current_timestamp - self.timestamp).abs() > tolerance_secs {
+            return Err(errors::WebhookSignatureError::TimestampOutsideTolerance.into());
+        }
+
+        let message = get_signed_message(self.timestamp, payload);
+        for (algorithm, signature) in &self.signatures {
+            for (key_algorithm, verification_key) in verification_keys {
+                if key_algorithm == algorithm
+                    && algorithm
+                        .verify(verification_key, signature, &message)
+                        .change_context(errors::WebhookSignatureError::SignatureMismatch)?
+                {
+                    return Ok(());
+                }
+            }
+        }
+
+        Err(errors::WebhookSignatureError::SignatureMismatch.into())
+    }
+}
+
+impl fmt::Display for WebhookSignatureHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{TIMESTAMP_KEY}={}", self.timestamp)?;
+        for (algorithm, signature) in &self.signatures {
+            write!(f, ",{}={}", algorithm.scheme(), hex::encode(signature))?;
+        }
+        Ok(())
+    }
+}
+
+fn get_signed_message(timestamp: i64, payload: &[u8]) -> Vec<u8> {
+    let mut message = format!("{timestamp}.").into_bytes();
+    message.extend_from_slice(payload);
+    message
+}
+
+/// Verifies the signature header of a webhook received from Hyperswitch against the raw request
+/// body, rejecting webhooks signed more than `tolerance_secs` seconds away from now.
+///
+/// ```
+/// use common_utils::webhook_signature::{
+///     verify_webhook_signature, WebhookSignatureAlgorithm, WebhookSignatureHeader,
+///     DEFAULT_TIMESTAMP_TOLERANCE_SECS,
+/// };
+///
+/// let secret = b"whsec_secret";
+/// let body = br#"{"event_type":"payment_succeeded"}"#;
+/// let header = WebhookSignatureHeader::sign(
+///     body,
+///     common_utils::date_time::now_unix_timestamp(),
+///     [(WebhookSignatureAlgorithm::HmacSha256, secret.as_slice())],
+/// )
+/// .unwrap()
+/// .to_string();
+///
+/// assert!(verify_webhook_signature(
+///     &header,
+///     body,
+///     &[(WebhookSignatureAlgorithm::HmacSha256, secret.as_slice())],
+///     DEFAULT_TIMESTAMP_TOLERANCE_SECS,
+/// )
+/// .is_ok());
+/// ```
+pub fn verify_webhook_signature(
+    header: &str,
+    payload: &[u8],
+    verification_keys: &[(WebhookSignatureAlgorithm, &[u8])],
+    tolerance_secs: i64,
+) -> CustomResult<(), errors::WebhookSignatureError> {
+    WebhookSignatureHeader::parse(header)?.verify(
+        payload,
+        verification_keys,
+        tolerance_secs,
+        date_time::now_unix_timestamp(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    const PAYLOAD: &[u8] = br#"{"event_type":"payment_succeeded"}"#;
+    const TIMESTAMP: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_signature_header_round_trip() {
+        let (key_pair, _) = crypto::Ed25519::generate_key_pair().expect("Key pair");
+        let header = WebhookSignatureHeader::sign(
+            PAYLOAD,
+            TIMESTAMP,
+            [
+                (WebhookSignatureAlgorithm::HmacSha256, b"secret".as_slice()),
+                (WebhookSignatureAlgorithm::Ed25519, key_pair.as_slice()),
+            ],
+        )
+        .expect("Signature header");
+
+        let header_value = header.to_string();
+        assert!(header_value.starts_with("t=1700000000,v1="));
+        assert!(header_value.contains(",v1_ed25519="));
+        assert_eq!(
+            WebhookSignatureHeader::parse(&format!("{header_value},v2=abcd")).expect("Parsed"),
+            header
+        );
+    }
+
+    #[test]
+    fn test_any_active_key_verifies_the_webhook() {
+        let (key_pair, public_key) = crypto::Ed25519::generate_key_pair().expect("Key pair");
+        let header = WebhookSignatureHeader::sign(
+            PAYLOAD,
+            TIMESTAMP,
+            [
+                (
+                    WebhookSignatureAlgorithm::HmacSha256,
+                    b"old_secret".as_slice(),
+                ),
+                (WebhookSignatureAlgorithm::Ed25519, key_pair.as_slice()),
+            ],
+        )
+        .expect("Signature header");
+
+        for verification_key in [
+            (
+                WebhookSignatureAlgorithm::HmacSha256,
+                b"old_secret".as_slice(),
+            ),
+            (WebhookSignatureAlgorithm::Ed25519, public_key.as_slice()),
+        ] {
+            assert!(header
+                .verify(PAYLOAD, &[verification_key], 300, TIMESTAMP + 10)
+                .is_ok());
+        }
+
+        let mismatch = header
+            .verify(
+                PAYLOAD,
+                &[(
+                    WebhookSignatureAlgorithm::HmacSha256,
+                    b"wrong_secret".as_slice(),
+                )],
+                300,
+                TIMESTAMP,
+            )
+            .expect_err("Mismatched secret");
+        assert_eq!(
+            mismatch.current_context(),
+            &errors::WebhookSignatureError::SignatureMismatch
+        );
+    }
+
+    #[test]
+    fn test_replayed_webhook_is_rejected() {
+        let verification_keys = [(WebhookSignatureAlgorithm::HmacSha256, b"secret".as_slice())];
+        let header = WebhookSignatureHeader::sign(PAYLOAD, TIMESTAMP, verification_keys)
+            .expect("Signature header");
+
+        let replayed = header
+            .verify(PAYLOAD, &verification_keys, 300, TIMESTAMP + 301)
+            .expect_err("Replayed webhook");
+        assert_eq!(
+            replayed.current_context(),
+            &errors::WebhookSignatureError::TimestampOutsideTolerance
+        );
+
+        let tampered = WebhookSignatureHeader {
+            timestamp: TIMESTAMP + 300,
+            ..header
+        }
+        .verify(PAYLOAD, &verification_keys, 300, TIMESTAMP + 301)
+        .expect_err("Tampered timestamp");
+        assert_eq!(
+            tampered.current_context(),
+            &errors::WebhookSignatureError::SignatureMismatch
+        );
+    }
+}
@@ -90,6 +90,22 @@ pub trait PaymentAttemptInterface {
         authentication_type: Option<Vec<storage_enums::AuthenticationType>>,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, errors::StorageError>;
+
+    async fn get_fee_aggregates_by_merchant_id_time_range(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: Option<PrimitiveDateTime>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttemptFeeAggregate>, errors::StorageError>;
+
+    async fn get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+        &self,
+        merchant_id: &str,
+        algorithm_ids: Vec<String>,
+        start_time: PrimitiveDateTime,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttemptRoutingArmAggregate>, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -145,6 +161,41 @@ pub struct PaymentAttempt {
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub processing_fee: Option<i64>,
+    pub interchange_fee: Option<i64>,
+    pub fx_markup: Option<i64>,
+    pub net_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    /// Gzip compressed JSON trace of how the attempt was routed
+    pub routing_trace: Option<Vec<u8>>,
+    /// The arm of a canary rollout the attempt was routed with
+    pub routing_arm: Option<storage_enums::RoutingArm>,
+    /// The routing algorithm the attempt was routed with
+    pub routing_algorithm_id: Option<String>,
+}
+
+/// Number of the attempts routed through an arm and algorithm which were created on a day with a
+/// status
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentAttemptRoutingArmAggregate {
+    pub routing_arm: Option<storage_enums::RoutingArm>,
+    pub routing_algorithm_id: Option<String>,
+    pub status: storage_enums::AttemptStatus,
+    pub date: time::Date,
+    pub attempt_count: i64,
+}
+
+/// The fees reported by a connector for the payments created on a day, per settlement currency
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentAttemptFeeAggregate {
+    pub connector: Option<String>,
+    pub date: time::Date,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub payment_count: i64,
+    pub processing_fee: i64,
+    pub interchange_fee: i64,
+    pub fx_markup: i64,
+    pub net_amount: i64,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -233,6 +284,9 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_trace: Option<Vec<u8>>,
+        routing_arm: Option<storage_enums::RoutingArm>,
+        routing_algorithm_id: Option<String>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -259,6 +313,9 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_trace: Option<Vec<u8>>,
+        routing_arm: Option<storage_enums::RoutingArm>,
+        routing_algorithm_id: Option<String>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -285,6 +342,11 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         amount_capturable: Option<i64>,
+        processing_fee: Option<i64>,
+        interchange_fee: Option<i64>,
+        fx_markup: Option<i64>,
+        net_amount: Option<i64>,
+        settlement_currency: Option<storage_enums::Currency>,
         updated_by: String,
     },
     UnresolvedResponseUpdate {
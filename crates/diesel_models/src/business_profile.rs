@@ -1,7 +1,7 @@
 use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 
-use crate::schema::business_profile;
+use crate::{encryption::Encryption, schema::business_profile};
 
 #[derive(
     Clone,
@@ -32,6 +32,7 @@ pub struct BusinessProfile {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub webhook_signing_keys: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -55,6 +56,7 @@ pub struct BusinessProfileNew {
     pub is_recon_enabled: bool,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub webhook_signing_keys: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -75,6 +77,7 @@ pub struct BusinessProfileUpdateInternal {
     pub is_recon_enabled: Option<bool>,
     #[diesel(deserialize_as = super::OptionalDieselArray<String>)]
     pub applepay_verified_domains: Option<Vec<String>>,
+    pub webhook_signing_keys: Option<Encryption>,
 }
 
 impl From<BusinessProfileNew> for BusinessProfile {
@@ -97,6 +100,7 @@ impl From<BusinessProfileNew> for BusinessProfile {
             payout_routing_algorithm: new.payout_routing_algorithm,
             is_recon_enabled: new.is_recon_enabled,
             applepay_verified_domains: new.applepay_verified_domains,
+            webhook_signing_keys: new.webhook_signing_keys,
         }
     }
 }
@@ -122,7 +126,30 @@ impl BusinessProfileUpdateInternal {
             payout_routing_algorithm: self.payout_routing_algorithm,
             is_recon_enabled: self.is_recon_enabled.unwrap_or(source.is_recon_enabled),
             applepay_verified_domains: self.applepay_verified_domains,
+            webhook_signing_keys: self.webhook_signing_keys.or(source.webhook_signing_keys),
             ..source
         }
     }
 }
+
+/// A key used for signing outgoing webhooks, stored encrypted with the merchant key store in
+/// `webhook_signing_keys`
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct WebhookSigningKey {
+    pub key_id: String,
+    pub algorithm: common_utils::webhook_signature::WebhookSignatureAlgorithm,
+    /// The shared secret for HMAC-SHA256, the hex encoded PKCS#8 key pair for Ed25519
+    pub signing_key: masking::Secret<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time after which the key stops signing webhooks, set when the key is rotated
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl WebhookSigningKey {
+    pub fn is_active(&self, current_time: time::PrimitiveDateTime) -> bool {
+        self.expires_at
+            .map_or(true, |expires_at| expires_at > current_time)
+    }
+}
@@ -41,6 +41,7 @@ pub enum RoutingAlgorithmKind {
     Priority,
     VolumeSplit,
     Advanced,
+    LeastCost,
 }
 
 #[derive(
@@ -62,6 +63,7 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    Payouts,
 }
 
 #[derive(
@@ -83,6 +85,7 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    PayoutDetails,
 }
 
 #[derive(
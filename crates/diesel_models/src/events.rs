@@ -16,6 +16,7 @@ pub struct EventNew {
     pub intent_reference_id: Option<String>,
     pub primary_object_id: String,
     pub primary_object_type: storage_enums::EventObjectType,
+    pub source_verification_status: Option<storage_enums::WebhookSourceVerificationStatus>,
 }
 
 #[derive(Debug)]
@@ -43,6 +44,8 @@ pub struct Event {
     pub primary_object_type: storage_enums::EventObjectType,
     #[serde(with = "custom_serde::iso8601")]
     pub created_at: PrimitiveDateTime,
+    /// Outcome of the source verification of the incoming webhook that caused the event
+    pub source_verification_status: Option<storage_enums::WebhookSourceVerificationStatus>,
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
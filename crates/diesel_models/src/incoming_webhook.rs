@@ -0,0 +1,112 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::incoming_webhook};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook)]
+pub struct IncomingWebhookNew {
+    pub webhook_id: String,
+    pub merchant_id: String,
+    pub connector_name_or_mca_id: String,
+    pub connector_name: Option<String>,
+    pub headers: serde_json::Value,
+    pub body: Option<serde_json::Value>,
+    pub raw_request: Encryption,
+    pub source_verification_status: storage_enums::WebhookSourceVerificationStatus,
+    pub status: storage_enums::IncomingWebhookStatus,
+    pub error_message: Option<String>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = incoming_webhook, primary_key(webhook_id))]
+pub struct IncomingWebhook {
+    pub webhook_id: String,
+    pub merchant_id: String,
+    /// The connector name or merchant connector account id on which the webhook was received
+    pub connector_name_or_mca_id: String,
+    pub connector_name: Option<String>,
+    /// The headers of the webhook, with the values of the sensitive headers masked
+    pub headers: serde_json::Value,
+    /// The body of the webhook with the sensitive fields masked, as text if the body is neither
+    /// JSON nor form encoded, `None` if the webhook has no body
+    pub body: Option<serde_json::Value>,
+    /// The encrypted request, from which the webhook is reprocessed
+    pub raw_request: Encryption,
+    /// Outcome of the source verification of the webhook when it was received
+    pub source_verification_status: storage_enums::WebhookSourceVerificationStatus,
+    pub status: storage_enums::IncomingWebhookStatus,
+    pub error_message: Option<String>,
+    pub payment_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub dispute_id: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum IncomingWebhookUpdate {
+    Processed {
+        payment_id: Option<String>,
+        refund_id: Option<String>,
+        dispute_id: Option<String>,
+    },
+    Failed {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook)]
+pub struct IncomingWebhookUpdateInternal {
+    status: storage_enums::IncomingWebhookStatus,
+    error_message: Option<Option<String>>,
+    payment_id: Option<String>,
+    refund_id: Option<String>,
+    dispute_id: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<IncomingWebhookUpdate> for IncomingWebhookUpdateInternal {
+    fn from(webhook_update: IncomingWebhookUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match webhook_update {
+            IncomingWebhookUpdate::Processed {
+                payment_id,
+                refund_id,
+                dispute_id,
+            } => Self {
+                status: storage_enums::IncomingWebhookStatus::Processed,
+                error_message: Some(None),
+                payment_id,
+                refund_id,
+                dispute_id,
+                modified_at,
+            },
+            IncomingWebhookUpdate::Failed { error_message } => Self {
+                status: storage_enums::IncomingWebhookStatus::Failed,
+                error_message: Some(Some(error_message)),
+                payment_id: None,
+                refund_id: None,
+                dispute_id: None,
+                modified_at,
+            },
+        }
+    }
+}
+
+/// Tracking data of the scheduler task that deletes the stored incoming webhooks of a merchant
+/// once they are past the retention period
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct IncomingWebhookRetentionTrackingData {
+    pub merchant_id: String,
+}
@@ -15,6 +15,7 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod incoming_webhook;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -32,12 +33,14 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
 pub mod user;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
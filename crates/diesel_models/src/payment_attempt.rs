@@ -61,6 +61,40 @@ pub struct PaymentAttempt {
     pub merchant_connector_id: Option<String>,
     pub authentication_data: Option<serde_json::Value>,
     pub encoded_data: Option<String>,
+    pub processing_fee: Option<i64>,
+    pub interchange_fee: Option<i64>,
+    pub fx_markup: Option<i64>,
+    pub net_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    /// Gzip compressed JSON trace of how the attempt was routed
+    pub routing_trace: Option<Vec<u8>>,
+    /// The arm of a canary rollout the attempt was routed with
+    pub routing_arm: Option<storage_enums::RoutingArm>,
+    /// The routing algorithm the attempt was routed with
+    pub routing_algorithm_id: Option<String>,
+}
+
+/// Number of the attempts routed through an arm and algorithm which were created on a day with a
+/// status
+#[derive(Clone, Debug, Eq, PartialEq, Queryable)]
+pub struct PaymentAttemptRoutingArmAggregate {
+    pub routing_arm: Option<storage_enums::RoutingArm>,
+    pub routing_algorithm_id: Option<String>,
+    pub status: storage_enums::AttemptStatus,
+    pub date: time::Date,
+    pub attempt_count: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentAttemptFeeAggregate {
+    pub connector: Option<String>,
+    pub date: time::Date,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub payment_count: i64,
+    pub processing_fee: i64,
+    pub interchange_fee: i64,
+    pub fx_markup: i64,
+    pub net_amount: i64,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Queryable, Serialize, Deserialize)]
@@ -150,6 +184,9 @@ pub enum PaymentAttemptUpdate {
         amount_capturable: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_trace: Option<Vec<u8>>,
+        routing_arm: Option<storage_enums::RoutingArm>,
+        routing_algorithm_id: Option<String>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -176,6 +213,9 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        routing_trace: Option<Vec<u8>>,
+        routing_arm: Option<storage_enums::RoutingArm>,
+        routing_algorithm_id: Option<String>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -202,6 +242,11 @@ pub enum PaymentAttemptUpdate {
         error_reason: Option<Option<String>>,
         connector_response_reference_id: Option<String>,
         amount_capturable: Option<i64>,
+        processing_fee: Option<i64>,
+        interchange_fee: Option<i64>,
+        fx_markup: Option<i64>,
+        net_amount: Option<i64>,
+        settlement_currency: Option<storage_enums::Currency>,
         updated_by: String,
     },
     UnresolvedResponseUpdate {
@@ -292,6 +337,14 @@ pub struct PaymentAttemptUpdateInternal {
     merchant_connector_id: Option<String>,
     authentication_data: Option<serde_json::Value>,
     encoded_data: Option<String>,
+    processing_fee: Option<i64>,
+    interchange_fee: Option<i64>,
+    fx_markup: Option<i64>,
+    net_amount: Option<i64>,
+    settlement_currency: Option<storage_enums::Currency>,
+    routing_trace: Option<Vec<u8>>,
+    routing_arm: Option<storage_enums::RoutingArm>,
+    routing_algorithm_id: Option<String>,
 }
 
 impl PaymentAttemptUpdate {
@@ -346,6 +399,16 @@ impl PaymentAttemptUpdate {
             merchant_connector_id: pa_update.merchant_connector_id,
             authentication_data: pa_update.authentication_data.or(source.authentication_data),
             encoded_data: pa_update.encoded_data.or(source.encoded_data),
+            processing_fee: pa_update.processing_fee.or(source.processing_fee),
+            interchange_fee: pa_update.interchange_fee.or(source.interchange_fee),
+            fx_markup: pa_update.fx_markup.or(source.fx_markup),
+            net_amount: pa_update.net_amount.or(source.net_amount),
+            settlement_currency: pa_update.settlement_currency.or(source.settlement_currency),
+            routing_trace: pa_update.routing_trace.or(source.routing_trace),
+            routing_arm: pa_update.routing_arm.or(source.routing_arm),
+            routing_algorithm_id: pa_update
+                .routing_algorithm_id
+                .or(source.routing_algorithm_id),
             ..source
         }
     }
@@ -417,6 +480,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -439,6 +505,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -477,6 +546,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             } => Self {
                 status: Some(status),
@@ -493,6 +567,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
                 ..Default::default()
             },
@@ -527,6 +606,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => Self {
                 payment_token,
                 connector,
@@ -534,6 +616,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
                 ..Default::default()
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
@@ -178,3 +178,10 @@ impl From<PayoutsUpdate> for PayoutsUpdateInternal {
         }
     }
 }
+
+#[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PayoutSyncWorkflow {
+    pub payout_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+}
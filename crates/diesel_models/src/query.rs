@@ -11,6 +11,7 @@ pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generics;
+pub mod incoming_webhook;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -24,7 +25,9 @@ pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
 pub mod user;
+pub mod webhook_endpoint;
@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 use router_env::{instrument, tracing};
 
 use super::generics;
@@ -49,6 +49,26 @@ impl Config {
         }
     }
 
+    /// Updates the config only if it still holds `current_config`, returning `None` when it was
+    /// changed in the meantime
+    #[instrument(skip(conn))]
+    pub async fn update_by_key_if_unchanged(
+        conn: &PgPooledConn,
+        key: &str,
+        current_config: &str,
+        config_update: ConfigUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::key
+                .eq(key.to_owned())
+                .and(dsl::config.eq(current_config.to_owned())),
+            ConfigUpdateInternal::from(config_update),
+        )
+        .await
+        .map(|mut configs| configs.pop())
+    }
+
     #[instrument(skip(conn))]
     pub async fn delete_by_key(conn: &PgPooledConn, key: &str) -> StorageResult<bool> {
         generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::key.eq(key.to_owned()))
@@ -0,0 +1,94 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    incoming_webhook::{
+        IncomingWebhook, IncomingWebhookNew, IncomingWebhookUpdate, IncomingWebhookUpdateInternal,
+    },
+    schema::incoming_webhook::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IncomingWebhookNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<IncomingWebhook> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl IncomingWebhook {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_webhook_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        webhook_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::webhook_id.eq(webhook_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_created_before(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::created_at.lt(created_before)),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        webhook_update: IncomingWebhookUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::webhook_id.eq(self.webhook_id.to_owned()),
+            IncomingWebhookUpdateInternal::from(webhook_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -1,19 +1,29 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
-    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
-    QueryDsl, Table,
+    associations::HasTable,
+    debug_query,
+    dsl::{count_star, sum},
+    expression::{is_aggregate, is_contained_in_group_by, IsContainedInGroupBy, ValidGrouping},
+    pg::Pg,
+    query_builder::{AstPass, QueryFragment, QueryId},
+    sql_function,
+    sql_types::{Date, Nullable, Numeric},
+    AppearsOnTable, BoolExpressionMethods, Column, Expression, ExpressionMethods, QueryDsl,
+    QueryResult, SelectableExpression, Table,
 };
 use error_stack::{IntoReport, ResultExt};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
     enums::{self, IntentStatus},
     errors::{self, DatabaseError},
     payment_attempt::{
-        PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+        PaymentAttempt, PaymentAttemptFeeAggregate, PaymentAttemptNew,
+        PaymentAttemptRoutingArmAggregate, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
     },
     query::generics::db_metrics,
     schema::payment_attempt::dsl,
@@ -135,6 +145,59 @@ impl PaymentAttempt {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_txn_ids_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_txn_ids: Vec<String>,
+        connector: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::connector_transaction_id.eq_any(connector_txn_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_statuses_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::status.eq_any(statuses))
+                .and(dsl::created_at.between(start_time, end_time)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_merchant_id_attempt_id(
         conn: &PgPooledConn,
@@ -347,4 +410,225 @@ impl PaymentAttempt {
         .change_context(errors::DatabaseError::Others)
         .attach_printable("Error filtering count of payments")
     }
+
+    /// Sums up the fees reported by connectors per connector, day and settlement currency.
+    /// Attempts without a settlement currency are assumed to be settled in the payment currency.
+    #[instrument(skip(conn))]
+    pub async fn get_fee_aggregates_by_merchant_id_time_range(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: Option<PrimitiveDateTime>,
+    ) -> StorageResult<Vec<PaymentAttemptFeeAggregate>> {
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start_time))
+            .filter(dsl::created_at.le(end_time.unwrap_or_else(common_utils::date_time::now)))
+            .filter(
+                dsl::processing_fee
+                    .is_not_null()
+                    .or(dsl::net_amount.is_not_null()),
+            )
+            .group_by((
+                dsl::connector,
+                CreatedDate,
+                dsl::settlement_currency,
+                dsl::currency,
+            ))
+            .select((
+                dsl::connector,
+                CreatedDate,
+                dsl::settlement_currency,
+                dsl::currency,
+                count_star(),
+                int8(sum(dsl::processing_fee)),
+                int8(sum(dsl::interchange_fee)),
+                int8(sum(dsl::fx_markup)),
+                int8(sum(dsl::net_amount)),
+            ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        let groups = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<FeeAggregateGroup>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error aggregating fee details of payment attempts")?;
+
+        // Attempts are grouped by both of their currencies, the groups without a settlement
+        // currency are merged into the groups settled in their payment currency
+        let mut fee_aggregates = HashMap::new();
+        for (
+            connector,
+            date,
+            settlement_currency,
+            currency,
+            payment_count,
+            processing_fee,
+            interchange_fee,
+            fx_markup,
+            net_amount,
+        ) in groups
+        {
+            let settlement_currency = settlement_currency.or(currency);
+            let fee_aggregate = fee_aggregates
+                .entry((connector.clone(), date, settlement_currency))
+                .or_insert_with(|| PaymentAttemptFeeAggregate {
+                    connector,
+                    date,
+                    settlement_currency,
+                    payment_count: 0,
+                    processing_fee: 0,
+                    interchange_fee: 0,
+                    fx_markup: 0,
+                    net_amount: 0,
+                });
+
+            fee_aggregate.payment_count += payment_count;
+            fee_aggregate.processing_fee += processing_fee.unwrap_or_default();
+            fee_aggregate.interchange_fee += interchange_fee.unwrap_or_default();
+            fee_aggregate.fx_markup += fx_markup.unwrap_or_default();
+            fee_aggregate.net_amount += net_amount.unwrap_or_default();
+        }
+
+        let mut fee_aggregates: Vec<PaymentAttemptFeeAggregate> =
+            fee_aggregates.into_values().collect();
+        fee_aggregates.sort_by_cached_key(|fee_aggregate| {
+            (
+                fee_aggregate.connector.clone(),
+                fee_aggregate.date,
+                fee_aggregate
+                    .settlement_currency
+                    .map(|currency| currency.to_string()),
+            )
+        });
+
+        Ok(fee_aggregates)
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        algorithm_ids: Vec<String>,
+        start_time: PrimitiveDateTime,
+    ) -> StorageResult<Vec<PaymentAttemptRoutingArmAggregate>> {
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start_time))
+            .filter(dsl::routing_arm.is_not_null())
+            .filter(dsl::routing_algorithm_id.eq_any(algorithm_ids))
+            .group_by((
+                dsl::routing_arm,
+                dsl::routing_algorithm_id,
+                dsl::status,
+                CreatedDate,
+            ))
+            .select((
+                dsl::routing_arm,
+                dsl::routing_algorithm_id,
+                dsl::status,
+                CreatedDate,
+                count_star(),
+            ));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<PaymentAttemptRoutingArmAggregate>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error aggregating routing arms of payment attempts")
+    }
 }
+
+sql_function! {
+    /// Converts the `NUMERIC` sum of `BIGINT` values back to a `BIGINT`
+    fn int8(x: Nullable<Numeric>) -> Nullable<BigInt>;
+}
+
+/// Fee sums of the attempts of a connector, day, settlement currency and payment currency
+type FeeAggregateGroup = (
+    Option<String>,
+    time::Date,
+    Option<enums::Currency>,
+    Option<enums::Currency>,
+    i64,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+    Option<i64>,
+);
+
+/// The day on which a payment attempt was created, that is `DATE(created_at)`.
+///
+/// Diesel only checks the grouping of columns, so this expression is declared as a column of the
+/// table to be usable in both the `GROUP BY` and the `SELECT` clauses of a query.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct CreatedDate;
+
+impl Expression for CreatedDate {
+    type SqlType = Date;
+}
+
+impl Column for CreatedDate {
+    type Table = dsl::payment_attempt;
+
+    const NAME: &'static str = "DATE(created_at)";
+}
+
+impl QueryFragment<Pg> for CreatedDate {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("DATE(");
+        dsl::created_at.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<QS> AppearsOnTable<QS> for CreatedDate where dsl::created_at: AppearsOnTable<QS> {}
+
+impl<QS> SelectableExpression<QS> for CreatedDate where dsl::created_at: SelectableExpression<QS> {}
+
+impl ValidGrouping<()> for CreatedDate {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<GB> ValidGrouping<GB> for CreatedDate
+where
+    GB: IsContainedInGroupBy<Self, Output = is_contained_in_group_by::Yes>,
+{
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl IsContainedInGroupBy<Self> for CreatedDate {
+    type Output = is_contained_in_group_by::Yes;
+}
+
+/// Allows grouping by the given columns together with [`CreatedDate`]
+macro_rules! group_with_created_date {
+    ($($column:ident),+) => {
+        $(
+            impl IsContainedInGroupBy<dsl::$column> for CreatedDate {
+                type Output = is_contained_in_group_by::No;
+            }
+
+            impl IsContainedInGroupBy<CreatedDate> for dsl::$column {
+                type Output = is_contained_in_group_by::No;
+            }
+        )+
+    };
+}
+
+group_with_created_date!(
+    connector,
+    settlement_currency,
+    currency,
+    routing_arm,
+    routing_algorithm_id,
+    status
+);
@@ -0,0 +1,154 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::generics::{self, db_metrics};
+use crate::{
+    enums, errors,
+    recon::{
+        ReconRecord, ReconReport, ReconReportNew, ReconReportUpdate, ReconReportUpdateInternal,
+    },
+    schema::{recon_record::dsl as record_dsl, recon_report::dsl},
+    PgPooledConn, StorageResult,
+};
+
+impl ReconReportNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReconReport> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReconReport {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::report_id.eq(report_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        report_update: ReconReportUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::report_id.eq(self.report_id.to_owned()),
+            ReconReportUpdateInternal::from(report_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl ReconRecord {
+    /// Inserts the records in statements of `batch_size` records each, within a single
+    /// transaction so that either all or none of them are inserted
+    #[instrument(skip(conn, records))]
+    pub async fn insert_in_batches(
+        conn: &PgPooledConn,
+        records: Vec<Self>,
+        batch_size: usize,
+    ) -> StorageResult<usize> {
+        if records.is_empty() {
+            return Ok(0);
+        }
+
+        let query = conn.transaction_async(|conn| async move {
+            let mut inserted_records = 0;
+            for batch in records.chunks(batch_size) {
+                inserted_records += diesel::insert_into(<Self as HasTable>::table())
+                    .values(batch.to_vec())
+                    .execute_async(&conn)
+                    .await?;
+            }
+            Ok::<_, diesel::result::Error>(inserted_records)
+        });
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query,
+            db_metrics::DatabaseOperation::Insert,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting recon records")
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn filter_by_merchant_id_report_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: &str,
+        outcome: Option<enums::ReconOutcome>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut filter = <Self as HasTable>::table()
+            .filter(record_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(record_dsl::report_id.eq(report_id.to_owned()))
+            .order(record_dsl::created_at.asc())
+            .into_boxed();
+
+        if let Some(outcome) = outcome {
+            filter = filter.filter(record_dsl::outcome.eq(outcome));
+        }
+
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable("Error filtering recon records by predicate")
+    }
+}
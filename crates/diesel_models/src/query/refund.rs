@@ -74,6 +74,31 @@ impl Refund {
         .await
     }
 
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_connector_refund_ids_connector(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_refund_id.eq_any(connector_refund_ids))
+                .and(dsl::connector.eq(connector.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_by_internal_reference_id_merchant_id(
         conn: &PgPooledConn,
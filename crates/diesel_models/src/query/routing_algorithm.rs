@@ -8,8 +8,11 @@ use crate::{
     enums,
     errors::DatabaseError,
     query::generics,
-    routing_algorithm::{RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata},
-    schema::routing_algorithm::dsl,
+    routing_algorithm::{
+        RoutingActivationAudit, RoutingActivationAuditNew, RoutingAlgorithm,
+        RoutingAlgorithmMetadata, RoutingProfileMetadata,
+    },
+    schema::{routing_activation_audit, routing_algorithm::dsl},
     PgPooledConn, StorageResult,
 };
 
@@ -64,6 +67,9 @@ impl RoutingAlgorithm {
                 dsl::kind,
                 dsl::created_at,
                 dsl::modified_at,
+                dsl::version,
+                dsl::created_by,
+                dsl::comment,
             ))
             .filter(
                 dsl::algorithm_id
@@ -79,6 +85,9 @@ impl RoutingAlgorithm {
                 enums::RoutingAlgorithmKind,
                 PrimitiveDateTime,
                 PrimitiveDateTime,
+                i32,
+                Option<String>,
+                Option<String>,
             )>(conn)
             .await
             .into_report()
@@ -88,7 +97,18 @@ impl RoutingAlgorithm {
             .ok_or(DatabaseError::NotFound)
             .into_report()
             .map(
-                |(profile_id, algorithm_id, name, description, kind, created_at, modified_at)| {
+                |(
+                    profile_id,
+                    algorithm_id,
+                    name,
+                    description,
+                    kind,
+                    created_at,
+                    modified_at,
+                    version,
+                    created_by,
+                    comment,
+                )| {
                     RoutingProfileMetadata {
                         profile_id,
                         algorithm_id,
@@ -97,6 +117,9 @@ impl RoutingAlgorithm {
                         kind,
                         created_at,
                         modified_at,
+                        version,
+                        created_by,
+                        comment,
                     }
                 },
             )
@@ -117,6 +140,9 @@ impl RoutingAlgorithm {
                 dsl::kind,
                 dsl::created_at,
                 dsl::modified_at,
+                dsl::version,
+                dsl::created_by,
+                dsl::comment,
             ))
             .filter(dsl::profile_id.eq(profile_id.to_owned()))
             .limit(limit)
@@ -128,13 +154,94 @@ impl RoutingAlgorithm {
                 enums::RoutingAlgorithmKind,
                 PrimitiveDateTime,
                 PrimitiveDateTime,
+                i32,
+                Option<String>,
+                Option<String>,
+            )>(conn)
+            .await
+            .into_report()
+            .change_context(DatabaseError::Others)?
+            .into_iter()
+            .map(
+                |(
+                    algorithm_id,
+                    name,
+                    description,
+                    kind,
+                    created_at,
+                    modified_at,
+                    version,
+                    created_by,
+                    comment,
+                )| {
+                    RoutingAlgorithmMetadata {
+                        algorithm_id,
+                        name,
+                        description,
+                        kind,
+                        created_at,
+                        modified_at,
+                        version,
+                        created_by,
+                        comment,
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// Lists the versions of the algorithm with the given name, latest version first
+    #[instrument(skip(conn))]
+    pub async fn list_metadata_by_profile_id_name(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        name: &str,
+    ) -> StorageResult<Vec<RoutingAlgorithmMetadata>> {
+        Ok(Self::table()
+            .select((
+                dsl::algorithm_id,
+                dsl::name,
+                dsl::description,
+                dsl::kind,
+                dsl::created_at,
+                dsl::modified_at,
+                dsl::version,
+                dsl::created_by,
+                dsl::comment,
+            ))
+            .filter(
+                dsl::profile_id
+                    .eq(profile_id.to_owned())
+                    .and(dsl::name.eq(name.to_owned())),
+            )
+            .order(dsl::version.desc())
+            .load_async::<(
+                String,
+                String,
+                Option<String>,
+                enums::RoutingAlgorithmKind,
+                PrimitiveDateTime,
+                PrimitiveDateTime,
+                i32,
+                Option<String>,
+                Option<String>,
             )>(conn)
             .await
             .into_report()
             .change_context(DatabaseError::Others)?
             .into_iter()
             .map(
-                |(algorithm_id, name, description, kind, created_at, modified_at)| {
+                |(
+                    algorithm_id,
+                    name,
+                    description,
+                    kind,
+                    created_at,
+                    modified_at,
+                    version,
+                    created_by,
+                    comment,
+                )| {
                     RoutingAlgorithmMetadata {
                         algorithm_id,
                         name,
@@ -142,6 +249,9 @@ impl RoutingAlgorithm {
                         kind,
                         created_at,
                         modified_at,
+                        version,
+                        created_by,
+                        comment,
                     }
                 },
             )
@@ -164,6 +274,9 @@ impl RoutingAlgorithm {
                 dsl::kind,
                 dsl::created_at,
                 dsl::modified_at,
+                dsl::version,
+                dsl::created_by,
+                dsl::comment,
             ))
             .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
             .limit(limit)
@@ -177,13 +290,27 @@ impl RoutingAlgorithm {
                 enums::RoutingAlgorithmKind,
                 PrimitiveDateTime,
                 PrimitiveDateTime,
+                i32,
+                Option<String>,
+                Option<String>,
             )>(conn)
             .await
             .into_report()
             .change_context(DatabaseError::Others)?
             .into_iter()
             .map(
-                |(profile_id, algorithm_id, name, description, kind, created_at, modified_at)| {
+                |(
+                    profile_id,
+                    algorithm_id,
+                    name,
+                    description,
+                    kind,
+                    created_at,
+                    modified_at,
+                    version,
+                    created_by,
+                    comment,
+                )| {
                     RoutingProfileMetadata {
                         profile_id,
                         algorithm_id,
@@ -192,9 +319,51 @@ impl RoutingAlgorithm {
                         kind,
                         created_at,
                         modified_at,
+                        version,
+                        created_by,
+                        comment,
                     }
                 },
             )
             .collect())
     }
 }
+
+impl RoutingActivationAuditNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RoutingActivationAudit> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RoutingActivationAudit {
+    /// Lists the activation changes of the merchant, latest change first. If a profile is given,
+    /// only the changes to the routing of that profile are listed.
+    #[instrument(skip(conn))]
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        profile_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(routing_activation_audit::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query =
+                query.filter(routing_activation_audit::dsl::profile_id.eq(profile_id.to_owned()));
+        }
+
+        query
+            .order(routing_activation_audit::dsl::created_at.desc())
+            .limit(limit)
+            .offset(offset)
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error listing routing activation audit records")
+    }
+}
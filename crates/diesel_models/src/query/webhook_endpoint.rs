@@ -0,0 +1,145 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    errors,
+    schema::{webhook_delivery::dsl as delivery_dsl, webhook_endpoint::dsl},
+    webhook_endpoint::{
+        WebhookDelivery, WebhookDeliveryNew, WebhookDeliveryUpdate, WebhookDeliveryUpdateInternal,
+        WebhookEndpoint, WebhookEndpointNew, WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookEndpointNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookEndpoint> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookEndpoint {
+    #[instrument(skip(conn))]
+    pub async fn find_by_profile_id_endpoint_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        endpoint_update: WebhookEndpointUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::endpoint_id.eq(self.endpoint_id.to_owned()),
+            WebhookEndpointUpdateInternal::from(endpoint_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_profile_id_endpoint_id(
+        conn: &PgPooledConn,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl WebhookDeliveryNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookDelivery> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookDelivery {
+    #[instrument(skip(conn))]
+    pub async fn list_by_endpoint_id(
+        conn: &PgPooledConn,
+        endpoint_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            delivery_dsl::endpoint_id.eq(endpoint_id.to_owned()),
+            Some(limit),
+            Some(offset),
+            Some(delivery_dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        delivery_update: WebhookDeliveryUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            delivery_dsl::delivery_id.eq(self.delivery_id.to_owned()),
+            WebhookDeliveryUpdateInternal::from(delivery_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
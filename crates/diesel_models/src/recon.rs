@@ -0,0 +1,118 @@
+use common_utils::custom_serde;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{recon_record, recon_report},
+};
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportNew {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub file_id: Option<String>,
+    pub status: storage_enums::ReconReportStatus,
+    pub total_records: i32,
+    pub matched_records: i32,
+    pub unmatched_records: i32,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = recon_report, primary_key(report_id))]
+pub struct ReconReport {
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub file_id: Option<String>,
+    pub status: storage_enums::ReconReportStatus,
+    pub total_records: i32,
+    pub matched_records: i32,
+    pub unmatched_records: i32,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ReconReportUpdate {
+    Completed {
+        total_records: i32,
+        matched_records: i32,
+        unmatched_records: i32,
+    },
+    Failed {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recon_report)]
+pub struct ReconReportUpdateInternal {
+    status: storage_enums::ReconReportStatus,
+    total_records: Option<i32>,
+    matched_records: Option<i32>,
+    unmatched_records: Option<i32>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<ReconReportUpdate> for ReconReportUpdateInternal {
+    fn from(report_update: ReconReportUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match report_update {
+            ReconReportUpdate::Completed {
+                total_records,
+                matched_records,
+                unmatched_records,
+            } => Self {
+                status: storage_enums::ReconReportStatus::Completed,
+                total_records: Some(total_records),
+                matched_records: Some(matched_records),
+                unmatched_records: Some(unmatched_records),
+                error_message: None,
+                modified_at,
+            },
+            ReconReportUpdate::Failed { error_message } => Self {
+                status: storage_enums::ReconReportStatus::Failed,
+                total_records: None,
+                matched_records: None,
+                unmatched_records: None,
+                error_message: Some(error_message),
+                modified_at,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Identifiable, Insertable, Queryable, Serialize)]
+#[diesel(table_name = recon_record, primary_key(record_id))]
+pub struct ReconRecord {
+    pub record_id: String,
+    pub report_id: String,
+    pub merchant_id: String,
+    pub connector: String,
+    pub settlement_batch_id: Option<String>,
+    pub transaction_type: storage_enums::ReconTransactionType,
+    pub connector_transaction_id: String,
+    pub reference_id: Option<String>,
+    pub reported_amount: Option<i64>,
+    pub reported_currency: Option<String>,
+    pub reported_fee: Option<i64>,
+    pub reported_net_amount: Option<i64>,
+    pub reported_status: Option<String>,
+    pub expected_amount: Option<i64>,
+    pub expected_currency: Option<String>,
+    pub outcome: storage_enums::ReconOutcome,
+    pub mismatch_details: Option<serde_json::Value>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
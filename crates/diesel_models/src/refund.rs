@@ -39,6 +39,13 @@ pub struct Refund {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub processing_fee: Option<i64>,
+    pub interchange_fee: Option<i64>,
+    pub fx_markup: Option<i64>,
+    pub net_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub refund_method: Option<storage_enums::RefundMethod>,
+    pub payout_id: Option<String>,
 }
 
 #[derive(
@@ -91,6 +98,11 @@ pub enum RefundUpdate {
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
         refund_arn: String,
+        processing_fee: Option<i64>,
+        interchange_fee: Option<i64>,
+        fx_markup: Option<i64>,
+        net_amount: Option<i64>,
+        settlement_currency: Option<storage_enums::Currency>,
         updated_by: String,
     },
     MetadataAndReasonUpdate {
@@ -110,6 +122,12 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    RefundMethodUpdate {
+        refund_method: storage_enums::RefundMethod,
+        payout_id: Option<String>,
+        refund_status: storage_enums::RefundStatus,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -124,6 +142,13 @@ pub struct RefundUpdateInternal {
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
     updated_by: String,
+    processing_fee: Option<i64>,
+    interchange_fee: Option<i64>,
+    fx_markup: Option<i64>,
+    net_amount: Option<i64>,
+    settlement_currency: Option<storage_enums::Currency>,
+    refund_method: Option<storage_enums::RefundMethod>,
+    payout_id: Option<String>,
 }
 
 impl RefundUpdateInternal {
@@ -138,6 +163,13 @@ impl RefundUpdateInternal {
             refund_reason: self.refund_reason,
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
+            processing_fee: self.processing_fee.or(source.processing_fee),
+            interchange_fee: self.interchange_fee.or(source.interchange_fee),
+            fx_markup: self.fx_markup.or(source.fx_markup),
+            net_amount: self.net_amount.or(source.net_amount),
+            settlement_currency: self.settlement_currency.or(source.settlement_currency),
+            refund_method: self.refund_method.or(source.refund_method),
+            payout_id: self.payout_id.or(source.payout_id),
             ..source
         }
     }
@@ -152,6 +184,11 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway,
                 refund_error_message,
                 refund_arn,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
@@ -159,6 +196,11 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
                 refund_arn: Some(refund_arn),
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
                 ..Default::default()
             },
@@ -196,6 +238,18 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            RefundUpdate::RefundMethodUpdate {
+                refund_method,
+                payout_id,
+                refund_status,
+                updated_by,
+            } => Self {
+                refund_method: Some(refund_method),
+                payout_id,
+                refund_status: Some(refund_status),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -215,6 +269,13 @@ impl RefundUpdate {
             metadata: pa_update.metadata.or(source.metadata),
             refund_reason: pa_update.refund_reason.or(source.refund_reason),
             updated_by: pa_update.updated_by,
+            processing_fee: pa_update.processing_fee.or(source.processing_fee),
+            interchange_fee: pa_update.interchange_fee.or(source.interchange_fee),
+            fx_markup: pa_update.fx_markup.or(source.fx_markup),
+            net_amount: pa_update.net_amount.or(source.net_amount),
+            settlement_currency: pa_update.settlement_currency.or(source.settlement_currency),
+            refund_method: pa_update.refund_method.or(source.refund_method),
+            payout_id: pa_update.payout_id.or(source.payout_id),
             ..source
         }
     }
@@ -1,7 +1,11 @@
+use common_utils::custom_serde;
 use diesel::{Identifiable, Insertable, Queryable};
 use serde::{Deserialize, Serialize};
 
-use crate::{enums, schema::routing_algorithm};
+use crate::{
+    enums,
+    schema::{routing_activation_audit, routing_algorithm},
+};
 
 #[derive(Clone, Debug, Identifiable, Insertable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = routing_algorithm, primary_key(algorithm_id))]
@@ -15,6 +19,10 @@ pub struct RoutingAlgorithm {
     pub algorithm_data: serde_json::Value,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
+    /// Algorithms with the same name in a profile are versions of one another
+    pub version: i32,
+    pub created_by: Option<String>,
+    pub comment: Option<String>,
 }
 
 pub struct RoutingAlgorithmMetadata {
@@ -24,6 +32,9 @@ pub struct RoutingAlgorithmMetadata {
     pub kind: enums::RoutingAlgorithmKind,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
+    pub version: i32,
+    pub created_by: Option<String>,
+    pub comment: Option<String>,
 }
 
 pub struct RoutingProfileMetadata {
@@ -34,4 +45,44 @@ pub struct RoutingProfileMetadata {
     pub kind: enums::RoutingAlgorithmKind,
     pub created_at: time::PrimitiveDateTime,
     pub modified_at: time::PrimitiveDateTime,
+    pub version: i32,
+    pub created_by: Option<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = routing_activation_audit)]
+pub struct RoutingActivationAuditNew {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    pub algorithm_id: Option<String>,
+    pub previous_algorithm_id: Option<String>,
+    pub action: enums::RoutingActivationAction,
+    pub created_by: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = routing_activation_audit, primary_key(audit_id))]
+pub struct RoutingActivationAudit {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub profile_id: Option<String>,
+    /// The algorithm active after the change, `None` if routing was deactivated
+    pub algorithm_id: Option<String>,
+    /// The algorithm active before the change
+    pub previous_algorithm_id: Option<String>,
+    pub action: enums::RoutingActivationAction,
+    pub created_by: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Tracking data of the scheduler task that activates a routing algorithm at a later time
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RoutingActivationTrackingData {
+    pub merchant_id: String,
+    pub algorithm_id: String,
+    pub created_by: Option<String>,
 }
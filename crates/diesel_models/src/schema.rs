@@ -83,6 +83,7 @@ diesel::table! {
         payout_routing_algorithm -> Nullable<Jsonb>,
         is_recon_enabled -> Bool,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
+        webhook_signing_keys -> Nullable<Bytea>,
     }
 }
 
@@ -268,6 +269,8 @@ diesel::table! {
         primary_object_id -> Varchar,
         primary_object_type -> EventObjectType,
         created_at -> Timestamp,
+        #[max_length = 32]
+        source_verification_status -> Nullable<Varchar>,
     }
 }
 
@@ -332,6 +335,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook (webhook_id) {
+        #[max_length = 64]
+        webhook_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector_name_or_mca_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Nullable<Varchar>,
+        headers -> Jsonb,
+        body -> Nullable<Jsonb>,
+        raw_request -> Bytea,
+        #[max_length = 32]
+        source_verification_status -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -490,6 +526,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         applepay_verified_domains -> Nullable<Array<Nullable<Text>>>,
         pm_auth_config -> Nullable<Jsonb>,
+        fee_schedule -> Nullable<Jsonb>,
     }
 }
 
@@ -582,6 +619,16 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         authentication_data -> Nullable<Json>,
         encoded_data -> Nullable<Text>,
+        processing_fee -> Nullable<Int8>,
+        interchange_fee -> Nullable<Int8>,
+        fx_markup -> Nullable<Int8>,
+        net_amount -> Nullable<Int8>,
+        settlement_currency -> Nullable<Currency>,
+        routing_trace -> Nullable<Bytea>,
+        #[max_length = 16]
+        routing_arm -> Nullable<Varchar>,
+        #[max_length = 64]
+        routing_algorithm_id -> Nullable<Varchar>,
     }
 }
 
@@ -806,6 +853,69 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_record (record_id) {
+        #[max_length = 64]
+        record_id -> Varchar,
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 128]
+        settlement_batch_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        transaction_type -> Varchar,
+        #[max_length = 128]
+        connector_transaction_id -> Varchar,
+        #[max_length = 64]
+        reference_id -> Nullable<Varchar>,
+        reported_amount -> Nullable<Int8>,
+        #[max_length = 8]
+        reported_currency -> Nullable<Varchar>,
+        reported_fee -> Nullable<Int8>,
+        reported_net_amount -> Nullable<Int8>,
+        #[max_length = 64]
+        reported_status -> Nullable<Varchar>,
+        expected_amount -> Nullable<Int8>,
+        #[max_length = 8]
+        expected_currency -> Nullable<Varchar>,
+        #[max_length = 32]
+        outcome -> Varchar,
+        mismatch_details -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recon_report (report_id) {
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        total_records -> Int4,
+        matched_records -> Int4,
+        unmatched_records -> Int4,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -853,6 +963,15 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        processing_fee -> Nullable<Int8>,
+        interchange_fee -> Nullable<Int8>,
+        fx_markup -> Nullable<Int8>,
+        net_amount -> Nullable<Int8>,
+        settlement_currency -> Nullable<Currency>,
+        #[max_length = 32]
+        refund_method -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
     }
 }
 
@@ -874,6 +993,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_activation_audit (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        algorithm_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        previous_algorithm_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        action -> Varchar,
+        #[max_length = 64]
+        created_by -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -892,6 +1034,11 @@ diesel::table! {
         algorithm_data -> Jsonb,
         created_at -> Timestamp,
         modified_at -> Timestamp,
+        version -> Int4,
+        #[max_length = 64]
+        created_by -> Nullable<Varchar>,
+        #[max_length = 256]
+        comment -> Nullable<Varchar>,
     }
 }
 
@@ -910,6 +1057,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_delivery (delivery_id) {
+        #[max_length = 64]
+        delivery_id -> Varchar,
+        #[max_length = 64]
+        event_id -> Varchar,
+        event_type -> EventType,
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        response_status_code -> Nullable<Int2>,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 2048]
+        url -> Varchar,
+        #[max_length = 256]
+        description -> Nullable<Varchar>,
+        enabled_events -> Nullable<Jsonb>,
+        headers -> Nullable<Jsonb>,
+        #[max_length = 128]
+        secret -> Varchar,
+        disabled -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -923,6 +1119,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     file_metadata,
     fraud_check,
+    incoming_webhook,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -936,8 +1133,13 @@ diesel::allow_tables_to_appear_in_same_query!(
     payout_attempt,
     payouts,
     process_tracker,
+    recon_record,
+    recon_report,
     refund,
     reverse_lookup,
+    routing_activation_audit,
     routing_algorithm,
     users,
+    webhook_delivery,
+    webhook_endpoint,
 );
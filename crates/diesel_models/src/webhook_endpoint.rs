@@ -0,0 +1,167 @@
+use common_utils::{custom_serde, pii};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{webhook_delivery, webhook_endpoint},
+};
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    pub enabled_events: Option<serde_json::Value>,
+    pub headers: Option<pii::SecretSerdeValue>,
+    pub secret: Secret<String>,
+    pub disabled: bool,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub profile_id: String,
+    pub url: String,
+    pub description: Option<String>,
+    /// The event types sent to the endpoint, every event type is sent when this is `None`
+    pub enabled_events: Option<serde_json::Value>,
+    /// Custom headers added to every webhook sent to the endpoint
+    pub headers: Option<pii::SecretSerdeValue>,
+    /// The secret with which the webhooks sent to the endpoint are signed
+    pub secret: Secret<String>,
+    pub disabled: bool,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        description: Option<String>,
+        enabled_events: Option<serde_json::Value>,
+        headers: Option<pii::SecretSerdeValue>,
+        disabled: Option<bool>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    url: Option<String>,
+    description: Option<String>,
+    enabled_events: Option<serde_json::Value>,
+    headers: Option<pii::SecretSerdeValue>,
+    disabled: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(endpoint_update: WebhookEndpointUpdate) -> Self {
+        match endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                description,
+                enabled_events,
+                headers,
+                disabled,
+            } => Self {
+                url,
+                description,
+                enabled_events,
+                headers,
+                disabled,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_delivery)]
+pub struct WebhookDeliveryNew {
+    pub delivery_id: String,
+    pub event_id: String,
+    pub event_type: storage_enums::EventType,
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::WebhookDeliveryStatus,
+    pub response_status_code: Option<i16>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
+#[diesel(table_name = webhook_delivery, primary_key(delivery_id))]
+pub struct WebhookDelivery {
+    pub delivery_id: String,
+    pub event_id: String,
+    pub event_type: storage_enums::EventType,
+    pub endpoint_id: String,
+    pub merchant_id: String,
+    pub status: storage_enums::WebhookDeliveryStatus,
+    pub response_status_code: Option<i16>,
+    pub error_message: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum WebhookDeliveryUpdate {
+    Delivered {
+        response_status_code: i16,
+    },
+    Failed {
+        response_status_code: Option<i16>,
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_delivery)]
+pub struct WebhookDeliveryUpdateInternal {
+    status: storage_enums::WebhookDeliveryStatus,
+    response_status_code: Option<i16>,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookDeliveryUpdate> for WebhookDeliveryUpdateInternal {
+    fn from(delivery_update: WebhookDeliveryUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match delivery_update {
+            WebhookDeliveryUpdate::Delivered {
+                response_status_code,
+            } => Self {
+                status: storage_enums::WebhookDeliveryStatus::Delivered,
+                response_status_code: Some(response_status_code),
+                error_message: None,
+                modified_at,
+            },
+            WebhookDeliveryUpdate::Failed {
+                response_status_code,
+                error_message,
+            } => Self {
+                status: storage_enums::WebhookDeliveryStatus::Failed,
+                response_status_code,
+                error_message: Some(error_message),
+                modified_at,
+            },
+        }
+    }
+}
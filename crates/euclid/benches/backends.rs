@@ -2,7 +2,9 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use euclid::{
-    backend::{inputs, EuclidBackend, InterpreterBackend, VirInterpreterBackend},
+    backend::{
+        inputs, DecisionTreeBackend, EuclidBackend, InterpreterBackend, VirInterpreterBackend,
+    },
     enums,
     frontend::ast::{self, parser},
     types::DummyOutput,
@@ -57,6 +59,8 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
             mandate_type: None,
             payment_type: None,
         },
+        time: None,
+        issuer: None,
     };
 
     let (_, program) = parser::program(code1).expect("Parser");
@@ -64,13 +68,51 @@ fn get_program_data() -> (ast::Program<DummyOutput>, inputs::BackendInput) {
     (program, inp)
 }
 
+/// A program with a few hundred rules, none of which match the input from [`get_program_data`]
+fn get_large_program() -> ast::Program<DummyOutput> {
+    let payment_methods = ["card", "wallet", "bank_redirect", "bank_transfer"];
+    let currencies = ["USD", "EUR", "GBP", "INR"];
+
+    let rules: Vec<String> = (0..400)
+        .map(|index| {
+            let payment_method = payment_methods[index % payment_methods.len()];
+            let currency = currencies[(index / payment_methods.len()) % currencies.len()];
+            let lower = 100 * index;
+
+            format!(
+                r#"
+        rule_{index}: ["stripe", "adyen"]
+        {{
+            payment_method = {payment_method} & amount >= {lower} {{
+                currency = {currency} & amount < {upper}
+                capture_method = manual & authentication_type /= three_ds
+            }}
+        }}
+"#,
+                upper = lower + 1000
+            )
+        })
+        .collect();
+
+    let code = format!(
+        "default: [\"stripe\", \"adyen\", \"checkout\"]\n{}",
+        rules.concat()
+    );
+    let (_, program) = parser::program(&code).expect("Parser");
+
+    program
+}
+
 fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
     let (program, binputs) = get_program_data();
 
     let interp_b = InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
 
     let vir_interp_b =
-        VirInterpreterBackend::with_program(program).expect("Vir Interpreter Backend");
+        VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+
+    let decision_tree_b =
+        DecisionTreeBackend::with_program(program).expect("Decision Tree Backend");
 
     c.bench_function("Raw Interpreter Backend", |b| {
         b.iter(|| {
@@ -87,7 +129,56 @@ fn interpreter_vs_jit_vs_vir_interpreter(c: &mut Criterion) {
                 .expect("Vir Interpreter execution");
         })
     });
+
+    c.bench_function("Decision Tree Backend", |b| {
+        b.iter(|| {
+            decision_tree_b
+                .execute(binputs.clone())
+                .expect("Decision Tree execution");
+        })
+    });
+}
+
+fn large_program_backends(c: &mut Criterion) {
+    let (_, binputs) = get_program_data();
+    let program = get_large_program();
+
+    let interp_b = InterpreterBackend::with_program(program.clone()).expect("Interpreter backend");
+
+    let vir_interp_b =
+        VirInterpreterBackend::with_program(program.clone()).expect("Vir Interpreter Backend");
+
+    let decision_tree_b =
+        DecisionTreeBackend::with_program(program).expect("Decision Tree Backend");
+
+    c.bench_function("Raw Interpreter Backend (large program)", |b| {
+        b.iter(|| {
+            interp_b
+                .execute(black_box(binputs.clone()))
+                .expect("Interpreter EXECUTION");
+        });
+    });
+
+    c.bench_function("Valued Interpreter Backend (large program)", |b| {
+        b.iter(|| {
+            vir_interp_b
+                .execute(black_box(binputs.clone()))
+                .expect("Vir Interpreter execution");
+        })
+    });
+
+    c.bench_function("Decision Tree Backend (large program)", |b| {
+        b.iter(|| {
+            decision_tree_b
+                .execute(black_box(binputs.clone()))
+                .expect("Decision Tree execution");
+        })
+    });
 }
 
-criterion_group!(benches, interpreter_vs_jit_vs_vir_interpreter);
+criterion_group!(
+    benches,
+    interpreter_vs_jit_vs_vir_interpreter,
+    large_program_backends
+);
 criterion_main!(benches);
@@ -1,8 +1,13 @@
+#[cfg(feature = "valued_jit")]
+pub mod decision_tree;
 pub mod inputs;
 pub mod interpreter;
+pub mod trace;
 #[cfg(feature = "valued_jit")]
 pub mod vir_interpreter;
 
+#[cfg(feature = "valued_jit")]
+pub use decision_tree::DecisionTreeBackend;
 pub use inputs::BackendInput;
 pub use interpreter::InterpreterBackend;
 #[cfg(feature = "valued_jit")]
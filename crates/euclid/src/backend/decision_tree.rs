@@ -0,0 +1,551 @@
+//! Decision Tree Backend
+//!
+//! Compiles a program into an index from the values present in an input to the rule conditions
+//! that they can satisfy, so that only those conditions are checked on execution instead of
+//! every condition of every rule. Results are identical to the
+//! [`VirInterpreterBackend`](crate::backend::VirInterpreterBackend).
+use rustc_hash::FxHashMap;
+
+use crate::{
+    backend::{self, inputs, vir_interpreter::types::Context, EuclidBackend},
+    dssa,
+    frontend::{
+        ast,
+        dir::{self, EuclidDirFilter},
+        vir,
+    },
+    types::{EuclidKey, EuclidValue, NumValue},
+};
+
+#[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
+pub enum DecisionTreeError {
+    #[error("Error when lowering the program: {0:?}")]
+    LoweringError(dssa::types::AnalysisError),
+}
+
+/// One way of satisfying a rule, that is the conjunction of all the conditions from a statement
+/// of the rule down to one of its innermost nested statements.
+struct Path {
+    rule: usize,
+    comparisons: Vec<vir::ValuedComparison>,
+}
+
+impl Path {
+    fn is_satisfied(&self, ctx: &Context) -> bool {
+        self.comparisons
+            .iter()
+            .all(|comparison| match &comparison.logic {
+                vir::ValuedComparisonLogic::PositiveDisjunction => {
+                    comparison.values.iter().any(|v| ctx.check_presence(v))
+                }
+                vir::ValuedComparisonLogic::NegativeConjunction => {
+                    comparison.values.iter().all(|v| !ctx.check_presence(v))
+                }
+            })
+    }
+
+    /// The comparison used to index the path. A path can only be satisfied if one of the values
+    /// of this comparison is present, so it has to be an asserting comparison. Comparisons
+    /// against exact values are preferred over numeric ones, since they rule out more inputs.
+    fn anchor(&self) -> Option<&vir::ValuedComparison> {
+        let mut asserting = self.comparisons.iter().filter(|comparison| {
+            matches!(
+                comparison.logic,
+                vir::ValuedComparisonLogic::PositiveDisjunction
+            )
+        });
+
+        asserting
+            .clone()
+            .find(|comparison| {
+                comparison
+                    .values
+                    .iter()
+                    .all(|value| value.get_num_value().is_none())
+            })
+            .or_else(|| asserting.next())
+    }
+}
+
+/// Splits the number line for a numeric key into segments within which every number satisfies the
+/// same values. A number equal to `boundaries[i]` falls in segment `2i + 1`, while a number lying
+/// strictly between `boundaries[i - 1]` and `boundaries[i]` falls in segment `2i`.
+struct RangePartition {
+    boundaries: Vec<i64>,
+    segments: Vec<Vec<usize>>,
+}
+
+impl RangePartition {
+    fn new(values: &[(NumValue, usize)]) -> Self {
+        let mut boundaries: Vec<i64> = values.iter().map(|(value, _)| value.number).collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        let segments = (0..=2 * boundaries.len())
+            .map(|segment| {
+                Self::representative(&boundaries, segment).map_or_else(Vec::new, |number| {
+                    let mut entries: Vec<usize> = values
+                        .iter()
+                        .filter(|(value, _)| value.is_satisfied_by(number))
+                        .map(|(_, entry)| *entry)
+                        .collect();
+                    entries.dedup();
+                    entries
+                })
+            })
+            .collect();
+
+        Self {
+            boundaries,
+            segments,
+        }
+    }
+
+    /// Any number lying in the segment, or `None` if the segment is empty
+    fn representative(boundaries: &[i64], segment: usize) -> Option<i64> {
+        let index = segment / 2;
+
+        if segment % 2 == 1 {
+            return boundaries.get(index).copied();
+        }
+
+        let lower = index.checked_sub(1).and_then(|i| boundaries.get(i));
+        match (lower, boundaries.get(index)) {
+            (None, None) => Some(0),
+            (None, Some(upper)) => upper.checked_sub(1),
+            (Some(lower), None) => lower.checked_add(1),
+            (Some(lower), Some(upper)) => lower.checked_add(1).filter(|number| number < upper),
+        }
+    }
+
+    fn entries_for(&self, number: i64) -> &[usize] {
+        let segment = match self.boundaries.binary_search(&number) {
+            Ok(index) => 2 * index + 1,
+            Err(index) => 2 * index,
+        };
+
+        self.segments.get(segment).map_or(&[], Vec::as_slice)
+    }
+}
+
+pub struct DecisionTreeBackend<O> {
+    default_selection: O,
+    rules: Vec<(String, O)>,
+    /// All paths of the program, ordered by the rule they belong to
+    paths: Vec<Path>,
+    value_index: FxHashMap<EuclidValue, Vec<usize>>,
+    range_index: FxHashMap<EuclidKey, RangePartition>,
+    /// Paths without any asserting comparison, which need to be checked for every input
+    unanchored_paths: Vec<usize>,
+}
+
+impl<O> DecisionTreeBackend<O> {
+    fn flatten_statement(statement: vir::ValuedIfStatement) -> Vec<Vec<vir::ValuedComparison>> {
+        match statement.nested {
+            None => vec![statement.condition],
+            Some(nested) => nested
+                .into_iter()
+                .flat_map(Self::flatten_statement)
+                .map(|mut nested_path| {
+                    let mut path = statement.condition.clone();
+                    path.append(&mut nested_path);
+                    path
+                })
+                .collect(),
+        }
+    }
+
+    fn compile(program: vir::ValuedProgram<O>) -> Self {
+        let mut rules = Vec::with_capacity(program.rules.len());
+        let mut paths = Vec::new();
+
+        for (rule_index, rule) in program.rules.into_iter().enumerate() {
+            paths.extend(
+                rule.statements
+                    .into_iter()
+                    .flat_map(Self::flatten_statement)
+                    .map(|comparisons| Path {
+                        rule: rule_index,
+                        comparisons,
+                    }),
+            );
+
+            rules.push((rule.name, rule.connector_selection));
+        }
+
+        let mut value_index: FxHashMap<EuclidValue, Vec<usize>> = FxHashMap::default();
+        let mut numeric_values: FxHashMap<EuclidKey, Vec<(NumValue, usize)>> = FxHashMap::default();
+        let mut unanchored_paths = Vec::new();
+
+        for (path_id, path) in paths.iter().enumerate() {
+            let anchor_values = match path.anchor() {
+                Some(anchor) => &anchor.values,
+                None => {
+                    unanchored_paths.push(path_id);
+                    continue;
+                }
+            };
+
+            for value in anchor_values {
+                match value.get_num_value() {
+                    Some(num_value) => numeric_values
+                        .entry(value.get_key())
+                        .or_default()
+                        .push((num_value, path_id)),
+                    None => value_index.entry(value.clone()).or_default().push(path_id),
+                }
+            }
+        }
+
+        let range_index = numeric_values
+            .into_iter()
+            .map(|(key, values)| (key, RangePartition::new(&values)))
+            .collect();
+
+        Self {
+            default_selection: program.default_selection,
+            rules,
+            paths,
+            value_index,
+            range_index,
+            unanchored_paths,
+        }
+    }
+
+    /// Index of the first rule having a path whose comparisons all hold for the given context
+    fn matching_rule(&self, ctx: &Context) -> Option<usize> {
+        let atomic_candidates = ctx
+            .atomic_values()
+            .iter()
+            .filter_map(|value| self.value_index.get(value))
+            .flatten();
+
+        let numeric_candidates = ctx
+            .numeric_values()
+            .iter()
+            .filter_map(|(key, value)| self.range_index.get(key).zip(value.get_num_value()))
+            .flat_map(|(partition, num_value)| partition.entries_for(num_value.number));
+
+        let mut candidates: Vec<usize> = atomic_candidates
+            .chain(numeric_candidates)
+            .chain(&self.unanchored_paths)
+            .copied()
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .filter_map(|path_id| self.paths.get(path_id))
+            .find(|path| path.is_satisfied(ctx))
+            .map(|path| path.rule)
+    }
+}
+
+impl<O> EuclidBackend<O> for DecisionTreeBackend<O>
+where
+    O: Clone + EuclidDirFilter,
+{
+    type Error = DecisionTreeError;
+
+    fn with_program(program: ast::Program<O>) -> Result<Self, Self::Error> {
+        let dir_program =
+            ast::lowering::lower_program(program).map_err(DecisionTreeError::LoweringError)?;
+
+        let vir_program =
+            dir::lowering::lower_program(dir_program).map_err(DecisionTreeError::LoweringError)?;
+
+        Ok(Self::compile(vir_program))
+    }
+
+    fn execute(
+        &self,
+        input: inputs::BackendInput,
+    ) -> Result<backend::BackendOutput<O>, Self::Error> {
+        let ctx = Context::from_input(input);
+
+        Ok(self
+            .matching_rule(&ctx)
+            .and_then(|rule_index| self.rules.get(rule_index))
+            .map_or_else(
+                || backend::BackendOutput {
+                    connector_selection: self.default_selection.clone(),
+                    rule_name: None,
+                },
+                |(name, connector_selection)| backend::BackendOutput {
+                    connector_selection: connector_selection.clone(),
+                    rule_name: Some(name.clone()),
+                },
+            ))
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used)]
+    use proptest::{prelude::*, sample};
+
+    use super::*;
+    use crate::{
+        backend::{InterpreterBackend, VirInterpreterBackend},
+        enums,
+        types::DummyOutput,
+    };
+
+    fn enum_comparison(
+        key: &'static str,
+        variants: &'static [&'static str],
+    ) -> impl Strategy<Value = String> {
+        (
+            sample::select(&["=", "/="][..]),
+            sample::subsequence(variants, 1..=variants.len()),
+        )
+            .prop_map(move |(operator, values)| match values.as_slice() {
+                [value] => format!("{key} {operator} {value}"),
+                _ => format!("{key} {operator} ({})", values.join(", ")),
+            })
+    }
+
+    fn amount_comparison() -> impl Strategy<Value = String> {
+        prop_oneof![
+            (
+                sample::select(&["=", "/=", ">", ">=", "<", "<="][..]),
+                0i64..100
+            )
+                .prop_map(|(operator, number)| format!("amount {operator} {number}")),
+            prop::collection::vec(0i64..100, 1..4).prop_map(|numbers| {
+                let numbers: Vec<String> = numbers.iter().map(ToString::to_string).collect();
+                format!("amount = ({})", numbers.join(", "))
+            }),
+        ]
+    }
+
+    fn comparison() -> impl Strategy<Value = String> {
+        prop_oneof![
+            enum_comparison(
+                "payment_method",
+                &["card", "pay_later", "wallet", "bank_redirect"]
+            ),
+            enum_comparison("capture_method", &["automatic", "manual"]),
+            enum_comparison("authentication_type", &["three_ds", "no_three_ds"]),
+            enum_comparison("currency", &["USD", "EUR", "GBP"]),
+            amount_comparison(),
+        ]
+    }
+
+    fn condition() -> impl Strategy<Value = String> {
+        prop::collection::vec(comparison(), 1..4).prop_map(|comparisons| comparisons.join(" & "))
+    }
+
+    fn statement() -> impl Strategy<Value = String> {
+        condition().prop_recursive(3, 24, 3, |inner| {
+            (condition(), prop::collection::vec(inner, 1..4)).prop_map(|(condition, nested)| {
+                format!("{condition} {{\n{}\n}}", nested.join("\n"))
+            })
+        })
+    }
+
+    fn program() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::collection::vec(statement(), 1..3), 1..8).prop_map(|rules| {
+            let rules: Vec<String> = rules
+                .iter()
+                .enumerate()
+                .map(|(index, statements)| {
+                    format!(
+                        "rule_{index}: [\"connector_{index}\"]\n{{\n{}\n}}",
+                        statements.join("\n")
+                    )
+                })
+                .collect();
+
+            format!("default: [\"stripe\"]\n\n{}", rules.join("\n\n"))
+        })
+    }
+
+    fn input() -> impl Strategy<Value = inputs::BackendInput> {
+        (
+            sample::select(vec![
+                enums::PaymentMethod::Card,
+                enums::PaymentMethod::PayLater,
+                enums::PaymentMethod::Wallet,
+                enums::PaymentMethod::BankRedirect,
+            ]),
+            sample::select(vec![
+                enums::CaptureMethod::Automatic,
+                enums::CaptureMethod::Manual,
+            ]),
+            sample::select(vec![
+                enums::AuthenticationType::ThreeDs,
+                enums::AuthenticationType::NoThreeDs,
+            ]),
+            sample::select(vec![
+                enums::Currency::USD,
+                enums::Currency::EUR,
+                enums::Currency::GBP,
+            ]),
+            0i64..100,
+        )
+            .prop_map(
+                |(payment_method, capture_method, authentication_type, currency, amount)| {
+                    inputs::BackendInput {
+                        metadata: None,
+                        payment: inputs::PaymentInput {
+                            amount,
+                            card_bin: None,
+                            currency,
+                            authentication_type: Some(authentication_type),
+                            capture_method: Some(capture_method),
+                            business_country: None,
+                            billing_country: None,
+                            business_label: None,
+                            setup_future_usage: None,
+                        },
+                        payment_method: inputs::PaymentMethodInput {
+                            payment_method: Some(payment_method),
+                            payment_method_type: None,
+                            card_network: None,
+                        },
+                        mandate: inputs::MandateData {
+                            mandate_acceptance_type: None,
+                            mandate_type: None,
+                            payment_type: None,
+                        },
+                        time: None,
+                        issuer: None,
+                    }
+                },
+            )
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(512))]
+
+        #[test]
+        fn proptest_matches_interpreters(
+            program_str in program(),
+            inputs in prop::collection::vec(input(), 1..8),
+        ) {
+            let (_, program) = ast::parser::program::<DummyOutput>(&program_str).expect("Program");
+
+            // Number values of a `/=` comparison are lowered with a `NotEqual` refinement, so the
+            // lowered backends only agree with the AST interpreter on programs without them
+            let lowering_matches_ast = !program_str.contains("amount /=");
+
+            let interpreter = InterpreterBackend::with_program(program.clone()).expect("Interpreter");
+            let vir_interpreter = VirInterpreterBackend::with_program(program.clone());
+            let decision_tree = DecisionTreeBackend::with_program(program);
+
+            // Programs with contradictory conditions are rejected during lowering
+            let (vir_interpreter, decision_tree) = match (vir_interpreter, decision_tree) {
+                (Ok(vir_interpreter), Ok(decision_tree)) => (vir_interpreter, decision_tree),
+                (vir_interpreter, decision_tree) => {
+                    prop_assert_eq!(vir_interpreter.is_ok(), decision_tree.is_ok());
+                    return Ok(());
+                }
+            };
+
+            for input in inputs {
+                let expected = interpreter.execute(input.clone()).expect("Interpreter execution");
+                let vir_result = vir_interpreter.execute(input.clone()).expect("Vir execution");
+                let result = decision_tree.execute(input).expect("Decision tree execution");
+
+                prop_assert_eq!(&result.rule_name, &vir_result.rule_name);
+                prop_assert_eq!(
+                    &result.connector_selection.outputs,
+                    &vir_result.connector_selection.outputs
+                );
+
+                if lowering_matches_ast {
+                    prop_assert_eq!(&result.rule_name, &expected.rule_name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_not_equal_operator() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           amount /= 100 & amount >= 50
+        }
+
+        rule_2: ["checkout"]
+        {
+           amount /= (100, 20)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let input = |amount| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: None,
+            issuer: None,
+        };
+        let backend = DecisionTreeBackend::<DummyOutput>::with_program(program).expect("Program");
+        let rule_name = |amount| backend.execute(input(amount)).expect("Execution").rule_name;
+
+        // Same results as the `VirInterpreterBackend`, since the tree is compiled from the
+        // lowered program
+        assert_eq!(rule_name(100).as_deref(), Some("rule_1"));
+        assert_eq!(rule_name(150), None);
+        assert_eq!(rule_name(20), None);
+        assert_eq!(rule_name(30), None);
+    }
+
+    #[test]
+    fn test_range_partition() {
+        let values = [
+            (
+                NumValue {
+                    number: 10,
+                    refinement: Some(crate::types::NumValueRefinement::GreaterThan),
+                },
+                0,
+            ),
+            (
+                NumValue {
+                    number: 20,
+                    refinement: None,
+                },
+                1,
+            ),
+            (
+                NumValue {
+                    number: 20,
+                    refinement: Some(crate::types::NumValueRefinement::LessThan),
+                },
+                2,
+            ),
+        ];
+        let partition = RangePartition::new(&values);
+
+        assert_eq!(partition.entries_for(5), &[2]);
+        assert_eq!(partition.entries_for(10), &[2]);
+        assert_eq!(partition.entries_for(15), &[0, 2]);
+        assert_eq!(partition.entries_for(20), &[0, 1]);
+        assert_eq!(partition.entries_for(i64::MAX), &[0]);
+    }
+}
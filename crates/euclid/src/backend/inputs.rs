@@ -30,10 +30,46 @@ pub struct PaymentInput {
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
 }
 
+/// The calendar position of the payment, computed in the timezone configured for the merchant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeInput {
+    /// Hour of the day, from 0 to 23
+    pub hour_of_day: i64,
+    /// Day of the week, from 1 (Monday) to 7 (Sunday)
+    pub day_of_week: i64,
+    /// Day of the month, from 1 to 31
+    pub day_of_month: i64,
+}
+
+/// Details about the card and its issuer, usually looked up from the card BIN
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssuerInput {
+    pub card_type: Option<enums::IssuerCardType>,
+    pub issuer_country: Option<enums::Country>,
+    pub card_issuer: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
     pub payment: PaymentInput,
     pub payment_method: PaymentMethodInput,
     pub mandate: MandateData,
+    #[serde(default)]
+    pub time: Option<TimeInput>,
+    #[serde(default)]
+    pub issuer: Option<IssuerInput>,
+}
+
+impl BackendInput {
+    /// A card is domestic when it was issued in the business country of the payment
+    pub fn is_domestic(&self) -> Option<enums::IsDomestic> {
+        self.issuer
+            .as_ref()
+            .and_then(|issuer| issuer.issuer_country)
+            .zip(self.payment.business_country)
+            .map(|(issuer_country, business_country)| {
+                enums::IsDomestic::from(issuer_country == business_country)
+            })
+    }
 }
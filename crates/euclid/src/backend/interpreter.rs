@@ -57,6 +57,8 @@ where
                 (EnumVariant(e), NotEqual, EnumVariantArray(evec)) => {
                     Ok(evec.iter().all(|v| e != v))
                 }
+                (StrValue(s1), Equal, StrValue(s2)) => Ok(s1 == s2),
+                (StrValue(s1), NotEqual, StrValue(s2)) => Ok(s1 != s2),
                 (Number(n1), Equal, Number(n2)) => Ok(n1 == n2),
                 (Number(n1), NotEqual, Number(n2)) => Ok(n1 != n2),
                 (Number(n1), LessThanEqual, Number(n2)) => Ok(n1 <= n2),
@@ -178,3 +180,63 @@ where
         Self::eval_program(&self.program, &ctx)
     }
 }
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod test {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+    use crate::{enums, types::DummyOutput};
+
+    #[test]
+    fn test_not_equal_operator() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           amount /= 100 & amount >= 50
+        }
+
+        rule_2: ["checkout"]
+        {
+           amount /= (100, 20)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let input = |amount| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: None,
+            issuer: None,
+        };
+        let backend = InterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let rule_name = |amount| backend.execute(input(amount)).expect("Execution").rule_name;
+
+        // This backend evaluates the AST directly, so `/=` excludes the given amounts
+        assert_eq!(rule_name(100), None);
+        assert_eq!(rule_name(150).as_deref(), Some("rule_1"));
+        assert_eq!(rule_name(20), None);
+        assert_eq!(rule_name(30).as_deref(), Some("rule_2"));
+    }
+}
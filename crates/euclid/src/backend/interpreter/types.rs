@@ -74,6 +74,57 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::IssuerCardType.to_string(),
+                input
+                    .issuer
+                    .as_ref()
+                    .and_then(|issuer| issuer.card_type.as_ref())
+                    .map(|ct| ValueType::EnumVariant(ct.to_string())),
+            ),
+            (
+                EuclidKey::IssuerCountry.to_string(),
+                input
+                    .issuer
+                    .as_ref()
+                    .and_then(|issuer| issuer.issuer_country)
+                    .map(|ic| ValueType::EnumVariant(ic.to_string())),
+            ),
+            (
+                EuclidKey::CardIssuer.to_string(),
+                input
+                    .issuer
+                    .as_ref()
+                    .and_then(|issuer| issuer.card_issuer.clone())
+                    .map(ValueType::StrValue),
+            ),
+            (
+                EuclidKey::IsDomestic.to_string(),
+                input
+                    .is_domestic()
+                    .map(|id| ValueType::EnumVariant(id.to_string())),
+            ),
+            (
+                EuclidKey::HourOfDay.to_string(),
+                input
+                    .time
+                    .as_ref()
+                    .map(|time| ValueType::Number(time.hour_of_day)),
+            ),
+            (
+                EuclidKey::DayOfWeek.to_string(),
+                input
+                    .time
+                    .as_ref()
+                    .map(|time| ValueType::Number(time.day_of_week)),
+            ),
+            (
+                EuclidKey::DayOfMonth.to_string(),
+                input
+                    .time
+                    .as_ref()
+                    .map(|time| ValueType::Number(time.day_of_month)),
+            ),
         ]);
 
         Self(ctx)
@@ -0,0 +1,92 @@
+//! Traces of program executions
+//!
+//! A trace records the outcome of every rule and comparison of a program for a given input,
+//! along with the values from the input that each comparison was checked against, so that the
+//! output of a backend can be explained after the fact.
+use serde::{Deserialize, Serialize};
+
+use crate::types::{EuclidValue, NumValue, NumValueRefinement};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonTraceLogic {
+    /// The comparison holds if any of the expected values is present in the input
+    AnyOf,
+    /// The comparison holds if none of the expected values are present in the input
+    NoneOf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComparisonTrace {
+    pub key: String,
+    pub logic: ComparisonTraceLogic,
+    pub expected: Vec<String>,
+    /// The values of the key present in the input, empty if the input did not have the key
+    pub actual: Vec<String>,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatementTrace {
+    pub comparisons: Vec<ComparisonTrace>,
+    pub nested: Option<Vec<StatementTrace>>,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleTrace {
+    pub name: String,
+    pub statements: Vec<StatementTrace>,
+    pub matched: bool,
+}
+
+/// The trace of a program execution. Every rule is evaluated, even the ones following the rule
+/// that was selected, so that the trace also explains why later rules would not have matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramTrace {
+    pub rules: Vec<RuleTrace>,
+    /// Name of the first matching rule, `None` if the default selection was used
+    pub selected_rule: Option<String>,
+}
+
+fn num_value_to_string(value: &NumValue) -> String {
+    let operator = match &value.refinement {
+        None => "",
+        Some(NumValueRefinement::NotEqual) => "/= ",
+        Some(NumValueRefinement::GreaterThan) => "> ",
+        Some(NumValueRefinement::LessThan) => "< ",
+        Some(NumValueRefinement::GreaterThanEqual) => ">= ",
+        Some(NumValueRefinement::LessThanEqual) => "<= ",
+    };
+
+    format!("{operator}{}", value.number)
+}
+
+/// Renders the value the way it would be written in a program
+pub fn value_to_string(value: &EuclidValue) -> String {
+    match value {
+        EuclidValue::PaymentMethod(pm) => pm.to_string(),
+        EuclidValue::PaymentMethodType(pmt) => pmt.to_string(),
+        EuclidValue::CardNetwork(network) => network.to_string(),
+        EuclidValue::AuthenticationType(at) => at.to_string(),
+        EuclidValue::CaptureMethod(cm) => cm.to_string(),
+        EuclidValue::PaymentType(pt) => pt.to_string(),
+        EuclidValue::MandateAcceptanceType(mat) => mat.to_string(),
+        EuclidValue::MandateType(mt) => mt.to_string(),
+        EuclidValue::PaymentCurrency(currency) => currency.to_string(),
+        EuclidValue::BusinessCountry(country)
+        | EuclidValue::BillingCountry(country)
+        | EuclidValue::IssuerCountry(country) => country.to_string(),
+        EuclidValue::SetupFutureUsage(sfu) => sfu.to_string(),
+        EuclidValue::IssuerCardType(card_type) => card_type.to_string(),
+        EuclidValue::IsDomestic(is_domestic) => is_domestic.to_string(),
+        EuclidValue::CardBin(str_value)
+        | EuclidValue::BusinessLabel(str_value)
+        | EuclidValue::CardIssuer(str_value) => str_value.value.clone(),
+        EuclidValue::Metadata(metadata) => format!("{}: {}", metadata.key, metadata.value),
+        EuclidValue::PaymentAmount(num_value)
+        | EuclidValue::HourOfDay(num_value)
+        | EuclidValue::DayOfWeek(num_value)
+        | EuclidValue::DayOfMonth(num_value) => num_value_to_string(num_value),
+    }
+}
@@ -1,7 +1,13 @@
 pub mod types;
 
 use crate::{
-    backend::{self, inputs, EuclidBackend},
+    backend::{
+        self, inputs,
+        trace::{
+            self, ComparisonTrace, ComparisonTraceLogic, ProgramTrace, RuleTrace, StatementTrace,
+        },
+        EuclidBackend,
+    },
     frontend::{
         ast,
         dir::{self, EuclidDirFilter},
@@ -71,6 +77,104 @@ where
     }
 }
 
+impl<O> VirInterpreterBackend<O>
+where
+    O: Clone,
+{
+    fn trace_comparison(comp: &vir::ValuedComparison, ctx: &types::Context) -> ComparisonTrace {
+        let key = comp
+            .values
+            .first()
+            .map(|value| value.get_key().to_string())
+            .unwrap_or_default();
+
+        let mut actual: Vec<String> = Vec::new();
+        for value in comp
+            .values
+            .iter()
+            .flat_map(|value| ctx.get_values_for(value))
+        {
+            let rendered = trace::value_to_string(value);
+            if !actual.contains(&rendered) {
+                actual.push(rendered);
+            }
+        }
+
+        ComparisonTrace {
+            key,
+            logic: match comp.logic {
+                vir::ValuedComparisonLogic::PositiveDisjunction => ComparisonTraceLogic::AnyOf,
+                vir::ValuedComparisonLogic::NegativeConjunction => ComparisonTraceLogic::NoneOf,
+            },
+            expected: comp.values.iter().map(trace::value_to_string).collect(),
+            actual,
+            passed: Self::eval_comparison(comp, ctx),
+        }
+    }
+
+    fn trace_statement(stmt: &vir::ValuedIfStatement, ctx: &types::Context) -> StatementTrace {
+        let comparisons: Vec<ComparisonTrace> = stmt
+            .condition
+            .iter()
+            .map(|comp| Self::trace_comparison(comp, ctx))
+            .collect();
+
+        let nested = stmt.nested.as_ref().map(|nested_stmts| {
+            nested_stmts
+                .iter()
+                .map(|s| Self::trace_statement(s, ctx))
+                .collect::<Vec<_>>()
+        });
+
+        let passed = comparisons.iter().all(|comp| comp.passed)
+            && nested
+                .as_ref()
+                .map_or(true, |nested| nested.iter().any(|s| s.passed));
+
+        StatementTrace {
+            comparisons,
+            nested,
+            passed,
+        }
+    }
+
+    /// Executes the program while recording the outcome of every rule and comparison. The output
+    /// is the same as the one returned by [`EuclidBackend::execute`].
+    pub fn execute_traced(
+        &self,
+        input: inputs::BackendInput,
+    ) -> (backend::BackendOutput<O>, ProgramTrace) {
+        let ctx = types::Context::from_input(input);
+
+        let rules: Vec<RuleTrace> = self
+            .program
+            .rules
+            .iter()
+            .map(|rule| {
+                let statements: Vec<StatementTrace> = rule
+                    .statements
+                    .iter()
+                    .map(|stmt| Self::trace_statement(stmt, &ctx))
+                    .collect();
+
+                RuleTrace {
+                    name: rule.name.clone(),
+                    matched: statements.iter().any(|stmt| stmt.passed),
+                    statements,
+                }
+            })
+            .collect();
+
+        let output = Self::eval_program(&self.program, &ctx);
+        let trace = ProgramTrace {
+            rules,
+            selected_rule: output.rule_name.clone(),
+        };
+
+        (output, trace)
+    }
+}
+
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
 where
     O: Clone + EuclidDirFilter,
@@ -145,6 +249,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -185,6 +291,8 @@ mod test {
                 mandate_type: None,
                 payment_type: Some(enums::PaymentType::SetupMandate),
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -226,6 +334,8 @@ mod test {
                 mandate_type: Some(enums::MandateType::SingleUse),
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -267,6 +377,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -308,6 +420,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -349,6 +463,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -390,6 +506,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -431,6 +549,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -474,6 +594,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -515,6 +637,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = 123;
@@ -565,6 +689,8 @@ mod test {
                 mandate_type: None,
                 payment_type: None,
             },
+            time: None,
+            issuer: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = 123;
@@ -580,4 +706,293 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_not_equal_operator() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           amount /= 100 & amount >= 50
+        }
+
+        rule_2: ["checkout"]
+        {
+           amount /= (100, 20)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let input = |amount| inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: None,
+            issuer: None,
+        };
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let rule_name = |amount| backend.execute(input(amount)).expect("Execution").rule_name;
+
+        // The values of `/=` are lowered with a `NotEqual` refinement on top of the negated
+        // comparison logic, so `amount /= 100` only holds for an amount of 100 and
+        // `amount /= (100, 20)` never holds, unlike in the `InterpreterBackend`
+        assert_eq!(rule_name(100).as_deref(), Some("rule_1"));
+        assert_eq!(rule_name(150), None);
+        assert_eq!(rule_name(20), None);
+        assert_eq!(rule_name(30), None);
+    }
+
+    #[test]
+    fn test_time_keys() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        business_hours: ["stripe"]
+        {
+           hour_of_day >= 9 & hour_of_day < 18 & day_of_week <= 5
+        }
+
+        month_end: ["adyen"]
+        {
+           day_of_month >= 28
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_business_hours = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 120,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: Some(inputs::TimeInput {
+                hour_of_day: 10,
+                day_of_week: 2,
+                day_of_month: 30,
+            }),
+            issuer: None,
+        };
+        let mut inp_weekend = inp_business_hours.clone();
+        inp_weekend.time = Some(inputs::TimeInput {
+            hour_of_day: 10,
+            day_of_week: 6,
+            day_of_month: 30,
+        });
+        let mut inp_no_time = inp_business_hours.clone();
+        inp_no_time.time = None;
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_business_hours = backend.execute(inp_business_hours).expect("Execution");
+        let result_weekend = backend.execute(inp_weekend).expect("Execution");
+        let result_no_time = backend.execute(inp_no_time).expect("Execution");
+        assert_eq!(
+            result_business_hours.rule_name.expect("Rule Name").as_str(),
+            "business_hours"
+        );
+        assert_eq!(
+            result_weekend.rule_name.expect("Rule Name").as_str(),
+            "month_end"
+        );
+        assert!(result_no_time.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_issuer_keys() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        domestic_debit: ["adyen"]
+        {
+           is_domestic = true & issuer_card_type = debit
+        }
+
+        hdfc_prepaid: ["stripe"]
+        {
+           card_issuer = "HDFC BANK" & issuer_card_type = prepaid & issuer_country = India
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_domestic_debit = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 120,
+                card_bin: Some("424242".to_string()),
+                currency: enums::Currency::INR,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::India),
+                billing_country: Some(enums::Country::India),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Debit),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: None,
+            issuer: Some(inputs::IssuerInput {
+                card_type: Some(enums::IssuerCardType::Debit),
+                issuer_country: Some(enums::Country::India),
+                card_issuer: Some("ICICI BANK".to_string()),
+            }),
+        };
+        let mut inp_foreign_debit = inp_domestic_debit.clone();
+        inp_foreign_debit.issuer = Some(inputs::IssuerInput {
+            card_type: Some(enums::IssuerCardType::Debit),
+            issuer_country: Some(enums::Country::UnitedStatesOfAmerica),
+            card_issuer: Some("ICICI BANK".to_string()),
+        });
+        let mut inp_prepaid = inp_domestic_debit.clone();
+        inp_prepaid.issuer = Some(inputs::IssuerInput {
+            card_type: Some(enums::IssuerCardType::Prepaid),
+            issuer_country: Some(enums::Country::India),
+            card_issuer: Some("HDFC BANK".to_string()),
+        });
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_domestic_debit = backend.execute(inp_domestic_debit).expect("Execution");
+        let result_foreign_debit = backend.execute(inp_foreign_debit).expect("Execution");
+        let result_prepaid = backend.execute(inp_prepaid).expect("Execution");
+        assert_eq!(
+            result_domestic_debit.rule_name.expect("Rule Name").as_str(),
+            "domestic_debit"
+        );
+        assert!(result_foreign_debit.rule_name.is_none());
+        assert_eq!(
+            result_prepaid.rule_name.expect("Rule Name").as_str(),
+            "hdfc_prepaid"
+        );
+    }
+
+    #[test]
+    fn test_execute_traced() {
+        let program_str = r#"
+        default: ["stripe"]
+        high_value: ["stripe"]
+        {
+            payment_method = card & amount > 100
+        }
+        cards_and_wallets: ["adyen"]
+        {
+            payment_method = (card, wallet) {
+                capture_method /= manual
+            }
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: 32,
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: None,
+                billing_country: None,
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: None,
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            time: None,
+            issuer: None,
+        };
+
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let (output, trace) = backend.execute_traced(inp.clone());
+        let expected_output = backend.execute(inp).expect("Execution");
+
+        assert_eq!(output.rule_name, expected_output.rule_name);
+        assert_eq!(trace.selected_rule.as_deref(), Some("cards_and_wallets"));
+
+        let high_value = trace.rules.first().expect("high_value rule trace");
+        assert!(!high_value.matched);
+        let comparisons = &high_value
+            .statements
+            .first()
+            .expect("high_value statement")
+            .comparisons;
+        assert_eq!(
+            comparisons,
+            &vec![
+                ComparisonTrace {
+                    key: "payment_method".to_string(),
+                    logic: ComparisonTraceLogic::AnyOf,
+                    expected: vec!["card".to_string()],
+                    actual: vec!["card".to_string()],
+                    passed: true,
+                },
+                ComparisonTrace {
+                    key: "amount".to_string(),
+                    logic: ComparisonTraceLogic::AnyOf,
+                    expected: vec!["> 100".to_string()],
+                    actual: vec!["32".to_string()],
+                    passed: false,
+                },
+            ]
+        );
+
+        let cards_and_wallets = trace.rules.get(1).expect("cards_and_wallets rule trace");
+        assert!(cards_and_wallets.matched);
+        let nested_comparison = cards_and_wallets
+            .statements
+            .first()
+            .and_then(|stmt| stmt.nested.as_ref())
+            .and_then(|nested| nested.first())
+            .and_then(|stmt| stmt.comparisons.first())
+            .expect("Nested comparison");
+        assert_eq!(nested_comparison.logic, ComparisonTraceLogic::NoneOf);
+        assert_eq!(nested_comparison.actual, vec!["automatic".to_string()]);
+        assert!(nested_comparison.passed);
+    }
 }
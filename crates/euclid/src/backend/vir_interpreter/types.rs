@@ -3,7 +3,7 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use crate::{
     backend::inputs::BackendInput,
     dssa,
-    types::{self, EuclidKey, EuclidValue, MetadataValue, NumValueRefinement, StrValue},
+    types::{self, EuclidKey, EuclidValue, MetadataValue, StrValue},
 };
 
 #[derive(Debug, Clone, serde::Serialize, thiserror::Error)]
@@ -31,31 +31,57 @@ impl Context {
                     .get(&key)
                     .and_then(|value| value.get_num_value());
 
-                value.get_num_value().zip(ctx_num_value).map_or(
-                    false,
-                    |(program_value, ctx_value)| {
-                        let program_num = program_value.number;
-                        let ctx_num = ctx_value.number;
-
-                        match &program_value.refinement {
-                            None => program_num == ctx_num,
-                            Some(NumValueRefinement::NotEqual) => ctx_num != program_num,
-                            Some(NumValueRefinement::GreaterThan) => ctx_num > program_num,
-                            Some(NumValueRefinement::GreaterThanEqual) => ctx_num >= program_num,
-                            Some(NumValueRefinement::LessThanEqual) => ctx_num <= program_num,
-                            Some(NumValueRefinement::LessThan) => ctx_num < program_num,
-                        }
-                    },
-                )
+                value
+                    .get_num_value()
+                    .zip(ctx_num_value)
+                    .map_or(false, |(program_value, ctx_value)| {
+                        program_value.is_satisfied_by(ctx_value.number)
+                    })
             }
         }
     }
 
+    /// The values present in the context for the key of the given value. For metadata, only the
+    /// entry with the same metadata key is returned.
+    pub fn get_values_for(&self, value: &EuclidValue) -> Vec<&EuclidValue> {
+        let key = value.get_key();
+
+        match (key.key_type(), value) {
+            (types::DataType::Number, _) => self.numeric_values.get(&key).into_iter().collect(),
+            (_, EuclidValue::Metadata(metadata)) => self
+                .atomic_values
+                .iter()
+                .filter(|ctx_value| {
+                    matches!(
+                        ctx_value,
+                        EuclidValue::Metadata(ctx_metadata) if ctx_metadata.key == metadata.key
+                    )
+                })
+                .collect(),
+            _ => self
+                .atomic_values
+                .iter()
+                .filter(|ctx_value| ctx_value.get_key() == key)
+                .collect(),
+        }
+    }
+
+    pub(crate) fn atomic_values(&self) -> &FxHashSet<EuclidValue> {
+        &self.atomic_values
+    }
+
+    pub(crate) fn numeric_values(&self) -> &FxHashMap<EuclidKey, EuclidValue> {
+        &self.numeric_values
+    }
+
     pub fn from_input(input: BackendInput) -> Self {
+        let is_domestic = input.is_domestic();
+        let issuer = input.issuer;
         let payment = input.payment;
         let payment_method = input.payment_method;
         let meta_data = input.metadata;
         let payment_mandate = input.mandate;
+        let time = input.time;
 
         let mut enum_values: FxHashSet<EuclidValue> =
             FxHashSet::from_iter([EuclidValue::PaymentCurrency(payment.currency)]);
@@ -109,8 +135,22 @@ impl Context {
         if let Some(mandate_acceptance_type) = payment_mandate.mandate_acceptance_type {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
+        if let Some(issuer) = issuer {
+            if let Some(card_type) = issuer.card_type {
+                enum_values.insert(EuclidValue::IssuerCardType(card_type));
+            }
+            if let Some(issuer_country) = issuer.issuer_country {
+                enum_values.insert(EuclidValue::IssuerCountry(issuer_country));
+            }
+            if let Some(card_issuer) = issuer.card_issuer {
+                enum_values.insert(EuclidValue::CardIssuer(StrValue { value: card_issuer }));
+            }
+        }
+        if let Some(is_domestic) = is_domestic {
+            enum_values.insert(EuclidValue::IsDomestic(is_domestic));
+        }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -118,6 +158,30 @@ impl Context {
             }),
         )]);
 
+        if let Some(time) = time {
+            numeric_values.insert(
+                EuclidKey::HourOfDay,
+                EuclidValue::HourOfDay(types::NumValue {
+                    number: time.hour_of_day,
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::DayOfWeek,
+                EuclidValue::DayOfWeek(types::NumValue {
+                    number: time.day_of_week,
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::DayOfMonth,
+                EuclidValue::DayOfMonth(types::NumValue {
+                    number: time.day_of_month,
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
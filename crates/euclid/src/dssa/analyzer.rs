@@ -8,13 +8,13 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::{graph::Memoization, types::EuclidAnalysable};
 use crate::{
-    dssa::{graph, state_machine, truth, types},
+    dssa::{graph, regions, state_machine, truth, types},
     frontend::{
         ast,
         dir::{self, EuclidDirFilter},
         vir,
     },
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValueRefinement},
 };
 
 /// Analyses conflicting assertions on the same key in a conjunctive context.
@@ -136,6 +136,65 @@ fn analyze_negated_assertions(
     Ok(())
 }
 
+/// Analyses range conditions on bounded numeric keys in a conjunctive context.
+///
+/// For example,
+/// ```notrust
+/// hour_of_day >= 18 && ... && hour_of_day < 9
+/// ```notrust
+/// This is a condition that will never evaluate to `true` since no hour of the day
+/// lies in both of the ranges. Conditions outside the domain of the key, such as
+/// `day_of_week = 8`, are caught in the same way.
+pub fn analyze_impossible_ranges(
+    keywise_range_assertions: &FxHashMap<dir::DirKey, Vec<&dir::DirValue>>,
+    assertion_metadata: &FxHashMap<&dir::DirValue, &Metadata>,
+) -> Result<(), types::AnalysisError> {
+    for (key, values) in keywise_range_assertions {
+        let (mut lower, mut upper) = if let Some(range) = key.kind.get_num_range() {
+            range
+        } else {
+            continue;
+        };
+
+        for num_value in values.iter().filter_map(|val| val.get_num_value()) {
+            let number = num_value.number;
+            match num_value.refinement {
+                None => {
+                    lower = lower.max(number);
+                    upper = upper.min(number);
+                }
+                Some(NumValueRefinement::GreaterThan) => lower = lower.max(number + 1),
+                Some(NumValueRefinement::GreaterThanEqual) => lower = lower.max(number),
+                Some(NumValueRefinement::LessThan) => upper = upper.min(number - 1),
+                Some(NumValueRefinement::LessThanEqual) => upper = upper.min(number),
+                Some(NumValueRefinement::NotEqual) => {}
+            }
+        }
+
+        if lower > upper {
+            let error_type = types::AnalysisErrorType::ImpossibleRange {
+                key: key.clone(),
+                values: values
+                    .iter()
+                    .map(|val| types::ValueData {
+                        value: (*val).clone(),
+                        metadata: assertion_metadata
+                            .get(val)
+                            .map(|meta| (*meta).clone())
+                            .unwrap_or_default(),
+                    })
+                    .collect(),
+            };
+
+            Err(types::AnalysisError {
+                error_type,
+                metadata: Default::default(),
+            })?;
+        }
+    }
+    Ok(())
+}
+
 fn perform_condition_analyses(
     context: &types::ConjunctiveContext<'_>,
 ) -> Result<(), types::AnalysisError> {
@@ -147,6 +206,8 @@ fn perform_condition_analyses(
         FxHashMap::default();
     let mut keywise_negations: FxHashMap<dir::DirKey, FxHashSet<&dir::DirValue>> =
         FxHashMap::default();
+    let mut keywise_range_assertions: FxHashMap<dir::DirKey, Vec<&dir::DirValue>> =
+        FxHashMap::default();
 
     for ctx_val in context {
         let key = if let Some(k) = ctx_val.value.get_key() {
@@ -159,6 +220,17 @@ fn perform_condition_analyses(
             continue;
         }
 
+        if matches!(key.kind.get_type(), DataType::Number) {
+            if let types::CtxValueKind::Assertion(val) = ctx_val.value {
+                keywise_range_assertions
+                    .entry(key.clone())
+                    .or_default()
+                    .push(val);
+                assertion_metadata.insert(val, ctx_val.metadata);
+            }
+            continue;
+        }
+
         if !matches!(key.kind.get_type(), DataType::EnumVariant) {
             continue;
         }
@@ -197,6 +269,7 @@ fn perform_condition_analyses(
         &keywise_negations,
         &negation_metadata,
     )?;
+    analyze_impossible_ranges(&keywise_range_assertions, &assertion_metadata)?;
 
     Ok(())
 }
@@ -216,14 +289,120 @@ fn perform_context_analyses(
     Ok(())
 }
 
-pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
-    program: ast::Program<O>,
-    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
-) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
-    let dir_program = ast::lowering::lower_program(program)?;
+/// The maximum number of disjoint regions tracked while checking how the rules of a program
+/// cover its inputs, beyond which the check is skipped so that large programs stay cheap to analyze
+const MAX_TRACKED_REGIONS: usize = 1024;
+/// The maximum number of regions listed for the inputs that use the default selection
+const MAX_REPORTED_REGIONS: usize = 10;
 
-    let selection_data = state_machine::make_connector_selection_data(&dir_program);
-    let mut ctx_manager = state_machine::AnalysisContextManager::new(&dir_program, &selection_data);
+/// Analyses how the rules of a program interact with one another.
+///
+/// Rules are matched in order, so a rule matching only inputs that are already matched by
+/// earlier rules is never selected. For example,
+/// ```notrust
+/// card_rule: ["stripe"] { payment_method = card }
+/// large_card_rule: ["adyen"] { payment_method = card & amount > 1000 }
+/// ```notrust
+/// Here `large_card_rule` is shadowed by `card_rule`. Rules matching some of the inputs of an
+/// earlier rule with a different output, and the inputs that fall through to the default
+/// selection, are reported as well. Regions are not checked against the knowledge graph, so
+/// the reported inputs can include combinations that never occur, such as a wallet payment
+/// with a card network.
+pub fn analyze_rule_interactions<O: PartialEq>(
+    program: &dir::DirProgram<O>,
+) -> Vec<types::AnalysisWarning> {
+    let make_warning = |warning_type| types::AnalysisWarning {
+        warning_type,
+        metadata: Default::default(),
+    };
+
+    let rule_regions: Vec<Vec<regions::Region>> =
+        program.rules.iter().map(regions::rule_regions).collect();
+    let mut warnings = Vec::new();
+
+    for (index, (rule, current_regions)) in program.rules.iter().zip(&rule_regions).enumerate() {
+        if current_regions.is_empty() {
+            warnings.push(make_warning(types::AnalysisWarningType::UnreachableRule {
+                rule: rule.name.clone(),
+            }));
+            continue;
+        }
+
+        let overlapping_rules: Vec<(&dir::DirRule<O>, &Vec<regions::Region>)> = program.rules
+            [..index]
+            .iter()
+            .zip(&rule_regions[..index])
+            .filter(|(_, earlier_regions)| {
+                earlier_regions.iter().any(|earlier| {
+                    current_regions
+                        .iter()
+                        .any(|current| current.overlaps(earlier))
+                })
+            })
+            .collect();
+
+        let earlier_regions: Vec<regions::Region> = overlapping_rules
+            .iter()
+            .flat_map(|(_, earlier_regions)| earlier_regions.iter().cloned())
+            .collect();
+
+        let is_shadowed = regions::subtract_all(
+            current_regions.clone(),
+            &earlier_regions,
+            MAX_TRACKED_REGIONS,
+        )
+        .map_or(false, |remaining| remaining.is_empty());
+
+        if is_shadowed {
+            warnings.push(make_warning(types::AnalysisWarningType::ShadowedRule {
+                rule: rule.name.clone(),
+                shadowed_by: overlapping_rules
+                    .iter()
+                    .map(|(earlier, _)| earlier.name.clone())
+                    .collect(),
+            }));
+            continue;
+        }
+
+        for (earlier, _) in overlapping_rules {
+            if earlier.connector_selection != rule.connector_selection {
+                warnings.push(make_warning(types::AnalysisWarningType::OverlappingRules {
+                    rule: rule.name.clone(),
+                    overlapping_rule: earlier.name.clone(),
+                }));
+            }
+        }
+    }
+
+    let all_regions: Vec<regions::Region> = rule_regions.into_iter().flatten().collect();
+    if let Some(unmatched) = regions::subtract_all(
+        vec![regions::Region::universe()],
+        &all_regions,
+        MAX_TRACKED_REGIONS,
+    ) {
+        if !unmatched.is_empty() {
+            warnings.push(make_warning(
+                types::AnalysisWarningType::DefaultSelectionReachable {
+                    truncated: unmatched.len() > MAX_REPORTED_REGIONS,
+                    regions: unmatched
+                        .iter()
+                        .take(MAX_REPORTED_REGIONS)
+                        .map(regions::Region::to_region_constraints)
+                        .collect(),
+                },
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn analyze_dir_program<O: EuclidAnalysable>(
+    dir_program: &dir::DirProgram<O>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<(), types::AnalysisError> {
+    let selection_data = state_machine::make_connector_selection_data(dir_program);
+    let mut ctx_manager = state_machine::AnalysisContextManager::new(dir_program, &selection_data);
     while let Some(ctx) = ctx_manager.advance().map_err(|err| types::AnalysisError {
         metadata: Default::default(),
         error_type: types::AnalysisErrorType::StateMachine(err),
@@ -231,9 +410,31 @@ pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
         perform_context_analyses(ctx, knowledge_graph.unwrap_or(&truth::ANALYSIS_GRAPH))?;
     }
 
+    Ok(())
+}
+
+pub fn analyze<O: EuclidAnalysable + EuclidDirFilter>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<vir::ValuedProgram<O>, types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    analyze_dir_program(&dir_program, knowledge_graph)?;
+
     dir::lowering::lower_program(dir_program)
 }
 
+/// Same as [`analyze`], but also returns the warnings from [`analyze_rule_interactions`]
+pub fn analyze_with_warnings<O: EuclidAnalysable + EuclidDirFilter + PartialEq>(
+    program: ast::Program<O>,
+    knowledge_graph: Option<&graph::KnowledgeGraph<'_>>,
+) -> Result<(vir::ValuedProgram<O>, Vec<types::AnalysisWarning>), types::AnalysisError> {
+    let dir_program = ast::lowering::lower_program(program)?;
+    analyze_dir_program(&dir_program, knowledge_graph)?;
+    let warnings = analyze_rule_interactions(&dir_program);
+
+    Ok((dir::lowering::lower_program(dir_program)?, warnings))
+}
+
 #[cfg(all(test, feature = "ast_parser"))]
 mod tests {
     #![allow(clippy::panic, clippy::expect_used)]
@@ -286,6 +487,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_impossible_range_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            night_shift: ["adyen"]
+            {
+                hour_of_day >= 18 {
+                    payment_method = card & hour_of_day < 9
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        if let Err(types::AnalysisError {
+            error_type: types::AnalysisErrorType::ImpossibleRange { key, values },
+            ..
+        }) = analysis_result
+        {
+            assert!(
+                matches!(key.kind, dir::DirKeyKind::HourOfDay),
+                "Key should be hour_of_day"
+            );
+            assert_eq!(values.len(), 2, "There should be 2 range conditions");
+        } else {
+            panic!("Did not receive impossible range error");
+        }
+    }
+
+    #[test]
+    fn test_out_of_domain_range_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            weekend: ["adyen"]
+            {
+                day_of_week > 7
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let analysis_result = analyze(program, None);
+
+        assert!(
+            matches!(
+                analysis_result,
+                Err(types::AnalysisError {
+                    error_type: types::AnalysisErrorType::ImpossibleRange { .. },
+                    ..
+                })
+            ),
+            "Expected impossible range error"
+        );
+    }
+
+    #[test]
+    fn test_valid_time_window() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            business_hours: ["adyen"]
+            {
+                hour_of_day >= 9 & hour_of_day < 18 & day_of_week <= 5
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        analyze(program, None).expect("Time window should pass analysis");
+    }
+
     #[test]
     fn test_exhaustive_negation_detection() {
         let program_str = r#"
@@ -366,6 +639,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_shadowed_rule_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            cards: ["adyen"]
+            {
+                payment_method = card
+            }
+
+            wallets: ["checkout"]
+            {
+                payment_method = wallet
+            }
+
+            large_cards_and_wallets: ["paypal"]
+            {
+                amount > 1000 {
+                    payment_method = (card, wallet)
+                }
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (_, warnings) = analyze_with_warnings(program, None).expect("Analysis");
+
+        let shadowed = warnings
+            .iter()
+            .find_map(|warning| match &warning.warning_type {
+                types::AnalysisWarningType::ShadowedRule { rule, shadowed_by } => {
+                    Some((rule.as_str(), shadowed_by.clone()))
+                }
+                _ => None,
+            });
+
+        assert_eq!(
+            shadowed,
+            Some((
+                "large_cards_and_wallets",
+                vec!["cards".to_string(), "wallets".to_string()]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_overlapping_rules_detection() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            business_hours: ["adyen"]
+            {
+                hour_of_day >= 9 & hour_of_day < 18
+            }
+
+            afternoon: ["checkout"]
+            {
+                hour_of_day >= 12
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (_, warnings) = analyze_with_warnings(program, None).expect("Analysis");
+
+        assert!(warnings.iter().any(|warning| matches!(
+            &warning.warning_type,
+            types::AnalysisWarningType::OverlappingRules { rule, overlapping_rule }
+                if rule == "afternoon" && overlapping_rule == "business_hours"
+        )));
+        assert!(!warnings.iter().any(|warning| matches!(
+            warning.warning_type,
+            types::AnalysisWarningType::ShadowedRule { .. }
+        )));
+
+        let regions = warnings
+            .iter()
+            .find_map(|warning| match &warning.warning_type {
+                types::AnalysisWarningType::DefaultSelectionReachable { regions, .. } => {
+                    Some(regions.clone())
+                }
+                _ => None,
+            })
+            .expect("Default selection should be reachable");
+
+        assert_eq!(regions.len(), 1, "Only hours before 9 use the default");
+        assert!(matches!(
+            regions[0].as_slice(),
+            [types::RegionConstraint {
+                condition: types::RegionCondition::Ranges(ranges),
+                ..
+            }] if matches!(ranges.as_slice(), [types::NumRange { start: 0, end: 8 }])
+        ));
+    }
+
+    #[test]
+    fn test_exhaustive_rules_leave_no_default() {
+        let program_str = r#"
+            default: ["stripe"]
+
+            small: ["adyen"]
+            {
+                amount <= 1000
+            }
+
+            large: ["checkout"]
+            {
+                amount > 1000
+            }
+        "#;
+
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let (_, warnings) = analyze_with_warnings(program, None).expect("Analysis");
+
+        assert!(warnings.is_empty(), "Unexpected warnings: {warnings:?}");
+    }
+
     #[test]
     fn test_negation_graph_analysis() {
         let graph = knowledge! {crate
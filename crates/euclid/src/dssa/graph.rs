@@ -12,7 +12,7 @@ use serde::Serialize;
 use crate::{
     dssa::types,
     frontend::dir,
-    types::{DataType, Metadata},
+    types::{DataType, Metadata, NumValue, NumValueRefinement},
     utils,
 };
 
@@ -249,6 +249,14 @@ pub enum AnalysisTrace {
     },
 }
 
+#[derive(Debug, Clone)]
+pub enum ValueValidity {
+    Valid,
+    /// The value does not have a node in the graph
+    NotInGraph,
+    Invalid(Weak<AnalysisTrace>),
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type", content = "details", rename_all = "snake_case")]
 pub enum AnalysisError {
@@ -647,6 +655,81 @@ impl<'a> KnowledgeGraphBuilder<'a> {
         Ok(node_id)
     }
 
+    /// Creates an aggregator node that is satisfied when the value of a time key lies in the
+    /// inclusive window `start..=end`. Windows where `start` is greater than `end` wrap around
+    /// the end of the key's range, e.g. `hour_of_day` from 22 to 6 covers the night.
+    pub fn make_time_window_aggregator<M: KgraphMetadata>(
+        &mut self,
+        key: dir::DirKeyKind,
+        start: i64,
+        end: i64,
+        info: Option<&'static str>,
+        metadata: Option<M>,
+        domain: Vec<DomainIdentifier<'_>>,
+    ) -> Result<NodeId, GraphError> {
+        let make_value: fn(NumValue) -> dir::DirValue = match key {
+            dir::DirKeyKind::HourOfDay => dir::DirValue::HourOfDay,
+            dir::DirKeyKind::DayOfWeek => dir::DirValue::DayOfWeek,
+            dir::DirKeyKind::DayOfMonth => dir::DirValue::DayOfMonth,
+            _ => Err(GraphError::MalformedGraph {
+                reason: format!("'{key}' is not a time key"),
+            })?,
+        };
+
+        let (lower, upper) = key.get_num_range().ok_or(GraphError::MalformedGraph {
+            reason: format!("'{key}' does not have a bounded range"),
+        })?;
+
+        if !(lower..=upper).contains(&start) || !(lower..=upper).contains(&end) {
+            Err(GraphError::MalformedGraph {
+                reason: format!("Time window {start}..={end} is out of range for '{key}'"),
+            })?;
+        }
+
+        let start_node = self.make_value_node(
+            make_value(NumValue {
+                number: start,
+                refinement: Some(NumValueRefinement::GreaterThanEqual),
+            })
+            .into(),
+            None,
+            domain.clone(),
+            None::<()>,
+        )?;
+        let end_node = self.make_value_node(
+            make_value(NumValue {
+                number: end,
+                refinement: Some(NumValueRefinement::LessThanEqual),
+            })
+            .into(),
+            None,
+            domain.clone(),
+            None::<()>,
+        )?;
+
+        if start <= end {
+            self.make_all_aggregator(
+                &[
+                    (start_node, Relation::Positive, Strength::Strong),
+                    (end_node, Relation::Positive, Strength::Strong),
+                ],
+                info,
+                metadata,
+                domain,
+            )
+        } else {
+            self.make_any_aggregator(
+                &[
+                    (start_node, Relation::Positive),
+                    (end_node, Relation::Positive),
+                ],
+                info,
+                metadata,
+                domain,
+            )
+        }
+    }
+
     fn ensure_node_exists(&self, id: NodeId) -> Result<(), GraphError> {
         if self.nodes.contains_key(id) {
             Ok(())
@@ -893,12 +976,24 @@ impl<'a> KnowledgeGraph<'a> {
         analysis_ctx: &AnalysisContext,
         memo: &mut Memoization,
     ) -> Result<bool, GraphError> {
+        self.check_value_validity_with_trace(val, analysis_ctx, memo)
+            .map(|validity| matches!(validity, ValueValidity::Valid))
+    }
+
+    /// Same as [`Self::check_value_validity`], but also returns the trace of the constraints that
+    /// were not satisfied when the value is invalid. The trace is kept alive by the memoization.
+    pub fn check_value_validity_with_trace(
+        &self,
+        val: dir::DirValue,
+        analysis_ctx: &AnalysisContext,
+        memo: &mut Memoization,
+    ) -> Result<ValueValidity, GraphError> {
         let maybe_node_id = self.value_map.get(&NodeValue::Value(val));
 
         let node_id = if let Some(nid) = maybe_node_id {
             nid
         } else {
-            return Ok(false);
+            return Ok(ValueValidity::NotInGraph);
         };
 
         let result = self.check_node(
@@ -910,11 +1005,8 @@ impl<'a> KnowledgeGraph<'a> {
         );
 
         match result {
-            Ok(_) => Ok(true),
-            Err(e) => {
-                e.get_analysis_trace()?;
-                Ok(false)
-            }
+            Ok(_) => Ok(ValueValidity::Valid),
+            Err(e) => e.get_analysis_trace().map(ValueValidity::Invalid),
         }
     }
 
@@ -1177,6 +1269,35 @@ mod test {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_value_validity_with_trace() {
+        let graph = knowledge! {crate
+            PaymentMethod(Card) ->> CaptureMethod(Automatic);
+        };
+        let memo = &mut Memoization::new();
+        let ctx = AnalysisContext::from_dir_values([dirval!(PaymentMethod = Wallet)]);
+
+        let validity = graph
+            .check_value_validity_with_trace(dirval!(CaptureMethod = Automatic), &ctx, memo)
+            .expect("Validity check");
+        let trace = match validity {
+            ValueValidity::Invalid(trace) => trace.upgrade().expect("Trace kept in memo"),
+            other => panic!("Expected invalid value, got {other:?}"),
+        };
+        assert!(matches!(
+            trace.as_ref(),
+            AnalysisTrace::Value {
+                value: NodeValue::Value(dir::DirValue::CaptureMethod(_)),
+                ..
+            }
+        ));
+
+        let validity = graph
+            .check_value_validity_with_trace(dirval!(CaptureMethod = Manual), &ctx, memo)
+            .expect("Validity check");
+        assert!(matches!(validity, ValueValidity::NotInGraph));
+    }
+
     #[test]
     fn test_strong_negative_relation_success() {
         let graph = knowledge! {crate
@@ -1240,6 +1361,79 @@ mod test {
         ));
     }
 
+    fn make_night_only_card_graph() -> KnowledgeGraph<'static> {
+        let mut builder = KnowledgeGraphBuilder::new();
+        let window = builder
+            .make_time_window_aggregator(
+                dir::DirKeyKind::HourOfDay,
+                22,
+                6,
+                None,
+                None::<()>,
+                Vec::new(),
+            )
+            .expect("Time window aggregator");
+        let card = builder
+            .make_value_node(
+                dirval!(PaymentMethod = Card).into(),
+                None,
+                Vec::new(),
+                None::<()>,
+            )
+            .expect("Value node");
+        builder
+            .make_edge(window, card, Strength::Strong, Relation::Positive)
+            .expect("Edge");
+        builder.build()
+    }
+
+    #[test]
+    fn test_time_window_aggregator_success() {
+        let graph = make_night_only_card_graph();
+        let memo = &mut Memoization::new();
+        let result = graph.key_value_analysis(
+            dirval!(PaymentMethod = Card),
+            &AnalysisContext::from_dir_values([
+                dirval!(PaymentMethod = Card),
+                dirval!(HourOfDay = 23),
+            ]),
+            memo,
+        );
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_time_window_aggregator_failure() {
+        let graph = make_night_only_card_graph();
+        let memo = &mut Memoization::new();
+        let result = graph.key_value_analysis(
+            dirval!(PaymentMethod = Card),
+            &AnalysisContext::from_dir_values([
+                dirval!(PaymentMethod = Card),
+                dirval!(HourOfDay = 12),
+            ]),
+            memo,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_time_window_aggregator_out_of_range() {
+        let mut builder = KnowledgeGraphBuilder::new();
+        let result = builder.make_time_window_aggregator(
+            dir::DirKeyKind::DayOfWeek,
+            1,
+            8,
+            None,
+            None::<()>,
+            Vec::new(),
+        );
+
+        assert!(matches!(result, Err(GraphError::MalformedGraph { .. })));
+    }
+
     #[test]
     fn test_all_aggregator_success() {
         let graph = knowledge! {crate
@@ -0,0 +1,424 @@
+//! Input Regions
+//!
+//! A region is a set of inputs described by a constraint on each of the keys it mentions, with
+//! keys that are not mentioned being unconstrained. Every path through the statements of a rule
+//! describes one region, and the set of inputs a rule matches is the union of its regions. This
+//! makes it possible to reason about how rules of a program interact with one another, for
+//! example whether the inputs of a rule are all matched by the rules before it.
+use crate::{
+    dssa::types,
+    frontend::dir,
+    types::{DataType, NumValueRefinement},
+};
+
+/// The constraint placed on the values of a single key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// The key takes one of the values
+    OneOf(Vec<dir::DirValue>),
+    /// The key takes any value except these, used for keys without a finite set of values
+    NoneOf(Vec<dir::DirValue>),
+    /// The key takes a number within one of the sorted, disjoint, inclusive ranges
+    Ranges(Vec<(i64, i64)>),
+}
+
+fn num_domain(key: &dir::DirKey) -> (i64, i64) {
+    key.kind.get_num_range().unwrap_or((i64::MIN, i64::MAX))
+}
+
+fn normalize_ranges(mut ranges: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    ranges.retain(|(start, end)| start <= end);
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    merged
+}
+
+fn value_ranges(key: &dir::DirKey, value: &dir::DirValue) -> Vec<(i64, i64)> {
+    let (lower, upper) = num_domain(key);
+    let num_value = match value.get_num_value() {
+        Some(num_value) => num_value,
+        None => return Vec::new(),
+    };
+    let number = num_value.number;
+
+    let ranges = match num_value.refinement {
+        None => vec![(number, number)],
+        Some(NumValueRefinement::GreaterThan) => vec![(number.saturating_add(1), upper)],
+        Some(NumValueRefinement::GreaterThanEqual) => vec![(number, upper)],
+        Some(NumValueRefinement::LessThan) => vec![(lower, number.saturating_sub(1))],
+        Some(NumValueRefinement::LessThanEqual) => vec![(lower, number)],
+        Some(NumValueRefinement::NotEqual) => vec![
+            (lower, number.saturating_sub(1)),
+            (number.saturating_add(1), upper),
+        ],
+    };
+
+    normalize_ranges(
+        ranges
+            .into_iter()
+            .map(|(start, end)| (start.max(lower), end.min(upper)))
+            .collect(),
+    )
+}
+
+fn dedup_values(values: &[dir::DirValue]) -> Vec<dir::DirValue> {
+    let mut deduped: Vec<dir::DirValue> = Vec::with_capacity(values.len());
+    for value in values {
+        if !deduped.contains(value) {
+            deduped.push(value.clone());
+        }
+    }
+    deduped
+}
+
+impl Constraint {
+    /// Makes the constraint that a comparison places on its key
+    pub fn from_comparison(key: &dir::DirKey, comparison: &dir::DirComparison) -> Self {
+        match (key.kind.get_type(), &comparison.logic) {
+            (DataType::Number, logic) => {
+                let ranges = normalize_ranges(
+                    comparison
+                        .values
+                        .iter()
+                        .flat_map(|value| value_ranges(key, value))
+                        .collect(),
+                );
+
+                match logic {
+                    dir::DirComparisonLogic::PositiveDisjunction => Self::Ranges(ranges),
+                    dir::DirComparisonLogic::NegativeConjunction => {
+                        Self::Ranges(ranges).complement(key)
+                    }
+                }
+            }
+
+            (_, dir::DirComparisonLogic::PositiveDisjunction) => {
+                Self::OneOf(dedup_values(&comparison.values))
+            }
+
+            (_, dir::DirComparisonLogic::NegativeConjunction) => {
+                Self::NoneOf(dedup_values(&comparison.values)).normalize(key)
+            }
+        }
+    }
+
+    /// Converts exclusions on keys with a finite set of values into the values that remain
+    fn normalize(self, key: &dir::DirKey) -> Self {
+        match self {
+            Self::NoneOf(excluded) => match key.kind.get_value_set() {
+                Some(value_set) => Self::OneOf(
+                    value_set
+                        .into_iter()
+                        .filter(|value| !excluded.contains(value))
+                        .collect(),
+                ),
+                None => Self::NoneOf(excluded),
+            },
+            other => other,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::OneOf(values) => values.is_empty(),
+            Self::NoneOf(_) => false,
+            Self::Ranges(ranges) => ranges.is_empty(),
+        }
+    }
+
+    pub fn complement(&self, key: &dir::DirKey) -> Self {
+        match self {
+            Self::OneOf(values) => Self::NoneOf(values.clone()).normalize(key),
+            Self::NoneOf(values) => Self::OneOf(values.clone()),
+            Self::Ranges(ranges) => {
+                let (lower, upper) = num_domain(key);
+                let mut complement = Vec::with_capacity(ranges.len() + 1);
+                let mut next_start = Some(lower);
+
+                for &(start, end) in ranges {
+                    if let Some(gap_start) = next_start {
+                        if gap_start < start {
+                            complement.push((gap_start, start.saturating_sub(1)));
+                        }
+                    }
+                    next_start = end.checked_add(1).filter(|next| *next <= upper);
+                }
+
+                if let Some(gap_start) = next_start {
+                    complement.push((gap_start, upper));
+                }
+
+                Self::Ranges(normalize_ranges(complement))
+            }
+        }
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Self::OneOf(first), Self::OneOf(second)) => Self::OneOf(
+                first
+                    .iter()
+                    .filter(|value| second.contains(value))
+                    .cloned()
+                    .collect(),
+            ),
+
+            (Self::OneOf(allowed), Self::NoneOf(excluded))
+            | (Self::NoneOf(excluded), Self::OneOf(allowed)) => Self::OneOf(
+                allowed
+                    .iter()
+                    .filter(|value| !excluded.contains(value))
+                    .cloned()
+                    .collect(),
+            ),
+
+            (Self::NoneOf(first), Self::NoneOf(second)) => {
+                let mut excluded = first.clone();
+                excluded.extend(second.iter().cloned());
+                Self::NoneOf(dedup_values(&excluded))
+            }
+
+            (Self::Ranges(first), Self::Ranges(second)) => {
+                let mut ranges = Vec::new();
+                for &(first_start, first_end) in first {
+                    for &(second_start, second_end) in second {
+                        let start = first_start.max(second_start);
+                        let end = first_end.min(second_end);
+                        if start <= end {
+                            ranges.push((start, end));
+                        }
+                    }
+                }
+                Self::Ranges(normalize_ranges(ranges))
+            }
+
+            // Numeric and non-numeric constraints are never placed on the same key
+            (Self::Ranges(_), _) | (_, Self::Ranges(_)) => Self::OneOf(Vec::new()),
+        }
+    }
+
+    pub fn to_region_condition(&self, key: &dir::DirKey) -> types::RegionCondition {
+        match self {
+            Self::OneOf(values) => match key.kind.get_value_set() {
+                // Render whichever of the values or the excluded values is shorter
+                Some(value_set) if values.len() * 2 > value_set.len() => {
+                    types::RegionCondition::NoneOf(
+                        value_set
+                            .into_iter()
+                            .filter(|value| !values.contains(value))
+                            .collect(),
+                    )
+                }
+                _ => types::RegionCondition::OneOf(values.clone()),
+            },
+            Self::NoneOf(values) => types::RegionCondition::NoneOf(values.clone()),
+            Self::Ranges(ranges) => types::RegionCondition::Ranges(
+                ranges
+                    .iter()
+                    .map(|&(start, end)| types::NumRange { start, end })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// A set of inputs, described by the constraints on the keys it mentions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    constraints: Vec<(dir::DirKey, Constraint)>,
+}
+
+impl Region {
+    /// The region of all inputs
+    pub fn universe() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.constraints
+            .iter()
+            .any(|(_, constraint)| constraint.is_empty())
+    }
+
+    /// Narrows the region to the inputs that also satisfy the constraint
+    pub fn constrain(&mut self, key: &dir::DirKey, constraint: &Constraint) {
+        match self.constraints.iter_mut().find(|(k, _)| k == key) {
+            Some((_, existing)) => *existing = existing.intersect(constraint),
+            None => self.constraints.push((key.clone(), constraint.clone())),
+        }
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        let mut intersection = self.clone();
+        for (key, constraint) in &other.constraints {
+            intersection.constrain(key, constraint);
+        }
+        intersection
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// Splits the inputs of this region that are not in `other` into disjoint regions
+    pub fn subtract(&self, other: &Self) -> Vec<Self> {
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+
+        let mut pieces = Vec::new();
+        let mut remaining = self.clone();
+        for (key, constraint) in &other.constraints {
+            let mut piece = remaining.clone();
+            piece.constrain(key, &constraint.complement(key));
+            if !piece.is_empty() {
+                pieces.push(piece);
+            }
+            remaining.constrain(key, constraint);
+        }
+
+        pieces
+    }
+
+    pub fn to_region_constraints(&self) -> Vec<types::RegionConstraint> {
+        self.constraints
+            .iter()
+            .filter(|(key, constraint)| match constraint {
+                Constraint::Ranges(ranges) => ranges.as_slice() != [num_domain(key)],
+                Constraint::NoneOf(values) => !values.is_empty(),
+                Constraint::OneOf(_) => true,
+            })
+            .map(|(key, constraint)| types::RegionConstraint {
+                key: key.clone(),
+                condition: constraint.to_region_condition(key),
+            })
+            .collect()
+    }
+}
+
+/// Subtracts every one of `others` from `regions`, returning the disjoint regions that remain.
+/// Returns `None` if the number of regions grows past `max_regions` along the way.
+pub fn subtract_all(
+    regions: Vec<Region>,
+    others: &[Region],
+    max_regions: usize,
+) -> Option<Vec<Region>> {
+    let mut remaining = regions;
+    for other in others {
+        remaining = remaining
+            .iter()
+            .flat_map(|region| region.subtract(other))
+            .collect();
+
+        if remaining.len() > max_regions {
+            return None;
+        }
+        if remaining.is_empty() {
+            break;
+        }
+    }
+
+    Some(remaining)
+}
+
+fn collect_statement_regions(
+    statement: &dir::DirIfStatement,
+    base: &Region,
+    regions: &mut Vec<Region>,
+) {
+    let mut region = base.clone();
+    for comparison in &statement.condition {
+        let key = match comparison.values.first() {
+            Some(value) => value.get_key(),
+            None => continue,
+        };
+
+        region.constrain(&key, &Constraint::from_comparison(&key, comparison));
+    }
+
+    if region.is_empty() {
+        return;
+    }
+
+    match &statement.nested {
+        None => regions.push(region),
+        Some(nested) => {
+            for nested_statement in nested {
+                collect_statement_regions(nested_statement, &region, regions);
+            }
+        }
+    }
+}
+
+/// The regions of the inputs matched by the rule, with unsatisfiable paths left out
+pub fn rule_regions<O>(rule: &dir::DirRule<O>) -> Vec<Region> {
+    let mut regions = Vec::new();
+    for statement in &rule.statements {
+        collect_statement_regions(statement, &Region::universe(), &mut regions);
+    }
+    regions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dirval, types::NumValue};
+
+    fn amount_key() -> dir::DirKey {
+        dir::DirKey::new(dir::DirKeyKind::PaymentAmount, None)
+    }
+
+    #[test]
+    fn test_range_complement() {
+        let hour_key = dir::DirKey::new(dir::DirKeyKind::HourOfDay, None);
+        let business_hours = Constraint::Ranges(vec![(9, 17)]);
+
+        assert_eq!(
+            business_hours.complement(&hour_key),
+            Constraint::Ranges(vec![(0, 8), (18, 23)])
+        );
+        assert_eq!(
+            business_hours.complement(&hour_key).complement(&hour_key),
+            business_hours
+        );
+    }
+
+    #[test]
+    fn test_region_subtraction() {
+        let pm_key = dir::DirKey::new(dir::DirKeyKind::PaymentMethod, None);
+        let mut cards = Region::universe();
+        cards.constrain(
+            &pm_key,
+            &Constraint::OneOf(vec![dirval!(PaymentMethod = Card)]),
+        );
+
+        let mut large_cards = cards.clone();
+        large_cards.constrain(
+            &amount_key(),
+            &Constraint::Ranges(value_ranges(
+                &amount_key(),
+                &dir::DirValue::PaymentAmount(NumValue {
+                    number: 1000,
+                    refinement: Some(NumValueRefinement::GreaterThan),
+                }),
+            )),
+        );
+
+        assert!(
+            large_cards.subtract(&cards).is_empty(),
+            "Large card payments are all card payments"
+        );
+
+        let mut expected_small_cards = cards.clone();
+        expected_small_cards.constrain(&amount_key(), &Constraint::Ranges(vec![(i64::MIN, 1000)]));
+        assert_eq!(cards.subtract(&large_cards), vec![expected_small_cards]);
+    }
+}
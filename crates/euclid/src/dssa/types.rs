@@ -139,6 +139,11 @@ pub enum AnalysisErrorType {
         assertion_metadata: Metadata,
         negation_metadata: Metadata,
     },
+    #[error("Key '{}' can never satisfy all of its range conditions", .key.kind)]
+    ImpossibleRange {
+        key: dir::DirKey,
+        values: Vec<ValueData>,
+    },
     #[error("Graph analysis error: {0:#?}")]
     GraphAnalysis(graph::AnalysisError, graph::Memoization),
     #[error("State machine error")]
@@ -151,6 +156,67 @@ pub enum AnalysisErrorType {
     NotSupported,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisWarning {
+    #[serde(flatten)]
+    pub warning_type: AnalysisWarningType,
+    pub metadata: Metadata,
+}
+
+impl fmt::Display for AnalysisWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.warning_type.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NumRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", content = "values", rename_all = "snake_case")]
+pub enum RegionCondition {
+    OneOf(Vec<dir::DirValue>),
+    NoneOf(Vec<dir::DirValue>),
+    Ranges(Vec<NumRange>),
+}
+
+/// The condition placed on a key by a region of inputs, keys that are not present in a region
+/// can take any value
+#[derive(Debug, Clone, Serialize)]
+pub struct RegionConstraint {
+    pub key: dir::DirKey,
+    pub condition: RegionCondition,
+}
+
+/// Problems with the way rules of a program interact with one another. Unlike
+/// [`AnalysisError`], these do not make the program invalid.
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+#[serde(tag = "type", content = "info", rename_all = "snake_case")]
+pub enum AnalysisWarningType {
+    #[error("Rule '{rule}' can never match any input")]
+    UnreachableRule { rule: String },
+    #[error("Rule '{rule}' only matches inputs already matched by earlier rules")]
+    ShadowedRule {
+        rule: String,
+        shadowed_by: Vec<String>,
+    },
+    #[error("Rule '{rule}' matches some inputs of the earlier rule '{overlapping_rule}' with a different output")]
+    OverlappingRules {
+        rule: String,
+        overlapping_rule: String,
+    },
+    #[error("Some inputs are not matched by any rule and use the default selection")]
+    DefaultSelectionReachable {
+        /// Disjoint regions of the inputs that are not matched by any rule
+        regions: Vec<Vec<RegionConstraint>>,
+        /// Whether there are more regions than the ones listed
+        truncated: bool,
+    },
+}
+
 #[derive(Debug, Clone)]
 pub enum ValueType {
     EnumVariants(Vec<EuclidValue>),
@@ -35,6 +35,8 @@ collect_variants!(Currency);
 collect_variants!(Country);
 collect_variants!(Connector);
 collect_variants!(SetupFutureUsage);
+collect_variants!(IssuerCardType);
+collect_variants!(IsDomestic);
 
 #[derive(
     Debug,
@@ -189,3 +191,56 @@ pub enum MandateType {
     SingleUse,
     MultiUse,
 }
+
+/// The funding type of a card as reported by its issuer
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumVariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
+pub enum IssuerCardType {
+    Credit,
+    Debit,
+    Prepaid,
+}
+
+/// Whether the card was issued in the business country of the payment
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::EnumVariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum IsDomestic {
+    True,
+    False,
+}
+
+impl From<bool> for IsDomestic {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod diff;
 pub mod lowering;
 #[cfg(feature = "ast_parser")]
 pub mod parser;
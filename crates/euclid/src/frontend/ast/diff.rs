@@ -0,0 +1,294 @@
+//! Structural diff between two versions of a program
+//!
+//! Rules are matched by name, and statements of matching rules are compared by their position.
+//! Comparison metadata is ignored since it is not used when running a program.
+use serde::Serialize;
+
+use crate::frontend::ast;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueChange<T> {
+    pub from: T,
+    pub to: T,
+}
+
+impl<T: PartialEq> ValueChange<T> {
+    fn between(from: T, to: T) -> Option<Self> {
+        (from != to).then_some(Self { from, to })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum StatementDiff {
+    Added {
+        index: usize,
+        statement: ast::IfStatement,
+    },
+    Removed {
+        index: usize,
+        statement: ast::IfStatement,
+    },
+    Modified {
+        index: usize,
+        added_comparisons: Vec<ast::Comparison>,
+        removed_comparisons: Vec<ast::Comparison>,
+        nested: Vec<StatementDiff>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum RuleDiff<O> {
+    Added {
+        name: String,
+        position: usize,
+        rule: ast::Rule<O>,
+    },
+    Removed {
+        name: String,
+        position: usize,
+        rule: ast::Rule<O>,
+    },
+    Modified {
+        name: String,
+        /// Set if the rule moved, which changes the order in which it is matched
+        position: Option<ValueChange<usize>>,
+        connector_selection: Option<ValueChange<O>>,
+        statements: Vec<StatementDiff>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProgramDiff<O> {
+    pub default_selection: Option<ValueChange<O>>,
+    pub rules: Vec<RuleDiff<O>>,
+}
+
+impl<O> ProgramDiff<O> {
+    pub fn is_empty(&self) -> bool {
+        self.default_selection.is_none() && self.rules.is_empty()
+    }
+}
+
+fn is_same_comparison(first: &ast::Comparison, second: &ast::Comparison) -> bool {
+    first.lhs == second.lhs && first.comparison == second.comparison && first.value == second.value
+}
+
+fn diff_statement_lists(from: &[ast::IfStatement], to: &[ast::IfStatement]) -> Vec<StatementDiff> {
+    let mut diffs = Vec::new();
+
+    for (index, (from_statement, to_statement)) in from.iter().zip(to).enumerate() {
+        let added_comparisons: Vec<ast::Comparison> = to_statement
+            .condition
+            .iter()
+            .filter(|comparison| {
+                !from_statement
+                    .condition
+                    .iter()
+                    .any(|other| is_same_comparison(comparison, other))
+            })
+            .cloned()
+            .collect();
+
+        let removed_comparisons: Vec<ast::Comparison> = from_statement
+            .condition
+            .iter()
+            .filter(|comparison| {
+                !to_statement
+                    .condition
+                    .iter()
+                    .any(|other| is_same_comparison(comparison, other))
+            })
+            .cloned()
+            .collect();
+
+        let nested = diff_statement_lists(
+            from_statement.nested.as_deref().unwrap_or_default(),
+            to_statement.nested.as_deref().unwrap_or_default(),
+        );
+
+        if !added_comparisons.is_empty() || !removed_comparisons.is_empty() || !nested.is_empty() {
+            diffs.push(StatementDiff::Modified {
+                index,
+                added_comparisons,
+                removed_comparisons,
+                nested,
+            });
+        }
+    }
+
+    diffs.extend(
+        from.iter()
+            .enumerate()
+            .skip(to.len())
+            .map(|(index, statement)| StatementDiff::Removed {
+                index,
+                statement: statement.clone(),
+            }),
+    );
+
+    diffs.extend(
+        to.iter()
+            .enumerate()
+            .skip(from.len())
+            .map(|(index, statement)| StatementDiff::Added {
+                index,
+                statement: statement.clone(),
+            }),
+    );
+
+    diffs
+}
+
+/// Computes the changes needed to turn the program `from` into the program `to`
+pub fn diff_programs<O: Clone + PartialEq>(
+    from: &ast::Program<O>,
+    to: &ast::Program<O>,
+) -> ProgramDiff<O> {
+    let mut rules = Vec::new();
+
+    for (from_position, from_rule) in from.rules.iter().enumerate() {
+        let matching_rule = to
+            .rules
+            .iter()
+            .enumerate()
+            .find(|(_, to_rule)| to_rule.name == from_rule.name);
+
+        match matching_rule {
+            None => rules.push(RuleDiff::Removed {
+                name: from_rule.name.clone(),
+                position: from_position,
+                rule: from_rule.clone(),
+            }),
+
+            Some((to_position, to_rule)) => {
+                let position = ValueChange::between(from_position, to_position);
+                let connector_selection = ValueChange::between(
+                    from_rule.connector_selection.clone(),
+                    to_rule.connector_selection.clone(),
+                );
+                let statements = diff_statement_lists(&from_rule.statements, &to_rule.statements);
+
+                if position.is_some() || connector_selection.is_some() || !statements.is_empty() {
+                    rules.push(RuleDiff::Modified {
+                        name: from_rule.name.clone(),
+                        position,
+                        connector_selection,
+                        statements,
+                    });
+                }
+            }
+        }
+    }
+
+    rules.extend(
+        to.rules
+            .iter()
+            .enumerate()
+            .filter(|(_, to_rule)| {
+                !from
+                    .rules
+                    .iter()
+                    .any(|from_rule| from_rule.name == to_rule.name)
+            })
+            .map(|(position, to_rule)| RuleDiff::Added {
+                name: to_rule.name.clone(),
+                position,
+                rule: to_rule.clone(),
+            }),
+    );
+
+    ProgramDiff {
+        default_selection: ValueChange::between(
+            from.default_selection.clone(),
+            to.default_selection.clone(),
+        ),
+        rules,
+    }
+}
+
+#[cfg(all(test, feature = "ast_parser"))]
+mod tests {
+    #![allow(clippy::expect_used, clippy::panic)]
+
+    use super::*;
+    use crate::{frontend::ast::parser, types::DummyOutput};
+
+    #[test]
+    fn test_program_diff() {
+        let from_str = r#"
+            default: ["stripe"]
+
+            cards: ["adyen"]
+            {
+                payment_method = card & amount > 100
+            }
+
+            wallets: ["checkout"]
+            {
+                payment_method = wallet
+            }
+        "#;
+
+        let to_str = r#"
+            default: ["stripe"]
+
+            wallets: ["checkout"]
+            {
+                payment_method = wallet
+            }
+
+            cards: ["adyen"]
+            {
+                payment_method = card & amount > 500
+            }
+
+            bank_debits: ["paypal"]
+            {
+                payment_method = bank_debit
+            }
+        "#;
+
+        let (_, from) = parser::program::<DummyOutput>(from_str).expect("Program");
+        let (_, to) = parser::program::<DummyOutput>(to_str).expect("Program");
+        let diff = diff_programs(&from, &to);
+
+        assert!(diff.default_selection.is_none());
+        assert_eq!(diff.rules.len(), 3, "Unexpected diff: {diff:?}");
+
+        match &diff.rules[0] {
+            RuleDiff::Modified {
+                name,
+                position: Some(ValueChange { from: 0, to: 1 }),
+                connector_selection: None,
+                statements,
+            } if name == "cards" => match statements.as_slice() {
+                [StatementDiff::Modified {
+                    index: 0,
+                    added_comparisons,
+                    removed_comparisons,
+                    nested,
+                }] => {
+                    assert_eq!(added_comparisons.len(), 1);
+                    assert_eq!(removed_comparisons.len(), 1);
+                    assert!(nested.is_empty());
+                }
+                _ => panic!("Expected the amount comparison to change: {statements:?}"),
+            },
+            other => panic!("Expected cards to be modified: {other:?}"),
+        }
+
+        assert!(matches!(
+            &diff.rules[1],
+            RuleDiff::Modified { name, position: Some(_), connector_selection: None, statements }
+                if name == "wallets" && statements.is_empty()
+        ));
+        assert!(matches!(
+            &diff.rules[2],
+            RuleDiff::Added { name, position: 2, .. } if name == "bank_debits"
+        ));
+
+        assert!(diff_programs(&to, &to).is_empty());
+    }
+}
@@ -68,15 +68,15 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key, these are payment_amount and the time keys
+/// (hour_of_day, day_of_week and day_of_month)
 
 macro_rules! lower_number {
     ($key:ident, $value:ident, $comp:ident) => {
         match $value {
             ast::ValueType::Number(num) => Ok(vec![dir::DirValue::$key(types::NumValue {
                 number: num,
-                refinement: $comp.into(),
+                refinement: number_refinement(&$comp),
             })]),
 
             ast::ValueType::NumberArray(na) => na
@@ -84,7 +84,7 @@ macro_rules! lower_number {
                 .map(|num| {
                     Ok(dir::DirValue::$key(types::NumValue {
                         number: num,
-                        refinement: $comp.clone().into(),
+                        refinement: number_refinement(&$comp),
                     }))
                 })
                 .collect(),
@@ -108,6 +108,14 @@ macro_rules! lower_number {
     };
 }
 
+/// refinement of the number values of a comparison
+///
+/// The values of a `/=` comparison keep their `NotEqual` refinement on top of the negated
+/// comparison logic, which is how existing programs are evaluated by the lowered backends
+fn number_refinement(comp: &ast::ComparisonType) -> Option<types::NumValueRefinement> {
+    comp.clone().into()
+}
+
 /// lowers the provided key & value to the respective DirValue
 ///
 /// For example
@@ -289,10 +297,24 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::BusinessLabel => lower_str!(BusinessLabel, value),
 
+        dir::DirKeyKind::IssuerCardType => lower_enum!(IssuerCardType, value),
+
+        dir::DirKeyKind::IssuerCountry => lower_enum!(IssuerCountry, value),
+
+        dir::DirKeyKind::CardIssuer => lower_str!(CardIssuer, value),
+
+        dir::DirKeyKind::IsDomestic => lower_enum!(IsDomestic, value),
+
         dir::DirKeyKind::MetaData => lower_metadata!(MetaData, value),
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::HourOfDay => lower_number!(HourOfDay, value, comparison),
+
+        dir::DirKeyKind::DayOfWeek => lower_number!(DayOfWeek, value, comparison),
+
+        dir::DirKeyKind::DayOfMonth => lower_number!(DayOfMonth, value, comparison),
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
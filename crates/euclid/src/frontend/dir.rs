@@ -312,6 +312,55 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_redirect")]
     CardRedirectType,
+    #[strum(
+        serialize = "hour_of_day",
+        detailed_message = "Hour of the day (0 to 23) in the timezone of the merchant",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "hour_of_day")]
+    HourOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week (1 for Monday to 7 for Sunday) in the timezone of the merchant",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "day_of_month",
+        detailed_message = "Day of the month (1 to 31) in the timezone of the merchant",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_month")]
+    DayOfMonth,
+    #[strum(
+        serialize = "issuer_card_type",
+        detailed_message = "Funding type of the card as reported by its issuer - eg. credit, debit, prepaid",
+        props(Category = "Issuer")
+    )]
+    #[serde(rename = "issuer_card_type")]
+    IssuerCardType,
+    #[strum(
+        serialize = "issuer_country",
+        detailed_message = "Country in which the card was issued",
+        props(Category = "Issuer")
+    )]
+    #[serde(rename = "issuer_country")]
+    IssuerCountry,
+    #[strum(
+        serialize = "card_issuer",
+        detailed_message = "Name of the bank that issued the card",
+        props(Category = "Issuer")
+    )]
+    #[serde(rename = "card_issuer")]
+    CardIssuer,
+    #[strum(
+        serialize = "is_domestic",
+        detailed_message = "Whether the card was issued in the business country of the payment",
+        props(Category = "Issuer")
+    )]
+    #[serde(rename = "is_domestic")]
+    IsDomestic,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -359,6 +408,13 @@ impl DirKeyKind {
             Self::BusinessLabel => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
             Self::CardRedirectType => types::DataType::EnumVariant,
+            Self::HourOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::Number,
+            Self::DayOfMonth => types::DataType::Number,
+            Self::IssuerCardType => types::DataType::EnumVariant,
+            Self::IssuerCountry => types::DataType::EnumVariant,
+            Self::CardIssuer => types::DataType::StrValue,
+            Self::IsDomestic => types::DataType::EnumVariant,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -485,6 +541,35 @@ impl DirKeyKind {
                     .map(DirValue::CardRedirectType)
                     .collect(),
             ),
+            Self::HourOfDay => None,
+            Self::DayOfWeek => None,
+            Self::DayOfMonth => None,
+            Self::IssuerCardType => Some(
+                enums::IssuerCardType::iter()
+                    .map(DirValue::IssuerCardType)
+                    .collect(),
+            ),
+            Self::IssuerCountry => Some(
+                enums::Country::iter()
+                    .map(DirValue::IssuerCountry)
+                    .collect(),
+            ),
+            Self::CardIssuer => None,
+            Self::IsDomestic => Some(
+                enums::IsDomestic::iter()
+                    .map(DirValue::IsDomestic)
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The inclusive range of values a numeric key can take, if it is bounded
+    pub fn get_num_range(&self) -> Option<(i64, i64)> {
+        match self {
+            Self::HourOfDay => Some((0, 23)),
+            Self::DayOfWeek => Some((1, 7)),
+            Self::DayOfMonth => Some((1, 31)),
+            _ => None,
         }
     }
 }
@@ -550,6 +635,20 @@ pub enum DirValue {
     SetupFutureUsage(enums::SetupFutureUsage),
     #[serde(rename = "card_redirect")]
     CardRedirectType(enums::CardRedirectType),
+    #[serde(rename = "hour_of_day")]
+    HourOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(types::NumValue),
+    #[serde(rename = "day_of_month")]
+    DayOfMonth(types::NumValue),
+    #[serde(rename = "issuer_card_type")]
+    IssuerCardType(enums::IssuerCardType),
+    #[serde(rename = "issuer_country")]
+    IssuerCountry(enums::Country),
+    #[serde(rename = "card_issuer")]
+    CardIssuer(types::StrValue),
+    #[serde(rename = "is_domestic")]
+    IsDomestic(enums::IsDomestic),
 }
 
 impl DirValue {
@@ -559,7 +658,7 @@ impl DirValue {
             Self::CardBin(_) => (DirKeyKind::CardBin, None),
             Self::RewardType(_) => (DirKeyKind::RewardType, None),
             Self::BusinessCountry(_) => (DirKeyKind::BusinessCountry, None),
-            Self::BillingCountry(_) => (DirKeyKind::CardBin, None),
+            Self::BillingCountry(_) => (DirKeyKind::BillingCountry, None),
             Self::BankTransferType(_) => (DirKeyKind::BankTransferType, None),
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
@@ -583,6 +682,13 @@ impl DirValue {
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
+            Self::HourOfDay(_) => (DirKeyKind::HourOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::DayOfMonth(_) => (DirKeyKind::DayOfMonth, None),
+            Self::IssuerCardType(_) => (DirKeyKind::IssuerCardType, None),
+            Self::IssuerCountry(_) => (DirKeyKind::IssuerCountry, None),
+            Self::CardIssuer(_) => (DirKeyKind::CardIssuer, None),
+            Self::IsDomestic(_) => (DirKeyKind::IsDomestic, None),
         };
 
         DirKey::new(kind, data)
@@ -617,19 +723,29 @@ impl DirValue {
             Self::BusinessLabel(_) => None,
             Self::SetupFutureUsage(_) => None,
             Self::CardRedirectType(_) => None,
+            Self::HourOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::DayOfMonth(_) => None,
+            Self::IssuerCardType(_) => None,
+            Self::IssuerCountry(_) => None,
+            Self::CardIssuer(_) => None,
+            Self::IsDomestic(_) => None,
         }
     }
 
     pub fn get_str_val(&self) -> Option<types::StrValue> {
         match self {
-            Self::CardBin(val) => Some(val.clone()),
+            Self::CardBin(val) | Self::CardIssuer(val) => Some(val.clone()),
             _ => None,
         }
     }
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val)
+            | Self::HourOfDay(val)
+            | Self::DayOfWeek(val)
+            | Self::DayOfMonth(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -662,6 +778,10 @@ impl DirValue {
             (Self::UpiType(ut1), Self::UpiType(ut2)) => ut1 == ut2,
             (Self::VoucherType(vt1), Self::VoucherType(vt2)) => vt1 == vt2,
             (Self::CardRedirectType(crt1), Self::CardRedirectType(crt2)) => crt1 == crt2,
+            (Self::IssuerCardType(ict1), Self::IssuerCardType(ict2)) => ict1 == ict2,
+            (Self::IssuerCountry(c1), Self::IssuerCountry(c2)) => c1 == c2,
+            (Self::CardIssuer(ci1), Self::CardIssuer(ci2)) => ci1 == ci2,
+            (Self::IsDomestic(id1), Self::IsDomestic(id2)) => id1 == id2,
             _ => false,
         }
     }
@@ -744,6 +864,13 @@ mod test {
             dirval!(CaptureMethod = Manual),
             dirval!(BillingCountry = UnitedStatesOfAmerica),
             dirval!(BusinessCountry = France),
+            dirval!(HourOfDay = 9),
+            dirval!(DayOfWeek = 1),
+            dirval!(DayOfMonth = 15),
+            dirval!(IssuerCardType = Prepaid),
+            dirval!(IssuerCountry = India),
+            dirval!(CardIssuer s= "HDFC BANK"),
+            dirval!(IsDomestic = True),
         ];
 
         for val in values {
@@ -3,8 +3,9 @@ use strum::VariantNames;
 use crate::enums::collect_variants;
 pub use crate::enums::{
     AuthenticationType, CaptureMethod, CardNetwork, Connector, Country, Country as BusinessCountry,
-    Country as BillingCountry, Currency as PaymentCurrency, MandateAcceptanceType, MandateType,
-    PaymentMethod, PaymentType, SetupFutureUsage,
+    Country as BillingCountry, Country as IssuerCountry, Currency as PaymentCurrency, IsDomestic,
+    IssuerCardType, MandateAcceptanceType, MandateType, PaymentMethod, PaymentType,
+    SetupFutureUsage,
 };
 
 #[derive(
@@ -87,6 +88,7 @@ pub enum WalletType {
     TouchNGo,
     Swish,
     Cashapp,
+    Venmo,
 }
 
 #[derive(
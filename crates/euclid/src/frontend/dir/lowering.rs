@@ -56,6 +56,7 @@ impl From<enums::WalletType> for global_enums::PaymentMethodType {
             enums::WalletType::TouchNGo => Self::TouchNGo,
             enums::WalletType::Swish => Self::Swish,
             enums::WalletType::Cashapp => Self::Cashapp,
+            enums::WalletType::Venmo => Self::Venmo,
         }
     }
 }
@@ -220,6 +221,13 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::RewardType(rt) => EuclidValue::PaymentMethodType(rt.into()),
         dir::DirValue::BusinessLabel(bl) => EuclidValue::BusinessLabel(bl),
         dir::DirValue::SetupFutureUsage(sfu) => EuclidValue::SetupFutureUsage(sfu),
+        dir::DirValue::HourOfDay(hod) => EuclidValue::HourOfDay(hod),
+        dir::DirValue::DayOfWeek(dow) => EuclidValue::DayOfWeek(dow),
+        dir::DirValue::DayOfMonth(dom) => EuclidValue::DayOfMonth(dom),
+        dir::DirValue::IssuerCardType(ict) => EuclidValue::IssuerCardType(ict),
+        dir::DirValue::IssuerCountry(ic) => EuclidValue::IssuerCountry(ic),
+        dir::DirValue::CardIssuer(ci) => EuclidValue::CardIssuer(ci),
+        dir::DirValue::IsDomestic(id) => EuclidValue::IsDomestic(id),
     })
 }
 
@@ -114,6 +114,7 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::KakaoPay => Ok(dirval!(WalletType = KakaoPay)),
             global_enums::PaymentMethodType::Twint => Ok(dirval!(WalletType = Twint)),
             global_enums::PaymentMethodType::Gcash => Ok(dirval!(WalletType = Gcash)),
+            global_enums::PaymentMethodType::Venmo => Ok(dirval!(WalletType = Venmo)),
             global_enums::PaymentMethodType::Vipps => Ok(dirval!(WalletType = Vipps)),
             global_enums::PaymentMethodType::Momo => Ok(dirval!(WalletType = Momo)),
             global_enums::PaymentMethodType::Alma => Ok(dirval!(PayLaterType = Alma)),
@@ -59,6 +59,20 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "hour_of_day")]
+    HourOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "day_of_month")]
+    DayOfMonth,
+    #[strum(serialize = "issuer_card_type")]
+    IssuerCardType,
+    #[strum(serialize = "issuer_country")]
+    IssuerCountry,
+    #[strum(serialize = "card_issuer")]
+    CardIssuer,
+    #[strum(serialize = "is_domestic")]
+    IsDomestic,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -76,6 +90,13 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::HourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::DayOfMonth,
+        DirKeyKind::IssuerCardType,
+        DirKeyKind::IssuerCountry,
+        DirKeyKind::CardIssuer,
+        DirKeyKind::IsDomestic,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -103,7 +124,7 @@ impl EuclidAnalysable for DummyOutput {
             .collect()
     }
 }
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct DummyOutput {
     pub outputs: Vec<String>,
 }
@@ -137,6 +158,13 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::HourOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::Number,
+            Self::DayOfMonth => DataType::Number,
+            Self::IssuerCardType => DataType::EnumVariant,
+            Self::IssuerCountry => DataType::EnumVariant,
+            Self::CardIssuer => DataType::StrValue,
+            Self::IsDomestic => DataType::EnumVariant,
         }
     }
 }
@@ -196,6 +224,18 @@ pub struct NumValue {
 }
 
 impl NumValue {
+    /// Checks whether a concrete number from the payment satisfies this value and its refinement
+    pub fn is_satisfied_by(&self, number: i64) -> bool {
+        match &self.refinement {
+            None => number == self.number,
+            Some(NumValueRefinement::NotEqual) => number != self.number,
+            Some(NumValueRefinement::GreaterThan) => number > self.number,
+            Some(NumValueRefinement::GreaterThanEqual) => number >= self.number,
+            Some(NumValueRefinement::LessThanEqual) => number <= self.number,
+            Some(NumValueRefinement::LessThan) => number < self.number,
+        }
+    }
+
     pub fn fits(&self, other: &Self) -> bool {
         let this_num = self.number;
         let other_num = other.number;
@@ -252,12 +292,22 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    HourOfDay(NumValue),
+    DayOfWeek(NumValue),
+    DayOfMonth(NumValue),
+    IssuerCardType(enums::IssuerCardType),
+    IssuerCountry(enums::Country),
+    CardIssuer(StrValue),
+    IsDomestic(enums::IsDomestic),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val)
+            | Self::HourOfDay(val)
+            | Self::DayOfWeek(val)
+            | Self::DayOfMonth(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -280,6 +330,13 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::HourOfDay(_) => EuclidKey::HourOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::DayOfMonth(_) => EuclidKey::DayOfMonth,
+            Self::IssuerCardType(_) => EuclidKey::IssuerCardType,
+            Self::IssuerCountry(_) => EuclidKey::IssuerCountry,
+            Self::CardIssuer(_) => EuclidKey::CardIssuer,
+            Self::IsDomestic(_) => EuclidKey::IsDomestic,
         }
     }
 }
@@ -128,8 +128,10 @@ pub fn get_valid_connectors_for_rule(rule: JsValue) -> JsResult {
 #[wasm_bindgen(js_name = analyzeProgram)]
 pub fn analyze_program(js_program: JsValue) -> JsResult {
     let program: ast::Program<ConnectorSelection> = serde_wasm_bindgen::from_value(js_program)?;
-    analyzer::analyze(program, SEED_DATA.get().map(|sd| &sd.kgraph)).err_to_js()?;
-    Ok(JsValue::NULL)
+    let (_, warnings) =
+        analyzer::analyze_with_warnings(program, SEED_DATA.get().map(|sd| &sd.kgraph))
+            .err_to_js()?;
+    Ok(serde_wasm_bindgen::to_value(&warnings)?)
 }
 
 #[wasm_bindgen(js_name = runProgram)]
@@ -200,11 +202,18 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::GiftCardType => dir_enums::GiftCardType::VARIANTS,
         dir::DirKeyKind::VoucherType => dir_enums::VoucherType::VARIANTS,
         dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::HourOfDay
+        | dir::DirKeyKind::DayOfWeek
+        | dir::DirKeyKind::DayOfMonth
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
+        | dir::DirKeyKind::CardIssuer
         | dir::DirKeyKind::MetaData => Err("Key does not have variants".to_string())?,
         dir::DirKeyKind::BankDebitType => dir_enums::BankDebitType::VARIANTS,
+        dir::DirKeyKind::IssuerCardType => dir_enums::IssuerCardType::VARIANTS,
+        dir::DirKeyKind::IssuerCountry => dir_enums::Country::VARIANTS,
+        dir::DirKeyKind::IsDomestic => dir_enums::IsDomestic::VARIANTS,
     };
 
     Ok(serde_wasm_bindgen::to_value(variants)?)
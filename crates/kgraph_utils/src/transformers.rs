@@ -17,6 +17,22 @@ impl IntoContext for BackendInput {
     fn into_context(self) -> Result<Vec<dir::DirValue>, KgraphError> {
         let mut ctx: Vec<dir::DirValue> = Vec::new();
 
+        if let Some(is_domestic) = self.is_domestic() {
+            ctx.push(dir::DirValue::IsDomestic(is_domestic));
+        }
+
+        if let Some(issuer) = self.issuer {
+            if let Some(card_type) = issuer.card_type {
+                ctx.push(dir::DirValue::IssuerCardType(card_type));
+            }
+            if let Some(issuer_country) = issuer.issuer_country {
+                ctx.push(dir::DirValue::IssuerCountry(issuer_country));
+            }
+            if let Some(card_issuer) = issuer.card_issuer {
+                ctx.push(dir::DirValue::CardIssuer(StrValue { value: card_issuer }));
+            }
+        }
+
         ctx.push(dir::DirValue::PaymentAmount(NumValue {
             number: self.payment.amount,
             refinement: None,
@@ -24,6 +40,21 @@ impl IntoContext for BackendInput {
 
         ctx.push(dir::DirValue::PaymentCurrency(self.payment.currency));
 
+        if let Some(time) = self.time {
+            ctx.push(dir::DirValue::HourOfDay(NumValue {
+                number: time.hour_of_day,
+                refinement: None,
+            }));
+            ctx.push(dir::DirValue::DayOfWeek(NumValue {
+                number: time.day_of_week,
+                refinement: None,
+            }));
+            ctx.push(dir::DirValue::DayOfMonth(NumValue {
+                number: time.day_of_month,
+                refinement: None,
+            }));
+        }
+
         if let Some(auth_type) = self.payment.authentication_type {
             ctx.push(dir::DirValue::AuthenticationType(auth_type));
         }
@@ -235,6 +266,7 @@ impl IntoDirValue for (api_enums::PaymentMethodType, api_enums::PaymentMethod) {
             api_enums::PaymentMethodType::KakaoPay => Ok(dirval!(WalletType = KakaoPay)),
             api_enums::PaymentMethodType::Twint => Ok(dirval!(WalletType = Twint)),
             api_enums::PaymentMethodType::Gcash => Ok(dirval!(WalletType = Gcash)),
+            api_enums::PaymentMethodType::Venmo => Ok(dirval!(WalletType = Venmo)),
             api_enums::PaymentMethodType::Vipps => Ok(dirval!(WalletType = Vipps)),
             api_enums::PaymentMethodType::Momo => Ok(dirval!(WalletType = Momo)),
             api_enums::PaymentMethodType::Alma => Ok(dirval!(PayLaterType = Alma)),
@@ -230,6 +230,28 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetExpiryFailed)
     }
 
+    /// Increments the value of the key, and sets the expiry of the key when it is created by the
+    /// increment. Returns the value after the increment.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        let value = self
+            .pool
+            .incr::<i64, _>(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::IncrementFailed)?;
+
+        if value == 1 {
+            self.set_expiry(key, seconds).await?;
+        }
+
+        Ok(value)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_expire_at(
         &self,
@@ -441,6 +463,36 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hgetall(key)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_fields_in_hash(
+        &self,
+        key: &str,
+        fields_to_increment: &[(&str, i64)],
+        ttl: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        for (field, increment) in fields_to_increment {
+            self.pool
+                .hincrby::<(), _, _>(key, *field, *increment)
+                .await
+                .into_report()
+                .change_context(errors::RedisError::IncrementHashFieldFailed)?;
+        }
+
+        self.set_expiry(key, ttl).await
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
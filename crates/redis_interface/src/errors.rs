@@ -50,6 +50,10 @@ pub enum RedisError {
     SetHashFieldFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to increment hash field in Redis")]
+    IncrementHashFieldFailed,
+    #[error("Failed to increment key value in Redis")]
+    IncrementFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -76,6 +76,10 @@ pub enum PTRunner {
     PaymentsSyncWorkflow,
     RefundWorkflowRouter,
     DeleteTokenizeDataWorkflow,
+    #[cfg(feature = "payouts")]
+    PayoutsSyncWorkflow,
+    RoutingActivationWorkflow,
+    IncomingWebhookRetentionWorkflow,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -100,6 +104,16 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
             Some(PTRunner::DeleteTokenizeDataWorkflow) => {
                 Box::new(workflows::tokenized_data::DeleteTokenizeDataWorkflow)
             }
+            #[cfg(feature = "payouts")]
+            Some(PTRunner::PayoutsSyncWorkflow) => {
+                Box::new(workflows::payout_sync::PayoutsSyncWorkflow)
+            }
+            Some(PTRunner::RoutingActivationWorkflow) => {
+                Box::new(workflows::routing_activation::RoutingActivationWorkflow)
+            }
+            Some(PTRunner::IncomingWebhookRetentionWorkflow) => {
+                Box::new(workflows::incoming_webhook_retention::IncomingWebhookRetentionWorkflow)
+            }
             _ => Err(ProcessTrackerError::UnexpectedFlow)?,
         };
         let app_state = &state.clone();
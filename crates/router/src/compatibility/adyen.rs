@@ -0,0 +1,35 @@
+pub mod app;
+pub mod errors;
+pub mod payments;
+pub mod refunds;
+use actix_web::{web, Scope};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{ApiErrorResponse, RouterResponse},
+    routes,
+    services::ApplicationResponse,
+};
+
+pub struct AdyenApis;
+
+impl AdyenApis {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/adyen/v71")
+            .service(app::Payments::server(state.clone()))
+            .service(app::Cancels::server(state))
+    }
+}
+
+/// Converts the response of the core into the Adyen response, for the Adyen responses which are
+/// built from the request as well
+fn map_json_response<T, R>(
+    response: ApplicationResponse<T>,
+    func: impl FnOnce(T) -> R,
+) -> RouterResponse<R> {
+    match response {
+        ApplicationResponse::Json(response) => Ok(ApplicationResponse::Json(func(response))),
+        _ => Err(report!(ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the core"),
+    }
+}
@@ -0,0 +1,30 @@
+use actix_web::{web, Scope};
+
+use super::{payments::*, refunds::*};
+use crate::routes;
+
+pub struct Payments;
+
+impl Payments {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/payments")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payments_create)))
+            .service(web::resource("/details").route(web::post().to(payments_details)))
+            .service(
+                web::resource("/{payment_id}/captures").route(web::post().to(payments_capture)),
+            )
+            .service(web::resource("/{payment_id}/refunds").route(web::post().to(refund_create)))
+            .service(web::resource("/{payment_id}/cancels").route(web::post().to(payments_cancel)))
+    }
+}
+
+pub struct Cancels;
+
+impl Cancels {
+    pub fn server(state: routes::AppState) -> Scope {
+        web::scope("/cancels")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(payments_cancel_by_reference)))
+    }
+}
@@ -0,0 +1,87 @@
+use actix_web::http::StatusCode;
+use common_utils::errors::ErrorSwitch;
+use serde::Serialize;
+
+use crate::core::errors;
+
+/// An error in the format of the Adyen Checkout API. The error code is the one of the
+/// corresponding error of this application, Adyen error codes are not reproduced.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenErrorResponse {
+    pub status: u16,
+    pub error_code: String,
+    pub message: String,
+    pub error_type: AdyenErrorType,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdyenErrorType {
+    Validation,
+    Security,
+    Internal,
+}
+
+impl From<errors::ApiErrorResponse> for AdyenErrorResponse {
+    fn from(error: errors::ApiErrorResponse) -> Self {
+        let status = actix_web::ResponseError::status_code(&error);
+        let mut api_error: api_models::errors::types::ApiErrorResponse = error.switch();
+        let error_info = api_error.get_internal_error_mut();
+        let error_type = match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => AdyenErrorType::Security,
+            status if status.is_server_error() => AdyenErrorType::Internal,
+            _ => AdyenErrorType::Validation,
+        };
+
+        Self {
+            status: status.as_u16(),
+            error_code: format!("{}_{:02}", error_info.sub_code, error_info.error_identifier),
+            message: error_info.error_message.clone(),
+            error_type,
+        }
+    }
+}
+
+impl From<serde_json::Error> for AdyenErrorResponse {
+    fn from(error: serde_json::Error) -> Self {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unexpected input: {error}"),
+        }
+        .into()
+    }
+}
+
+impl ::core::fmt::Display for AdyenErrorResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).unwrap_or_else(|_| "API error response".to_string())
+        )
+    }
+}
+
+impl std::error::Error for AdyenErrorResponse {}
+
+impl actix_web::ResponseError for AdyenErrorResponse {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        use actix_web::http::header;
+
+        actix_web::HttpResponseBuilder::new(self.status_code())
+            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
+            .body(self.to_string())
+    }
+}
+
+impl ErrorSwitch<AdyenErrorResponse> for errors::ApiErrorResponse {
+    fn switch(&self) -> AdyenErrorResponse {
+        self.clone().into()
+    }
+}
+
+impl crate::services::EmbedError for error_stack::Report<AdyenErrorResponse> {}
@@ -0,0 +1,421 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payments as payment_types;
+use common_utils::errors::ReportSwitchExt;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use super::map_json_response;
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{
+        api_locking::GetLockingInput,
+        errors::{self as core_errors, RouterResult, StorageErrorExt},
+        payment_methods::Oss,
+        payments::{self, PaymentRedirectFlow},
+    },
+    routes,
+    services::{api, authentication as auth},
+    types::{
+        api::{self as api_types, enums, PaymentIdTypeExt},
+        domain,
+        transformers::ForeignFrom,
+    },
+    utils::OptionExt,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCreate))]
+pub async fn payments_create(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::AdyenPaymentRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_payment_req = match payment_types::PaymentsRequest::try_from(payload).switch() {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response::<errors::AdyenErrorResponse>(err),
+    };
+
+    let flow = Flow::PaymentsCreate;
+    let locking_action = create_payment_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payment_req,
+        |state, auth, req| {
+            let eligible_connectors = req.connector.clone();
+            payments::payments_core::<api_types::Authorize, api_types::PaymentsResponse, _, _, _, Oss>(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payments::PaymentCreate,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                eligible_connectors,
+                api_types::HeaderPayload::default(),
+            )
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// The shopper is usually redirected back through this application, which completes the payment
+/// before returning to the `returnUrl`. While the payment is still waiting for the shopper, the
+/// submitted details are handled like the redirect response of the connector.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRedirect))]
+pub async fn payments_details(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::AdyenPaymentDetailsRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let redirect_response_req = match payments::PaymentsRedirectResponseData::try_from(payload)
+        .switch()
+    {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response::<errors::AdyenErrorResponse>(err),
+    };
+
+    let flow = Flow::PaymentsRedirect;
+    let locking_action = redirect_response_req.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenPaymentResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        redirect_response_req,
+        |state, auth, req| async move {
+            let (req, connector_action) =
+                get_payment_details_action(&state, &auth.merchant_account, req).await?;
+            <payments::PaymentRedirectSync as PaymentRedirectFlow<Oss>>::call_payment_flow(
+                &payments::PaymentRedirectSync,
+                &state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                connector_action,
+            )
+            .await
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+/// Decides how the connector is called for the submitted details. Once the payment no longer
+/// waits for the shopper the details have already been used, and the payment is only synced.
+async fn get_payment_details_action(
+    state: &routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    req: payments::PaymentsRedirectResponseData,
+) -> RouterResult<(
+    payments::PaymentsRedirectResponseData,
+    payments::CallConnectorAction,
+)> {
+    let payment_id = req.resource_id.get_payment_intent_id().change_context(
+        core_errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "paymentData",
+        },
+    )?;
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCustomerAction {
+        return Ok((
+            payments::PaymentsRedirectResponseData {
+                param: None,
+                json_payload: None,
+                ..req
+            },
+            payments::CallConnectorAction::Trigger,
+        ));
+    }
+
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_id,
+            &merchant_account.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(core_errors::ApiErrorResponse::PaymentNotFound)?;
+    let connector = payment_attempt.connector.get_required_value("connector")?;
+
+    let connector_data = api_types::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector,
+        api_types::GetToken::Connector,
+        None,
+    )?;
+    let connector_action = connector_data
+        .connector
+        .get_flow_type(
+            req.param.as_deref().unwrap_or_default(),
+            req.json_payload.clone(),
+            api::PaymentAction::PSync,
+        )
+        .change_context(core_errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decide the response flow")?;
+
+    Ok((
+        payments::PaymentsRedirectResponseData {
+            connector: Some(connector),
+            ..req
+        },
+        connector_action,
+    ))
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCapture))]
+pub async fn payments_capture(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let adyen_payload: types::AdyenCaptureRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let payload = payment_types::PaymentsCaptureRequest {
+        payment_id: path.into_inner(),
+        amount_to_capture: Some(adyen_payload.amount.value),
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCapture;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload| {
+            let adyen_payload = adyen_payload.clone();
+            async move {
+                let response = payments::payments_core::<
+                    api_types::Capture,
+                    api_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payments::PaymentCapture,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    api_types::HeaderPayload::default(),
+                )
+                .await?;
+                map_json_response(response, |response| {
+                    types::AdyenModificationResponse::foreign_from((response, adyen_payload))
+                })
+            }
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel))]
+pub async fn payments_cancel(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let adyen_payload: types::AdyenCancelRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let payload = payment_types::PaymentsCancelRequest {
+        payment_id: path.into_inner(),
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCancel;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload| {
+            let adyen_payload = adyen_payload.clone();
+            async move {
+                let response = payments::payments_core::<
+                    api_types::Void,
+                    api_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payments::PaymentCancel,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    api_types::HeaderPayload::default(),
+                )
+                .await?;
+                map_json_response(response, |response| {
+                    types::AdyenModificationResponse::foreign_from((response, adyen_payload))
+                })
+            }
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel))]
+pub async fn payments_cancel_by_reference(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+) -> HttpResponse {
+    let adyen_payload: types::AdyenStandaloneCancelRequest =
+        match serde_json::from_slice(&json_payload)
+            .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+        {
+            Ok(p) => p,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    let payload = payment_types::PaymentsCancelRequest {
+        payment_id: adyen_payload.payment_reference.clone(),
+        ..Default::default()
+    };
+
+    let flow = Flow::PaymentsCancel;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, payload| {
+            let adyen_payload = adyen_payload.clone();
+            async move {
+                let response = payments::payments_core::<
+                    api_types::Void,
+                    api_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    Oss,
+                >(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    payments::PaymentCancel,
+                    payload,
+                    api::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    api_types::HeaderPayload::default(),
+                )
+                .await?;
+                map_json_response(response, |response| {
+                    types::AdyenModificationResponse::foreign_from((response, adyen_payload))
+                })
+            }
+        },
+        &auth::ApiKeyAuth,
+        locking_action,
+    ))
+    .await
+}
@@ -0,0 +1,606 @@
+use api_models::{payments, refunds};
+use common_utils::{ext_traits::StringExt, pii};
+use error_stack::{IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{errors, payments as payments_core},
+    pii::Email,
+    types::{api::enums as api_enums, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+/// The key the Adyen `reference` of a payment is stored under in its metadata
+pub const MERCHANT_REFERENCE_KEY: &str = "merchant_reference";
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdyenAmount {
+    pub value: i64,
+    pub currency: String,
+}
+
+impl AdyenAmount {
+    fn get_currency(&self) -> errors::RouterResult<api_enums::Currency> {
+        self.currency
+            .to_uppercase()
+            .parse_enum("currency")
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "amount.currency",
+            })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCard {
+    pub number: cards::CardNumber,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cvc: Secret<String>,
+    pub holder_name: Option<Secret<String>>,
+}
+
+/// Only card details sent in plain text are supported, the client side encrypted fields of the
+/// Adyen Web and mobile components are not.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AdyenPaymentMethod {
+    Scheme(AdyenCard),
+}
+
+impl From<AdyenPaymentMethod> for payments::PaymentMethodData {
+    fn from(payment_method: AdyenPaymentMethod) -> Self {
+        match payment_method {
+            AdyenPaymentMethod::Scheme(card) => Self::Card(payments::Card {
+                card_number: card.number,
+                card_exp_month: card.expiry_month,
+                card_exp_year: card.expiry_year,
+                card_holder_name: card.holder_name.unwrap_or("name".to_string().into()),
+                card_cvc: card.cvc,
+                card_issuer: None,
+                card_network: None,
+                card_type: None,
+                card_issuing_country: None,
+                bank_code: None,
+                nick_name: None,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAddress {
+    pub street: Secret<String>,
+    pub house_number_or_name: Secret<String>,
+    pub city: String,
+    pub postal_code: Secret<String>,
+    pub state_or_province: Option<Secret<String>>,
+    pub country: api_enums::CountryAlpha2,
+}
+
+impl From<AdyenAddress> for payments::Address {
+    fn from(address: AdyenAddress) -> Self {
+        Self {
+            address: Some(payments::AddressDetails {
+                city: Some(address.city),
+                country: Some(address.country),
+                line1: Some(address.street),
+                line2: Some(address.house_number_or_name),
+                line3: None,
+                zip: Some(address.postal_code),
+                state: address.state_or_province,
+                first_name: None,
+                last_name: None,
+            }),
+            phone: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct AdyenAdditionalData {
+    #[serde(rename = "manualCapture")]
+    pub manual_capture: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentRequest {
+    /// The merchant account is the one the API key belongs to, this field is not used
+    pub merchant_account: Option<String>,
+    pub amount: AdyenAmount,
+    /// Stored in the metadata of the payment under [`MERCHANT_REFERENCE_KEY`], the payment id is
+    /// generated and returned as the `pspReference`
+    pub reference: String,
+    pub payment_method: AdyenPaymentMethod,
+    pub return_url: Option<url::Url>,
+    pub shopper_reference: Option<String>,
+    pub shopper_email: Option<Email>,
+    pub billing_address: Option<AdyenAddress>,
+    pub capture_delay_hours: Option<u32>,
+    pub additional_data: Option<AdyenAdditionalData>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl TryFrom<AdyenPaymentRequest> for payments::PaymentsRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: AdyenPaymentRequest) -> errors::RouterResult<Self> {
+        let manual_capture = item
+            .additional_data
+            .and_then(|additional_data| additional_data.manual_capture)
+            .map_or(false, |manual_capture| manual_capture == "true");
+
+        let capture_method = match (manual_capture, item.capture_delay_hours) {
+            (true, _) => Some(api_enums::CaptureMethod::Manual),
+            (false, Some(0)) => Some(api_enums::CaptureMethod::Automatic),
+            (false, None) => None,
+            (false, Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Delayed automatic capture is not supported, set \
+                          `additionalData.manualCapture` and capture the payment instead"
+                    .to_string(),
+            })?,
+        };
+
+        let metadata = match item.metadata.map(|metadata| metadata.expose()) {
+            None => serde_json::Map::new(),
+            Some(serde_json::Value::Object(metadata)) => metadata,
+            Some(_) => Err(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "metadata",
+            })?,
+        };
+
+        Ok(Self {
+            currency: Some(item.amount.get_currency()?),
+            amount: Some(item.amount.value.into()),
+            capture_method,
+            confirm: Some(true),
+            customer_id: item.shopper_reference,
+            email: item.shopper_email,
+            return_url: item.return_url,
+            payment_method: Some(api_enums::PaymentMethod::Card),
+            payment_method_data: Some(item.payment_method.into()),
+            billing: item.billing_address.map(From::from),
+            metadata: Some(Secret::new(serde_json::Value::Object(
+                metadata
+                    .into_iter()
+                    .chain([(
+                        MERCHANT_REFERENCE_KEY.to_string(),
+                        serde_json::Value::String(item.reference),
+                    )])
+                    .collect(),
+            ))),
+            ..Self::default()
+        })
+    }
+}
+
+/// Adyen no longer requires `paymentData` to be sent back, it is required here as it holds the
+/// `pspReference` of the payment the details are submitted for.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentDetailsRequest {
+    /// The parameters the shopper was redirected back with, such as `redirectResult`
+    pub details: Option<serde_json::Value>,
+    pub payment_data: Option<String>,
+}
+
+impl TryFrom<AdyenPaymentDetailsRequest> for payments_core::PaymentsRedirectResponseData {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: AdyenPaymentDetailsRequest) -> errors::RouterResult<Self> {
+        let payment_id = item.payment_data.get_required_value("paymentData")?;
+        let details = item.details.get_required_value("details")?;
+
+        // The details are passed on as the query parameters of a redirect response would be
+        let param = serde_urlencoded::to_string(&details)
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "details",
+            })?;
+
+        Ok(Self {
+            connector: None,
+            param: Some(param),
+            merchant_id: None,
+            json_payload: Some(details),
+            resource_id: payments::PaymentIdType::PaymentIntentId(payment_id),
+            force_sync: true,
+            creds_identifier: None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub enum AdyenResultCode {
+    Authorised,
+    Refused,
+    Pending,
+    Received,
+    Cancelled,
+    RedirectShopper,
+    PresentToShopper,
+}
+
+impl ForeignFrom<(api_enums::IntentStatus, Option<&payments::NextActionData>)> for AdyenResultCode {
+    fn foreign_from(
+        (status, next_action): (api_enums::IntentStatus, Option<&payments::NextActionData>),
+    ) -> Self {
+        match status {
+            // A payment waiting to be captured has been authorised by the issuer
+            api_enums::IntentStatus::Succeeded
+            | api_enums::IntentStatus::RequiresCapture
+            | api_enums::IntentStatus::PartiallyCaptured => Self::Authorised,
+            api_enums::IntentStatus::Failed | api_enums::IntentStatus::RequiresPaymentMethod => {
+                Self::Refused
+            }
+            api_enums::IntentStatus::Processing => Self::Received,
+            api_enums::IntentStatus::Cancelled => Self::Cancelled,
+            api_enums::IntentStatus::RequiresCustomerAction => match next_action {
+                Some(payments::NextActionData::RedirectToUrl { .. }) => Self::RedirectShopper,
+                Some(
+                    payments::NextActionData::DisplayBankTransferInformation { .. }
+                    | payments::NextActionData::QrCodeInformation { .. }
+                    | payments::NextActionData::DisplayVoucherInformation { .. },
+                ) => Self::PresentToShopper,
+                Some(
+                    payments::NextActionData::ThirdPartySdkSessionToken { .. }
+                    | payments::NextActionData::WaitScreenInformation { .. },
+                )
+                | None => Self::Pending,
+            },
+            api_enums::IntentStatus::RequiresMerchantAction
+            | api_enums::IntentStatus::RequiresConfirmation => Self::Pending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdyenAction {
+    #[serde(rename_all = "camelCase")]
+    Redirect {
+        method: &'static str,
+        url: String,
+        /// The `pspReference` of the payment, to be sent back with the payment details
+        payment_data: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPaymentResponse {
+    pub psp_reference: String,
+    pub merchant_reference: String,
+    pub result_code: AdyenResultCode,
+    pub amount: AdyenAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<AdyenAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refusal_reason_code: Option<String>,
+}
+
+impl From<payments::PaymentsResponse> for AdyenPaymentResponse {
+    fn from(res: payments::PaymentsResponse) -> Self {
+        let payment_id = res.payment_id.unwrap_or_default();
+        let merchant_reference = res
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.peek().get(MERCHANT_REFERENCE_KEY))
+            .and_then(serde_json::Value::as_str)
+            .map_or_else(|| payment_id.clone(), ToString::to_string);
+        let result_code = AdyenResultCode::foreign_from((res.status, res.next_action.as_ref()));
+        let action = res.next_action.and_then(|next_action| match next_action {
+            payments::NextActionData::RedirectToUrl { redirect_to_url } => {
+                Some(AdyenAction::Redirect {
+                    method: "GET",
+                    url: redirect_to_url,
+                    payment_data: payment_id.clone(),
+                })
+            }
+            _ => None,
+        });
+        let is_refused = result_code == AdyenResultCode::Refused;
+
+        Self {
+            psp_reference: payment_id,
+            merchant_reference,
+            result_code,
+            amount: AdyenAmount {
+                value: res.amount,
+                currency: res.currency,
+            },
+            action,
+            refusal_reason: res.error_message.filter(|_| is_refused),
+            refusal_reason_code: res.error_code.filter(|_| is_refused),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCaptureRequest {
+    pub merchant_account: Option<String>,
+    pub amount: AdyenAmount,
+    pub reference: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenCancelRequest {
+    pub merchant_account: Option<String>,
+    pub reference: Option<String>,
+}
+
+/// The merchant reference is not unique, so the `paymentReference` has to be the `pspReference`
+/// of the payment to be cancelled
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenStandaloneCancelRequest {
+    pub merchant_account: Option<String>,
+    pub payment_reference: String,
+    pub reference: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenRefundRequest {
+    pub merchant_account: Option<String>,
+    pub amount: AdyenAmount,
+    /// Used as the refund id, which makes it the `pspReference` of the refund as well
+    pub reference: Option<String>,
+    pub merchant_refund_reason: Option<String>,
+}
+
+impl ForeignFrom<(String, AdyenRefundRequest)> for refunds::RefundRequest {
+    fn foreign_from((payment_id, item): (String, AdyenRefundRequest)) -> Self {
+        Self {
+            refund_id: item.reference,
+            payment_id,
+            amount: Some(item.amount.value),
+            reason: item.merchant_refund_reason,
+            refund_type: Some(refunds::RefundType::Instant),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdyenModificationStatus {
+    Received,
+}
+
+/// As with Adyen, modifications are acknowledged with the `received` status. Their outcome is
+/// known by retrieving the payment or the refund.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenModificationResponse {
+    pub merchant_account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_psp_reference: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_reference: Option<String>,
+    pub psp_reference: String,
+    pub reference: Option<String>,
+    pub status: AdyenModificationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<AdyenAmount>,
+}
+
+impl ForeignFrom<(payments::PaymentsResponse, AdyenCaptureRequest)> for AdyenModificationResponse {
+    fn foreign_from((res, req): (payments::PaymentsResponse, AdyenCaptureRequest)) -> Self {
+        let payment_id = res.payment_id.unwrap_or_default();
+        Self {
+            merchant_account: req.merchant_account,
+            payment_psp_reference: Some(payment_id.clone()),
+            payment_reference: None,
+            psp_reference: payment_id,
+            reference: req.reference,
+            status: AdyenModificationStatus::Received,
+            amount: Some(req.amount),
+        }
+    }
+}
+
+impl ForeignFrom<(payments::PaymentsResponse, AdyenCancelRequest)> for AdyenModificationResponse {
+    fn foreign_from((res, req): (payments::PaymentsResponse, AdyenCancelRequest)) -> Self {
+        let payment_id = res.payment_id.unwrap_or_default();
+        Self {
+            merchant_account: req.merchant_account,
+            payment_psp_reference: Some(payment_id.clone()),
+            payment_reference: None,
+            psp_reference: payment_id,
+            reference: req.reference,
+            status: AdyenModificationStatus::Received,
+            amount: None,
+        }
+    }
+}
+
+impl ForeignFrom<(payments::PaymentsResponse, AdyenStandaloneCancelRequest)>
+    for AdyenModificationResponse
+{
+    fn foreign_from(
+        (res, req): (payments::PaymentsResponse, AdyenStandaloneCancelRequest),
+    ) -> Self {
+        Self {
+            merchant_account: req.merchant_account,
+            payment_psp_reference: None,
+            payment_reference: Some(req.payment_reference),
+            psp_reference: res.payment_id.unwrap_or_default(),
+            reference: req.reference,
+            status: AdyenModificationStatus::Received,
+            amount: None,
+        }
+    }
+}
+
+impl ForeignFrom<(refunds::RefundResponse, AdyenRefundRequest)> for AdyenModificationResponse {
+    fn foreign_from((res, req): (refunds::RefundResponse, AdyenRefundRequest)) -> Self {
+        Self {
+            merchant_account: req.merchant_account,
+            payment_psp_reference: Some(res.payment_id),
+            payment_reference: None,
+            psp_reference: res.refund_id,
+            reference: req.reference,
+            status: AdyenModificationStatus::Received,
+            amount: Some(AdyenAmount {
+                value: res.amount,
+                currency: res.currency,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_payment_request_from_adyen_request() {
+        let request: AdyenPaymentRequest = serde_json::from_str(
+            r#"{
+                "merchantAccount": "TestMerchant",
+                "amount": {"value": 1000, "currency": "EUR"},
+                "reference": "order_1",
+                "paymentMethod": {
+                    "type": "scheme",
+                    "number": "4111111111111111",
+                    "expiryMonth": "03",
+                    "expiryYear": "2030",
+                    "cvc": "737",
+                    "holderName": "John Smith"
+                },
+                "returnUrl": "https://example.com/return",
+                "shopperReference": "shopper_1",
+                "additionalData": {"manualCapture": "true"},
+                "metadata": {"udf1": "value"}
+            }"#,
+        )
+        .expect("Failed to deserialize the payment request");
+
+        let payment_request =
+            payments::PaymentsRequest::try_from(request).expect("Failed to convert the request");
+
+        assert_eq!(payment_request.payment_id, None);
+        assert_eq!(
+            payment_request.metadata.map(|metadata| metadata.expose()),
+            Some(serde_json::json!({"udf1": "value", "merchant_reference": "order_1"}))
+        );
+        assert_eq!(payment_request.currency, Some(api_enums::Currency::EUR));
+        assert_eq!(
+            payment_request.capture_method,
+            Some(api_enums::CaptureMethod::Manual)
+        );
+        assert_eq!(payment_request.confirm, Some(true));
+        assert_eq!(payment_request.customer_id.as_deref(), Some("shopper_1"));
+        assert!(matches!(
+            payment_request.payment_method_data,
+            Some(payments::PaymentMethodData::Card(_))
+        ));
+    }
+
+    #[test]
+    fn test_delayed_automatic_capture_is_rejected() {
+        let request: AdyenPaymentRequest = serde_json::from_str(
+            r#"{
+                "amount": {"value": 1000, "currency": "EUR"},
+                "reference": "order_1",
+                "paymentMethod": {
+                    "type": "scheme",
+                    "number": "4111111111111111",
+                    "expiryMonth": "03",
+                    "expiryYear": "2030",
+                    "cvc": "737"
+                },
+                "captureDelayHours": 24
+            }"#,
+        )
+        .expect("Failed to deserialize the payment request");
+
+        assert!(payments::PaymentsRequest::try_from(request).is_err());
+    }
+
+    #[test]
+    fn test_result_code_mapping() {
+        let redirect = payments::NextActionData::RedirectToUrl {
+            redirect_to_url: "https://example.com/3ds".to_string(),
+        };
+
+        assert_eq!(
+            AdyenResultCode::foreign_from((api_enums::IntentStatus::RequiresCapture, None)),
+            AdyenResultCode::Authorised
+        );
+        assert_eq!(
+            AdyenResultCode::foreign_from((
+                api_enums::IntentStatus::RequiresCustomerAction,
+                Some(&redirect)
+            )),
+            AdyenResultCode::RedirectShopper
+        );
+        assert_eq!(
+            AdyenResultCode::foreign_from((api_enums::IntentStatus::Failed, None)),
+            AdyenResultCode::Refused
+        );
+    }
+
+    #[test]
+    fn test_redirect_payment_response() {
+        let response = AdyenPaymentResponse::from(payments::PaymentsResponse {
+            payment_id: Some("pay_1".to_string()),
+            metadata: Some(Secret::new(
+                serde_json::json!({"merchant_reference": "order_1"}),
+            )),
+            status: api_enums::IntentStatus::RequiresCustomerAction,
+            amount: 1000,
+            currency: "EUR".to_string(),
+            next_action: Some(payments::NextActionData::RedirectToUrl {
+                redirect_to_url: "https://example.com/3ds".to_string(),
+            }),
+            ..Default::default()
+        });
+        let response = serde_json::to_value(response).expect("Failed to serialize the response");
+
+        assert_eq!(response["pspReference"], "pay_1");
+        assert_eq!(response["merchantReference"], "order_1");
+        assert_eq!(response["resultCode"], "RedirectShopper");
+        assert_eq!(response["amount"]["value"], 1000);
+        assert_eq!(response["action"]["type"], "redirect");
+        assert_eq!(response["action"]["url"], "https://example.com/3ds");
+        assert_eq!(response["action"]["paymentData"], "pay_1");
+        assert!(response.get("refusalReason").is_none());
+    }
+
+    #[test]
+    fn test_payment_details_are_forwarded_as_redirect_response() {
+        let request: AdyenPaymentDetailsRequest = serde_json::from_str(
+            r#"{
+                "details": {"redirectResult": "X6XtfGC3"},
+                "paymentData": "pay_1"
+            }"#,
+        )
+        .expect("Failed to deserialize the payment details request");
+
+        let redirect_response = payments_core::PaymentsRedirectResponseData::try_from(request)
+            .expect("Failed to convert the request");
+
+        assert_eq!(
+            redirect_response.param.as_deref(),
+            Some("redirectResult=X6XtfGC3")
+        );
+        assert_eq!(
+            redirect_response.json_payload,
+            Some(serde_json::json!({"redirectResult": "X6XtfGC3"}))
+        );
+        assert!(matches!(
+            redirect_response.resource_id,
+            payments::PaymentIdType::PaymentIntentId(payment_id) if payment_id == "pay_1"
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::report;
+use router_env::{instrument, tracing, Flow};
+
+use super::{map_json_response, payments::types};
+use crate::{
+    compatibility::{adyen::errors, wrap},
+    core::{api_locking, refunds},
+    routes,
+    services::{api, authentication as auth},
+    types::{api::refunds as refund_types, transformers::ForeignFrom},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsCreate))]
+pub async fn refund_create(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    json_payload: web::Bytes,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let adyen_payload: types::AdyenRefundRequest = match serde_json::from_slice(&json_payload)
+        .map_err(|err| report!(errors::AdyenErrorResponse::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_refund_req =
+        refund_types::RefundRequest::foreign_from((path.into_inner(), adyen_payload.clone()));
+
+    let flow = Flow::RefundsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::AdyenModificationResponse,
+        errors::AdyenErrorResponse,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_refund_req,
+        |state, auth, req| {
+            let adyen_payload = adyen_payload.clone();
+            async move {
+                let response =
+                    refunds::refund_create_core(state, auth.merchant_account, auth.key_store, req)
+                        .await?;
+                map_json_response(response, |response| {
+                    types::AdyenModificationResponse::foreign_from((response, adyen_payload))
+                })
+            }
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
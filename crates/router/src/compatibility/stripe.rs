@@ -1,6 +1,11 @@
 pub mod app;
 pub mod customers;
+pub mod disputes;
+pub mod mandates;
 pub mod payment_intents;
+pub mod payment_methods;
+#[cfg(feature = "payouts")]
+pub mod payouts;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -14,13 +19,20 @@ impl StripeApis {
     pub fn server(state: routes::AppState) -> Scope {
         let max_depth = 10;
         let strict = false;
-        web::scope("/vs/v1")
+        let route = web::scope("/vs/v1")
             .app_data(web::Data::new(serde_qs::Config::new(max_depth, strict)))
             .service(app::SetupIntents::server(state.clone()))
             .service(app::PaymentIntents::server(state.clone()))
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state.clone()))
+            .service(app::Mandates::server(state.clone()))
+            .service(app::Disputes::server(state.clone()));
+        #[cfg(feature = "payouts")]
+        let route = route.service(app::Payouts::server(state.clone()));
+        #[cfg(all(feature = "olap", feature = "payouts"))]
+        let route = route.service(app::BalanceTransactions::server(state));
+        route
     }
 }
@@ -1,6 +1,11 @@
 use actix_web::{web, Scope};
 
-use super::{customers::*, payment_intents::*, refunds::*, setup_intents::*, webhooks::*};
+#[cfg(feature = "payouts")]
+use super::payouts::*;
+use super::{
+    customers::*, disputes::*, mandates::*, payment_intents::*, payment_methods::*, refunds::*,
+    setup_intents::*, webhooks::*,
+};
 use crate::routes::{self, mandates, webhooks};
 
 pub struct PaymentIntents;
@@ -112,12 +117,78 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
         web::scope("/payment_methods")
             .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::post().to(payment_method_create))
+                    .route(web::get().to(payment_method_list)),
+            )
+            .service(
+                web::resource("/{payment_method_id}").route(web::get().to(payment_method_retrieve)),
+            )
             .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
     }
 }
+
+pub struct Mandates;
+
+impl Mandates {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/mandates")
+            .app_data(web::Data::new(config))
+            .service(web::resource("/{mandate_id}").route(web::get().to(mandate_retrieve)))
+    }
+}
+
+pub struct Disputes;
+
+impl Disputes {
+    pub fn server(config: routes::AppState) -> Scope {
+        let mut route = web::scope("/disputes").app_data(web::Data::new(config));
+        #[cfg(feature = "olap")]
+        {
+            route = route.service(web::resource("").route(web::get().to(dispute_list)))
+        }
+        route
+            .service(
+                web::resource("/{dispute_id}")
+                    .route(web::get().to(dispute_retrieve))
+                    .route(web::post().to(dispute_update)),
+            )
+            .service(web::resource("/{dispute_id}/close").route(web::post().to(dispute_close)))
+    }
+}
+
+#[cfg(feature = "payouts")]
+pub struct Payouts;
+
+#[cfg(feature = "payouts")]
+impl Payouts {
+    pub fn server(config: routes::AppState) -> Scope {
+        let payouts = web::resource("").route(web::post().to(payout_create));
+        #[cfg(feature = "olap")]
+        let payouts = payouts.route(web::get().to(payout_list));
+        web::scope("/payouts")
+            .app_data(web::Data::new(config))
+            .service(payouts)
+            .service(web::resource("/{payout_id}").route(web::get().to(payout_retrieve)))
+            .service(web::resource("/{payout_id}/cancel").route(web::post().to(payout_cancel)))
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub struct BalanceTransactions;
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+impl BalanceTransactions {
+    pub fn server(config: routes::AppState) -> Scope {
+        web::scope("/balance_transactions")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(balance_transaction_list)))
+    }
+}
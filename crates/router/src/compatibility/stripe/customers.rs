@@ -7,7 +7,7 @@ use crate::{
     compatibility::{stripe::errors, wrap},
     core::{api_locking, customers, payment_methods::cards},
     routes,
-    services::{api, authentication as auth},
+    services::{self, api, authentication as auth},
     types::api::{customers as customer_types, payment_methods},
 };
 
@@ -199,6 +199,7 @@ pub async fn list_customer_payment_method_api(
                 auth.key_store,
                 Some(req),
                 Some(customer_id.as_str()),
+                services::AuthFlow::Merchant,
             )
         },
         &auth::ApiKeyAuth,
@@ -0,0 +1,192 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::disputes as dispute_models;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, disputes, errors as core_errors},
+    routes,
+    services::{self, api, authentication as auth},
+    types::{api::disputes as dispute_types, transformers::ForeignFrom},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesList))]
+#[cfg(feature = "olap")]
+pub async fn dispute_list(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let payload = match qs_config
+        .deserialize_str::<types::StripeDisputeListConstraints>(req.query_string())
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let limit = payload.limit;
+    let payload = match dispute_models::DisputeListConstraints::try_from(payload) {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::DisputesList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| async move {
+            match disputes::retrieve_disputes_list(state, auth.merchant_account, req).await? {
+                services::ApplicationResponse::Json(list) => {
+                    Ok(services::ApplicationResponse::Json(
+                        types::StripeDisputeListResponse::from_disputes(list, limit),
+                    ))
+                }
+                _ => Err(report!(core_errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unexpected response from disputes list"),
+            }
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| disputes::retrieve_dispute(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceSubmit))]
+pub async fn dispute_update(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeUpdateDisputeRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    if payload.submit == Some(false) {
+        return api::log_and_return_error_response(report!(
+            errors::StripeErrorCode::InvalidRequestData {
+                message: "Staging dispute evidence is not supported, evidence is submitted \
+                          with every update"
+                    .to_string(),
+            }
+        ));
+    }
+
+    let submit_evidence_req =
+        dispute_models::SubmitEvidenceRequest::foreign_from((path.into_inner(), payload.evidence));
+
+    let flow = Flow::DisputesEvidenceSubmit;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        submit_evidence_req,
+        |state, auth, req| {
+            disputes::submit_evidence(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesRetrieve))]
+pub async fn dispute_close(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+
+    let flow = Flow::DisputesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeDisputeResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            disputes::accept_dispute(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,329 @@
+use api_models::disputes;
+use common_utils::date_time;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::from_timestamp_to_datetime,
+    core::errors,
+    types::{api::enums as api_enums, transformers::ForeignFrom},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StripeDisputeListConstraints {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub created: Option<i64>,
+    #[serde(rename = "created[lt]")]
+    pub created_lt: Option<i64>,
+    #[serde(rename = "created[gt]")]
+    pub created_gt: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+impl TryFrom<StripeDisputeListConstraints> for disputes::DisputeListConstraints {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeDisputeListConstraints) -> Result<Self, Self::Error> {
+        Ok(Self {
+            // One more dispute than asked for is fetched to tell whether there are more
+            limit: Some(item.limit.saturating_add(1)),
+            profile_id: None,
+            dispute_status: None,
+            dispute_stage: None,
+            reason: None,
+            connector: None,
+            received_time: from_timestamp_to_datetime(item.created)?,
+            received_time_lt: from_timestamp_to_datetime(item.created_lt)?,
+            received_time_gt: from_timestamp_to_datetime(item.created_gt)?,
+            received_time_lte: from_timestamp_to_datetime(item.created_lte)?,
+            received_time_gte: from_timestamp_to_datetime(item.created_gte)?,
+        })
+    }
+}
+
+/// The evidence fields accepted by Stripe. Fields holding documents take the id of a file
+/// uploaded through the files API.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StripeDisputeEvidence {
+    pub access_activity_log: Option<String>,
+    pub billing_address: Option<String>,
+    pub cancellation_policy: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub customer_communication: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub customer_signature: Option<String>,
+    pub duplicate_charge_documentation: Option<String>,
+    pub product_description: Option<String>,
+    pub receipt: Option<String>,
+    pub refund_policy: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub service_date: Option<String>,
+    pub service_documentation: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_documentation: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_file: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StripeUpdateDisputeRequest {
+    #[serde(default)]
+    pub evidence: StripeDisputeEvidence,
+    /// Evidence is always submitted to the connector, staging it is not supported
+    pub submit: Option<bool>,
+}
+
+impl ForeignFrom<(String, StripeDisputeEvidence)> for disputes::SubmitEvidenceRequest {
+    fn foreign_from((dispute_id, evidence): (String, StripeDisputeEvidence)) -> Self {
+        Self {
+            dispute_id,
+            access_activity_log: evidence.access_activity_log,
+            billing_address: evidence.billing_address,
+            cancellation_policy: evidence.cancellation_policy,
+            cancellation_policy_disclosure: evidence.cancellation_policy_disclosure,
+            cancellation_rebuttal: evidence.cancellation_rebuttal,
+            customer_communication: evidence.customer_communication,
+            customer_email_address: evidence.customer_email_address,
+            customer_name: evidence.customer_name,
+            customer_purchase_ip: evidence.customer_purchase_ip,
+            customer_signature: evidence.customer_signature,
+            product_description: evidence.product_description,
+            receipt: evidence.receipt,
+            refund_policy: evidence.refund_policy,
+            refund_policy_disclosure: evidence.refund_policy_disclosure,
+            refund_refusal_explanation: evidence.refund_refusal_explanation,
+            service_date: evidence.service_date,
+            service_documentation: evidence.service_documentation,
+            shipping_address: evidence.shipping_address,
+            shipping_carrier: evidence.shipping_carrier,
+            shipping_date: evidence.shipping_date,
+            shipping_documentation: evidence.shipping_documentation,
+            shipping_tracking_number: evidence.shipping_tracking_number,
+            invoice_showing_distinct_transactions: evidence.duplicate_charge_documentation,
+            recurring_transaction_agreement: None,
+            uncategorized_file: evidence.uncategorized_file,
+            uncategorized_text: evidence.uncategorized_text,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeDisputeStatus {
+    WarningNeedsResponse,
+    WarningUnderReview,
+    WarningClosed,
+    NeedsResponse,
+    UnderReview,
+    Won,
+    Lost,
+}
+
+impl From<(api_enums::DisputeStage, api_enums::DisputeStatus)> for StripeDisputeStatus {
+    fn from((stage, status): (api_enums::DisputeStage, api_enums::DisputeStatus)) -> Self {
+        match (stage, status) {
+            // Inquiries which have not become disputes yet are reported as warnings
+            (api_enums::DisputeStage::PreDispute, api_enums::DisputeStatus::DisputeOpened) => {
+                Self::WarningNeedsResponse
+            }
+            (api_enums::DisputeStage::PreDispute, api_enums::DisputeStatus::DisputeChallenged) => {
+                Self::WarningUnderReview
+            }
+            (api_enums::DisputeStage::PreDispute, _) => Self::WarningClosed,
+            (_, api_enums::DisputeStatus::DisputeOpened) => Self::NeedsResponse,
+            (_, api_enums::DisputeStatus::DisputeChallenged) => Self::UnderReview,
+            // A dispute withdrawn by the customer leaves the funds with the merchant
+            (
+                _,
+                api_enums::DisputeStatus::DisputeWon | api_enums::DisputeStatus::DisputeCancelled,
+            ) => Self::Won,
+            (
+                _,
+                api_enums::DisputeStatus::DisputeLost
+                | api_enums::DisputeStatus::DisputeAccepted
+                | api_enums::DisputeStatus::DisputeExpired,
+            ) => Self::Lost,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeDisputeEvidenceDetails {
+    pub due_by: Option<i64>,
+    pub past_due: bool,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeDisputeResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: Option<i64>,
+    pub currency: String,
+    pub charge: String,
+    pub payment_intent: String,
+    pub reason: String,
+    pub status: StripeDisputeStatus,
+    pub evidence_details: StripeDisputeEvidenceDetails,
+    pub created: i64,
+    pub livemode: bool,
+}
+
+impl From<disputes::DisputeResponse> for StripeDisputeResponse {
+    fn from(res: disputes::DisputeResponse) -> Self {
+        let now = date_time::now();
+        Self {
+            id: res.dispute_id,
+            object: "dispute",
+            amount: res.amount.parse().ok(),
+            currency: res.currency.to_ascii_lowercase(),
+            charge: res.attempt_id,
+            payment_intent: res.payment_id,
+            reason: res
+                .connector_reason
+                .unwrap_or_else(|| "general".to_string()),
+            status: StripeDisputeStatus::from((res.dispute_stage, res.dispute_status)),
+            evidence_details: StripeDisputeEvidenceDetails {
+                due_by: res
+                    .challenge_required_by
+                    .map(|due_by| due_by.assume_utc().unix_timestamp()),
+                past_due: res
+                    .challenge_required_by
+                    .map_or(false, |due_by| due_by < now),
+            },
+            created: res.created_at.assume_utc().unix_timestamp(),
+            livemode: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeDisputeListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeDisputeResponse>,
+}
+
+impl StripeDisputeListResponse {
+    /// Builds the list from the disputes fetched for one more than the limit, the extra dispute
+    /// only tells whether there are more disputes
+    pub fn from_disputes(mut res: Vec<disputes::DisputeResponse>, limit: i64) -> Self {
+        let limit = usize::try_from(limit).unwrap_or_default();
+        let has_more = res.len() > limit;
+        res.truncate(limit);
+        Self {
+            object: "list",
+            url: "/v1/disputes",
+            has_more,
+            data: res.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_dispute_status_mapping() {
+        use api_enums::{DisputeStage, DisputeStatus};
+
+        assert_eq!(
+            StripeDisputeStatus::from((DisputeStage::PreDispute, DisputeStatus::DisputeOpened)),
+            StripeDisputeStatus::WarningNeedsResponse
+        );
+        assert_eq!(
+            StripeDisputeStatus::from((DisputeStage::PreDispute, DisputeStatus::DisputeWon)),
+            StripeDisputeStatus::WarningClosed
+        );
+        assert_eq!(
+            StripeDisputeStatus::from((DisputeStage::Dispute, DisputeStatus::DisputeChallenged)),
+            StripeDisputeStatus::UnderReview
+        );
+        assert_eq!(
+            StripeDisputeStatus::from((DisputeStage::Dispute, DisputeStatus::DisputeAccepted)),
+            StripeDisputeStatus::Lost
+        );
+        assert_eq!(
+            StripeDisputeStatus::from((
+                DisputeStage::PreArbitration,
+                DisputeStatus::DisputeCancelled
+            )),
+            StripeDisputeStatus::Won
+        );
+    }
+
+    #[test]
+    fn test_update_dispute_request_from_form() {
+        let form = "evidence[receipt]=file_123&evidence[duplicate_charge_documentation]=file_456\
+                    &evidence[uncategorized_text]=Delivered&submit=true";
+        let req: StripeUpdateDisputeRequest = serde_qs::Config::new(10, false)
+            .deserialize_str(form)
+            .expect("Failed to deserialize dispute update request");
+        assert_eq!(req.submit, Some(true));
+
+        let evidence_request =
+            disputes::SubmitEvidenceRequest::foreign_from(("dp_123".to_string(), req.evidence));
+        assert_eq!(evidence_request.dispute_id, "dp_123");
+        assert_eq!(evidence_request.receipt.as_deref(), Some("file_123"));
+        assert_eq!(
+            evidence_request
+                .invoice_showing_distinct_transactions
+                .as_deref(),
+            Some("file_456")
+        );
+        assert_eq!(
+            evidence_request.uncategorized_text.as_deref(),
+            Some("Delivered")
+        );
+    }
+
+    #[test]
+    fn test_dispute_list_has_more_only_past_the_limit() {
+        let dispute = |dispute_id: &str| disputes::DisputeResponse {
+            dispute_id: dispute_id.to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            amount: "1000".to_string(),
+            currency: "USD".to_string(),
+            dispute_stage: api_enums::DisputeStage::Dispute,
+            dispute_status: api_enums::DisputeStatus::DisputeOpened,
+            connector: "stripe".to_string(),
+            connector_status: "needs_response".to_string(),
+            connector_dispute_id: format!("dp_{dispute_id}"),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            created_at: common_utils::date_time::now(),
+        };
+
+        let list =
+            StripeDisputeListResponse::from_disputes(vec![dispute("dp_1"), dispute("dp_2")], 2);
+        assert!(!list.has_more);
+        assert_eq!(list.data.len(), 2);
+
+        let list = StripeDisputeListResponse::from_disputes(
+            vec![dispute("dp_1"), dispute("dp_2"), dispute("dp_3")],
+            2,
+        );
+        assert!(list.has_more);
+        assert_eq!(list.data.len(), 2);
+    }
+}
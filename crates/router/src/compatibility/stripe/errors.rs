@@ -221,6 +221,8 @@ pub enum StripeErrorCode {
     MissingFileContentType,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Dispute id not found in the request")]
     MissingDisputeId,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "{reason}")]
+    DisputeStatusValidationFailed { reason: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "File does not exists in our records")]
     FileNotFound,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "File not available")]
@@ -561,7 +563,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 id,
             },
             errors::ApiErrorResponse::DisputeStatusValidationFailed { reason } => {
-                Self::InternalServerError
+                Self::DisputeStatusValidationFailed { reason }
             }
             errors::ApiErrorResponse::FileValidationFailed { .. } => Self::FileValidationFailed,
             errors::ApiErrorResponse::MissingFile => Self::MissingFile,
@@ -656,6 +658,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MissingFileContentType
             | Self::MissingFilePurpose
             | Self::MissingDisputeId
+            | Self::DisputeStatusValidationFailed { .. }
             | Self::FileNotFound
             | Self::FileNotAvailable
             | Self::FileProviderNotSupported
@@ -0,0 +1,45 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, mandate},
+    routes,
+    services::authentication as auth,
+    types::api::mandates as mandate_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::MandatesRetrieve))]
+pub async fn mandate_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = mandate_types::MandateId {
+        mandate_id: path.into_inner(),
+    };
+
+    let flow = Flow::MandatesRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeMandateResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| mandate::get_mandate(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
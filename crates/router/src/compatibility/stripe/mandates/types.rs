@@ -0,0 +1,77 @@
+use api_models::payments;
+use common_utils::pii;
+use serde::Serialize;
+
+use crate::types::api::{enums as api_enums, mandates};
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeMandateStatus {
+    Active,
+    Inactive,
+    Pending,
+}
+
+impl From<api_enums::MandateStatus> for StripeMandateStatus {
+    fn from(status: api_enums::MandateStatus) -> Self {
+        match status {
+            api_enums::MandateStatus::Active => Self::Active,
+            api_enums::MandateStatus::Inactive | api_enums::MandateStatus::Revoked => {
+                Self::Inactive
+            }
+            api_enums::MandateStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeOnlineAcceptance {
+    pub ip_address: Option<masking::Secret<String, pii::IpAddress>>,
+    pub user_agent: String,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeCustomerAcceptance {
+    #[serde(rename = "type")]
+    pub acceptance_type: payments::AcceptanceType,
+    pub accepted_at: Option<i64>,
+    pub online: Option<StripeOnlineAcceptance>,
+}
+
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct StripeMandateResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub payment_method: String,
+    pub status: StripeMandateStatus,
+    pub customer_acceptance: Option<StripeCustomerAcceptance>,
+    pub livemode: bool,
+}
+
+impl From<payments::CustomerAcceptance> for StripeCustomerAcceptance {
+    fn from(acceptance: payments::CustomerAcceptance) -> Self {
+        Self {
+            acceptance_type: acceptance.acceptance_type,
+            accepted_at: acceptance
+                .accepted_at
+                .map(|accepted_at| accepted_at.assume_utc().unix_timestamp()),
+            online: acceptance.online.map(|online| StripeOnlineAcceptance {
+                ip_address: online.ip_address,
+                user_agent: online.user_agent,
+            }),
+        }
+    }
+}
+
+impl From<mandates::MandateResponse> for StripeMandateResponse {
+    fn from(res: mandates::MandateResponse) -> Self {
+        Self {
+            id: res.mandate_id,
+            object: "mandate",
+            payment_method: res.payment_method_id,
+            status: res.status.into(),
+            customer_acceptance: res.customer_acceptance.map(From::from),
+            livemode: false,
+        }
+    }
+}
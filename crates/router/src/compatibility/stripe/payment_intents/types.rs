@@ -643,7 +643,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
 }
 
 #[inline]
-fn from_timestamp_to_datetime(
+pub fn from_timestamp_to_datetime(
     time: Option<i64>,
 ) -> Result<Option<PrimitiveDateTime>, errors::ApiErrorResponse> {
     if let Some(time) = time {
@@ -0,0 +1,166 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, errors as core_errors, payment_methods::cards},
+    routes,
+    services::{self, api, authentication as auth},
+    types::api::{enums as api_enums, payment_methods as payment_method_types},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsCreate))]
+pub async fn payment_method_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeCreatePaymentMethodRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_pm_req: payment_method_types::PaymentMethodCreate = payload.into();
+
+    let flow = Flow::PaymentMethodsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_pm_req,
+        |state, auth, req| async move {
+            cards::add_payment_method(state, req, &auth.merchant_account, &auth.key_store).await
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetrieve))]
+pub async fn payment_method_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payment_method_types::PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    let flow = Flow::PaymentMethodsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| cards::retrieve_payment_method(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsList))]
+pub async fn payment_method_list(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let payload: types::StripePaymentMethodListConstraints = match qs_config
+        .deserialize_str(req.query_string())
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::CustomerPaymentMethodsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePaymentMethodListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req: types::StripePaymentMethodListConstraints| async move {
+            let customer_id =
+                req.customer
+                    .ok_or(core_errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "customer",
+                    })?;
+            let response = cards::do_list_customer_pm_fetch_customer_if_not_passed(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                None,
+                Some(customer_id.as_str()),
+                services::AuthFlow::Merchant,
+            )
+            .await?;
+
+            match response {
+                services::ApplicationResponse::Json(mut list) => {
+                    if let Some(stype) = req.stype {
+                        let payment_method = api_enums::PaymentMethod::from(stype);
+                        list.customer_payment_methods
+                            .retain(|pm| pm.payment_method == payment_method);
+                    }
+                    let has_more = req
+                        .limit
+                        .map_or(false, |limit| list.customer_payment_methods.len() > limit);
+                    if let Some(limit) = req.limit {
+                        list.customer_payment_methods.truncate(limit);
+                    }
+                    Ok(services::ApplicationResponse::Json(
+                        types::StripePaymentMethodListResponse {
+                            has_more,
+                            ..list.into()
+                        },
+                    ))
+                }
+                _ => Err(report!(core_errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unexpected response from customer payment methods list"),
+            }
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,199 @@
+use api_models::payment_methods;
+use common_utils::pii;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::StripePaymentMethodType,
+    types::api::enums as api_enums,
+};
+
+#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: masking::Secret<String>,
+    pub exp_year: masking::Secret<String>,
+}
+
+#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodBillingDetails {
+    pub name: Option<masking::Secret<String>>,
+}
+
+#[derive(Default, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripeCreatePaymentMethodRequest {
+    #[serde(rename = "type")]
+    pub stype: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripePaymentMethodBillingDetails>,
+    // Payment methods are always saved against a customer, so the customer is required at
+    // creation instead of being attached later
+    pub customer: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+impl From<StripeCreatePaymentMethodRequest> for payment_methods::PaymentMethodCreate {
+    fn from(req: StripeCreatePaymentMethodRequest) -> Self {
+        let card_holder_name = req
+            .billing_details
+            .and_then(|billing_details| billing_details.name);
+        Self {
+            payment_method: req.stype.into(),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: req.card.map(|card| payment_methods::CardDetail {
+                card_number: card.number,
+                card_exp_month: card.exp_month,
+                card_exp_year: card.exp_year,
+                card_holder_name,
+                nick_name: None,
+            }),
+            metadata: req.metadata,
+            customer_id: req.customer,
+            card_network: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StripePaymentMethodListConstraints {
+    pub customer: Option<String>,
+    #[serde(rename = "type")]
+    pub stype: Option<StripePaymentMethodType>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Default, Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodCardResponse {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub last4: Option<String>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub stype: api_enums::PaymentMethod,
+    pub customer: Option<String>,
+    pub card: Option<StripePaymentMethodCardResponse>,
+    pub billing_details: StripePaymentMethodBillingDetails,
+    pub created: Option<i64>,
+    pub livemode: bool,
+    pub metadata: pii::SecretSerdeValue,
+}
+
+#[derive(Serialize, PartialEq, Eq, Debug)]
+pub struct StripePaymentMethodListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePaymentMethodResponse>,
+}
+
+impl From<payment_methods::CardDetailFromLocker> for StripePaymentMethodCardResponse {
+    fn from(card: payment_methods::CardDetailFromLocker) -> Self {
+        Self {
+            brand: card.scheme.map(|scheme| scheme.to_lowercase()),
+            country: card.issuer_country,
+            exp_month: card.expiry_month,
+            exp_year: card.expiry_year,
+            last4: card.last4_digits,
+            fingerprint: card.card_fingerprint,
+        }
+    }
+}
+
+impl From<payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: payment_methods::PaymentMethodResponse) -> Self {
+        let name = res
+            .card
+            .as_ref()
+            .and_then(|card| card.card_holder_name.clone());
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            stype: res.payment_method,
+            customer: res.customer_id,
+            card: res.card.map(From::from),
+            billing_details: StripePaymentMethodBillingDetails { name },
+            created: res.created.map(|t| t.assume_utc().unix_timestamp()),
+            livemode: false,
+            metadata: res
+                .metadata
+                .unwrap_or_else(|| masking::Secret::new(serde_json::json!({}))),
+        }
+    }
+}
+
+impl From<payment_methods::CustomerPaymentMethod> for StripePaymentMethodResponse {
+    fn from(pm: payment_methods::CustomerPaymentMethod) -> Self {
+        let name = pm
+            .card
+            .as_ref()
+            .and_then(|card| card.card_holder_name.clone());
+        Self {
+            id: pm.payment_method_id.unwrap_or_default(),
+            object: "payment_method",
+            stype: pm.payment_method,
+            customer: Some(pm.customer_id),
+            card: pm.card.map(From::from),
+            billing_details: StripePaymentMethodBillingDetails { name },
+            created: pm.created.map(|t| t.assume_utc().unix_timestamp()),
+            livemode: false,
+            metadata: pm
+                .metadata
+                .unwrap_or_else(|| masking::Secret::new(serde_json::json!({}))),
+        }
+    }
+}
+
+impl From<payment_methods::CustomerPaymentMethodsListResponse> for StripePaymentMethodListResponse {
+    fn from(res: payment_methods::CustomerPaymentMethodsListResponse) -> Self {
+        Self {
+            object: "list",
+            url: "/v1/payment_methods",
+            has_more: false,
+            data: res
+                .customer_payment_methods
+                .into_iter()
+                .map(From::from)
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use masking::PeekInterface;
+
+    use super::*;
+
+    #[test]
+    fn test_create_payment_method_request_from_form() {
+        let form = "type=card&card[number]=4242424242424242&card[exp_month]=12&card[exp_year]=2030\
+                    &billing_details[name]=John+Doe&customer=cus_123";
+        let req: StripeCreatePaymentMethodRequest = serde_qs::Config::new(10, false)
+            .deserialize_str(form)
+            .expect("Failed to deserialize payment method request");
+
+        let pm_create = payment_methods::PaymentMethodCreate::from(req);
+        assert_eq!(pm_create.payment_method, api_enums::PaymentMethod::Card);
+        assert_eq!(pm_create.customer_id.as_deref(), Some("cus_123"));
+
+        let card = pm_create.card.expect("Card details are missing");
+        assert_eq!(card.card_exp_year.peek(), "2030");
+        assert_eq!(
+            card.card_holder_name
+                .as_ref()
+                .map(|name| name.peek().as_str()),
+            Some("John Doe")
+        );
+    }
+}
@@ -0,0 +1,232 @@
+pub mod types;
+use actix_web::{web, HttpRequest, HttpResponse};
+use error_stack::report;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    compatibility::{stripe::errors, wrap},
+    core::{api_locking, payouts},
+    routes,
+    services::{api, authentication as auth},
+    types::api::payouts as payout_types,
+};
+#[cfg(feature = "olap")]
+use crate::{core::errors as core_errors, services};
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsCreate))]
+pub async fn payout_create(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+) -> HttpResponse {
+    let payload: types::StripeCreatePayoutRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let create_payout_req = match payout_types::PayoutCreateRequest::try_from(payload) {
+        Ok(req) => req,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PayoutsCreate;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayoutResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        create_payout_req,
+        |state, auth, req| {
+            payouts::payouts_create_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsRetrieve))]
+pub async fn payout_retrieve(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payout_types::PayoutRetrieveRequest {
+        payout_id: path.into_inner(),
+        force_sync: Some(false),
+    };
+
+    let flow = Flow::PayoutsRetrieve;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayoutResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            payouts::payouts_retrieve_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsCancel))]
+pub async fn payout_cancel(
+    state: web::Data<routes::AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let payload = payout_types::PayoutActionRequest {
+        payout_id: path.into_inner(),
+    };
+
+    let flow = Flow::PayoutsCancel;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayoutResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| {
+            payouts::payouts_cancel_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+#[cfg(feature = "olap")]
+pub async fn payout_list(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let payload = match qs_config
+        .deserialize_str::<types::StripePayoutListConstraints>(req.query_string())
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let payload = match payout_types::PayoutListRequest::try_from(payload) {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PayoutsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripePayoutListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req| payouts::payouts_list_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+#[cfg(feature = "olap")]
+pub async fn balance_transaction_list(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let payload = match qs_config
+        .deserialize_str::<types::StripeBalanceTransactionListConstraints>(req.query_string())
+        .map_err(|err| report!(errors::StripeErrorCode::from(err)))
+    {
+        Ok(p) => p,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    let flow = Flow::PayoutsList;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeBalanceTransactionListResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req: types::StripeBalanceTransactionListConstraints| async move {
+            let list_req = payout_types::PayoutListRequest::try_from(&req)?;
+            match payouts::payouts_list_core(state, auth.merchant_account, list_req).await? {
+                services::ApplicationResponse::Json(list) => {
+                    Ok(services::ApplicationResponse::Json(
+                        types::StripeBalanceTransactionListResponse::from_payouts(
+                            list,
+                            req.transaction_type.as_ref(),
+                            req.limit,
+                        ),
+                    ))
+                }
+                _ => Err(report!(core_errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unexpected response from payouts list"),
+            }
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,448 @@
+use api_models::{payouts, refunds::TimeRange};
+use common_utils::{ext_traits::StringExt, pii};
+use error_stack::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::from_timestamp_to_datetime, core::errors,
+    types::api::enums as api_enums,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutMethod {
+    Standard,
+    Instant,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct StripeCreatePayoutRequest {
+    pub amount: Option<i64>,
+    pub currency: Option<String>,
+    pub description: Option<String>,
+    /// The payout token of a payout method saved for the customer
+    pub destination: Option<String>,
+    pub method: Option<StripePayoutMethod>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    // Payouts are made to customers instead of the account's bank account, so the customer and
+    // the kind of payout method have to be passed along with the destination
+    pub customer: Option<String>,
+    pub payout_type: Option<api_enums::PayoutType>,
+}
+
+impl TryFrom<StripeCreatePayoutRequest> for payouts::PayoutCreateRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripeCreatePayoutRequest) -> Result<Self, Self::Error> {
+        let payout_type = match (item.method, item.payout_type) {
+            (Some(StripePayoutMethod::Instant), Some(api_enums::PayoutType::Bank) | None) => {
+                Some(api_enums::PayoutType::InstantTransfer)
+            }
+            (_, payout_type) => payout_type,
+        };
+
+        Ok(Self {
+            amount: item.amount.map(|amount| amount.into()),
+            currency: item
+                .currency
+                .as_ref()
+                .map(|c| c.to_uppercase().parse_enum("currency"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "currency",
+                })?,
+            description: item.description,
+            payout_token: item.destination,
+            metadata: item.metadata,
+            customer_id: item.customer,
+            payout_type,
+            // Stripe payouts are sent as soon as they are created
+            confirm: Some(true),
+            auto_fulfill: Some(true),
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutStatus {
+    Paid,
+    Pending,
+    InTransit,
+    Canceled,
+    Failed,
+}
+
+impl From<api_enums::PayoutStatus> for StripePayoutStatus {
+    fn from(status: api_enums::PayoutStatus) -> Self {
+        match status {
+            api_enums::PayoutStatus::Success => Self::Paid,
+            api_enums::PayoutStatus::Pending => Self::InTransit,
+            api_enums::PayoutStatus::RequiresCreation
+            | api_enums::PayoutStatus::RequiresPayoutMethodData
+            | api_enums::PayoutStatus::RequiresFulfillment => Self::Pending,
+            api_enums::PayoutStatus::Cancelled => Self::Canceled,
+            // Stripe reports payouts returned after being paid as failed
+            api_enums::PayoutStatus::Failed
+            | api_enums::PayoutStatus::Ineligible
+            | api_enums::PayoutStatus::Expired
+            | api_enums::PayoutStatus::Reversed => Self::Failed,
+        }
+    }
+}
+
+impl StripePayoutStatus {
+    /// The payout statuses which are reported as this status
+    fn get_payout_statuses(&self) -> Vec<api_enums::PayoutStatus> {
+        match self {
+            Self::Paid => vec![api_enums::PayoutStatus::Success],
+            Self::InTransit => vec![api_enums::PayoutStatus::Pending],
+            Self::Pending => vec![
+                api_enums::PayoutStatus::RequiresCreation,
+                api_enums::PayoutStatus::RequiresPayoutMethodData,
+                api_enums::PayoutStatus::RequiresFulfillment,
+            ],
+            Self::Canceled => vec![api_enums::PayoutStatus::Cancelled],
+            Self::Failed => vec![
+                api_enums::PayoutStatus::Failed,
+                api_enums::PayoutStatus::Ineligible,
+                api_enums::PayoutStatus::Expired,
+                api_enums::PayoutStatus::Reversed,
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePayoutDestinationType {
+    BankAccount,
+    Card,
+    Wallet,
+}
+
+impl From<api_enums::PayoutType> for StripePayoutDestinationType {
+    fn from(payout_type: api_enums::PayoutType) -> Self {
+        match payout_type {
+            api_enums::PayoutType::Card => Self::Card,
+            api_enums::PayoutType::Bank | api_enums::PayoutType::InstantTransfer => {
+                Self::BankAccount
+            }
+            api_enums::PayoutType::Wallet => Self::Wallet,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StripePayoutResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: String,
+    pub customer: String,
+    pub description: Option<String>,
+    pub method: StripePayoutMethod,
+    pub status: StripePayoutStatus,
+    #[serde(rename = "type")]
+    pub destination_type: StripePayoutDestinationType,
+    pub failure_code: Option<String>,
+    pub failure_message: Option<String>,
+    pub metadata: pii::SecretSerdeValue,
+    pub livemode: bool,
+}
+
+impl From<payouts::PayoutCreateResponse> for StripePayoutResponse {
+    fn from(res: payouts::PayoutCreateResponse) -> Self {
+        let method = if res.payout_type == api_enums::PayoutType::InstantTransfer {
+            StripePayoutMethod::Instant
+        } else {
+            StripePayoutMethod::Standard
+        };
+        Self {
+            id: res.payout_id,
+            object: "payout",
+            amount: res.amount,
+            currency: res.currency.to_string().to_lowercase(),
+            customer: res.customer_id,
+            description: res.description,
+            method,
+            status: res.status.into(),
+            destination_type: res.payout_type.into(),
+            failure_code: res.error_code,
+            failure_message: res.error_message,
+            metadata: res
+                .metadata
+                .unwrap_or_else(|| masking::Secret::new(serde_json::json!({}))),
+            livemode: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StripePayoutListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripePayoutResponse>,
+}
+
+impl From<payouts::PayoutListResponse> for StripePayoutListResponse {
+    fn from(res: payouts::PayoutListResponse) -> Self {
+        let has_more = i64::try_from(res.count).map_or(false, |count| count < res.total_count);
+        Self {
+            object: "list",
+            url: "/v1/payouts",
+            has_more,
+            data: res.data.into_iter().map(From::from).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StripePayoutListConstraints {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub status: Option<StripePayoutStatus>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    10
+}
+
+fn get_time_range(
+    created_gte: Option<i64>,
+    created_lte: Option<i64>,
+) -> Result<Option<TimeRange>, errors::ApiErrorResponse> {
+    let end_time = from_timestamp_to_datetime(created_lte)?;
+    Ok(match from_timestamp_to_datetime(created_gte)? {
+        Some(start_time) => Some(TimeRange {
+            start_time,
+            end_time,
+        }),
+        None => end_time.map(|end_time| {
+            let epoch = time::OffsetDateTime::UNIX_EPOCH;
+            TimeRange {
+                start_time: time::PrimitiveDateTime::new(epoch.date(), epoch.time()),
+                end_time: Some(end_time),
+            }
+        }),
+    })
+}
+
+impl TryFrom<StripePayoutListConstraints> for payouts::PayoutListRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: StripePayoutListConstraints) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payout_id: None,
+            customer_id: None,
+            profile_id: None,
+            limit: Some(item.limit),
+            offset: None,
+            time_range: get_time_range(item.created_gte, item.created_lte)?,
+            connector: None,
+            currency: None,
+            status: item
+                .status
+                .as_ref()
+                .map(StripePayoutStatus::get_payout_statuses),
+            payout_type: None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeBalanceTransactionType {
+    Payout,
+    PayoutFailure,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeBalanceTransactionStatus {
+    Available,
+    Pending,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StripeBalanceTransactionListConstraints {
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub payout: Option<String>,
+    #[serde(rename = "type")]
+    pub transaction_type: Option<StripeBalanceTransactionType>,
+    #[serde(rename = "created[gte]")]
+    pub created_gte: Option<i64>,
+    #[serde(rename = "created[lte]")]
+    pub created_lte: Option<i64>,
+}
+
+impl TryFrom<&StripeBalanceTransactionListConstraints> for payouts::PayoutListRequest {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+    fn try_from(item: &StripeBalanceTransactionListConstraints) -> Result<Self, Self::Error> {
+        // Only payouts which have been sent move funds out of the balance
+        let status = match item.transaction_type {
+            Some(StripeBalanceTransactionType::PayoutFailure) => {
+                vec![api_enums::PayoutStatus::Reversed]
+            }
+            Some(StripeBalanceTransactionType::Payout) | None => vec![
+                api_enums::PayoutStatus::Pending,
+                api_enums::PayoutStatus::Success,
+                api_enums::PayoutStatus::Reversed,
+            ],
+        };
+        Ok(Self {
+            payout_id: item.payout.clone(),
+            customer_id: None,
+            profile_id: None,
+            limit: Some(item.limit),
+            offset: None,
+            time_range: get_time_range(item.created_gte, item.created_lte)?,
+            connector: None,
+            currency: None,
+            status: Some(status),
+            payout_type: None,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeBalanceTransaction {
+    pub id: String,
+    pub object: &'static str,
+    pub amount: i64,
+    pub currency: String,
+    pub description: Option<String>,
+    pub fee: i64,
+    pub net: i64,
+    pub source: String,
+    pub status: StripeBalanceTransactionStatus,
+    #[serde(rename = "type")]
+    pub transaction_type: StripeBalanceTransactionType,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeBalanceTransactionListResponse {
+    pub object: &'static str,
+    pub url: &'static str,
+    pub has_more: bool,
+    pub data: Vec<StripeBalanceTransaction>,
+}
+
+impl StripeBalanceTransactionListResponse {
+    /// Builds the balance transactions of the payouts. A payout debits the balance once it has been
+    /// sent, and a payout which was returned credits the amount back. Fees charged by the
+    /// connectors for payouts are not tracked, so they are reported as zero.
+    pub fn from_payouts(
+        res: payouts::PayoutListResponse,
+        transaction_type: Option<&StripeBalanceTransactionType>,
+        limit: i64,
+    ) -> Self {
+        let limit = usize::try_from(limit).unwrap_or_default();
+        let has_more_payouts =
+            i64::try_from(res.count).map_or(false, |count| count < res.total_count);
+        let mut data: Vec<_> = res
+            .data
+            .into_iter()
+            .flat_map(|payout| {
+                let currency = payout.currency.to_string().to_lowercase();
+                let payout_transaction = StripeBalanceTransaction {
+                    id: format!("txn_{}", payout.payout_id),
+                    object: "balance_transaction",
+                    amount: -payout.amount,
+                    currency: currency.clone(),
+                    description: payout.description.clone(),
+                    fee: 0,
+                    net: -payout.amount,
+                    source: payout.payout_id.clone(),
+                    status: if payout.status == api_enums::PayoutStatus::Pending {
+                        StripeBalanceTransactionStatus::Pending
+                    } else {
+                        StripeBalanceTransactionStatus::Available
+                    },
+                    transaction_type: StripeBalanceTransactionType::Payout,
+                };
+                let failure_transaction = (payout.status == api_enums::PayoutStatus::Reversed)
+                    .then(|| StripeBalanceTransaction {
+                        id: format!("txn_{}_failure", payout.payout_id),
+                        object: "balance_transaction",
+                        amount: payout.amount,
+                        currency,
+                        description: payout.description,
+                        fee: 0,
+                        net: payout.amount,
+                        source: payout.payout_id,
+                        status: StripeBalanceTransactionStatus::Available,
+                        transaction_type: StripeBalanceTransactionType::PayoutFailure,
+                    });
+                std::iter::once(payout_transaction).chain(failure_transaction)
+            })
+            .filter(|transaction| {
+                transaction_type.map_or(true, |transaction_type| {
+                    &transaction.transaction_type == transaction_type
+                })
+            })
+            .collect();
+        // A payout can have two transactions, so the transactions of the payouts fetched for the
+        // limit can exceed it as well
+        let has_more = has_more_payouts || data.len() > limit;
+        data.truncate(limit);
+
+        Self {
+            object: "list",
+            url: "/v1/balance_transactions",
+            has_more,
+            data,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_payout_status_round_trip() {
+        let statuses = [
+            StripePayoutStatus::Paid,
+            StripePayoutStatus::Pending,
+            StripePayoutStatus::InTransit,
+            StripePayoutStatus::Canceled,
+            StripePayoutStatus::Failed,
+        ];
+        for status in statuses {
+            for payout_status in status.get_payout_statuses() {
+                assert_eq!(StripePayoutStatus::from(payout_status), status);
+            }
+        }
+    }
+
+    #[test]
+    fn test_instant_payout_request() {
+        let req = StripeCreatePayoutRequest {
+            amount: Some(1000),
+            currency: Some("eur".to_string()),
+            destination: Some("token_123".to_string()),
+            method: Some(StripePayoutMethod::Instant),
+            customer: Some("cus_123".to_string()),
+            ..Default::default()
+        };
+        let payout_req =
+            payouts::PayoutCreateRequest::try_from(req).expect("Failed to convert request");
+
+        assert_eq!(payout_req.currency, Some(api_enums::Currency::EUR));
+        assert_eq!(
+            payout_req.payout_type,
+            Some(api_enums::PayoutType::InstantTransfer)
+        );
+        assert_eq!(payout_req.payout_token.as_deref(), Some("token_123"));
+        assert_eq!(payout_req.confirm, Some(true));
+    }
+}
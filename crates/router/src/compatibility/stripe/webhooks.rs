@@ -1,13 +1,13 @@
-use api_models::{
-    enums::{DisputeStatus, MandateStatus},
-    webhooks::{self as api},
-};
+use api_models::{enums::EventType, webhooks as api};
 use common_utils::{crypto::SignMessage, date_time, ext_traits};
 use error_stack::{IntoReport, ResultExt};
 use router_env::logger;
 use serde::Serialize;
 
+#[cfg(feature = "payouts")]
+use super::payouts::types::StripePayoutResponse;
 use super::{
+    disputes::types::StripeDisputeResponse, mandates::types::StripeMandateResponse,
     payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
 };
 use crate::{
@@ -16,15 +16,16 @@ use crate::{
     services::request::Maskable,
 };
 
+/// An outgoing webhook in the format of a Stripe `Event`
 #[derive(Serialize, Debug)]
 pub struct StripeOutgoingWebhook {
     id: String,
+    object: &'static str,
     #[serde(rename = "type")]
     stype: &'static str,
-    object: &'static str,
-    data: StripeWebhookObject,
+    data: StripeWebhookData,
     created: u64,
-    // api_version: "2019-11-05", // not used
+    livemode: bool,
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
@@ -32,8 +33,6 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
         &self,
         payment_response_hash_key: Option<String>,
     ) -> errors::CustomResult<Option<String>, errors::WebhooksFlowError> {
-        let timestamp = self.created;
-
         let payment_response_hash_key = payment_response_hash_key
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .into_report()
@@ -44,19 +43,12 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
                 .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
                 .attach_printable("failed encoding outgoing webhook payload")?;
 
-        let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
-        let v1 = hex::encode(
-            common_utils::crypto::HmacSha256::sign_message(
-                &common_utils::crypto::HmacSha256,
-                payment_response_hash_key.as_bytes(),
-                new_signature_payload.as_bytes(),
-            )
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
-            .attach_printable("Failed to sign the message")?,
-        );
-
-        let t = timestamp;
-        Ok(Some(format!("t={t},v1={v1}")))
+        get_stripe_signature(
+            &payment_response_hash_key,
+            &webhook_signature_payload,
+            date_time::now_unix_timestamp(),
+        )
+        .map(Some)
     }
 
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String) {
@@ -67,122 +59,70 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
     }
 }
 
-#[derive(Serialize, Debug)]
-#[serde(tag = "type", content = "object", rename_all = "snake_case")]
-pub enum StripeWebhookObject {
-    PaymentIntent(StripePaymentIntentResponse),
-    Refund(StripeRefundResponse),
-    Dispute(StripeDisputeResponse),
-    Mandate(StripeMandateResponse),
-}
+/// Builds the value of the `Stripe-Signature` header. As with Stripe, the timestamp is the time
+/// the webhook is signed at, so that consumers enforcing a tolerance accept retried webhooks.
+fn get_stripe_signature(
+    secret: &str,
+    payload: &str,
+    timestamp: i64,
+) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+    let signed_payload = format!("{timestamp}.{payload}");
+    let v1 = hex::encode(
+        common_utils::crypto::HmacSha256::sign_message(
+            &common_utils::crypto::HmacSha256,
+            secret.as_bytes(),
+            signed_payload.as_bytes(),
+        )
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the message")?,
+    );
 
-#[derive(Serialize, Debug)]
-pub struct StripeDisputeResponse {
-    pub id: String,
-    pub amount: String,
-    pub currency: String,
-    pub payment_intent: String,
-    pub reason: Option<String>,
-    pub status: StripeDisputeStatus,
+    Ok(format!("t={timestamp},v1={v1}"))
 }
 
 #[derive(Serialize, Debug)]
-pub struct StripeMandateResponse {
-    pub mandate_id: String,
-    pub status: StripeMandateStatus,
-    pub payment_method_id: String,
-    pub payment_method: String,
+pub struct StripeWebhookData {
+    object: StripeWebhookObject,
 }
 
 #[derive(Serialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum StripeMandateStatus {
-    Active,
-    Inactive,
-    Pending,
+#[serde(untagged)]
+pub enum StripeWebhookObject {
+    PaymentIntent(StripePaymentIntentResponse),
+    Refund(StripeRefundResponse),
+    Dispute(StripeDisputeResponse),
+    Mandate(StripeMandateResponse),
+    #[cfg(feature = "payouts")]
+    Payout(StripePayoutResponse),
 }
 
-#[derive(Serialize, Debug)]
-#[serde(rename_all = "snake_case")]
-pub enum StripeDisputeStatus {
-    WarningNeedsResponse,
-    WarningUnderReview,
-    WarningClosed,
-    NeedsResponse,
-    UnderReview,
-    ChargeRefunded,
-    Won,
-    Lost,
-}
+fn get_stripe_event_type(event_type: EventType) -> &'static str {
+    match event_type {
+        EventType::PaymentSucceeded => "payment_intent.succeeded",
+        EventType::PaymentFailed => "payment_intent.payment_failed",
+        EventType::PaymentProcessing => "payment_intent.processing",
+        EventType::PaymentCancelled => "payment_intent.canceled",
+        EventType::ActionRequired => "payment_intent.requires_action",
 
-impl From<api_models::disputes::DisputeResponse> for StripeDisputeResponse {
-    fn from(res: api_models::disputes::DisputeResponse) -> Self {
-        Self {
-            id: res.dispute_id,
-            amount: res.amount,
-            currency: res.currency,
-            payment_intent: res.payment_id,
-            reason: res.connector_reason,
-            status: StripeDisputeStatus::from(res.dispute_status),
-        }
-    }
-}
+        // Stripe sends the charge for `charge.refunded`, refunds are not tied to a charge object
+        // here, so the refund is sent instead
+        EventType::RefundSucceeded => "charge.refunded",
+        EventType::RefundFailed => "charge.refund.updated",
 
-impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
-    fn from(res: api_models::mandates::MandateResponse) -> Self {
-        Self {
-            mandate_id: res.mandate_id,
-            payment_method: res.payment_method,
-            payment_method_id: res.payment_method_id,
-            status: StripeMandateStatus::from(res.status),
-        }
-    }
-}
+        EventType::DisputeOpened => "charge.dispute.created",
+        EventType::DisputeChallenged => "charge.dispute.updated",
+        EventType::DisputeExpired
+        | EventType::DisputeAccepted
+        | EventType::DisputeCancelled
+        | EventType::DisputeWon
+        | EventType::DisputeLost => "charge.dispute.closed",
 
-impl From<MandateStatus> for StripeMandateStatus {
-    fn from(status: MandateStatus) -> Self {
-        match status {
-            MandateStatus::Active => Self::Active,
-            MandateStatus::Inactive | MandateStatus::Revoked => Self::Inactive,
-            MandateStatus::Pending => Self::Pending,
-        }
-    }
-}
-
-impl From<DisputeStatus> for StripeDisputeStatus {
-    fn from(status: DisputeStatus) -> Self {
-        match status {
-            DisputeStatus::DisputeOpened => Self::WarningNeedsResponse,
-            DisputeStatus::DisputeExpired => Self::Lost,
-            DisputeStatus::DisputeAccepted => Self::Lost,
-            DisputeStatus::DisputeCancelled => Self::WarningClosed,
-            DisputeStatus::DisputeChallenged => Self::WarningUnderReview,
-            DisputeStatus::DisputeWon => Self::Won,
-            DisputeStatus::DisputeLost => Self::Lost,
-        }
-    }
-}
+        EventType::MandateActive | EventType::MandateRevoked => "mandate.updated",
 
-fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static str {
-    match event_type {
-        api_models::enums::EventType::PaymentSucceeded => "payment_intent.succeeded",
-        api_models::enums::EventType::PaymentFailed => "payment_intent.payment_failed",
-        api_models::enums::EventType::PaymentProcessing => "payment_intent.processing",
-        api_models::enums::EventType::PaymentCancelled => "payment_intent.canceled",
-
-        // the below are not really stripe compatible because stripe doesn't provide this
-        api_models::enums::EventType::ActionRequired => "action.required",
-        api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
-        api_models::enums::EventType::RefundFailed => "refund.failed",
-        api_models::enums::EventType::DisputeOpened => "dispute.failed",
-        api_models::enums::EventType::DisputeExpired => "dispute.expired",
-        api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
-        api_models::enums::EventType::DisputeCancelled => "dispute.cancelled",
-        api_models::enums::EventType::DisputeChallenged => "dispute.challenged",
-        api_models::enums::EventType::DisputeWon => "dispute.won",
-        api_models::enums::EventType::DisputeLost => "dispute.lost",
-        api_models::enums::EventType::MandateActive => "mandate.active",
-        api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        EventType::PayoutSuccess => "payout.paid",
+        EventType::PayoutFailed | EventType::PayoutExpired => "payout.failed",
+        EventType::PayoutProcessing | EventType::PayoutReversed => "payout.updated",
+        EventType::PayoutCancelled => "payout.canceled",
     }
 }
 
@@ -190,9 +130,11 @@ impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
     fn from(value: api::OutgoingWebhook) -> Self {
         Self {
             id: value.event_id,
-            stype: get_stripe_event_type(value.event_type),
-            data: StripeWebhookObject::from(value.content),
             object: "event",
+            stype: get_stripe_event_type(value.event_type),
+            data: StripeWebhookData {
+                object: StripeWebhookObject::from(value.content),
+            },
             // put this conversion it into a function
             created: u64::try_from(value.timestamp.assume_utc().unix_timestamp()).unwrap_or_else(
                 |error| {
@@ -206,6 +148,7 @@ impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
                         .unwrap_or_default()
                 },
             ),
+            livemode: false,
         }
     }
 }
@@ -223,6 +166,107 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            #[cfg(feature = "payouts")]
+            api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use api_models::{disputes, enums as api_enums, refunds};
+
+    use super::*;
+
+    fn outgoing_webhook(
+        event_type: EventType,
+        content: api::OutgoingWebhookContent,
+    ) -> serde_json::Value {
+        let webhook = StripeOutgoingWebhook::from(api::OutgoingWebhook {
+            merchant_id: "merchant_test".to_string(),
+            event_id: "evt_test".to_string(),
+            event_type,
+            content,
+            timestamp: date_time::now(),
+        });
+        serde_json::to_value(webhook).expect("Failed to serialize webhook")
+    }
+
+    #[test]
+    fn test_refund_webhook_is_a_stripe_event() {
+        let webhook = outgoing_webhook(
+            EventType::RefundSucceeded,
+            api::OutgoingWebhookContent::RefundDetails(refunds::RefundResponse {
+                refund_id: "ref_test".to_string(),
+                payment_id: "pay_test".to_string(),
+                amount: 100,
+                currency: "USD".to_string(),
+                status: refunds::RefundStatus::Succeeded,
+                reason: None,
+                metadata: None,
+                error_message: None,
+                error_code: None,
+                created_at: None,
+                updated_at: None,
+                connector: "stripe".to_string(),
+                profile_id: None,
+                fee_details: None,
+                refund_method: api_enums::RefundMethod::OriginalPaymentMethod,
+                payout_id: None,
+            }),
+        );
+
+        assert_eq!(webhook["id"], "evt_test");
+        assert_eq!(webhook["object"], "event");
+        assert_eq!(webhook["type"], "charge.refunded");
+        assert_eq!(webhook["livemode"], false);
+        assert!(webhook["created"].is_u64());
+        assert_eq!(webhook["data"]["object"]["object"], "refund");
+        assert_eq!(webhook["data"]["object"]["id"], "ref_test");
+        assert_eq!(webhook["data"]["object"]["payment_intent"], "pay_test");
+    }
+
+    #[test]
+    fn test_dispute_webhook_is_a_stripe_event() {
+        let webhook = outgoing_webhook(
+            EventType::DisputeOpened,
+            api::OutgoingWebhookContent::DisputeDetails(Box::new(disputes::DisputeResponse {
+                dispute_id: "dp_test".to_string(),
+                payment_id: "pay_test".to_string(),
+                attempt_id: "pay_test_1".to_string(),
+                amount: "100".to_string(),
+                currency: "USD".to_string(),
+                dispute_stage: api_enums::DisputeStage::Dispute,
+                dispute_status: api_enums::DisputeStatus::DisputeOpened,
+                connector: "stripe".to_string(),
+                connector_status: "needs_response".to_string(),
+                connector_dispute_id: "dp_connector".to_string(),
+                connector_reason: None,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                connector_created_at: None,
+                connector_updated_at: None,
+                created_at: date_time::now(),
+            })),
+        );
+
+        assert_eq!(webhook["type"], "charge.dispute.created");
+        assert_eq!(webhook["data"]["object"]["object"], "dispute");
+        assert_eq!(webhook["data"]["object"]["status"], "needs_response");
+        assert_eq!(webhook["data"]["object"]["charge"], "pay_test_1");
+    }
+
+    #[test]
+    fn test_stripe_signature() {
+        let signature = get_stripe_signature("whsec_test", r#"{"id":"evt_test"}"#, 1700000000)
+            .expect("Failed to sign the webhook");
+        let expected = hex::encode(
+            common_utils::crypto::HmacSha256
+                .sign_message(b"whsec_test", br#"1700000000.{"id":"evt_test"}"#)
+                .expect("Failed to sign the message"),
+        );
+
+        assert_eq!(signature, format!("t=1700000000,v1={expected}"));
+    }
+}
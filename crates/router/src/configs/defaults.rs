@@ -103,6 +103,38 @@ impl Default for super::settings::DrainerSettings {
     }
 }
 
+impl Default for super::settings::ConnectorHealthConfig {
+    fn default() -> Self {
+        Self {
+            circuit_breaker_enabled: false,
+            window_seconds: 60,
+            min_calls: 20,
+            failure_rate_threshold: 0.5,
+            open_duration_seconds: 30,
+            probe_timeout_seconds: 30,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookReplayProtectionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timestamp_tolerance_secs: 300,
+            deduplication_ttl_secs: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::IncomingWebhookStorageSettings {
+    fn default() -> Self {
+        Self {
+            max_stored_per_minute: 600,
+            retention_days: 30,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::KvConfig {
     fn default() -> Self {
@@ -103,6 +103,34 @@ pub struct Settings {
     pub payment_link: PaymentLink,
     #[cfg(feature = "kv_store")]
     pub kv_config: KvConfig,
+    pub connector_health: ConnectorHealthConfig,
+    pub routing: RoutingConfig,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RoutingConfig {
+    /// Whether the routing trace, along with the eligibility of every connector that was
+    /// considered, is recorded for each payment attempt
+    pub debug: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorHealthConfig {
+    /// Whether routing skips connectors whose circuit is open
+    pub circuit_breaker_enabled: bool,
+    /// Length of the window over which the calls to a connector are counted, in seconds
+    pub window_seconds: i64,
+    /// Minimum number of calls in the window before the circuit of a connector can open
+    pub min_calls: u64,
+    /// Failure rate, between 0 and 1, at which the circuit of a connector opens
+    pub failure_rate_threshold: f64,
+    /// Time for which an open circuit skips the connector before letting a probe through
+    pub open_duration_seconds: i64,
+    /// Time after which a probe that never reported back is given up, so that another one can
+    /// be let through
+    pub probe_timeout_seconds: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -193,6 +221,13 @@ pub struct DummyConnector {
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
     pub authorize_ttl: i64,
+    pub dispute_ttl: i64,
+    pub mandate_ttl: i64,
+    pub payout_ttl: i64,
+    pub payout_duration: u64,
+    pub payout_tolerance: u64,
+    pub merchant_config_ttl: i64,
+    pub timeout_duration: u64,
     pub assets_base_url: String,
     pub default_return_url: String,
     pub slack_invite_url: String,
@@ -578,6 +613,60 @@ pub struct Connectors {
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
     pub zen: ConnectorParams,
+    /// Outbound http client settings, with optional overrides for individual connectors
+    pub http_client: ConnectorHttpClientSettings,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorHttpClientSettings {
+    /// Settings applied to every connector that has no override
+    pub default: ConnectorHttpClientConfig,
+    /// Overrides keyed by connector name, unset fields fall back to `default`
+    pub overrides: HashMap<String, ConnectorHttpClientConfig>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ConnectorHttpClientConfig {
+    /// Time in seconds allowed for establishing a connection to the connector
+    pub connect_timeout: Option<u64>,
+    /// Time in seconds allowed for the whole request, from connecting to the connector to receiving
+    /// the complete response
+    pub request_timeout: Option<u64>,
+    /// Maximum number of idle connections kept open per host
+    pub pool_max_idle_per_host: Option<usize>,
+    /// Number of times an idempotent request, such as a payment or refund sync, is retried on
+    /// connection failures, timeouts and 502, 503 or 504 responses
+    pub max_retries: Option<u8>,
+    /// Delay in milliseconds before the first retry, doubled for every subsequent retry
+    pub retry_backoff_ms: Option<u64>,
+}
+
+impl ConnectorHttpClientConfig {
+    /// Fills the unset fields of `self` from `fallback`
+    pub fn merge(&self, fallback: &Self) -> Self {
+        Self {
+            connect_timeout: self.connect_timeout.or(fallback.connect_timeout),
+            request_timeout: self.request_timeout.or(fallback.request_timeout),
+            pool_max_idle_per_host: self
+                .pool_max_idle_per_host
+                .or(fallback.pool_max_idle_per_host),
+            max_retries: self.max_retries.or(fallback.max_retries),
+            retry_backoff_ms: self.retry_backoff_ms.or(fallback.retry_backoff_ms),
+        }
+    }
+}
+
+impl ConnectorHttpClientSettings {
+    /// Returns the effective client settings for the connector along with a flag
+    /// indicating whether the connector has its own override
+    pub fn get_config(&self, connector: Option<&str>) -> (ConnectorHttpClientConfig, bool) {
+        match connector.and_then(|connector| self.overrides.get(connector)) {
+            Some(connector_config) => (connector_config.merge(&self.default), true),
+            None => (self.default.clone(), false),
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Clone, Default, router_derive::ConfigValidate)]
@@ -633,6 +722,29 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub replay_protection: WebhookReplayProtectionSettings,
+    pub incoming_storage: IncomingWebhookStorageSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookReplayProtectionSettings {
+    /// Reject incoming webhooks that are stale or have already been processed
+    pub enabled: bool,
+    /// Maximum age (in seconds) of the signature timestamp of an incoming webhook
+    pub timestamp_tolerance_secs: i64,
+    /// Duration (in seconds) for which processed incoming webhooks are remembered
+    pub deduplication_ttl_secs: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IncomingWebhookStorageSettings {
+    /// Maximum number of incoming webhooks stored per merchant in a minute, the webhooks above
+    /// the limit are processed without being stored
+    pub max_stored_per_minute: i64,
+    /// Number of days after which stored incoming webhooks are deleted
+    pub retention_days: i64,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -794,6 +906,9 @@ impl Settings {
         #[cfg(feature = "s3")]
         self.file_upload_config.validate()?;
         self.lock_settings.validate()?;
+        self.connector_health.validate()?;
+        self.webhooks.replay_protection.validate()?;
+        self.webhooks.incoming_storage.validate()?;
         Ok(())
     }
 }
@@ -193,3 +193,83 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::ConnectorHealthConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.window_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health window_seconds must be greater than 0".into(),
+            ))
+        })?;
+
+        when(!(0.0..=1.0).contains(&self.failure_rate_threshold), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health failure_rate_threshold must be between 0 and 1".into(),
+            ))
+        })?;
+
+        when(
+            self.open_duration_seconds <= 0 || self.probe_timeout_seconds <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health open_duration_seconds and probe_timeout_seconds must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorHttpClientSettings {
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        std::iter::once(("default", &self.default))
+            .chain(
+                self.overrides
+                    .iter()
+                    .map(|(connector, config)| (connector.as_str(), config)),
+            )
+            .try_for_each(|(name, config)| {
+                when(
+                    config.connect_timeout == Some(0) || config.request_timeout == Some(0),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "{parent_field}.{name} connect_timeout and request_timeout must be greater than 0"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::IncomingWebhookStorageSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.max_stored_per_minute <= 0 || self.retention_days <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "incoming webhook storage max_stored_per_minute and retention_days must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::WebhookReplayProtectionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.timestamp_tolerance_secs <= 0 || self.deduplication_ttl_secs <= 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhook replay protection timestamp_tolerance_secs and deduplication_ttl_secs must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
@@ -1200,8 +1200,16 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
             "{}pal/servlet/Payout/v68/{}",
             connectors.adyen.secondary_base_url,
             match req.request.payout_type {
-                storage_enums::PayoutType::Bank => "confirmThirdParty".to_string(),
+                storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet => {
+                    "confirmThirdParty".to_string()
+                }
                 storage_enums::PayoutType::Card => "payout".to_string(),
+                storage_enums::PayoutType::InstantTransfer => {
+                    Err(errors::ConnectorError::NotSupported {
+                        message: "Instant transfer payouts are not supported".to_string(),
+                        connector: "Adyen",
+                    })?
+                }
             }
         ))
     }
@@ -1222,10 +1230,12 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
         let mut api_key = vec![(
             headers::X_API_KEY.to_string(),
             match req.request.payout_type {
-                storage_enums::PayoutType::Bank => {
+                storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet => {
                     auth.review_key.unwrap_or(auth.api_key).into_masked()
                 }
-                storage_enums::PayoutType::Card => auth.api_key.into_masked(),
+                storage_enums::PayoutType::Card | storage_enums::PayoutType::InstantTransfer => {
+                    auth.api_key.into_masked()
+                }
             },
         )];
         header.append(&mut api_key);
@@ -1542,6 +1552,11 @@ impl api::IncomingWebhook for Adyen {
                 ),
             ));
         }
+        if adyen::is_payout_event(&notif.event_code) {
+            return Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+                api_models::webhooks::PayoutIdType::PayoutId(notif.merchant_reference),
+            ));
+        }
         Err(errors::ConnectorError::WebhookReferenceIdNotFound).into_report()
     }
 
@@ -1551,6 +1566,12 @@ impl api::IncomingWebhook for Adyen {
     ) -> CustomResult<IncomingWebhookEvent, errors::ConnectorError> {
         let notif = get_webhook_object_from_body(request.body)
             .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        if adyen::is_payout_event(&notif.event_code) {
+            return Ok(adyen::get_payout_webhook_event(
+                notif.event_code,
+                notif.success.as_str(),
+            ));
+        }
         Ok(IncomingWebhookEvent::foreign_from((
             notif.event_code,
             notif.additional_data.dispute_status,
@@ -1573,6 +1594,20 @@ impl api::IncomingWebhook for Adyen {
         Ok(res_json)
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let notif = get_webhook_object_from_body(request.body)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        // Adyen identifies a notification by its psp reference, event code and success field
+        Ok(Some(format!(
+            "{}_{}_{}",
+            notif.psp_reference, notif.event_code, notif.success
+        )))
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
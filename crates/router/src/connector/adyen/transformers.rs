@@ -3630,6 +3630,10 @@ pub enum WebhookEventCode {
     PrearbitrationLost,
     Capture,
     CaptureFailed,
+    PayoutThirdparty,
+    PayoutDecline,
+    PayoutExpire,
+    PaidoutReversed,
     #[serde(other)]
     Unknown,
 }
@@ -3666,6 +3670,29 @@ pub fn is_chargeback_event(event_code: &WebhookEventCode) -> bool {
     )
 }
 
+pub fn is_payout_event(event_code: &WebhookEventCode) -> bool {
+    matches!(
+        event_code,
+        WebhookEventCode::PayoutThirdparty
+            | WebhookEventCode::PayoutDecline
+            | WebhookEventCode::PayoutExpire
+            | WebhookEventCode::PaidoutReversed
+    )
+}
+
+pub fn get_payout_webhook_event(
+    event_code: WebhookEventCode,
+    success: &str,
+) -> webhooks::IncomingWebhookEvent {
+    match (event_code, success) {
+        // A failed PAYOUT_THIRDPARTY notification means the payout was rejected by the bank
+        (WebhookEventCode::PayoutThirdparty, "false") => {
+            webhooks::IncomingWebhookEvent::PayoutFailure
+        }
+        (event_code, _) => webhooks::IncomingWebhookEvent::foreign_from((event_code, None)),
+    }
+}
+
 impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::IncomingWebhookEvent {
     fn foreign_from((code, status): (WebhookEventCode, Option<DisputeStatus>)) -> Self {
         match (code, status) {
@@ -3691,6 +3718,10 @@ impl ForeignFrom<(WebhookEventCode, Option<DisputeStatus>)> for webhooks::Incomi
             (WebhookEventCode::Unknown, _) => Self::EventNotSupported,
             (WebhookEventCode::Capture, _) => Self::PaymentIntentSuccess,
             (WebhookEventCode::CaptureFailed, _) => Self::PaymentIntentFailure,
+            (WebhookEventCode::PayoutThirdparty, _) => Self::PayoutSuccess,
+            (WebhookEventCode::PayoutDecline, _) => Self::PayoutCancelled,
+            (WebhookEventCode::PayoutExpire, _) => Self::PayoutExpired,
+            (WebhookEventCode::PaidoutReversed, _) => Self::PayoutReversal,
         }
     }
 }
@@ -3792,7 +3823,8 @@ pub struct AdyenPayoutCreateRequest {
     amount: Amount,
     recurring: RecurringContract,
     merchant_account: Secret<String>,
-    bank: PayoutBankDetails,
+    bank: Option<PayoutBankDetails>,
+    selected_brand: Option<PayoutBrand>,
     reference: String,
     shopper_reference: String,
     shopper_email: Option<Email>,
@@ -3816,6 +3848,13 @@ struct PayoutBankDetails {
     tax_id: Option<Secret<String>>,
 }
 
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum PayoutBrand {
+    Paypal,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -3888,14 +3927,14 @@ pub enum PayoutEligibility {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum AdyenPayoutFulfillRequest {
-    Bank(PayoutFulfillBankRequest),
+    GenericFulfillRequest(PayoutFulfillGenericRequest),
     Card(Box<PayoutFulfillCardRequest>),
 }
 
 #[cfg(feature = "payouts")]
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct PayoutFulfillBankRequest {
+pub struct PayoutFulfillGenericRequest {
     merchant_account: Secret<String>,
     original_reference: String,
 }
@@ -3983,7 +4022,10 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutC
             .to_owned()
             .map_or((None, None), |c| (c.name, c.email));
 
-        match item.router_data.get_payout_method_data()? {
+        let (bank, selected_brand, shopper_email) = match item
+            .router_data
+            .get_payout_method_data()?
+        {
             PayoutMethodData::Card(_) => Err(errors::ConnectorError::NotSupported {
                 message: "Card payout creation is not supported".to_string(),
                 connector: "Adyen",
@@ -4004,31 +4046,47 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutC
                         connector: "Adyen",
                     })?,
                 };
-                let address: &payments::AddressDetails = item.router_data.get_billing_address()?;
-                Ok(Self {
-                    amount: Amount {
-                        value: item.amount.to_owned(),
-                        currency: item.router_data.request.destination_currency.to_string(),
-                    },
-                    recurring: RecurringContract {
-                        contract: Contract::Payout,
-                    },
-                    merchant_account,
-                    bank: bank_details,
-                    reference: item.router_data.request.payout_id.to_owned(),
-                    shopper_reference: item.router_data.merchant_id.to_owned(),
-                    shopper_email: customer_email,
-                    shopper_name: ShopperName {
-                        first_name: address.get_first_name().ok().cloned(),
-                        last_name: address.get_last_name().ok().cloned(),
-                    },
-                    date_of_birth: None,
-                    entity_type: Some(item.router_data.request.entity_type),
-                    nationality: get_country_code(item.router_data.address.billing.as_ref()),
-                    billing_address: get_address_info(item.router_data.address.billing.as_ref()),
-                })
+                (Some(bank_details), None, customer_email)
             }
-        }
+            PayoutMethodData::Wallet(wallet_data) => match wallet_data {
+                // The payout is credited to the PayPal account linked with the shopper's email
+                payouts::Wallet::Paypal(paypal_data) => {
+                    (None, Some(PayoutBrand::Paypal), Some(paypal_data.email))
+                }
+                payouts::Wallet::Venmo(_) => Err(errors::ConnectorError::NotSupported {
+                    message: "Venmo payouts are not supported".to_string(),
+                    connector: "Adyen",
+                })?,
+            },
+            PayoutMethodData::InstantTransfer(_) => Err(errors::ConnectorError::NotSupported {
+                message: "Instant transfer payouts are not supported".to_string(),
+                connector: "Adyen",
+            })?,
+        };
+        let address: &payments::AddressDetails = item.router_data.get_billing_address()?;
+        Ok(Self {
+            amount: Amount {
+                value: item.amount.to_owned(),
+                currency: item.router_data.request.destination_currency.to_string(),
+            },
+            recurring: RecurringContract {
+                contract: Contract::Payout,
+            },
+            merchant_account,
+            bank,
+            selected_brand,
+            reference: item.router_data.request.payout_id.to_owned(),
+            shopper_reference: item.router_data.merchant_id.to_owned(),
+            shopper_email,
+            shopper_name: ShopperName {
+                first_name: address.get_first_name().ok().cloned(),
+                last_name: address.get_last_name().ok().cloned(),
+            },
+            date_of_birth: None,
+            entity_type: Some(item.router_data.request.entity_type),
+            nationality: get_country_code(item.router_data.address.billing.as_ref()),
+            billing_address: get_address_info(item.router_data.address.billing.as_ref()),
+        })
     }
 }
 
@@ -4041,15 +4099,23 @@ impl<F> TryFrom<&AdyenRouterData<&types::PayoutsRouterData<F>>> for AdyenPayoutF
         let payout_type = item.router_data.request.payout_type.to_owned();
         let merchant_account = auth_type.merchant_account;
         match payout_type {
-            storage_enums::PayoutType::Bank => Ok(Self::Bank(PayoutFulfillBankRequest {
-                merchant_account,
-                original_reference: item
-                    .router_data
-                    .request
-                    .connector_payout_id
-                    .clone()
-                    .unwrap_or("".to_string()),
-            })),
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::Wallet => {
+                Ok(Self::GenericFulfillRequest(PayoutFulfillGenericRequest {
+                    merchant_account,
+                    original_reference: item
+                        .router_data
+                        .request
+                        .connector_payout_id
+                        .clone()
+                        .unwrap_or("".to_string()),
+                }))
+            }
+            storage_enums::PayoutType::InstantTransfer => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Instant transfer payouts are not supported".to_string(),
+                    connector: "Adyen",
+                })?
+            }
             storage_enums::PayoutType::Card => {
                 let address = item.router_data.get_billing_address()?;
                 Ok(Self::Card(Box::new(PayoutFulfillCardRequest {
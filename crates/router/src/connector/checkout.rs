@@ -916,6 +916,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::SettlementReport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "settlement reports are not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -1261,6 +1266,18 @@ impl api::IncomingWebhook for Checkout {
         Ok(resource_object)
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let details: checkout::CheckoutWebhookEventId = request
+            .body
+            .parse_struct("CheckoutWebhookEventId")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(Some(details.id))
+    }
+
     fn get_dispute_details(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -1095,6 +1095,11 @@ pub struct CheckoutWebhookEventTypeBody {
     pub transaction_type: CheckoutWebhookEventType,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookEventId {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CheckoutWebhookData {
     pub id: String,
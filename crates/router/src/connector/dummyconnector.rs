@@ -2,6 +2,7 @@ pub mod transformers;
 
 use std::fmt::Debug;
 
+use common_utils::{crypto, ext_traits::ByteSliceExt};
 use diesel_models::enums;
 use error_stack::{IntoReport, ResultExt};
 
@@ -24,6 +25,8 @@ use crate::{
     utils::{self, BytesExt},
 };
 
+const WEBHOOK_SIGNATURE_HEADER: &str = "X-Dummy-Connector-Signature";
+
 #[derive(Debug, Clone)]
 pub struct DummyConnector<const T: u8>;
 
@@ -150,6 +153,78 @@ impl<const T: u8>
         types::PaymentsResponseData,
     > for DummyConnector<T>
 {
+    fn get_headers(
+        &self,
+        req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/payment", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SetupMandateRouterData,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let connector_request = transformers::DummyConnectorPaymentsRequest::<T>::try_from(req)?;
+        let dummmy_mandate_request = types::RequestBody::log_and_get_request_body(
+            &connector_request,
+            utils::Encode::<transformers::DummyConnectorPaymentsRequest::<T>>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummmy_mandate_request))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::SetupMandateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::SetupMandateType::get_headers(self, req, connectors)?)
+                .body(types::SetupMandateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::SetupMandateRouterData,
+        res: Response,
+    ) -> CustomResult<types::SetupMandateRouterData, errors::ConnectorError> {
+        let response: transformers::PaymentsResponse = res
+            .response
+            .parse_struct("DummyConnector SetupMandateResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
 }
 
 impl<const T: u8>
@@ -226,7 +301,6 @@ impl<const T: u8>
             .response
             .parse_struct("DummyConnector PaymentsResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
-        println!("handle_response: {:#?}", response);
         types::RouterData::try_from(types::ResponseRouterData {
             response,
             data: data.clone(),
@@ -338,17 +412,27 @@ impl<const T: u8>
 
     fn get_url(
         &self,
-        _req: &types::PaymentsCaptureRouterData,
-        _connectors: &settings::Connectors,
+        req: &types::PaymentsCaptureRouterData,
+        connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_url method".to_string()).into())
+        Ok(format!(
+            "{}/{}/capture",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
     }
 
     fn get_request_body(
         &self,
-        _req: &types::PaymentsCaptureRouterData,
+        req: &types::PaymentsCaptureRouterData,
     ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
-        Err(errors::ConnectorError::NotImplemented("get_request_body method".to_string()).into())
+        let connector_request = transformers::DummyConnectorCaptureRequest::try_from(req)?;
+        let dummmy_capture_request = types::RequestBody::log_and_get_request_body(
+            &connector_request,
+            utils::Encode::<transformers::DummyConnectorCaptureRequest>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummmy_capture_request))
     }
 
     fn build_request(
@@ -364,6 +448,7 @@ impl<const T: u8>
                 .headers(types::PaymentsCaptureType::get_headers(
                     self, req, connectors,
                 )?)
+                .body(types::PaymentsCaptureType::get_request_body(self, req)?)
                 .build(),
         ))
     }
@@ -397,6 +482,68 @@ impl<const T: u8>
     ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsResponseData>
     for DummyConnector<T>
 {
+    fn get_headers(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/{}/void",
+            self.base_url(connectors),
+            req.request.connector_transaction_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsVoidType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PaymentsVoidType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsCancelRouterData,
+        res: Response,
+    ) -> CustomResult<types::PaymentsCancelRouterData, errors::ConnectorError> {
+        let response: transformers::PaymentsResponse = res
+            .response
+            .parse_struct("transformers PaymentsCancelResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
 }
 
 impl<const T: u8> ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
@@ -550,26 +697,288 @@ impl<const T: u8> ConnectorIntegration<api::RSync, types::RefundsData, types::Re
     }
 }
 
+impl<const T: u8> api::Payouts for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutCreate for DummyConnector<T> {}
+#[cfg(feature = "payouts")]
+impl<const T: u8> api::PayoutSync for DummyConnector<T> {}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8>
+    ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/payouts", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+    ) -> CustomResult<Option<types::RequestBody>, errors::ConnectorError> {
+        let connector_request = transformers::DummyConnectorPayoutRequest::<T>::try_from(req)?;
+        let dummmy_payout_request = types::RequestBody::log_and_get_request_body(
+            &connector_request,
+            utils::Encode::<transformers::DummyConnectorPayoutRequest<T>>::encode_to_string_of_json,
+        )
+        .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(dummmy_payout_request))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+                .body(types::PayoutCreateType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("DummyConnectorPayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8> ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for DummyConnector<T>
+{
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let payout_id = req
+            .request
+            .connector_payout_id
+            .as_ref()
+            .filter(|id| !id.is_empty())
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "connector_payout_id",
+            })?;
+        Ok(format!(
+            "{}/payouts/{}",
+            self.base_url(connectors),
+            payout_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::PayoutSyncType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PayoutSyncType::get_headers(self, req, connectors)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoSync>,
+        res: Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
+        let response: transformers::DummyConnectorPayoutResponse = res
+            .response
+            .parse_struct("DummyConnectorPayoutSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 #[async_trait::async_trait]
 impl<const T: u8> api::IncomingWebhook for DummyConnector<T> {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha256))
+    }
+
+    fn get_webhook_source_verification_signature(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature =
+            connector_utils::get_header_key_value(WEBHOOK_SIGNATURE_HEADER, request.headers)?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        _merchant_id: &str,
+        _connector_webhook_secrets: &api_models::webhooks::ConnectorWebhookSecrets,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(request.body.to_vec())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        let object: transformers::DummyConnectorWebhookObject =
+            serde_json::from_value(webhook.data)
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        match webhook.event_type {
+            transformers::DummyConnectorWebhookEventType::PaymentSucceeded
+            | transformers::DummyConnectorWebhookEventType::PaymentFailed
+            | transformers::DummyConnectorWebhookEventType::PaymentAuthorized
+            | transformers::DummyConnectorWebhookEventType::PaymentCancelled => {
+                Ok(api::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(object.id),
+                ))
+            }
+            transformers::DummyConnectorWebhookEventType::RefundSucceeded => {
+                Ok(api::webhooks::ObjectReferenceId::RefundId(
+                    api_models::webhooks::RefundIdType::ConnectorRefundId(object.id),
+                ))
+            }
+            transformers::DummyConnectorWebhookEventType::DisputeOpened
+            | transformers::DummyConnectorWebhookEventType::DisputeWon
+            | transformers::DummyConnectorWebhookEventType::DisputeLost => {
+                Ok(api::webhooks::ObjectReferenceId::PaymentId(
+                    api_models::payments::PaymentIdType::ConnectorTransactionId(
+                        object
+                            .payment_id
+                            .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)?,
+                    ),
+                ))
+            }
+            transformers::DummyConnectorWebhookEventType::PayoutSucceeded
+            | transformers::DummyConnectorWebhookEventType::PayoutFailed => {
+                Ok(api::webhooks::ObjectReferenceId::PayoutId(
+                    api_models::webhooks::PayoutIdType::ConnectorPayoutId(object.id),
+                ))
+            }
+        }
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Ok(api::IncomingWebhookEvent::EventNotSupported)
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        Ok(api::IncomingWebhookEvent::from(webhook.event_type))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(webhook.data)
+    }
+
+    fn get_dispute_details(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api::disputes::DisputePayload, errors::ConnectorError> {
+        let webhook: transformers::DummyConnectorWebhook = request
+            .body
+            .parse_struct("DummyConnectorWebhook")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        let dispute: transformers::DummyConnectorDisputeResponse =
+            serde_json::from_value(webhook.data)
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(api::disputes::DisputePayload {
+            amount: dispute.amount.to_string(),
+            currency: dispute.currency.to_string(),
+            dispute_stage: api_models::enums::DisputeStage::Dispute,
+            connector_status: dispute.status.to_string(),
+            connector_dispute_id: dispute.id,
+            connector_reason: dispute.reason,
+            connector_reason_code: None,
+            challenge_required_by: Some(dispute.challenge_required_by),
+            created_at: Some(dispute.created),
+            updated_at: Some(dispute.updated),
+        })
     }
 }
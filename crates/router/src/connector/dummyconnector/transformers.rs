@@ -1,9 +1,11 @@
 use diesel_models::enums::Currency;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
 use url::Url;
 
 use crate::{
+    connector::utils::PaymentsAuthorizeRequestData,
     core::errors,
     services,
     types::{self, api, storage::enums},
@@ -64,6 +66,15 @@ pub struct DummyConnectorPaymentsRequest<const T: u8> {
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
     connector: DummyConnectors,
+    capture_method: DummyConnectorCaptureMethod,
+    setup_mandate: bool,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorCaptureMethod {
+    Automatic,
+    Manual,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -72,6 +83,12 @@ pub enum PaymentMethodData {
     Card(DummyConnectorCard),
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    Mandate(DummyConnectorMandate),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorMandate {
+    mandate_id: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -141,32 +158,71 @@ impl TryFrom<api_models::payments::PayLaterData> for DummyConnectorPayLater {
     }
 }
 
+impl TryFrom<(&api::PaymentMethodData, Option<String>)> for PaymentMethodData {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        (payment_method_data, connector_mandate_id): (&api::PaymentMethodData, Option<String>),
+    ) -> Result<Self, Self::Error> {
+        match payment_method_data {
+            api::PaymentMethodData::Card(req_card) => Ok(Self::Card(req_card.clone().into())),
+            api::PaymentMethodData::Wallet(wallet_data) => {
+                Ok(Self::Wallet(wallet_data.clone().try_into()?))
+            }
+            api::PaymentMethodData::PayLater(pay_later_data) => {
+                Ok(Self::PayLater(pay_later_data.clone().try_into()?))
+            }
+            api::PaymentMethodData::MandatePayment => Ok(Self::Mandate(DummyConnectorMandate {
+                mandate_id: connector_mandate_id.ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "connector_mandate_id",
+                    },
+                )?,
+            })),
+            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        }
+    }
+}
+
 impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
     for DummyConnectorPaymentsRequest<T>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let payment_method_data: Result<PaymentMethodData, Self::Error> = match item
-            .request
-            .payment_method_data
-        {
-            api::PaymentMethodData::Card(ref req_card) => {
-                Ok(PaymentMethodData::Card(req_card.clone().into()))
-            }
-            api::PaymentMethodData::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodData::Wallet(wallet_data.clone().try_into()?))
-            }
-            api::PaymentMethodData::PayLater(ref pay_later_data) => Ok(
-                PaymentMethodData::PayLater(pay_later_data.clone().try_into()?),
-            ),
-            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+        let payment_method_data = PaymentMethodData::try_from((
+            &item.request.payment_method_data,
+            item.request.connector_mandate_id(),
+        ))?;
+        let capture_method = if item.request.is_auto_capture()? {
+            DummyConnectorCaptureMethod::Automatic
+        } else {
+            DummyConnectorCaptureMethod::Manual
         };
         Ok(Self {
             amount: item.request.amount,
             currency: item.request.currency,
-            payment_method_data: payment_method_data?,
+            payment_method_data,
             return_url: item.request.router_return_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            capture_method,
+            setup_mandate: item.request.setup_mandate_details.is_some(),
+        })
+    }
+}
+
+impl<const T: u8> TryFrom<&types::SetupMandateRouterData> for DummyConnectorPaymentsRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SetupMandateRouterData) -> Result<Self, Self::Error> {
+        // A mandate is set up with a payment method of the customer, not with another mandate
+        let payment_method_data =
+            PaymentMethodData::try_from((&item.request.payment_method_data, None))?;
+        Ok(Self {
+            amount: item.request.amount.unwrap_or(0),
+            currency: item.request.currency,
+            payment_method_data,
+            return_url: item.request.router_return_url.clone(),
+            connector: Into::<DummyConnectors>::into(T),
+            capture_method: DummyConnectorCaptureMethod::Automatic,
+            setup_mandate: true,
         })
     }
 }
@@ -188,7 +244,6 @@ impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
     }
 }
 // PaymentsResponse
-//TODO: Append the remaining status flags
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum DummyConnectorPaymentStatus {
@@ -196,6 +251,8 @@ pub enum DummyConnectorPaymentStatus {
     Failed,
     #[default]
     Processing,
+    Authorized,
+    Cancelled,
 }
 
 impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
@@ -204,6 +261,8 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
             DummyConnectorPaymentStatus::Succeeded => Self::Charged,
             DummyConnectorPaymentStatus::Failed => Self::Failure,
             DummyConnectorPaymentStatus::Processing => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::Cancelled => Self::Voided,
         }
     }
 }
@@ -217,6 +276,21 @@ pub struct PaymentsResponse {
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    amount_captured: Option<i64>,
+    mandate_id: Option<String>,
+}
+
+impl PaymentsResponse {
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match (&self.status, self.amount_captured) {
+            (DummyConnectorPaymentStatus::Succeeded, Some(amount_captured))
+                if amount_captured < self.amount =>
+            {
+                enums::AttemptStatus::PartialCharged
+            }
+            (status, _) => enums::AttemptStatus::from(status.clone()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -225,6 +299,7 @@ pub enum PaymentMethodType {
     Card,
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    Mandate,
 }
 
 impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::PaymentsResponseData>>
@@ -234,6 +309,14 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
     fn try_from(
         item: types::ResponseRouterData<F, PaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let status = item.response.get_attempt_status();
+        let mandate_reference =
+            item.response
+                .mandate_id
+                .map(|mandate_id| types::MandateReference {
+                    connector_mandate_id: Some(mandate_id),
+                    payment_method_id: None,
+                });
         let redirection_data = item
             .response
             .next_action
@@ -242,11 +325,11 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data,
-                mandate_reference: None,
+                mandate_reference,
                 connector_metadata: None,
                 network_txn_id: None,
                 connector_response_reference_id: None,
@@ -270,6 +353,20 @@ impl DummyConnectorNextAction {
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorCaptureRequest {
+    amount: i64,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
@@ -353,6 +450,76 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     }
 }
 
+// PAYOUTS :
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorPayoutRequest<const T: u8> {
+    amount: i64,
+    currency: Currency,
+    connector: DummyConnectors,
+}
+
+#[cfg(feature = "payouts")]
+impl<const T: u8, F> TryFrom<&types::PayoutsRouterData<F>> for DummyConnectorPayoutRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.destination_currency,
+            connector: Into::<DummyConnectors>::into(T),
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutStatus {
+    Succeeded,
+    Failed,
+    Processing,
+}
+
+#[cfg(feature = "payouts")]
+impl From<DummyConnectorPayoutStatus> for enums::PayoutStatus {
+    fn from(item: DummyConnectorPayoutStatus) -> Self {
+        match item {
+            DummyConnectorPayoutStatus::Succeeded => Self::Success,
+            DummyConnectorPayoutStatus::Failed => Self::Failed,
+            DummyConnectorPayoutStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutResponse {
+    id: String,
+    status: DummyConnectorPayoutStatus,
+    amount: i64,
+    currency: Currency,
+    created: String,
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(item.response.status)),
+                connector_payout_id: item.response.id,
+                payout_eligible: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
 pub struct DummyConnectorErrorResponse {
     pub error: ErrorData,
@@ -364,3 +531,73 @@ pub struct ErrorData {
     pub message: String,
     pub reason: Option<String>,
 }
+
+// WEBHOOKS :
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCancelled,
+    RefundSucceeded,
+    DisputeOpened,
+    DisputeWon,
+    DisputeLost,
+    PayoutSucceeded,
+    PayoutFailed,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: DummyConnectorWebhookEventType) -> Self {
+        match event_type {
+            DummyConnectorWebhookEventType::PaymentSucceeded => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+            DummyConnectorWebhookEventType::PaymentAuthorized => Self::PaymentIntentProcessing,
+            DummyConnectorWebhookEventType::PaymentCancelled => Self::PaymentIntentCancelled,
+            DummyConnectorWebhookEventType::RefundSucceeded => Self::RefundSuccess,
+            DummyConnectorWebhookEventType::DisputeOpened => Self::DisputeOpened,
+            DummyConnectorWebhookEventType::DisputeWon => Self::DisputeWon,
+            DummyConnectorWebhookEventType::DisputeLost => Self::DisputeLost,
+            DummyConnectorWebhookEventType::PayoutSucceeded => Self::PayoutSuccess,
+            DummyConnectorWebhookEventType::PayoutFailed => Self::PayoutFailure,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhook {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: serde_json::Value,
+}
+
+/// The id of the object a webhook is for, along with the payment it belongs to for disputes
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookObject {
+    pub id: String,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, strum::Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorDisputeResponse {
+    pub id: String,
+    pub status: DummyConnectorDisputeStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub challenge_required_by: PrimitiveDateTime,
+}
@@ -373,6 +373,7 @@ impl
                     | api_models::enums::PaymentMethodType::Trustly
                     | api_models::enums::PaymentMethodType::Twint
                     | api_models::enums::PaymentMethodType::UpiCollect
+                    | api_models::enums::PaymentMethodType::Venmo
                     | api_models::enums::PaymentMethodType::Vipps
                     | api_models::enums::PaymentMethodType::Walley
                     | api_models::enums::PaymentMethodType::WeChatPay
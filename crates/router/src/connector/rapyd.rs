@@ -916,6 +916,31 @@ impl api::IncomingWebhook for Rapyd {
         Ok(res_json)
     }
 
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let webhook: transformers::RapydIncomingWebhook = request
+            .body
+            .parse_struct("RapydIncomingWebhook")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(Some(webhook.id))
+    }
+
+    fn get_webhook_signature_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        let timestamp = conn_utils::get_header_key_value("timestamp", request.headers)?;
+
+        timestamp
+            .parse::<i64>()
+            .map(Some)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
     fn get_dispute_details(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
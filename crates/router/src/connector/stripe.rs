@@ -650,7 +650,7 @@ impl
                 self.base_url(connectors),
                 "v1/payment_intents",
                 x,
-                "?expand[0]=latest_charge" //updated payment_id(if present) reside inside latest_charge field
+                "?expand[0]=latest_charge&expand[1]=latest_charge.balance_transaction" //updated payment_id(if present) reside inside latest_charge field
             )),
             x => x.change_context(errors::ConnectorError::MissingConnectorTransactionID),
         }
@@ -1320,7 +1320,11 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
         let id = req.request.get_connector_refund_id()?;
-        Ok(format!("{}v1/refunds/{}", self.base_url(connectors), id))
+        Ok(format!(
+            "{}v1/refunds/{}?expand[0]=balance_transaction",
+            self.base_url(connectors),
+            id
+        ))
     }
 
     fn build_request(
@@ -1419,6 +1423,11 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::SettlementReport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "settlement reports are not uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -2025,6 +2034,37 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(details.data.object)
     }
+
+    fn get_webhook_event_id(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let details: stripe::WebhookEventId = request
+            .body
+            .parse_struct("WebhookEventId")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        Ok(Some(details.id))
+    }
+
+    fn get_webhook_signature_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(request.headers)?;
+
+        let timestamp = security_header_kvs
+            .remove("t")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+
+        String::from_utf8_lossy(&timestamp)
+            .parse::<i64>()
+            .map(Some)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
     fn get_dispute_details(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -1,4 +1,4 @@
-use std::ops::Deref;
+use std::{ops::Deref, str::FromStr};
 
 use api_models::{self, enums as api_enums, payments};
 use common_utils::{
@@ -658,6 +658,7 @@ impl TryFrom<enums::PaymentMethodType> for StripePaymentMethodType {
             | enums::PaymentMethodType::TouchNGo
             | enums::PaymentMethodType::Trustly
             | enums::PaymentMethodType::Twint
+            | enums::PaymentMethodType::Venmo
             | enums::PaymentMethodType::Vipps
             | enums::PaymentMethodType::Alfamart
             | enums::PaymentMethodType::BcaBankTransfer
@@ -2140,6 +2141,73 @@ pub enum StripeChargeEnum {
 pub struct StripeCharge {
     pub id: String,
     pub payment_method_details: Option<StripePaymentMethodDetailsResponse>,
+    pub balance_transaction: Option<StripeBalanceTransactionEnum>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum StripeBalanceTransactionEnum {
+    BalanceTransactionId(String),
+    BalanceTransactionObject(StripeBalanceTransaction),
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StripeBalanceTransaction {
+    pub currency: String,
+    pub fee: i64,
+    pub fee_details: Vec<StripeFeeDetails>,
+    pub net: i64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct StripeFeeDetails {
+    pub amount: i64,
+    #[serde(rename = "type")]
+    pub fee_type: StripeFeeType,
+}
+
+#[derive(Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeFeeType {
+    StripeFee,
+    // interchange and scheme fees passed through on interchange plus pricing
+    PaymentMethodPassthroughFee,
+    #[serde(other)]
+    Other,
+}
+
+impl From<StripeBalanceTransaction> for types::ConnectorFeeDetails {
+    fn from(balance_transaction: StripeBalanceTransaction) -> Self {
+        let interchange_fee = balance_transaction
+            .fee_details
+            .iter()
+            .filter(|fee_details| {
+                fee_details.fee_type == StripeFeeType::PaymentMethodPassthroughFee
+            })
+            .map(|fee_details| fee_details.amount)
+            .reduce(|total, amount| total + amount);
+        Self {
+            processing_fee: Some(balance_transaction.fee - interchange_fee.unwrap_or_default()),
+            interchange_fee,
+            fx_markup: None,
+            net_amount: Some(balance_transaction.net),
+            settlement_currency: enums::Currency::from_str(
+                &balance_transaction.currency.to_uppercase(),
+            )
+            .ok(),
+        }
+    }
+}
+
+fn get_connector_fee_details(
+    balance_transaction: Option<StripeBalanceTransactionEnum>,
+) -> Option<types::ConnectorFeeDetails> {
+    match balance_transaction {
+        Some(StripeBalanceTransactionEnum::BalanceTransactionObject(balance_transaction)) => {
+            Some(balance_transaction.into())
+        }
+        Some(StripeBalanceTransactionEnum::BalanceTransactionId(_)) | None => None,
+    }
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -2492,10 +2560,18 @@ impl<F, T>
             Err,
         );
 
+        let connector_fee_details = match item.response.latest_charge.clone() {
+            Some(StripeChargeEnum::ChargeObject(charge)) => {
+                get_connector_fee_details(charge.balance_transaction)
+            }
+            Some(StripeChargeEnum::ChargeId(_)) | None => None,
+        };
+
         Ok(Self {
             status: enums::AttemptStatus::from(item.response.status.to_owned()),
             response,
             amount_captured: item.response.amount_received,
+            connector_fee_details,
             ..item.data
         })
     }
@@ -2739,6 +2815,8 @@ pub struct RefundResponse {
     pub metadata: StripeMetadata,
     pub payment_intent: String,
     pub status: RefundStatus,
+    #[serde(skip_serializing)]
+    pub balance_transaction: Option<StripeBalanceTransactionEnum>,
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -2753,6 +2831,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
             }),
+            connector_fee_details: get_connector_fee_details(item.response.balance_transaction),
             ..item.data
         })
     }
@@ -2770,6 +2849,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
                 connector_refund_id: item.response.id,
                 refund_status: enums::RefundStatus::from(item.response.status),
             }),
+            connector_fee_details: get_connector_fee_details(item.response.balance_transaction),
             ..item.data
         })
     }
@@ -3158,6 +3238,11 @@ pub struct WebhookEvent {
     pub event_data: WebhookEventData,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventId {
+    pub id: String,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WebhookEventTypeBody {
     #[serde(rename = "type")]
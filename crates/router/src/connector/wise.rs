@@ -1,7 +1,8 @@
 pub mod transformers;
 use std::fmt::Debug;
 
-use error_stack::{IntoReport, ResultExt};
+use common_utils::ext_traits::ByteSliceExt;
+use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
 use masking::PeekInterface;
 #[cfg(feature = "payouts")]
@@ -208,6 +209,8 @@ impl api::PayoutQuote for Wise {}
 impl api::PayoutRecipient for Wise {}
 #[cfg(feature = "payouts")]
 impl api::PayoutFulfill for Wise {}
+#[cfg(feature = "payouts")]
+impl api::PayoutSync for Wise {}
 
 #[cfg(feature = "payouts")]
 impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -661,6 +664,77 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
     }
 }
 
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
+    for Wise
+{
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let transfer_id = req
+            .request
+            .connector_payout_id
+            .to_owned()
+            .filter(|id| !id.is_empty())
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "transfer_id",
+            })?;
+        Ok(format!(
+            "{}v1/transfers/{}",
+            connectors.wise.base_url, transfer_id
+        ))
+    }
+
+    fn get_headers(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoSync>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutSyncType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutSyncType::get_headers(self, req, connectors)?)
+            .build();
+
+        Ok(Some(request))
+    }
+
+    #[instrument(skip_all)]
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoSync>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoSync>, errors::ConnectorError> {
+        let response: wise::WisePayoutSyncResponse = res
+            .response
+            .parse_struct("WisePayoutSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 impl api::Refund for Wise {}
 impl api::RefundExecute for Wise {}
 impl api::RefundSync for Wise {}
@@ -679,22 +753,46 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 impl api::IncomingWebhook for Wise {
     fn get_webhook_object_reference_id(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook_body: wise::WiseWebhookBody = request
+            .body
+            .parse_struct("WiseWebhookBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(api_models::webhooks::ObjectReferenceId::PayoutId(
+            api_models::webhooks::PayoutIdType::ConnectorPayoutId(
+                webhook_body.data.resource.id.to_string(),
+            ),
+        ))
     }
 
     fn get_webhook_event_type(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook_body: wise::WiseWebhookBody = request
+            .body
+            .parse_struct("WiseWebhookBody")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        // Only transfer state changes are subscribed to, everything else is acknowledged
+        if webhook_body.event_type != "transfers#state-change"
+            || webhook_body.data.resource.resource_type != "transfer"
+        {
+            return Ok(api::IncomingWebhookEvent::EventNotSupported);
+        }
+        Ok(api::IncomingWebhookEvent::from(
+            webhook_body.data.current_state,
+        ))
     }
 
     fn get_webhook_resource_object(
         &self,
-        _request: &api::IncomingWebhookRequestDetails<'_>,
+        request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook_body: serde_json::Value = request
+            .body
+            .parse_struct("WiseWebhookBody")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        Ok(webhook_body)
     }
 }
@@ -3,9 +3,7 @@ use api_models::payouts::PayoutMethodData;
 #[cfg(feature = "payouts")]
 use common_utils::pii::Email;
 use masking::Secret;
-use serde::Deserialize;
-#[cfg(feature = "payouts")]
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 type Error = error_stack::Report<errors::ConnectorError>;
 
@@ -274,7 +272,6 @@ pub struct WiseFulfillResponse {
     balance_transaction_id: Option<i64>,
 }
 
-#[cfg(feature = "payouts")]
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum WiseStatus {
@@ -291,6 +288,50 @@ pub enum WiseStatus {
 
     #[serde(rename = "incoming_payment_waiting")]
     IncomingPaymentWaiting,
+
+    #[serde(rename = "incoming_payment_initiated")]
+    IncomingPaymentInitiated,
+
+    #[serde(rename = "funds_converted")]
+    FundsConverted,
+
+    #[serde(rename = "outgoing_payment_sent")]
+    OutgoingPaymentSent,
+
+    #[serde(rename = "funds_refunded")]
+    FundsRefunded,
+
+    #[serde(rename = "bounced_back")]
+    BouncedBack,
+
+    #[serde(rename = "charged_back")]
+    ChargedBack,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize)]
+pub struct WisePayoutSyncResponse {
+    id: i64,
+    status: WiseStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookBody {
+    pub event_type: String,
+    pub data: WiseWebhookData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookData {
+    pub resource: WiseWebhookResource,
+    pub current_state: WiseStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WiseWebhookResource {
+    pub id: i64,
+    #[serde(rename = "type")]
+    pub resource_type: String,
 }
 
 #[cfg(feature = "payouts")]
@@ -321,6 +362,14 @@ fn get_payout_bank_details(
             field_name: "address",
         }),
     }?;
+    // Wise settles over the fastest rail available for the recipient account, so instant
+    // transfers are created with the same account details as regular bank transfers
+    let payout_method_data = match payout_method_data {
+        PayoutMethodData::InstantTransfer(instant_transfer) => {
+            PayoutMethodData::Bank(instant_transfer.into())
+        }
+        payout_method_data => payout_method_data,
+    };
     match payout_method_data {
         PayoutMethodData::Bank(payouts::BankPayout::Ach(b)) => Ok(WiseBankDetails {
             legal_type: LegalType::foreign_from(entity_type),
@@ -345,7 +394,7 @@ fn get_payout_bank_details(
             ..WiseBankDetails::default()
         }),
         _ => Err(errors::ConnectorError::NotSupported {
-            message: "Card payout creation is not supported".to_string(),
+            message: "Card and wallet payout creation is not supported".to_string(),
             connector: "Wise",
         }),
     }
@@ -371,11 +420,13 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WiseRecipientCreateRequest {
             }),
         }?;
         match request.payout_type.to_owned() {
-            storage_enums::PayoutType::Card => Err(errors::ConnectorError::NotSupported {
-                message: "Card payout creation is not supported".to_string(),
-                connector: "Wise",
-            })?,
-            storage_enums::PayoutType::Bank => {
+            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!("{} payout creation is not supported", request.payout_type),
+                    connector: "Wise",
+                })?
+            }
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::InstantTransfer => {
                 let account_holder_name = customer_details
                     .ok_or(errors::ConnectorError::MissingRequiredField {
                         field_name: "customer_details for PayoutRecipient creation",
@@ -425,17 +476,24 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutQuoteRequest {
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         let request = item.request.to_owned();
         match request.payout_type.to_owned() {
-            storage_enums::PayoutType::Bank => Ok(Self {
-                source_amount: Some(request.amount),
-                source_currency: request.source_currency.to_string(),
-                target_amount: None,
-                target_currency: request.destination_currency.to_string(),
-                pay_out: WisePayOutOption::default(),
-            }),
-            storage_enums::PayoutType::Card => Err(errors::ConnectorError::NotSupported {
-                message: "Card payout fulfillment is not supported".to_string(),
-                connector: "Wise",
-            })?,
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::InstantTransfer => {
+                Ok(Self {
+                    source_amount: Some(request.amount),
+                    source_currency: request.source_currency.to_string(),
+                    target_amount: None,
+                    target_currency: request.destination_currency.to_string(),
+                    pay_out: WisePayOutOption::default(),
+                })
+            }
+            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!(
+                        "{} payout fulfillment is not supported",
+                        request.payout_type
+                    ),
+                    connector: "Wise",
+                })?
+            }
         }
     }
 }
@@ -469,7 +527,7 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutCreateRequest {
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         let request = item.request.to_owned();
         match request.payout_type.to_owned() {
-            storage_enums::PayoutType::Bank => {
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::InstantTransfer => {
                 let connector_customer_id = item.get_connector_customer_id()?;
                 let quote_uuid = item.get_quote_id()?;
                 let wise_transfer_details = WiseTransferDetails {
@@ -489,10 +547,15 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutCreateRequest {
                     details: wise_transfer_details,
                 })
             }
-            storage_enums::PayoutType::Card => Err(errors::ConnectorError::NotSupported {
-                message: "Card payout fulfillment is not supported".to_string(),
-                connector: "Wise",
-            })?,
+            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!(
+                        "{} payout fulfillment is not supported",
+                        request.payout_type
+                    ),
+                    connector: "Wise",
+                })?
+            }
         }
     }
 }
@@ -530,13 +593,20 @@ impl<F> TryFrom<&types::PayoutsRouterData<F>> for WisePayoutFulfillRequest {
     fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
         let request = item.request.to_owned();
         match request.payout_type.to_owned() {
-            storage_enums::PayoutType::Bank => Ok(Self {
-                fund_type: FundType::default(),
-            }),
-            storage_enums::PayoutType::Card => Err(errors::ConnectorError::NotSupported {
-                message: "Card payout fulfillment is not supported".to_string(),
-                connector: "Wise",
-            })?,
+            storage_enums::PayoutType::Bank | storage_enums::PayoutType::InstantTransfer => {
+                Ok(Self {
+                    fund_type: FundType::default(),
+                })
+            }
+            storage_enums::PayoutType::Card | storage_enums::PayoutType::Wallet => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: format!(
+                        "{} payout fulfillment is not supported",
+                        request.payout_type
+                    ),
+                    connector: "Wise",
+                })?
+            }
         }
     }
 }
@@ -567,12 +637,53 @@ impl<F> TryFrom<types::PayoutsResponseRouterData<F, WiseFulfillResponse>>
 impl ForeignFrom<WiseStatus> for storage_enums::PayoutStatus {
     fn foreign_from(wise_status: WiseStatus) -> Self {
         match wise_status {
-            WiseStatus::Completed => Self::Success,
-            WiseStatus::Rejected => Self::Failed,
+            WiseStatus::Completed | WiseStatus::OutgoingPaymentSent => Self::Success,
+            WiseStatus::Rejected | WiseStatus::FundsRefunded => Self::Failed,
             WiseStatus::Cancelled => Self::Cancelled,
-            WiseStatus::Pending | WiseStatus::Processing | WiseStatus::IncomingPaymentWaiting => {
-                Self::Pending
-            }
+            WiseStatus::BouncedBack | WiseStatus::ChargedBack => Self::Reversed,
+            WiseStatus::Pending
+            | WiseStatus::Processing
+            | WiseStatus::IncomingPaymentWaiting
+            | WiseStatus::IncomingPaymentInitiated
+            | WiseStatus::FundsConverted => Self::Pending,
+        }
+    }
+}
+
+// Payouts sync response transform
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, WisePayoutSyncResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response: WisePayoutSyncResponse = item.response;
+
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(storage_enums::PayoutStatus::foreign_from(response.status)),
+                connector_payout_id: response.id.to_string(),
+                payout_eligible: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl From<WiseStatus> for api_models::webhooks::IncomingWebhookEvent {
+    fn from(wise_status: WiseStatus) -> Self {
+        match wise_status {
+            WiseStatus::Completed | WiseStatus::OutgoingPaymentSent => Self::PayoutSuccess,
+            WiseStatus::Rejected | WiseStatus::FundsRefunded => Self::PayoutFailure,
+            WiseStatus::Cancelled => Self::PayoutCancelled,
+            WiseStatus::BouncedBack | WiseStatus::ChargedBack => Self::PayoutReversal,
+            WiseStatus::Pending
+            | WiseStatus::Processing
+            | WiseStatus::IncomingPaymentWaiting
+            | WiseStatus::IncomingPaymentInitiated
+            | WiseStatus::FundsConverted => Self::PayoutProcessing,
         }
     }
 }
@@ -599,6 +710,15 @@ impl TryFrom<PayoutMethodData> for RecipientType {
             PayoutMethodData::Bank(api_models::payouts::Bank::Ach(_)) => Ok(Self::Aba),
             PayoutMethodData::Bank(api_models::payouts::Bank::Bacs(_)) => Ok(Self::SortCode),
             PayoutMethodData::Bank(api_models::payouts::Bank::Sepa(_)) => Ok(Self::Iban),
+            PayoutMethodData::InstantTransfer(api_models::payouts::InstantTransfer::Rtp(_)) => {
+                Ok(Self::Aba)
+            }
+            PayoutMethodData::InstantTransfer(
+                api_models::payouts::InstantTransfer::FasterPayments(_),
+            ) => Ok(Self::SortCode),
+            PayoutMethodData::InstantTransfer(
+                api_models::payouts::InstantTransfer::SepaInstant(_),
+            ) => Ok(Self::Iban),
             _ => Err(errors::ConnectorError::NotSupported {
                 message: "Requested payout_method_type is not supported".to_string(),
                 connector: "Wise",
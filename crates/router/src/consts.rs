@@ -47,5 +47,12 @@ pub(crate) const QR_IMAGE_DATA_SOURCE_STRING: &str = "data:image/png;base64";
 pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32";
 
 pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
+pub(crate) const METRICS_CONNECTOR_TAG_NAME: &str = "connector";
+
+/// Default delay before retrying an idempotent connector request
+pub(crate) const DEFAULT_CONNECTOR_RETRY_BACKOFF_MS: u64 = 100;
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
+pub const ROUTING_ACTIVATION_AUDIT_DEFAULT_LIMIT: i64 = 100;
+/// Number of times a routing algorithm is inserted again when another request took its version
+pub const ROUTING_ALGORITHM_VERSION_CONFLICT_RETRIES: usize = 3;
@@ -4,6 +4,7 @@ pub mod api_locking;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_health;
 pub mod customers;
 pub mod disputes;
 pub mod errors;
@@ -15,8 +16,11 @@ pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+#[cfg(feature = "olap")]
+pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod surcharge_decision_config;
 pub mod utils;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub mod verification;
@@ -995,35 +995,8 @@ pub async fn create_payment_connector(
 
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
 
-    // The purpose of this merchant account update is just to update the
-    // merchant account `modified_at` field for KGraph cache invalidation
-    let merchant_account_update = storage::MerchantAccountUpdate::Update {
-        merchant_name: None,
-        merchant_details: None,
-        return_url: None,
-        webhook_details: None,
-        sub_merchants_enabled: None,
-        parent_merchant_id: None,
-        enable_payment_response_hash: None,
-        locker_id: None,
-        payment_response_hash_key: None,
-        primary_business_details: None,
-        metadata: None,
-        publishable_key: None,
-        redirect_to_merchant_with_http_post: None,
-        routing_algorithm: None,
-        intent_fulfillment_time: None,
-        frm_routing_algorithm: None,
-        payout_routing_algorithm: None,
-        default_profile: None,
-        payment_link_config: None,
-    };
-
-    state
-        .store
-        .update_specific_fields_in_merchant(merchant_id, merchant_account_update, &key_store)
+    touch_merchant_account(&state, merchant_id, &key_store)
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error updating the merchant account when creating payment connector")?;
 
     let merchant_connector_account = domain::MerchantConnectorAccount {
@@ -1069,6 +1042,7 @@ pub async fn create_payment_connector(
         profile_id: Some(profile_id.clone()),
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config.clone(),
+        fee_schedule: get_fee_schedule_as_value(req.fee_schedule.as_ref())?,
     };
 
     let mut default_routing_config =
@@ -1190,6 +1164,44 @@ pub async fn list_payment_connectors(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Updates just the `modified_at` field of the merchant account, which the routing caches built
+/// from the merchant's connector accounts (the knowledge graph and the fee schedules) are
+/// invalidated by
+async fn touch_merchant_account(
+    state: &AppState,
+    merchant_id: &str,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let merchant_account_update = storage::MerchantAccountUpdate::Update {
+        merchant_name: None,
+        merchant_details: None,
+        return_url: None,
+        webhook_details: None,
+        sub_merchants_enabled: None,
+        parent_merchant_id: None,
+        enable_payment_response_hash: None,
+        locker_id: None,
+        payment_response_hash_key: None,
+        primary_business_details: None,
+        metadata: None,
+        publishable_key: None,
+        redirect_to_merchant_with_http_post: None,
+        routing_algorithm: None,
+        intent_fulfillment_time: None,
+        frm_routing_algorithm: None,
+        payout_routing_algorithm: None,
+        default_profile: None,
+        payment_link_config: None,
+    };
+
+    state
+        .store
+        .update_specific_fields_in_merchant(merchant_id, merchant_account_update, key_store)
+        .await
+        .map(|_| ())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
 pub async fn update_payment_connector(
     state: AppState,
     merchant_id: &str,
@@ -1261,6 +1273,7 @@ pub async fn update_payment_connector(
         },
         applepay_verified_domains: None,
         pm_auth_config: req.pm_auth_config,
+        fee_schedule: get_fee_schedule_as_value(req.fee_schedule.as_ref())?,
     };
 
     let updated_mca = db
@@ -1271,6 +1284,10 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
+    touch_merchant_account(&state, merchant_id, &key_store)
+        .await
+        .attach_printable("error updating the merchant account when updating payment connector")?;
+
     let response = updated_mca.try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -1313,6 +1330,10 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    touch_merchant_account(&state, &merchant_id, &key_store)
+        .await
+        .attach_printable("error updating the merchant account when deleting payment connector")?;
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1430,6 +1451,27 @@ pub fn get_frm_config_as_secret(
     }
 }
 
+fn get_fee_schedule_as_value(
+    fee_schedule: Option<&api_models::admin::ConnectorFeeSchedule>,
+) -> RouterResult<Option<serde_json::Value>> {
+    fee_schedule
+        .map(|fee_schedule| {
+            let has_invalid_fee = fee_schedule.rules.iter().any(|rule| {
+                rule.fixed_fee < 0 || !rule.percentage.is_finite() || rule.percentage < 0.0
+            });
+            if has_invalid_fee {
+                return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "fee_schedule",
+                }))
+                .attach_printable("Fees in the fee schedule must not be negative");
+            }
+            utils::Encode::<api_models::admin::ConnectorFeeSchedule>::encode_to_value(fee_schedule)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the fee schedule")
+        })
+        .transpose()
+}
+
 pub async fn create_and_insert_business_profile(
     db: &dyn StorageInterface,
     request: api::BusinessProfileCreate,
@@ -1607,6 +1649,7 @@ pub async fn update_business_profile(
         payout_routing_algorithm: request.payout_routing_algorithm,
         is_recon_enabled: None,
         applepay_verified_domains: request.applepay_verified_domains,
+        webhook_signing_keys: None,
     };
 
     let updated_business_profile = db
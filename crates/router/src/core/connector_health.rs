@@ -0,0 +1,705 @@
+//! Tracks the health of connectors from the outcome of the calls made to them, and breaks the
+//! circuit of unhealthy connectors so that routing skips them until they recover.
+//!
+//! Calls are counted per connector across all merchants, and per merchant connector account, in
+//! fixed windows stored in redis. A circuit opens when the failure rate over the current and the
+//! previous window reaches the configured threshold. Once it has been open for a while the circuit
+//! turns half open, and a single probe payment is routed to the connector: the circuit closes if
+//! the probe succeeds and opens again otherwise.
+
+use std::collections::HashMap;
+
+use api_models::{connector_health as health_types, enums as api_enums};
+use common_utils::{date_time, errors::CustomResult};
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::{errors::RedisError, RedisConnectionPool, SetnxReply};
+use router_env::{logger, tracing::Instrument};
+use strum::IntoEnumIterator;
+
+use crate::{
+    configs::settings::ConnectorHealthConfig,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+    types::{self, domain},
+};
+
+const TOTAL_CALLS: &str = "total_calls";
+const FAILED_CALLS: &str = "failed_calls";
+const TOTAL_LATENCY_MS: &str = "total_latency_ms";
+
+/// Outcome of a call made to a connector
+#[derive(Debug, Clone, Copy)]
+pub struct ConnectorCallOutcome {
+    /// The connector responded with a 5xx status code, timed out or could not be reached
+    pub failed: bool,
+    pub latency_ms: u128,
+}
+
+impl ConnectorCallOutcome {
+    /// Outcome of a connector flow, `None` if the flow completed without calling the connector.
+    /// Flows which errored out only count as failed calls if the connector could not be reached,
+    /// errors of this application say nothing about the health of the connector.
+    pub fn from_router_data_result<F, Req, Res>(
+        router_data_result: &RouterResult<types::RouterData<F, Req, Res>>,
+        elapsed: std::time::Duration,
+    ) -> Option<Self> {
+        match router_data_result {
+            Ok(router_data) => router_data
+                .connector_http_status_code
+                .map(|status_code| Self {
+                    failed: (500..=599).contains(&status_code),
+                    latency_ms: router_data.external_latency.unwrap_or(elapsed.as_millis()),
+                }),
+            Err(error) => error
+                .downcast_ref::<errors::ApiClientError>()
+                .filter(|error| is_connector_transport_error(error))
+                .map(|_| Self {
+                    failed: true,
+                    latency_ms: elapsed.as_millis(),
+                }),
+        }
+    }
+}
+
+fn is_connector_transport_error(error: &errors::ApiClientError) -> bool {
+    matches!(
+        error,
+        errors::ApiClientError::RequestNotSent(_)
+            | errors::ApiClientError::RequestTimeoutReceived
+            | errors::ApiClientError::ConnectionClosed
+            | errors::ApiClientError::InternalServerErrorReceived
+            | errors::ApiClientError::BadGatewayReceived
+            | errors::ApiClientError::ServiceUnavailableReceived
+            | errors::ApiClientError::GatewayTimeoutReceived
+    )
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HealthScope<'a> {
+    Connector(&'a str),
+    MerchantConnectorAccount(&'a str),
+}
+
+impl HealthScope<'_> {
+    fn key_prefix(&self) -> String {
+        match self {
+            Self::Connector(connector) => format!("connector_health_{connector}"),
+            Self::MerchantConnectorAccount(merchant_connector_id) => {
+                format!("connector_health_mca_{merchant_connector_id}")
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Connector(_) => "connector",
+            Self::MerchantConnectorAccount(_) => "merchant_connector_account",
+        }
+    }
+
+    fn window_key(&self, window: i64) -> String {
+        format!("{}_calls_{window}", self.key_prefix())
+    }
+
+    /// The circuits of the connectors are read together with a single `MGET`, so their keys
+    /// share a hash tag to be in the same slot when redis runs as a cluster
+    fn circuit_key(&self) -> String {
+        format!("{{connector_health_circuit}}_{}", self.key_prefix())
+    }
+
+    fn probe_key(&self) -> String {
+        format!("{}_probe", self.key_prefix())
+    }
+}
+
+/// Stored in redis for as long as the circuit is not closed
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct OpenCircuit {
+    opened_at: i64,
+    failure_rate: Option<f64>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CallCounts {
+    total_calls: u64,
+    failed_calls: u64,
+    total_latency_ms: u64,
+}
+
+impl CallCounts {
+    fn add_window(&mut self, fields: &HashMap<String, String>) {
+        let get_field = |name: &str| {
+            fields
+                .get(name)
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or_default()
+        };
+        self.total_calls += get_field(TOTAL_CALLS);
+        self.failed_calls += get_field(FAILED_CALLS);
+        self.total_latency_ms += get_field(TOTAL_LATENCY_MS);
+    }
+
+    #[allow(clippy::as_conversions)]
+    fn failure_rate(&self) -> Option<f64> {
+        if self.total_calls > 0 {
+            Some(self.failed_calls as f64 / self.total_calls as f64)
+        } else {
+            None
+        }
+    }
+
+    fn average_latency_ms(&self) -> Option<u64> {
+        self.total_latency_ms.checked_div(self.total_calls)
+    }
+}
+
+fn get_circuit_state(
+    circuit: Option<&OpenCircuit>,
+    now: i64,
+    config: &ConnectorHealthConfig,
+) -> health_types::CircuitState {
+    match circuit {
+        None => health_types::CircuitState::Closed,
+        Some(circuit) if now < circuit.opened_at + config.open_duration_seconds => {
+            health_types::CircuitState::Open
+        }
+        Some(_) => health_types::CircuitState::HalfOpen,
+    }
+}
+
+async fn get_open_circuit(
+    redis: &RedisConnectionPool,
+    scope: HealthScope<'_>,
+) -> CustomResult<Option<OpenCircuit>, RedisError> {
+    match redis
+        .get_and_deserialize_key::<OpenCircuit>(&scope.circuit_key(), "OpenCircuit")
+        .await
+    {
+        Ok(circuit) => Ok(Some(circuit)),
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Counts of the calls made in the current and the previous window
+async fn get_call_counts(
+    redis: &RedisConnectionPool,
+    scope: HealthScope<'_>,
+    window: i64,
+) -> CustomResult<CallCounts, RedisError> {
+    let mut counts = CallCounts::default();
+    for window in [window - 1, window] {
+        let fields = redis
+            .get_hash_fields::<HashMap<String, String>>(&scope.window_key(window))
+            .await?;
+        counts.add_window(&fields);
+    }
+    Ok(counts)
+}
+
+fn record_circuit_state_change(
+    scope: HealthScope<'_>,
+    connector: &str,
+    state: health_types::CircuitState,
+) {
+    logger::info!(
+        connector,
+        scope = scope.name(),
+        circuit_state = ?state,
+        "Connector circuit state changed"
+    );
+    metrics::CONNECTOR_CIRCUIT_STATE_CHANGE.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::request::add_attributes("connector", connector.to_string()),
+            metrics::request::add_attributes("scope", scope.name()),
+            metrics::request::add_attributes(
+                "state",
+                match state {
+                    health_types::CircuitState::Closed => "closed",
+                    health_types::CircuitState::Open => "open",
+                    health_types::CircuitState::HalfOpen => "half_open",
+                },
+            ),
+        ],
+    );
+}
+
+async fn open_circuit(
+    redis: &RedisConnectionPool,
+    scope: HealthScope<'_>,
+    connector: &str,
+    now: i64,
+    failure_rate: Option<f64>,
+) -> CustomResult<(), RedisError> {
+    redis
+        .serialize_and_set_key(
+            &scope.circuit_key(),
+            OpenCircuit {
+                opened_at: now,
+                failure_rate,
+            },
+        )
+        .await?;
+    // Lets the next half open period send a new probe
+    redis.delete_key(&scope.probe_key()).await?;
+
+    record_circuit_state_change(scope, connector, health_types::CircuitState::Open);
+    Ok(())
+}
+
+async fn close_circuit(
+    redis: &RedisConnectionPool,
+    scope: HealthScope<'_>,
+    connector: &str,
+    window: i64,
+) -> CustomResult<(), RedisError> {
+    redis.delete_key(&scope.circuit_key()).await?;
+    redis.delete_key(&scope.probe_key()).await?;
+    // The failures which opened the circuit must not open it again right away
+    for window in [window - 1, window] {
+        redis.delete_key(&scope.window_key(window)).await?;
+    }
+
+    record_circuit_state_change(scope, connector, health_types::CircuitState::Closed);
+    Ok(())
+}
+
+async fn record_call_in_scope(
+    redis: &RedisConnectionPool,
+    config: &ConnectorHealthConfig,
+    scope: HealthScope<'_>,
+    connector: &str,
+    outcome: ConnectorCallOutcome,
+) -> CustomResult<(), RedisError> {
+    let now = date_time::now_unix_timestamp();
+    let window = now / config.window_seconds;
+
+    redis
+        .increment_fields_in_hash(
+            &scope.window_key(window),
+            &[
+                (TOTAL_CALLS, 1),
+                (FAILED_CALLS, i64::from(outcome.failed)),
+                (
+                    TOTAL_LATENCY_MS,
+                    i64::try_from(outcome.latency_ms).unwrap_or(i64::MAX),
+                ),
+            ],
+            config.window_seconds * 2,
+        )
+        .await?;
+
+    if !config.circuit_breaker_enabled {
+        return Ok(());
+    }
+
+    let circuit = get_open_circuit(redis, scope).await?;
+    match get_circuit_state(circuit.as_ref(), now, config) {
+        // Calls which were routed before the circuit opened
+        health_types::CircuitState::Open => Ok(()),
+        health_types::CircuitState::HalfOpen if outcome.failed => {
+            let counts = get_call_counts(redis, scope, window).await?;
+            open_circuit(redis, scope, connector, now, counts.failure_rate()).await
+        }
+        health_types::CircuitState::HalfOpen => {
+            close_circuit(redis, scope, connector, window).await
+        }
+        health_types::CircuitState::Closed => {
+            let counts = get_call_counts(redis, scope, window).await?;
+            let failure_rate = counts.failure_rate();
+            if counts.total_calls >= config.min_calls
+                && failure_rate.map_or(false, |rate| rate >= config.failure_rate_threshold)
+            {
+                open_circuit(redis, scope, connector, now, failure_rate).await
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Records the outcome of a call made to a connector, and opens or closes the circuits of the
+/// connector and of the merchant connector account accordingly. The recording takes a few redis
+/// round trips, so it is done in the background rather than on the path of the payment. Failures
+/// are only logged, as the health of the connectors must not affect the payment itself.
+pub fn record_connector_call(
+    state: &AppState,
+    connector: String,
+    merchant_connector_id: Option<String>,
+    outcome: ConnectorCallOutcome,
+) {
+    let state = state.clone();
+    tokio::spawn(
+        async move {
+            let redis = match state.store.get_redis_conn() {
+                Ok(redis) => redis,
+                Err(error) => {
+                    logger::error!(?error, "Failed to get redis connection");
+                    return;
+                }
+            };
+
+            let scopes = [
+                Some(HealthScope::Connector(&connector)),
+                merchant_connector_id
+                    .as_deref()
+                    .map(HealthScope::MerchantConnectorAccount),
+            ];
+            for scope in scopes.into_iter().flatten() {
+                record_call_in_scope(
+                    &redis,
+                    &state.conf.connector_health,
+                    scope,
+                    &connector,
+                    outcome,
+                )
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to record connector call"))
+                .ok();
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Whether routing may send a payment to each of the connectors, given by their name and their
+/// merchant connector account. A half open circuit only lets the payment through while no probe
+/// is in flight, the probe slot itself is only taken by [`take_probe_slot`] once the connector is
+/// called. The circuits of all the connectors are fetched at once, and the connectors are
+/// considered available if their circuits cannot be read.
+pub async fn get_connectors_availability(
+    state: &AppState,
+    connectors: &[(&str, Option<&str>)],
+) -> Vec<bool> {
+    let config = &state.conf.connector_health;
+    if !config.circuit_breaker_enabled {
+        return vec![true; connectors.len()];
+    }
+
+    let redis = match state.store.get_redis_conn() {
+        Ok(redis) => redis,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return vec![true; connectors.len()];
+        }
+    };
+
+    let connector_scopes = connectors
+        .iter()
+        .map(|&(connector, merchant_connector_id)| {
+            [
+                Some(HealthScope::Connector(connector)),
+                merchant_connector_id.map(HealthScope::MerchantConnectorAccount),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let circuit_keys = connector_scopes
+        .iter()
+        .flatten()
+        .map(HealthScope::circuit_key)
+        .collect::<Vec<_>>();
+    let mut circuits = match redis
+        .get_and_deserialize_multiple_keys::<_, OpenCircuit>(circuit_keys, "OpenCircuit")
+        .await
+    {
+        Ok(circuits) => circuits.into_iter(),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch connector circuits");
+            return vec![true; connectors.len()];
+        }
+    };
+
+    let now = date_time::now_unix_timestamp();
+    let mut availability = Vec::with_capacity(connectors.len());
+    for (&(connector, _), scopes) in connectors.iter().zip(connector_scopes) {
+        let mut available = true;
+        for scope in scopes {
+            // Every circuit is taken off the iterator, so that it stays in line with the scopes
+            let circuit = circuits.next().flatten();
+            if !available {
+                continue;
+            }
+
+            available = match get_circuit_state(circuit.as_ref(), now, config) {
+                health_types::CircuitState::Closed => true,
+                health_types::CircuitState::Open => false,
+                health_types::CircuitState::HalfOpen => {
+                    !matches!(redis.exists::<Vec<u8>>(&scope.probe_key()).await, Ok(true))
+                }
+            };
+
+            if !available {
+                metrics::CONNECTOR_CIRCUIT_OPEN_SKIP_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[
+                        metrics::request::add_attributes("connector", connector.to_string()),
+                        metrics::request::add_attributes("scope", scope.name()),
+                    ],
+                );
+            }
+        }
+        availability.push(available);
+    }
+
+    availability
+}
+
+/// Takes the probe slot of the half open circuits of the connector which is about to be called.
+/// Another payment may have taken the slot since routing selected the connector, the call is made
+/// regardless as it is already on its way, and its outcome closes or opens the circuit all the
+/// same.
+pub async fn take_probe_slot(
+    state: &AppState,
+    connector: &str,
+    merchant_connector_id: Option<&str>,
+) {
+    let config = &state.conf.connector_health;
+    if !config.circuit_breaker_enabled {
+        return;
+    }
+
+    let redis = match state.store.get_redis_conn() {
+        Ok(redis) => redis,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let now = date_time::now_unix_timestamp();
+    let scopes = [
+        Some(HealthScope::Connector(connector)),
+        merchant_connector_id.map(HealthScope::MerchantConnectorAccount),
+    ];
+    for scope in scopes.into_iter().flatten() {
+        let circuit = get_open_circuit(&redis, scope)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch connector circuit"))
+            .ok()
+            .flatten();
+
+        if get_circuit_state(circuit.as_ref(), now, config) != health_types::CircuitState::HalfOpen
+        {
+            continue;
+        }
+
+        match redis
+            .set_key_if_not_exists_with_expiry(
+                &scope.probe_key(),
+                "probe",
+                Some(config.probe_timeout_seconds),
+            )
+            .await
+        {
+            Ok(SetnxReply::KeySet) => {}
+            Ok(SetnxReply::KeyNotSet) => logger::info!(
+                connector,
+                scope = scope.name(),
+                "Probe of the half open circuit is already in flight"
+            ),
+            Err(error) => logger::error!(?error, "Failed to take the probe slot of the circuit"),
+        }
+    }
+}
+
+async fn get_connector_health(
+    redis: &RedisConnectionPool,
+    config: &ConnectorHealthConfig,
+    scope: HealthScope<'_>,
+    connector: String,
+    now: i64,
+) -> RouterResult<health_types::ConnectorHealth> {
+    let circuit = get_open_circuit(redis, scope)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector circuit")?;
+    let counts = get_call_counts(redis, scope, now / config.window_seconds)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector call counts")?;
+
+    let opened_at = circuit
+        .as_ref()
+        .map(|circuit| time::OffsetDateTime::from_unix_timestamp(circuit.opened_at))
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid opening time of connector circuit")?
+        .map(|opened_at| time::PrimitiveDateTime::new(opened_at.date(), opened_at.time()));
+
+    Ok(health_types::ConnectorHealth {
+        connector,
+        merchant_connector_id: match scope {
+            HealthScope::Connector(_) => None,
+            HealthScope::MerchantConnectorAccount(merchant_connector_id) => {
+                Some(merchant_connector_id.to_string())
+            }
+        },
+        circuit_state: get_circuit_state(circuit.as_ref(), now, config),
+        opened_at,
+        total_calls: counts.total_calls,
+        failed_calls: counts.failed_calls,
+        failure_rate: counts.failure_rate(),
+        average_latency_ms: counts.average_latency_ms(),
+    })
+}
+
+pub async fn retrieve_connector_health(
+    state: AppState,
+    query: health_types::ConnectorHealthQuery,
+) -> RouterResponse<health_types::ConnectorHealthResponse> {
+    let config = &state.conf.connector_health;
+    let redis = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let now = date_time::now_unix_timestamp();
+
+    let mut connectors = Vec::new();
+    for connector in api_enums::RoutableConnectors::iter() {
+        let connector = connector.to_string();
+        let health = get_connector_health(
+            &redis,
+            config,
+            HealthScope::Connector(&connector),
+            connector.clone(),
+            now,
+        )
+        .await?;
+
+        if health.total_calls > 0 || health.circuit_state != health_types::CircuitState::Closed {
+            connectors.push(health);
+        }
+    }
+
+    let mut merchant_connector_accounts = Vec::new();
+    if let Some(merchant_id) = query.merchant_id {
+        let db = state.store.as_ref();
+        let key_store: domain::MerchantKeyStore = db
+            .get_merchant_key_store_by_merchant_id(
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+        let accounts = db
+            .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+                &merchant_id,
+                true,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant connector accounts")?;
+
+        for account in accounts {
+            merchant_connector_accounts.push(
+                get_connector_health(
+                    &redis,
+                    config,
+                    HealthScope::MerchantConnectorAccount(&account.merchant_connector_id),
+                    account.connector_name.clone(),
+                    now,
+                )
+                .await?,
+            );
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        health_types::ConnectorHealthResponse {
+            connectors,
+            merchant_connector_accounts,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_state_transitions_with_time() {
+        let config = ConnectorHealthConfig::default();
+        let circuit = OpenCircuit {
+            opened_at: 1_700_000_000,
+            failure_rate: Some(0.8),
+        };
+
+        assert_eq!(
+            get_circuit_state(None, 1_700_000_000, &config),
+            health_types::CircuitState::Closed
+        );
+        assert_eq!(
+            get_circuit_state(Some(&circuit), 1_700_000_000 + 1, &config),
+            health_types::CircuitState::Open
+        );
+        assert_eq!(
+            get_circuit_state(
+                Some(&circuit),
+                1_700_000_000 + config.open_duration_seconds,
+                &config
+            ),
+            health_types::CircuitState::HalfOpen
+        );
+    }
+
+    #[test]
+    fn test_call_counts_across_windows() {
+        let mut counts = CallCounts::default();
+        assert_eq!(counts.failure_rate(), None);
+        assert_eq!(counts.average_latency_ms(), None);
+
+        counts.add_window(&HashMap::from([
+            (TOTAL_CALLS.to_string(), "3".to_string()),
+            (FAILED_CALLS.to_string(), "1".to_string()),
+            (TOTAL_LATENCY_MS.to_string(), "900".to_string()),
+        ]));
+        counts.add_window(&HashMap::from([(TOTAL_CALLS.to_string(), "1".to_string())]));
+
+        assert_eq!(
+            counts,
+            CallCounts {
+                total_calls: 4,
+                failed_calls: 1,
+                total_latency_ms: 900,
+            }
+        );
+        assert_eq!(counts.failure_rate(), Some(0.25));
+        assert_eq!(counts.average_latency_ms(), Some(225));
+    }
+
+    #[test]
+    fn test_only_connector_errors_are_counted_as_failed_calls() {
+        let elapsed = std::time::Duration::from_millis(100);
+
+        let internal_error: RouterResult<types::PaymentsAuthorizeRouterData> = Err(
+            error_stack::report!(errors::ApiErrorResponse::InternalServerError),
+        );
+        assert!(ConnectorCallOutcome::from_router_data_result(&internal_error, elapsed).is_none());
+
+        let connection_error: RouterResult<types::PaymentsAuthorizeRouterData> = Err(
+            error_stack::report!(errors::ApiClientError::ConnectionClosed)
+                .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+                .change_context(errors::ApiErrorResponse::InternalServerError),
+        );
+        let outcome = ConnectorCallOutcome::from_router_data_result(&connection_error, elapsed);
+        assert!(outcome.map_or(false, |outcome| outcome.failed));
+
+        let encoding_error: RouterResult<types::PaymentsAuthorizeRouterData> = Err(
+            error_stack::report!(errors::ApiClientError::BodySerializationFailed)
+                .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+                .change_context(errors::ApiErrorResponse::InternalServerError),
+        );
+        assert!(ConnectorCallOutcome::from_router_data_result(&encoding_error, elapsed).is_none());
+    }
+}
@@ -137,6 +137,8 @@ pub enum ConnectorError {
     WebhookSignatureNotFound,
     #[error("Failed to verify webhook source")]
     WebhookSourceVerificationFailed,
+    #[error("Webhook source verification is not supported by the connector")]
+    WebhookSourceVerificationNotSupported,
     #[error("Could not find merchant secret in DB for incoming webhook source verification")]
     WebhookVerificationSecretNotFound,
     #[error("Merchant secret found for incoming webhook source verification is invalid")]
@@ -240,6 +242,10 @@ pub enum WebhooksFlowError {
     WebhookEventCreationFailed,
     #[error("Webhook event updation failed")]
     WebhookEventUpdationFailed,
+    #[error("Webhook delivery creation failed")]
+    WebhookDeliveryCreationFailed,
+    #[error("Webhook delivery updation failed")]
+    WebhookDeliveryUpdationFailed,
     #[error("Outgoing webhook body signing failed")]
     OutgoingWebhookSigningFailed,
     #[error("Unable to fork webhooks flow for outgoing webhooks")]
@@ -370,4 +376,6 @@ pub enum RoutingError {
     VolumeSplitFailed,
     #[error("Unable to parse metadata")]
     MetadataParsingError,
+    #[error("failed to fetch the merchant connector accounts for the merchant")]
+    MerchantConnectorAccountsFetchFailed,
 }
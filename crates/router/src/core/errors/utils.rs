@@ -230,6 +230,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::WebhookBodyDecodingFailed |
                 errors::ConnectorError::WebhookSignatureNotFound |
                 errors::ConnectorError::WebhookSourceVerificationFailed |
+                errors::ConnectorError::WebhookSourceVerificationNotSupported |
                 errors::ConnectorError::WebhookVerificationSecretNotFound |
                 errors::ConnectorError::WebhookVerificationSecretInvalid |
                 errors::ConnectorError::WebhookReferenceIdNotFound |
@@ -313,6 +314,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 | errors::ConnectorError::WebhookBodyDecodingFailed
                 | errors::ConnectorError::WebhookSignatureNotFound
                 | errors::ConnectorError::WebhookSourceVerificationFailed
+                | errors::ConnectorError::WebhookSourceVerificationNotSupported
                 | errors::ConnectorError::WebhookVerificationSecretNotFound
                 | errors::ConnectorError::WebhookVerificationSecretInvalid
                 | errors::ConnectorError::WebhookReferenceIdNotFound
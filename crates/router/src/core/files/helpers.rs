@@ -26,6 +26,7 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("settlement_report") => Some(api::FilePurpose::SettlementReport),
         _ => None,
     }
 }
@@ -108,6 +109,19 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::SettlementReport => {
+            if !merchant_account.is_recon_enabled {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Reconciliation is not enabled for this merchant account".to_string(),
+                })?
+            }
+            if create_file_request.file_type != mime::TEXT_CSV {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV format".to_string(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -161,11 +175,37 @@ pub async fn retrieve_file_from_connector(
             .attach_printable("Missing file upload provider")?,
     )?
     .to_string();
+    let router_data = utils::construct_retrieve_file_router_data(
+        state,
+        merchant_account,
+        key_store,
+        &file_metadata,
+        connector,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed constructing the retrieve file router data")?;
+    call_connector_retrieve_file(
+        state,
+        connector,
+        file_metadata.merchant_connector_id,
+        &router_data,
+    )
+    .await
+}
+
+/// Downloads a file through the retrieve file integration of the connector
+pub async fn call_connector_retrieve_file(
+    state: &AppState,
+    connector: &str,
+    merchant_connector_id: Option<String>,
+    router_data: &types::RetrieveFileRouterData,
+) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
     let connector_data = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
         connector,
         api::GetToken::Connector,
-        file_metadata.merchant_connector_id.clone(),
+        merchant_connector_id,
     )?;
     let connector_integration: services::BoxedConnectorIntegration<
         '_,
@@ -173,20 +213,10 @@ pub async fn retrieve_file_from_connector(
         types::RetrieveFileRequestData,
         types::RetrieveFileResponse,
     > = connector_data.connector.get_connector_integration();
-    let router_data = utils::construct_retrieve_file_router_data(
-        state,
-        merchant_account,
-        key_store,
-        &file_metadata,
-        connector,
-    )
-    .await
-    .change_context(errors::ApiErrorResponse::InternalServerError)
-    .attach_printable("Failed constructing the retrieve file router data")?;
     let response = services::execute_connector_processing_step(
         state,
         connector_integration,
-        &router_data,
+        router_data,
         payments::CallConnectorAction::Trigger,
         None,
     )
@@ -379,5 +409,20 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::SettlementReport => {
+            upload_file(
+                #[cfg(feature = "s3")]
+                state,
+                file_key.clone(),
+                create_file_request.file.clone(),
+            )
+            .await?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
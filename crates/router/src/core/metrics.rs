@@ -36,6 +36,9 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_SOURCE_VERIFICATION_COUNT, GLOBAL_METER); // No. of incoming webhooks per source verification status
+counter_metric!(WEBHOOK_INCOMING_DUPLICATE_COUNT, GLOBAL_METER); // No. of incoming webhooks that were already processed
+counter_metric!(WEBHOOK_INCOMING_STALE_COUNT, GLOBAL_METER); // No. of incoming webhooks rejected for a stale signature timestamp
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
@@ -28,6 +28,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
+            surcharge_decision_configs,
             transformers::{self as payment_methods},
             vault,
         },
@@ -1052,6 +1053,9 @@ pub async fn list_payment_methods(
             amount_capturable: None,
             updated_by: merchant_account.storage_scheme.to_string(),
             merchant_connector_id: None,
+            routing_trace: None,
+            routing_arm: None,
+            routing_algorithm_id: None,
         };
 
         state
@@ -1432,6 +1436,44 @@ pub async fn list_payment_methods(
         });
     }
 
+    let mut show_surcharge_breakup_screen = false;
+    if let Some((payment_attempt, payment_intent)) =
+        payment_attempt.as_ref().zip(payment_intent.as_ref())
+    {
+        let algorithm_ref: routing_types::RoutingAlgorithmRef = merchant_account
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not decode the routing algorithm ref")?
+            .unwrap_or_default();
+        let (surcharge_metadata, merchant_surcharge_configs) =
+            surcharge_decision_configs::perform_surcharge_decision_management_for_payment_method_list(
+                &state,
+                algorithm_ref,
+                payment_attempt,
+                payment_intent,
+                billing_address.as_ref(),
+                &mut payment_method_responses,
+            )
+            .await?;
+        if !surcharge_metadata.surcharge_results.is_empty() {
+            surcharge_decision_configs::persist_surcharge_metadata(
+                &state,
+                &payment_attempt.attempt_id,
+                &surcharge_metadata,
+                merchant_account
+                    .intent_fulfillment_time
+                    .unwrap_or(crate::consts::DEFAULT_FULFILLMENT_TIME),
+            )
+            .await?;
+        }
+        show_surcharge_breakup_screen = merchant_surcharge_configs
+            .show_surcharge_breakup_screen
+            .unwrap_or_default();
+    }
+
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodListResponse {
             redirect_url: merchant_account.return_url,
@@ -1463,7 +1505,7 @@ pub async fn list_payment_methods(
                     }
                 },
             ),
-            show_surcharge_breakup_screen: false,
+            show_surcharge_breakup_screen,
         },
     ))
 }
@@ -1939,10 +1981,19 @@ pub async fn do_list_customer_pm_fetch_customer_if_not_passed(
     key_store: domain::MerchantKeyStore,
     req: Option<api::PaymentMethodListRequest>,
     customer_id: Option<&str>,
+    auth_flow: services::AuthFlow,
 ) -> errors::RouterResponse<api::CustomerPaymentMethodsListResponse> {
     let db = state.store.as_ref();
     if let Some(customer_id) = customer_id {
-        list_customer_payment_method(&state, merchant_account, key_store, None, customer_id).await
+        list_customer_payment_method(
+            &state,
+            merchant_account,
+            key_store,
+            None,
+            customer_id,
+            auth_flow,
+        )
+        .await
     } else {
         let cloned_secret = req.and_then(|r| r.client_secret.as_ref().cloned());
         let payment_intent = helpers::verify_payment_intent_time_and_client_secret(
@@ -1961,6 +2012,7 @@ pub async fn do_list_customer_pm_fetch_customer_if_not_passed(
             key_store,
             payment_intent,
             &customer_id,
+            auth_flow,
         )
         .await
     }
@@ -1972,6 +2024,7 @@ pub async fn list_customer_payment_method(
     key_store: domain::MerchantKeyStore,
     payment_intent: Option<storage::PaymentIntent>,
     customer_id: &str,
+    auth_flow: services::AuthFlow,
 ) -> errors::RouterResponse<api::CustomerPaymentMethodsListResponse> {
     let db = &*state.store;
     db.find_customer_by_customer_id_merchant_id(
@@ -2026,6 +2079,10 @@ pub async fn list_customer_payment_method(
         //Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: parent_payment_method_token.to_owned(),
+            // The payment method id allows the payment method to be retrieved and deleted with the
+            // API key, it is not exposed to the client
+            payment_method_id: (auth_flow == services::AuthFlow::Merchant)
+                .then(|| pm.payment_method_id.clone()),
             customer_id: pm.customer_id,
             payment_method: pm.payment_method,
             payment_method_type: pm.payment_method_type,
@@ -2170,24 +2227,28 @@ pub async fn get_lookup_key_for_payout_method(
         .to_string()
         .parse_struct("PayoutMethodData")
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
-    match &pm_parsed {
-        api::PayoutMethodData::Bank(bank) => {
-            vault::Vault::store_payout_method_data_in_locker(
-                state,
-                Some(payout_token.to_string()),
-                &pm_parsed,
-                Some(pm.customer_id.to_owned()),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Error storing payout method data in temporary locker")?;
-            Ok(bank.to_owned())
+    let bank = match &pm_parsed {
+        api::PayoutMethodData::Bank(bank) => bank.to_owned(),
+        api::PayoutMethodData::InstantTransfer(instant_transfer) => {
+            api::BankPayout::from(instant_transfer.to_owned())
         }
-        api::PayoutMethodData::Card(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
-            message: "Expected bank details, found card details instead".to_string(),
+        api::PayoutMethodData::Card(_) | api::PayoutMethodData::Wallet(_) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Expected bank details, found non-bank payout method details instead"
+                    .to_string(),
+            })?
         }
-        .into()),
-    }
+    };
+    vault::Vault::store_payout_method_data_in_locker(
+        state,
+        Some(payout_token.to_string()),
+        &pm_parsed,
+        Some(pm.customer_id.to_owned()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error storing payout method data in temporary locker")?;
+    Ok(bank)
 }
 
 pub struct BasiliskCardSupport;
@@ -2359,6 +2420,7 @@ impl BasiliskCardSupport {
 #[instrument(skip_all)]
 pub async fn retrieve_payment_method(
     state: routes::AppState,
+    merchant_account: domain::MerchantAccount,
     pm: api::PaymentMethodId,
 ) -> errors::RouterResponse<api::PaymentMethodResponse> {
     let db = state.store.as_ref();
@@ -2366,6 +2428,10 @@ pub async fn retrieve_payment_method(
         .find_payment_method(&pm.payment_method_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    // Payment methods of other merchants are not disclosed
+    utils::when(pm.merchant_id != merchant_account.merchant_id, || {
+        Err(errors::ApiErrorResponse::PaymentMethodNotFound).into_report()
+    })?;
     let card = if pm.payment_method == enums::PaymentMethod::Card {
         let card = get_card_from_locker(
             &state,
@@ -0,0 +1,481 @@
+use std::future::Future;
+
+use api_models::{
+    enums as api_enums,
+    payment_methods::{ResponsePaymentMethodsEnabled, SurchargeDetailsResponse, SurchargeMetadata},
+    routing,
+    surcharge_decision_configs::{
+        MerchantSurchargeConfigs, SurchargeDecisionConfigs, SurchargeDecisionManagerRecord,
+        SurchargeDetailsOutput,
+    },
+};
+use common_utils::{
+    ext_traits::{ByteSliceExt, StringExt, ValueExt},
+    static_cache::StaticCache,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use euclid::{
+    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    enums as euclid_enums,
+};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    logger,
+    routes::AppState,
+    types::{domain, storage, transformers::ForeignInto},
+};
+
+static SURCHARGE_CACHE: StaticCache<CachedSurchargeDecisionConfig> = StaticCache::new();
+
+struct CachedSurchargeDecisionConfig {
+    interpreter: backend::VirInterpreterBackend<SurchargeDecisionConfigs>,
+    merchant_surcharge_configs: MerchantSurchargeConfigs,
+}
+
+/// Evaluates the merchant's surcharge rules for every payment method type (and card network) in
+/// the list, filling in the `surcharge_details` of each entry.
+pub async fn perform_surcharge_decision_management_for_payment_method_list(
+    state: &AppState,
+    algorithm_ref: routing::RoutingAlgorithmRef,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_address: Option<&domain::Address>,
+    response_payment_method_types: &mut [ResponsePaymentMethodsEnabled],
+) -> RouterResult<(SurchargeMetadata, MerchantSurchargeConfigs)> {
+    let mut surcharge_metadata = SurchargeMetadata {
+        surcharge_results: Default::default(),
+    };
+    let (algorithm_id, currency) = match algorithm_ref
+        .surcharge_config_algo_id
+        .zip(payment_attempt.currency)
+    {
+        Some(algorithm_id_and_currency) => algorithm_id_and_currency,
+        None => return Ok((surcharge_metadata, MerchantSurchargeConfigs::default())),
+    };
+
+    let key = ensure_algorithm_cached(
+        state,
+        &payment_attempt.merchant_id,
+        algorithm_ref.timestamp,
+        &algorithm_id,
+    )
+    .await?;
+    let cached_algorithm = SURCHARGE_CACHE
+        .retrieve(&key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve cached surcharge decision config")?;
+
+    let amount = payment_attempt.amount;
+    let mut backend_input =
+        make_dsl_input(payment_attempt, payment_intent, billing_address, currency);
+
+    for payment_methods_enabled in response_payment_method_types.iter_mut() {
+        for payment_method_type_response in payment_methods_enabled.payment_method_types.iter_mut()
+        {
+            backend_input.payment_method.payment_method =
+                Some(payment_methods_enabled.payment_method);
+            backend_input.payment_method.payment_method_type =
+                Some(payment_method_type_response.payment_method_type);
+
+            if let Some(card_network_list) = &mut payment_method_type_response.card_networks {
+                for card_network_type in card_network_list.iter_mut() {
+                    backend_input.payment_method.card_network =
+                        Some(card_network_type.card_network.clone());
+                    let surcharge_details = execute_dsl_and_get_surcharge_details(
+                        &cached_algorithm.interpreter,
+                        backend_input.clone(),
+                        amount,
+                    )?;
+                    if let Some(surcharge_details) = surcharge_details.clone() {
+                        surcharge_metadata.surcharge_results.insert(
+                            SurchargeMetadata::get_key_for_surcharge_details_hash_map(
+                                &payment_methods_enabled.payment_method,
+                                &payment_method_type_response.payment_method_type,
+                                Some(&card_network_type.card_network),
+                            ),
+                            surcharge_details,
+                        );
+                    }
+                    card_network_type.surcharge_details = surcharge_details;
+                }
+                backend_input.payment_method.card_network = None;
+            } else {
+                let surcharge_details = execute_dsl_and_get_surcharge_details(
+                    &cached_algorithm.interpreter,
+                    backend_input.clone(),
+                    amount,
+                )?;
+                if let Some(surcharge_details) = surcharge_details.clone() {
+                    surcharge_metadata.surcharge_results.insert(
+                        SurchargeMetadata::get_key_for_surcharge_details_hash_map(
+                            &payment_methods_enabled.payment_method,
+                            &payment_method_type_response.payment_method_type,
+                            None,
+                        ),
+                        surcharge_details,
+                    );
+                }
+                payment_method_type_response.surcharge_details = surcharge_details;
+            }
+        }
+    }
+
+    Ok((
+        surcharge_metadata,
+        cached_algorithm.merchant_surcharge_configs.clone(),
+    ))
+}
+
+/// Stores the surcharge computed while listing payment methods so that it can be validated and
+/// persisted when the payment is confirmed.
+pub async fn persist_surcharge_metadata(
+    state: &AppState,
+    attempt_id: &str,
+    surcharge_metadata: &SurchargeMetadata,
+    expiry_in_seconds: i64,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_surcharge_metadata_redis_key(attempt_id),
+            surcharge_metadata,
+            expiry_in_seconds,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store surcharge metadata in redis")
+}
+
+pub async fn get_surcharge_metadata(
+    state: &AppState,
+    attempt_id: &str,
+) -> RouterResult<Option<SurchargeMetadata>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let surcharge_metadata_bytes: Option<Vec<u8>> = redis_conn
+        .get_key(&get_surcharge_metadata_redis_key(attempt_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch surcharge metadata from redis")?;
+
+    surcharge_metadata_bytes
+        .map(|bytes| bytes.parse_struct("SurchargeMetadata"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse surcharge metadata")
+}
+
+/// Looks up the surcharge which was computed for the payment method of the attempt while listing
+/// payment methods, evaluating the merchant's surcharge rules again when it is no longer cached.
+/// Returns `None` when the merchant has no surcharge rules configured. Card surcharges can differ
+/// by card network, so a card payment whose network can't be resolved is rejected rather than
+/// confirmed without its surcharge.
+pub async fn get_surcharge_details_for_payment_attempt<F, Fut>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_address: Option<&domain::Address>,
+    get_card_network: F,
+) -> RouterResult<Option<SurchargeDetailsResponse>>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = RouterResult<Option<api_enums::CardNetwork>>>,
+{
+    let algorithm_ref: routing::RoutingAlgorithmRef = merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm ref")?
+        .unwrap_or_default();
+    let (algorithm_id, (payment_method, payment_method_type)) =
+        match algorithm_ref.surcharge_config_algo_id.clone().zip(
+            payment_attempt
+                .payment_method
+                .zip(payment_attempt.payment_method_type),
+        ) {
+            Some(algorithm_id_and_payment_method) => algorithm_id_and_payment_method,
+            None => return Ok(None),
+        };
+
+    let card_network = match payment_method {
+        api_enums::PaymentMethod::Card => Some(get_card_network().await?.ok_or(report!(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unable to determine the card network to apply the surcharge".to_string(),
+            }
+        ))?),
+        _ => None,
+    };
+
+    let surcharge_metadata =
+        match get_surcharge_metadata(state, &payment_attempt.attempt_id).await? {
+            Some(surcharge_metadata) => surcharge_metadata,
+            None => {
+                let currency = match payment_attempt.currency {
+                    Some(currency) => currency,
+                    None => return Ok(None),
+                };
+                let key = ensure_algorithm_cached(
+                    state,
+                    &payment_attempt.merchant_id,
+                    algorithm_ref.timestamp,
+                    &algorithm_id,
+                )
+                .await?;
+                let cached_algorithm = SURCHARGE_CACHE
+                    .retrieve(&key)
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to retrieve cached surcharge decision config")?;
+
+                let mut backend_input =
+                    make_dsl_input(payment_attempt, payment_intent, billing_address, currency);
+                backend_input.payment_method.payment_method = Some(payment_method);
+                backend_input.payment_method.payment_method_type = Some(payment_method_type);
+                backend_input.payment_method.card_network = card_network;
+
+                return execute_dsl_and_get_surcharge_details(
+                    &cached_algorithm.interpreter,
+                    backend_input,
+                    payment_attempt.amount,
+                );
+            }
+        };
+    let surcharge_details = card_network
+        .and_then(|card_network| {
+            surcharge_metadata.surcharge_results.get(
+                &SurchargeMetadata::get_key_for_surcharge_details_hash_map(
+                    &payment_method,
+                    &payment_method_type,
+                    Some(&card_network),
+                ),
+            )
+        })
+        .or_else(|| {
+            surcharge_metadata.surcharge_results.get(
+                &SurchargeMetadata::get_key_for_surcharge_details_hash_map(
+                    &payment_method,
+                    &payment_method_type,
+                    None,
+                ),
+            )
+        })
+        .cloned();
+
+    Ok(surcharge_details)
+}
+
+fn get_surcharge_metadata_redis_key(attempt_id: &str) -> String {
+    format!("surcharge_metadata_{attempt_id}")
+}
+
+pub fn get_surcharge_details_response(
+    surcharge_output: SurchargeDetailsOutput,
+    amount: i64,
+) -> RouterResult<SurchargeDetailsResponse> {
+    let surcharge_amount = match &surcharge_output.surcharge {
+        api_models::payment_methods::Surcharge::Fixed(value) => *value,
+        api_models::payment_methods::Surcharge::Rate(percentage) => percentage
+            .apply_and_ceil_result(amount)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to calculate surcharge amount by applying percentage")?,
+    };
+    let tax_on_surcharge_amount = surcharge_output
+        .tax_on_surcharge
+        .as_ref()
+        .map(|tax_on_surcharge| tax_on_surcharge.apply_and_ceil_result(surcharge_amount))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to calculate tax amount on surcharge")?
+        .unwrap_or_default();
+
+    Ok(SurchargeDetailsResponse {
+        surcharge: surcharge_output.surcharge,
+        tax_on_surcharge: surcharge_output.tax_on_surcharge,
+        surcharge_amount,
+        tax_on_surcharge_amount,
+        final_amount: amount + surcharge_amount + tax_on_surcharge_amount,
+    })
+}
+
+fn execute_dsl_and_get_surcharge_details(
+    interpreter: &backend::VirInterpreterBackend<SurchargeDecisionConfigs>,
+    backend_input: dsl_inputs::BackendInput,
+    amount: i64,
+) -> RouterResult<Option<SurchargeDetailsResponse>> {
+    let output = interpreter
+        .execute(backend_input)
+        .map_err(|_| errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to execute the surcharge decision program")?;
+
+    output
+        .connector_selection
+        .surcharge_details
+        .map(|surcharge_output| get_surcharge_details_response(surcharge_output, amount))
+        .transpose()
+}
+
+fn make_dsl_input(
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_address: Option<&domain::Address>,
+    currency: api_enums::Currency,
+) -> dsl_inputs::BackendInput {
+    let metadata = payment_intent
+        .metadata
+        .clone()
+        .map(|val| val.parse_value("routing_parameters"))
+        .transpose()
+        .unwrap_or_else(|err| {
+            logger::error!(error=?err, "Unable to parse routing_parameters from metadata of payment_intent");
+            None
+        });
+
+    dsl_inputs::BackendInput {
+        metadata,
+        payment: dsl_inputs::PaymentInput {
+            amount: payment_attempt.amount,
+            currency,
+            authentication_type: payment_attempt.authentication_type,
+            card_bin: None,
+            capture_method: payment_attempt
+                .capture_method
+                .and_then(|cm| cm.foreign_into()),
+            business_country: payment_intent
+                .business_country
+                .map(api_enums::Country::from_alpha2),
+            billing_country: billing_address
+                .and_then(|address| address.country)
+                .map(api_enums::Country::from_alpha2),
+            business_label: payment_intent.business_label.clone(),
+            setup_future_usage: payment_intent.setup_future_usage,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: None,
+            payment_method_type: None,
+            card_network: None,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: Some(if payment_attempt.mandate_details.is_some() {
+                euclid_enums::PaymentType::SetupMandate
+            } else {
+                euclid_enums::PaymentType::NonMandate
+            }),
+        },
+        time: None,
+        issuer: None,
+    }
+}
+
+async fn ensure_algorithm_cached(
+    state: &AppState,
+    merchant_id: &str,
+    timestamp: i64,
+    algorithm_id: &str,
+) -> RouterResult<String> {
+    let key = format!("surcharge_dsl_{merchant_id}");
+    let present = SURCHARGE_CACHE
+        .present(&key)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error checking presence of surcharge decision config in cache")?;
+    let expired = SURCHARGE_CACHE
+        .expired(&key, timestamp)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error checking expiry of surcharge decision config in cache")?;
+
+    if !present || expired {
+        refresh_surcharge_algorithm_cache(state, key.clone(), algorithm_id, timestamp).await?;
+    }
+
+    Ok(key)
+}
+
+async fn refresh_surcharge_algorithm_cache(
+    state: &AppState,
+    key: String,
+    algorithm_id: &str,
+    timestamp: i64,
+) -> RouterResult<()> {
+    let config = state
+        .store
+        .find_config_by_key(algorithm_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Surcharge decision config not found in DB")?;
+    let record: SurchargeDecisionManagerRecord = config
+        .config
+        .parse_struct("SurchargeDecisionManagerRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error parsing the surcharge decision config")?;
+    let interpreter = backend::VirInterpreterBackend::with_program(record.algorithm)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error initializing DSL interpreter backend")?;
+
+    SURCHARGE_CACHE
+        .save(
+            key,
+            CachedSurchargeDecisionConfig {
+                interpreter,
+                merchant_surcharge_configs: record.merchant_surcharge_configs,
+            },
+            timestamp,
+        )
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error saving surcharge decision config to cache")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::payment_methods::Surcharge;
+    use common_utils::types::Percentage;
+
+    use super::*;
+
+    #[test]
+    fn test_get_surcharge_details_response() {
+        let fixed = get_surcharge_details_response(
+            SurchargeDetailsOutput {
+                surcharge: Surcharge::Fixed(100),
+                tax_on_surcharge: Some(Percentage::from_string("18".to_string()).unwrap()),
+            },
+            1000,
+        )
+        .unwrap();
+        assert_eq!(fixed.surcharge_amount, 100);
+        assert_eq!(fixed.tax_on_surcharge_amount, 18);
+        assert_eq!(fixed.final_amount, 1118);
+
+        let rate = get_surcharge_details_response(
+            SurchargeDetailsOutput {
+                surcharge: Surcharge::Rate(Percentage::from_string("2.5".to_string()).unwrap()),
+                tax_on_surcharge: None,
+            },
+            1001,
+        )
+        .unwrap();
+        assert_eq!(rate.surcharge_amount, 26);
+        assert_eq!(rate.tax_on_surcharge_amount, 0);
+        assert_eq!(rate.final_amount, 1027);
+    }
+}
@@ -552,12 +552,119 @@ impl Vaultable for api::BankPayout {
     }
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedWalletSensitiveValues {
+    pub email: Option<common_utils::pii::Email>,
+    pub telephone_number: Option<masking::Secret<String>>,
+    pub wallet_type: enums::PaymentMethodType,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TokenizedWalletInsensitiveValues {
+    pub customer_id: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl Vaultable for payouts::Wallet {
+    fn get_value1(&self, _customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value1 = match self {
+            Self::Paypal(paypal_data) => TokenizedWalletSensitiveValues {
+                email: Some(paypal_data.email.clone()),
+                telephone_number: None,
+                wallet_type: enums::PaymentMethodType::Paypal,
+            },
+            Self::Venmo(venmo_data) => TokenizedWalletSensitiveValues {
+                email: None,
+                telephone_number: Some(venmo_data.telephone_number.clone()),
+                wallet_type: enums::PaymentMethodType::Venmo,
+            },
+        };
+
+        utils::Encode::<TokenizedWalletSensitiveValues>::encode_to_string_of_json(&value1)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode wallet data - TokenizedWalletSensitiveValues")
+    }
+
+    fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        let value2 = TokenizedWalletInsensitiveValues { customer_id };
+
+        utils::Encode::<TokenizedWalletInsensitiveValues>::encode_to_string_of_json(&value2)
+            .change_context(errors::VaultError::RequestEncodingFailed)
+            .attach_printable("Failed to encode data - TokenizedWalletInsensitiveValues")
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let value1: TokenizedWalletSensitiveValues = value1
+            .parse_struct("TokenizedWalletSensitiveValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into wallet data wallet_sensitive_data")?;
+
+        let value2: TokenizedWalletInsensitiveValues = value2
+            .parse_struct("TokenizedWalletInsensitiveValues")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+            .attach_printable("Could not deserialize into wallet data wallet_insensitive_data")?;
+
+        let wallet = match value1.wallet_type {
+            enums::PaymentMethodType::Paypal => Self::Paypal(payouts::Paypal {
+                email: value1
+                    .email
+                    .ok_or(errors::VaultError::ResponseDeserializationFailed)?,
+            }),
+            enums::PaymentMethodType::Venmo => Self::Venmo(payouts::Venmo {
+                telephone_number: value1
+                    .telephone_number
+                    .ok_or(errors::VaultError::ResponseDeserializationFailed)?,
+            }),
+            _ => Err(errors::VaultError::PayoutMethodNotSupported)?,
+        };
+
+        let supp_data = SupplementaryVaultData {
+            customer_id: value2.customer_id,
+            payment_method_id: None,
+        };
+
+        Ok((wallet, supp_data))
+    }
+}
+
+/// Instant transfers carry the same account details as regular bank payouts, so they are
+/// tokenized as bank details and the rail is restored from the account details on retrieval.
+#[cfg(feature = "payouts")]
+impl Vaultable for payouts::InstantTransfer {
+    fn get_value1(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        api::BankPayout::from(self.clone()).get_value1(customer_id)
+    }
+
+    fn get_value2(&self, customer_id: Option<String>) -> CustomResult<String, errors::VaultError> {
+        api::BankPayout::from(self.clone()).get_value2(customer_id)
+    }
+
+    fn from_values(
+        value1: String,
+        value2: String,
+    ) -> CustomResult<(Self, SupplementaryVaultData), errors::VaultError> {
+        let (bank, supp_data) = api::BankPayout::from_values(value1, value2)?;
+        let instant_transfer = match bank {
+            api::BankPayout::Sepa(b) => Self::SepaInstant(b),
+            api::BankPayout::Bacs(b) => Self::FasterPayments(b),
+            api::BankPayout::Ach(b) => Self::Rtp(b),
+        };
+
+        Ok((instant_transfer, supp_data))
+    }
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "type", content = "value", rename_all = "snake_case")]
 pub enum VaultPayoutMethod {
     Card(String),
     Bank(String),
+    Wallet(String),
+    InstantTransfer(String),
 }
 
 #[cfg(feature = "payouts")]
@@ -566,6 +673,10 @@ impl Vaultable for api::PayoutMethodData {
         let value1 = match self {
             Self::Card(card) => VaultPayoutMethod::Card(card.get_value1(customer_id)?),
             Self::Bank(bank) => VaultPayoutMethod::Bank(bank.get_value1(customer_id)?),
+            Self::Wallet(wallet) => VaultPayoutMethod::Wallet(wallet.get_value1(customer_id)?),
+            Self::InstantTransfer(instant_transfer) => {
+                VaultPayoutMethod::InstantTransfer(instant_transfer.get_value1(customer_id)?)
+            }
         };
 
         utils::Encode::<VaultPaymentMethod>::encode_to_string_of_json(&value1)
@@ -577,6 +688,10 @@ impl Vaultable for api::PayoutMethodData {
         let value2 = match self {
             Self::Card(card) => VaultPayoutMethod::Card(card.get_value2(customer_id)?),
             Self::Bank(bank) => VaultPayoutMethod::Bank(bank.get_value2(customer_id)?),
+            Self::Wallet(wallet) => VaultPayoutMethod::Wallet(wallet.get_value2(customer_id)?),
+            Self::InstantTransfer(instant_transfer) => {
+                VaultPayoutMethod::InstantTransfer(instant_transfer.get_value2(customer_id)?)
+            }
         };
 
         utils::Encode::<VaultPaymentMethod>::encode_to_string_of_json(&value2)
@@ -607,6 +722,18 @@ impl Vaultable for api::PayoutMethodData {
                 let (bank, supp_data) = api::BankPayout::from_values(mvalue1, mvalue2)?;
                 Ok((Self::Bank(bank), supp_data))
             }
+            (VaultPayoutMethod::Wallet(mvalue1), VaultPayoutMethod::Wallet(mvalue2)) => {
+                let (wallet, supp_data) = payouts::Wallet::from_values(mvalue1, mvalue2)?;
+                Ok((Self::Wallet(wallet), supp_data))
+            }
+            (
+                VaultPayoutMethod::InstantTransfer(mvalue1),
+                VaultPayoutMethod::InstantTransfer(mvalue2),
+            ) => {
+                let (instant_transfer, supp_data) =
+                    payouts::InstantTransfer::from_values(mvalue1, mvalue2)?;
+                Ok((Self::InstantTransfer(instant_transfer), supp_data))
+            }
             _ => Err(errors::VaultError::PayoutMethodNotSupported)
                 .into_report()
                 .attach_printable("Payout method not supported"),
@@ -42,8 +42,10 @@ use super::errors::StorageErrorExt;
 use crate::{
     configs::settings::PaymentMethodTypeTokenFilter,
     core::{
+        connector_health,
         errors::{self, CustomResult, RouterResponse, RouterResult},
         payment_methods::PaymentMethodRetrieve,
+        routing::helpers as routing_helpers,
         utils,
     },
     db::StorageInterface,
@@ -858,6 +860,12 @@ where
         // This is added because few connector integrations do not update the status,
         // and rely on previous status set in router_data
         router_data.status = payment_data.payment_attempt.status;
+        connector_health::take_probe_slot(
+            state,
+            &connector.connector_name.to_string(),
+            merchant_connector_account.get_mca_id().as_deref(),
+        )
+        .await;
         router_data
             .decide_flows(
                 state,
@@ -873,6 +881,18 @@ where
         Ok(router_data)
     };
 
+    if let Some(outcome) = connector_health::ConnectorCallOutcome::from_router_data_result(
+        &router_data_res,
+        stime_connector.elapsed(),
+    ) {
+        connector_health::record_connector_call(
+            state,
+            connector.connector_name.to_string(),
+            merchant_connector_account.get_mca_id(),
+            outcome,
+        );
+    }
+
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
     tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
@@ -1855,6 +1875,102 @@ pub async fn get_filters_for_payments(
     ))
 }
 
+#[cfg(feature = "olap")]
+pub async fn get_connector_fee_aggregates(
+    state: AppState,
+    merchant: domain::MerchantAccount,
+    time_range: api::TimeRange,
+) -> RouterResponse<api::ConnectorFeeAggregateResponse> {
+    let fee_aggregates = state
+        .store
+        .get_fee_aggregates_by_merchant_id_time_range(
+            &merchant.merchant_id,
+            time_range.start_time,
+            time_range.end_time,
+            // since OLAP doesn't have KV. Force to get the data from PSQL.
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while aggregating the fee details of payment attempts")?;
+
+    let data = fee_aggregates
+        .into_iter()
+        .map(api::ConnectorFeeAggregate::foreign_from)
+        .collect::<Vec<_>>();
+    Ok(services::ApplicationResponse::Json(
+        api::ConnectorFeeAggregateResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+pub async fn get_routing_trace(
+    state: AppState,
+    merchant: domain::MerchantAccount,
+    payment_id: String,
+    req: api::PaymentsRoutingTraceRequest,
+) -> RouterResponse<api::PaymentsRoutingTraceResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &payment_id,
+            &merchant.merchant_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let attempt_id = req
+        .attempt_id
+        .unwrap_or_else(|| payment_intent.active_attempt.get_id());
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &attempt_id,
+            &merchant.merchant_id,
+            merchant.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_attempt.payment_id != payment_intent.payment_id {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    let mut routing_trace = payment_attempt
+        .routing_trace
+        .as_deref()
+        .map(routing_helpers::decode_routing_trace)
+        .transpose()?;
+
+    if let Some(trace) = routing_trace.as_mut() {
+        if let Some((algorithm_id, input)) = trace.algorithm_id.as_deref().zip(trace.input.clone())
+        {
+            trace.evaluation = routing::explain_routing_evaluation(
+                &state,
+                algorithm_id,
+                input,
+                #[cfg(feature = "business_profile_routing")]
+                payment_intent.profile_id.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to evaluate the routing algorithm of the payment again")?;
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsRoutingTraceResponse {
+            payment_id: payment_attempt.payment_id,
+            attempt_id: payment_attempt.attempt_id,
+            connector: payment_attempt.connector,
+            routing_trace,
+        },
+    ))
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -2454,22 +2570,26 @@ where
             .unwrap_or_default()
     };
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, mut routing_trace) = routing::perform_static_routing_v1(
         state,
+        key_store,
         &merchant_account.merchant_id,
+        merchant_account.modified_at.assume_utc().unix_timestamp(),
         algorithm_ref,
         payment_data,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
-    let connectors = routing::perform_eligibility_analysis_with_fallback(
+    let record_routing_trace = state.conf.routing.debug;
+    let (connectors, eligibility) = routing::perform_eligibility_analysis_with_fallback_and_trace(
         &state.clone(),
         key_store,
         merchant_account.modified_at.assume_utc().unix_timestamp(),
         connectors,
         payment_data,
         eligible_connectors,
+        record_routing_trace,
         #[cfg(feature = "business_profile_routing")]
         payment_data.payment_intent.profile_id.clone(),
     )
@@ -2477,6 +2597,16 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    payment_data.payment_attempt.routing_arm = routing_trace.routing_arm;
+    payment_data.payment_attempt.routing_algorithm_id = routing_trace.algorithm_id.clone();
+    if record_routing_trace {
+        routing_trace.eligibility = eligibility;
+        payment_data.payment_attempt.routing_trace =
+            routing_helpers::encode_routing_trace(&routing_trace)
+                .map_err(|error| logger::error!(?error, "Failed to encode routing trace"))
+                .ok();
+    }
+
     let first_connector_choice = connectors
         .first()
         .ok_or(errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration)
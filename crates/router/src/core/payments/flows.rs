@@ -888,9 +888,6 @@ macro_rules! default_imp_for_payouts {
     };
 }
 
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::Payouts for connector::DummyConnector<T> {}
-
 default_imp_for_payouts!(
     connector::Aci,
     connector::Airwallex,
@@ -958,17 +955,6 @@ macro_rules! default_imp_for_payouts_create {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutCreate for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_create!(
     connector::Aci,
@@ -1421,6 +1407,75 @@ default_imp_for_payouts_recipient!(
     connector::Zen
 );
 
+#[cfg(feature = "payouts")]
+macro_rules! default_imp_for_payouts_sync {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::PayoutSync for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::PoSync,
+            types::PayoutsData,
+            types::PayoutsResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "payouts")]
+default_imp_for_payouts_sync!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Airwallex,
+    connector::Authorizedotnet,
+    connector::Bambora,
+    connector::Bankofamerica,
+    connector::Bitpay,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Cashtocode,
+    connector::Checkout,
+    connector::Cryptopay,
+    connector::Cybersource,
+    connector::Coinbase,
+    connector::Dlocal,
+    connector::Fiserv,
+    connector::Forte,
+    connector::Globalpay,
+    connector::Globepay,
+    connector::Gocardless,
+    connector::Helcim,
+    connector::Iatapay,
+    connector::Klarna,
+    connector::Mollie,
+    connector::Multisafepay,
+    connector::Nexinets,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Payeezy,
+    connector::Payme,
+    connector::Paypal,
+    connector::Payu,
+    connector::Powertranz,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Square,
+    connector::Stax,
+    connector::Stripe,
+    connector::Shift4,
+    connector::Trustpay,
+    connector::Tsys,
+    connector::Volt,
+    connector::Worldline,
+    connector::Worldpay,
+    connector::Zen
+);
+
 macro_rules! default_imp_for_approve {
     ($($path:ident::$connector:ident),*) => {
         $(
@@ -1316,6 +1316,93 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
     ))
 }
 
+/// Looks up the temporary locker key of the payment method which a payment token refers to
+async fn get_hyperswitch_token(
+    state: &AppState,
+    token: &str,
+    payment_method: storage_enums::PaymentMethod,
+) -> RouterResult<String> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = format!("pm_token_{token}_{payment_method}_hyperswitch");
+
+    redis_conn
+        .get_key::<Option<String>>(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token from redis")?
+        .ok_or(error_stack::Report::new(
+            errors::ApiErrorResponse::UnprocessableEntity {
+                message: "Token is invalid or expired".to_owned(),
+            },
+        ))
+}
+
+/// Resolves the network of the card used for a payment the same way it is shown for the payment:
+/// the network sent with the card, the scheme of the saved card which the token refers to, or else
+/// the network found by looking up the BIN of the card
+pub async fn get_card_network_for_payment(
+    state: &AppState,
+    payment_method_data: Option<&api::PaymentMethodData>,
+    token: Option<&str>,
+    is_mandate_token: bool,
+) -> RouterResult<Option<api_enums::CardNetwork>> {
+    let db = &*state.store;
+    let (card, payment_method_id) = match (payment_method_data, token) {
+        (Some(api::PaymentMethodData::Card(card)), _) => (Some(card.clone()), None),
+        (_, Some(token)) => {
+            let hyperswitch_token = if is_mandate_token {
+                token.to_owned()
+            } else {
+                get_hyperswitch_token(state, token, storage_enums::PaymentMethod::Card).await?
+            };
+            let (payment_method_data, supplementary_data) =
+                vault::Vault::get_payment_method_data_from_locker(state, &hyperswitch_token)
+                    .await
+                    .attach_printable(
+                        "Payment method for given token not found or there was a problem fetching it",
+                    )?;
+            let card = match payment_method_data {
+                Some(api::PaymentMethodData::Card(card)) => Some(card),
+                _ => None,
+            };
+            (card, supplementary_data.payment_method_id)
+        }
+        (_, None) => (None, None),
+    };
+
+    if let Some(card_network) = card.as_ref().and_then(|card| card.card_network.clone()) {
+        return Ok(Some(card_network));
+    }
+
+    if let Some(payment_method_id) = payment_method_id {
+        let saved_card_network = db
+            .find_payment_method(&payment_method_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?
+            .scheme
+            .and_then(|scheme| scheme.parse::<api_enums::CardNetwork>().ok());
+        if saved_card_network.is_some() {
+            return Ok(saved_card_network);
+        }
+    }
+
+    Ok(card
+        .async_and_then(|card| async move {
+            db.get_card_info(&card.card_number.get_card_isin())
+                .await
+                .map_err(|error| services::logger::warn!(card_info_error=?error))
+                .ok()
+        })
+        .await
+        .flatten()
+        .and_then(|card_info| card_info.card_network))
+}
+
 pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
     operation: BoxedOperation<'a, F, R, Ctx>,
     state: &'a AppState,
@@ -1331,34 +1418,18 @@ pub async fn make_pm_data<'a, F: Clone, R, Ctx: PaymentMethodRetrieve>(
         Some(_) => token,
         None => {
             if let Some(token) = token {
-                let redis_conn = state
-                    .store
-                    .get_redis_conn()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to get redis connection")?;
-
-                let key = format!(
-                    "pm_token_{}_{}_hyperswitch",
-                    token,
-                    payment_data
-                        .payment_attempt
-                        .payment_method
-                        .to_owned()
-                        .get_required_value("payment_method")?,
-                );
-
-                let key = redis_conn
-                    .get_key::<Option<String>>(&key)
-                    .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed to fetch the token from redis")?
-                    .ok_or(error_stack::Report::new(
-                        errors::ApiErrorResponse::UnprocessableEntity {
-                            message: "Token is invalid or expired".to_owned(),
-                        },
-                    ))?;
-
-                Some(key)
+                Some(
+                    get_hyperswitch_token(
+                        state,
+                        &token,
+                        payment_data
+                            .payment_attempt
+                            .payment_method
+                            .to_owned()
+                            .get_required_value("payment_method")?,
+                    )
+                    .await?,
+                )
             } else {
                 None
             }
@@ -1706,6 +1777,7 @@ pub fn validate_payment_method_type_against_payment_method(
                 | api_enums::PaymentMethodType::MobilePay
                 | api_enums::PaymentMethodType::SamsungPay
                 | api_enums::PaymentMethodType::Twint
+                | api_enums::PaymentMethodType::Venmo
                 | api_enums::PaymentMethodType::Vipps
                 | api_enums::PaymentMethodType::TouchNGo
                 | api_enums::PaymentMethodType::Swish
@@ -2727,6 +2799,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_http_status_code: router_data.connector_http_status_code,
         external_latency: router_data.external_latency,
         apple_pay_flow: router_data.apple_pay_flow,
+        connector_fee_details: router_data.connector_fee_details,
     }
 }
 
@@ -1,9 +1,9 @@
 use std::marker::PhantomData;
 
-use api_models::{enums::FrmSuggestion, payment_methods};
+use api_models::enums::FrmSuggestion;
 use async_trait::async_trait;
 use common_utils::ext_traits::{AsyncExt, Encode};
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use futures::FutureExt;
 use router_derive::PaymentOperation;
 use router_env::{instrument, tracing};
@@ -12,7 +12,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::PaymentMethodRetrieve,
+        payment_methods::{surcharge_decision_configs, PaymentMethodRetrieve},
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
     },
     db::StorageInterface,
@@ -333,18 +333,50 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             sm
         });
 
+        // surcharge calculated from the merchant's surcharge rules, the surcharge in the request is
+        // only accepted when it matches this one
+        let calculated_surcharge_details =
+            surcharge_decision_configs::get_surcharge_details_for_payment_attempt(
+                state,
+                merchant_account,
+                &payment_attempt,
+                &payment_intent,
+                billing_address.as_ref(),
+                || {
+                    helpers::get_card_network_for_payment(
+                        state,
+                        request.payment_method_data.as_ref(),
+                        token.as_deref(),
+                        recurring_mandate_payment_data.is_some(),
+                    )
+                },
+            )
+            .await?;
+
         // populate payment_data.surcharge_details from request
-        let surcharge_details = request.surcharge_details.map(|surcharge_details| {
-            payment_methods::SurchargeDetailsResponse {
-                surcharge: payment_methods::Surcharge::Fixed(surcharge_details.surcharge_amount),
-                tax_on_surcharge: None,
-                surcharge_amount: surcharge_details.surcharge_amount,
-                tax_on_surcharge_amount: surcharge_details.tax_amount.unwrap_or(0),
-                final_amount: payment_attempt.amount
-                    + surcharge_details.surcharge_amount
-                    + surcharge_details.tax_amount.unwrap_or(0),
+        let surcharge_details = match (request.surcharge_details, calculated_surcharge_details) {
+            (Some(request_surcharge_details), Some(calculated_surcharge_details)) => {
+                utils::when(
+                    request_surcharge_details.surcharge_amount
+                        != calculated_surcharge_details.surcharge_amount
+                        || request_surcharge_details.tax_amount.unwrap_or(0)
+                            != calculated_surcharge_details.tax_on_surcharge_amount,
+                    || {
+                        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                            message: "surcharge_details do not match the calculated surcharge"
+                                .to_string(),
+                        }))
+                    },
+                )?;
+                Some(calculated_surcharge_details)
             }
-        });
+            (None, calculated_surcharge_details) => calculated_surcharge_details,
+            (Some(_), None) => {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "surcharge_details can't be applied as no surcharge is calculated for the payment method".to_string(),
+                }))
+            }
+        };
 
         Ok((
             Box::new(self),
@@ -513,6 +545,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
 
         let connector = payment_data.payment_attempt.connector.clone();
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let routing_trace = payment_data.payment_attempt.routing_trace.clone();
+        let routing_arm = payment_data.payment_attempt.routing_arm;
+        let routing_algorithm_id = payment_data.payment_attempt.routing_algorithm_id.clone();
 
         let straight_through_algorithm = payment_data
             .payment_attempt
@@ -593,6 +628,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    routing_trace,
+                    routing_arm,
+                    routing_algorithm_id,
                 },
                 storage_scheme,
             )
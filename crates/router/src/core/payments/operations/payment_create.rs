@@ -429,6 +429,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .clone();
         let authorized_amount = payment_data.payment_attempt.amount;
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let routing_trace = payment_data.payment_attempt.routing_trace.clone();
+        let routing_arm = payment_data.payment_attempt.routing_arm;
+        let routing_algorithm_id = payment_data.payment_attempt.routing_algorithm_id.clone();
 
         payment_data.payment_attempt = db
             .update_payment_attempt_with_attempt_id(
@@ -443,6 +446,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     },
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    routing_trace,
+                    routing_arm,
+                    routing_algorithm_id,
                 },
                 storage_scheme,
             )
@@ -450,36 +450,47 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             )];
                             (Some((multiple_capture_data, capture_update_list)), None)
                         }
-                        None => (
-                            None,
-                            Some(storage::PaymentAttemptUpdate::ResponseUpdate {
-                                status: router_data.status,
-                                connector: None,
-                                connector_transaction_id: connector_transaction_id.clone(),
-                                authentication_type: None,
-                                payment_method_id: Some(router_data.payment_method_id),
-                                mandate_id: payment_data
-                                    .mandate_id
-                                    .clone()
-                                    .map(|mandate| mandate.mandate_id),
-                                connector_metadata,
-                                payment_token: None,
-                                error_code: error_status.clone(),
-                                error_message: error_status.clone(),
-                                error_reason: error_status,
-                                connector_response_reference_id,
-                                amount_capturable: if router_data.status.is_terminal_status()
-                                    || router_data
-                                        .status
-                                        .maps_to_intent_status(enums::IntentStatus::Processing)
-                                {
-                                    Some(0)
-                                } else {
-                                    None
-                                },
-                                updated_by: storage_scheme.to_string(),
-                            }),
-                        ),
+                        None => {
+                            let fee_details = router_data
+                                .connector_fee_details
+                                .clone()
+                                .unwrap_or_default();
+                            (
+                                None,
+                                Some(storage::PaymentAttemptUpdate::ResponseUpdate {
+                                    status: router_data.status,
+                                    connector: None,
+                                    connector_transaction_id: connector_transaction_id.clone(),
+                                    authentication_type: None,
+                                    payment_method_id: Some(router_data.payment_method_id),
+                                    mandate_id: payment_data
+                                        .mandate_id
+                                        .clone()
+                                        .map(|mandate| mandate.mandate_id),
+                                    connector_metadata,
+                                    payment_token: None,
+                                    error_code: error_status.clone(),
+                                    error_message: error_status.clone(),
+                                    error_reason: error_status,
+                                    connector_response_reference_id,
+                                    amount_capturable: if router_data.status.is_terminal_status()
+                                        || router_data
+                                            .status
+                                            .maps_to_intent_status(enums::IntentStatus::Processing)
+                                    {
+                                        Some(0)
+                                    } else {
+                                        None
+                                    },
+                                    processing_fee: fee_details.processing_fee,
+                                    interchange_fee: fee_details.interchange_fee,
+                                    fx_markup: fee_details.fx_markup,
+                                    net_amount: fee_details.net_amount,
+                                    settlement_currency: fee_details.settlement_currency,
+                                    updated_by: storage_scheme.to_string(),
+                                }),
+                            )
+                        }
                     };
 
                 let connector_response_update = storage::ConnectorResponseUpdate::ResponseUpdate {
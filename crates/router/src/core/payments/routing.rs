@@ -1,8 +1,10 @@
+mod least_cost;
 mod transformers;
 
 use std::{
     collections::hash_map,
     hash::{Hash, Hasher},
+    str::FromStr,
     sync::Arc,
 };
 
@@ -15,7 +17,7 @@ use common_utils::static_cache::StaticCache;
 use diesel_models::enums as storage_enums;
 use error_stack::{IntoReport, ResultExt};
 use euclid::{
-    backend::{self, inputs as dsl_inputs, EuclidBackend},
+    backend::{self, inputs as dsl_inputs, trace::ProgramTrace, EuclidBackend},
     dssa::graph::{self as euclid_graph, Memoization},
     enums as euclid_enums,
     frontend::ast,
@@ -35,7 +37,8 @@ use rustc_hash::FxHashMap;
 use crate::utils::StringExt;
 use crate::{
     core::{
-        errors as oss_errors, errors, payments as payments_oss, routing::helpers as routing_helpers,
+        connector_health, errors as oss_errors, errors, payments as payments_oss,
+        routing::helpers as routing_helpers,
     },
     logger,
     types::{
@@ -51,6 +54,7 @@ pub(super) enum CachedAlgorithm {
     Priority(Vec<routing_types::RoutableConnectorChoice>),
     VolumeSplit(Vec<routing_types::ConnectorVolumeSplit>),
     Advanced(backend::VirInterpreterBackend<ConnectorSelection>),
+    LeastCost(routing_types::LeastCostRoutingConfig),
 }
 
 pub struct SessionFlowRoutingInput<'a> {
@@ -195,30 +199,147 @@ where
         payment: payment_input,
         payment_method: payment_method_input,
         mandate: mandate_data,
+        time: None,
+        issuer: None,
     })
 }
 
+/// Provides the issuer details of the card used in the payment. Details passed in the request
+/// take precedence over the ones looked up from `cards_info` using the card BIN.
+async fn get_issuer_input<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+) -> Option<dsl_inputs::IssuerInput> {
+    let card = match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => card,
+        _ => return None,
+    };
+
+    let card_info = state
+        .store
+        .get_card_info(&card.card_number.clone().get_card_isin())
+        .await
+        .map_err(|error| logger::warn!(card_info_error=?error))
+        .ok()
+        .flatten();
+
+    let card_type = card
+        .card_type
+        .clone()
+        .or_else(|| card_info.as_ref().and_then(|info| info.card_type.clone()))
+        .and_then(|card_type| euclid_enums::IssuerCardType::from_str(card_type.trim()).ok());
+
+    let issuer_country = card
+        .card_issuing_country
+        .as_deref()
+        .and_then(parse_issuer_country)
+        .or_else(|| {
+            card_info.as_ref().and_then(|info| {
+                info.country_code
+                    .as_deref()
+                    .or(info.card_issuing_country.as_deref())
+                    .and_then(parse_issuer_country)
+            })
+        });
+
+    let card_issuer = card
+        .card_issuer
+        .clone()
+        .or_else(|| card_info.and_then(|info| info.card_issuer));
+
+    (card_type.is_some() || issuer_country.is_some() || card_issuer.is_some()).then_some(
+        dsl_inputs::IssuerInput {
+            card_type,
+            issuer_country,
+            card_issuer,
+        },
+    )
+}
+
+fn parse_issuer_country(country: &str) -> Option<api_enums::Country> {
+    CountryAlpha2::from_str(&country.trim().to_uppercase())
+        .ok()
+        .map(api_enums::Country::from_alpha2)
+}
+
+/// Computes the calendar position of the given unix timestamp in the provided timezone
+pub fn make_time_input(timestamp: i64, timezone: chrono_tz::Tz) -> Option<dsl_inputs::TimeInput> {
+    use chrono::{Datelike, TimeZone, Timelike};
+
+    timezone
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|date_time| dsl_inputs::TimeInput {
+            hour_of_day: i64::from(date_time.hour()),
+            day_of_week: i64::from(date_time.weekday().number_from_monday()),
+            day_of_month: i64::from(date_time.day()),
+        })
+}
+
+async fn get_time_input(state: &AppState, merchant_id: &str) -> Option<dsl_inputs::TimeInput> {
+    let timezone = routing_helpers::get_merchant_routing_timezone(&*state.store, merchant_id).await;
+
+    make_time_input(common_utils::date_time::now_unix_timestamp(), timezone)
+}
+
+/// Evaluates the routing algorithm of the merchant, returning the chosen connectors along with a
+/// trace of the routing. The eligibility of the connectors is not part of the returned trace, and
+/// neither is the evaluation of advanced algorithms, which [`explain_routing_evaluation`] computes
+/// from the recorded input when it is asked for.
 pub async fn perform_static_routing_v1<F: Clone>(
     state: &AppState,
+    key_store: &domain::MerchantKeyStore,
     merchant_id: &str,
+    merchant_last_modified: i64,
     algorithm_ref: routing_types::RoutingAlgorithmRef,
     payment_data: &mut payments_oss::PaymentData<F>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let algorithm_id = if let Some(id) = algorithm_ref.algorithm_id {
-        id
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    routing_types::RoutingTrace,
+)> {
+    let (algorithm_id, routing_arm) = if let Some(id) = algorithm_ref.algorithm_id {
+        match algorithm_ref.canary {
+            Some(canary) => {
+                let sticky_key = match canary.stickiness {
+                    routing_types::RoutingCanaryStickiness::Customer => payment_data
+                        .payment_intent
+                        .customer_id
+                        .as_deref()
+                        .unwrap_or(&payment_data.payment_intent.payment_id),
+                    routing_types::RoutingCanaryStickiness::Payment => {
+                        &payment_data.payment_intent.payment_id
+                    }
+                };
+
+                match get_canary_routing_arm(&canary, sticky_key) {
+                    storage_enums::RoutingArm::Canary => {
+                        (canary.algorithm_id, Some(storage_enums::RoutingArm::Canary))
+                    }
+                    storage_enums::RoutingArm::Active => {
+                        (id, Some(storage_enums::RoutingArm::Active))
+                    }
+                }
+            }
+            None => (id, None),
+        }
     } else {
         let fallback_config =
             routing_helpers::get_merchant_default_config(&*state.clone().store, merchant_id)
                 .await
                 .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        let trace = routing_types::RoutingTrace {
+            selection: fallback_config.clone(),
+            ..Default::default()
+        };
+        return Ok((fallback_config, trace));
     };
     let key = ensure_algorithm_cached_v1(
         state,
         merchant_id,
         algorithm_ref.timestamp,
         &algorithm_id,
+        routing_arm,
         #[cfg(feature = "business_profile_routing")]
         payment_data.payment_intent.profile_id.clone(),
     )
@@ -229,20 +350,82 @@ pub async fn perform_static_routing_v1<F: Clone>(
         .change_context(errors::RoutingError::CacheMiss)
         .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
 
-    Ok(match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+    let mut connector_costs = Vec::new();
+    let (connectors, input) = match cached_algorithm.as_ref() {
+        CachedAlgorithm::Single(conn) => (vec![(**conn).clone()], None),
 
-        CachedAlgorithm::Priority(plist) => plist.clone(),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec(), None)
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            None,
+        ),
+
+        CachedAlgorithm::LeastCost(config) => {
+            let mut backend_input = make_dsl_input(payment_data)?;
+            backend_input.issuer = get_issuer_input(state, payment_data).await;
+
+            let (connectors, costs) = least_cost::perform_least_cost_routing(
+                state,
+                key_store,
+                merchant_last_modified,
+                config,
+                &backend_input,
+                #[cfg(feature = "business_profile_routing")]
+                payment_data.payment_intent.profile_id.clone(),
+            )
+            .await?;
+            connector_costs = costs;
+            (connectors, None)
+        }
 
         CachedAlgorithm::Advanced(interpreter) => {
-            let backend_input = make_dsl_input(payment_data)?;
+            let mut backend_input = make_dsl_input(payment_data)?;
+            backend_input.time = get_time_input(state, merchant_id).await;
+            backend_input.issuer = get_issuer_input(state, payment_data).await;
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            (
+                execute_dsl_and_get_connector_v1(backend_input.clone(), interpreter)?,
+                Some(backend_input),
+            )
         }
-    })
+    };
+
+    let trace = routing_types::RoutingTrace {
+        algorithm_id: Some(algorithm_id),
+        routing_arm,
+        evaluation: None,
+        input,
+        selection: connectors.clone(),
+        eligibility: Vec::new(),
+        connector_costs,
+    };
+
+    Ok((connectors, trace))
+}
+
+/// Decides the arm of a canary rollout a payment is routed with. The sticky key is hashed
+/// together with the candidate algorithm, so that every rollout samples different customers, and
+/// with FNV-1a rather than the std hasher, whose output may change between releases.
+fn get_canary_routing_arm(
+    canary: &routing_types::RoutingCanaryConfig,
+    sticky_key: &str,
+) -> storage_enums::RoutingArm {
+    let hash = canary
+        .algorithm_id
+        .bytes()
+        .chain(std::iter::once(b':'))
+        .chain(sticky_key.bytes())
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    if hash % 100 < u64::from(canary.percentage) {
+        storage_enums::RoutingArm::Canary
+    } else {
+        storage_enums::RoutingArm::Active
+    }
 }
 
 async fn ensure_algorithm_cached_v1(
@@ -250,6 +433,7 @@ async fn ensure_algorithm_cached_v1(
     merchant_id: &str,
     timestamp: i64,
     algorithm_id: &str,
+    routing_arm: Option<storage_enums::RoutingArm>,
     #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
 ) -> RoutingResult<String> {
     #[cfg(feature = "business_profile_routing")]
@@ -265,6 +449,12 @@ async fn ensure_algorithm_cached_v1(
     #[cfg(not(feature = "business_profile_routing"))]
     let key = format!("dsl_{merchant_id}");
 
+    // The candidate algorithm of a canary rollout is cached next to the active one
+    let key = match routing_arm {
+        Some(storage_enums::RoutingArm::Canary) => format!("{key}_canary"),
+        Some(storage_enums::RoutingArm::Active) | None => key,
+    };
+
     let present = ROUTING_CACHE
         .present(&key)
         .into_report()
@@ -319,12 +509,20 @@ fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    let connector_selection = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
+        .map(|out| out.connector_selection)
         .into_report()
         .change_context(errors::RoutingError::DslExecutionError)?;
 
+    get_connectors_from_dsl_output(connector_selection)
+}
+
+fn get_connectors_from_dsl_output(
+    connector_selection: ConnectorSelection,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let routing_output: routing_types::RoutingAlgorithm = connector_selection.foreign_into();
+
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
@@ -344,6 +542,44 @@ pub async fn refresh_routing_cache_v1(
     timestamp: i64,
     #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
 ) -> RoutingResult<()> {
+    let algorithm = fetch_routing_algorithm(
+        state,
+        algorithm_id,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await?;
+    let cached_algorithm = match algorithm {
+        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
+        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            CachedAlgorithm::VolumeSplit(splits)
+        }
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .into_report()
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+
+            CachedAlgorithm::Advanced(interpreter)
+        }
+        routing_types::RoutingAlgorithm::LeastCost(config) => CachedAlgorithm::LeastCost(config),
+    };
+
+    ROUTING_CACHE
+        .save(key, cached_algorithm, timestamp)
+        .into_report()
+        .change_context(errors::RoutingError::DslCachePoisoned)
+        .attach_printable("Error saving DSL to cache")?;
+
+    Ok(())
+}
+
+async fn fetch_routing_algorithm(
+    state: &AppState,
+    algorithm_id: &str,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<routing_types::RoutingAlgorithm> {
     #[cfg(feature = "business_profile_routing")]
     let algorithm = {
         let algorithm = state
@@ -377,29 +613,41 @@ pub async fn refresh_routing_cache_v1(
             .attach_printable("Error parsing routing algorithm from configs")?;
         algorithm
     };
-    let cached_algorithm = match algorithm {
-        routing_types::RoutingAlgorithm::Single(conn) => CachedAlgorithm::Single(conn),
-        routing_types::RoutingAlgorithm::Priority(plist) => CachedAlgorithm::Priority(plist),
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
-            CachedAlgorithm::VolumeSplit(splits)
-        }
+
+    Ok(algorithm)
+}
+
+/// Evaluates the advanced routing algorithm again with the input recorded in the routing trace of
+/// a payment, this time recording the outcome of every rule and comparison. Tracing is left out of
+/// routing the payment itself, as it is only needed when someone asks why a connector was chosen.
+pub async fn explain_routing_evaluation(
+    state: &AppState,
+    algorithm_id: &str,
+    input: dsl_inputs::BackendInput,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<Option<ProgramTrace>> {
+    let algorithm = fetch_routing_algorithm(
+        state,
+        algorithm_id,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await?;
+
+    match algorithm {
         routing_types::RoutingAlgorithm::Advanced(program) => {
             let interpreter = backend::VirInterpreterBackend::with_program(program)
                 .into_report()
                 .change_context(errors::RoutingError::DslBackendInitError)
                 .attach_printable("Error initializing DSL interpreter backend")?;
-
-            CachedAlgorithm::Advanced(interpreter)
+            let (_, evaluation) = interpreter.execute_traced(input);
+            Ok(Some(evaluation))
         }
-    };
-
-    ROUTING_CACHE
-        .save(key, cached_algorithm, timestamp)
-        .into_report()
-        .change_context(errors::RoutingError::DslCachePoisoned)
-        .attach_printable("Error saving DSL to cache")?;
-
-    Ok(())
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_)
+        | routing_types::RoutingAlgorithm::LeastCost(_) => Ok(None),
+    }
 }
 
 pub fn perform_volume_split(
@@ -545,6 +793,40 @@ async fn perform_kgraph_filtering(
     eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
     #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_kgraph_filtering_with_trace(
+        state,
+        key_store,
+        merchant_last_modified,
+        chosen,
+        backend_input,
+        eligible_connectors,
+        routing_types::EligibilityStage::Selection,
+        false,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await
+    .map(|(final_selection, _)| final_selection)
+}
+
+/// Filters the chosen connectors with the constraint graph of the merchant. The eligibility of
+/// every connector is only returned when `record_eligibility` is set, as serializing the
+/// constraints that were not satisfied is costly.
+#[allow(clippy::too_many_arguments)]
+async fn perform_kgraph_filtering_with_trace(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    backend_input: dsl_inputs::BackendInput,
+    eligible_connectors: Option<&Vec<api_enums::RoutableConnectors>>,
+    stage: routing_types::EligibilityStage,
+    record_eligibility: bool,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorEligibilityTrace>,
+)> {
     let context = euclid_graph::AnalysisContext::from_dir_values(
         backend_input
             .into_context()
@@ -561,6 +843,7 @@ async fn perform_kgraph_filtering(
     .await?;
 
     let mut final_selection = Vec::<routing_types::RoutableConnectorChoice>::new();
+    let mut eligibility = Vec::<routing_types::ConnectorEligibilityTrace>::new();
     for choice in chosen {
         let routable_connector = choice.connector;
         let euclid_choice: ast::ConnectorChoice = choice.clone().foreign_into();
@@ -568,20 +851,54 @@ async fn perform_kgraph_filtering(
             .into_dir_value()
             .into_report()
             .change_context(errors::RoutingError::KgraphAnalysisError)?;
-        let kgraph_eligible = cached_kgraph
-            .check_value_validity(dir_val, &context, &mut Memoization::new())
+
+        // The analysis trace only lives as long as the memoization, so it is serialized here
+        let mut memo = Memoization::new();
+        let kgraph_ineligibility_reason = match cached_kgraph
+            .check_value_validity_with_trace(dir_val, &context, &mut memo)
             .into_report()
-            .change_context(errors::RoutingError::KgraphAnalysisError)?;
+            .change_context(errors::RoutingError::KgraphAnalysisError)?
+        {
+            euclid_graph::ValueValidity::Valid => None,
+            euclid_graph::ValueValidity::NotInGraph => {
+                Some(routing_types::IneligibilityReason::NotConfigured)
+            }
+            euclid_graph::ValueValidity::Invalid(trace) => Some(
+                routing_types::IneligibilityReason::UnsatisfiedConstraints(if record_eligibility {
+                    serde_json::to_value(&trace)
+                        .map_err(|error| {
+                            logger::error!(
+                                ?error,
+                                "Failed to serialize the constraint analysis trace"
+                            )
+                        })
+                        .unwrap_or_default()
+                } else {
+                    serde_json::Value::Null
+                }),
+            ),
+        };
 
         let filter_eligible =
             eligible_connectors.map_or(true, |list| list.contains(&routable_connector));
 
-        if kgraph_eligible && filter_eligible {
-            final_selection.push(choice);
+        let ineligibility_reason = kgraph_ineligibility_reason.or((!filter_eligible)
+            .then_some(routing_types::IneligibilityReason::NotInEligibleConnectors));
+
+        if ineligibility_reason.is_none() {
+            final_selection.push(choice.clone());
+        }
+
+        if record_eligibility {
+            eligibility.push(routing_types::ConnectorEligibilityTrace {
+                connector: choice,
+                stage,
+                ineligibility_reason,
+            });
         }
     }
 
-    Ok(final_selection)
+    Ok((final_selection, eligibility))
 }
 
 pub async fn perform_eligibility_analysis<F: Clone>(
@@ -644,32 +961,79 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
     #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let mut final_selection = perform_eligibility_analysis(
+    perform_eligibility_analysis_with_fallback_and_trace(
         state,
         key_store,
         merchant_last_modified,
         chosen,
         payment_data,
-        eligible_connectors.as_ref(),
+        eligible_connectors,
+        false,
         #[cfg(feature = "business_profile_routing")]
-        profile_id.clone(),
+        profile_id,
     )
-    .await?;
+    .await
+    .map(|(final_selection, _)| final_selection)
+}
 
-    let fallback_selection = perform_fallback_routing(
+/// Same as [`perform_eligibility_analysis_with_fallback`], but also returns the outcome of the
+/// eligibility analysis for every connector that was considered when `record_eligibility` is set
+#[allow(clippy::too_many_arguments)]
+pub async fn perform_eligibility_analysis_with_fallback_and_trace<F: Clone>(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    chosen: Vec<routing_types::RoutableConnectorChoice>,
+    payment_data: &payments_oss::PaymentData<F>,
+    eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
+    record_eligibility: bool,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorEligibilityTrace>,
+)> {
+    let (mut final_selection, mut eligibility) = perform_kgraph_filtering_with_trace(
         state,
         key_store,
         merchant_last_modified,
-        payment_data,
+        chosen,
+        make_dsl_input(payment_data)?,
         eligible_connectors.as_ref(),
+        routing_types::EligibilityStage::Selection,
+        record_eligibility,
         #[cfg(feature = "business_profile_routing")]
-        profile_id,
+        profile_id.clone(),
     )
-    .await;
+    .await?;
+
+    let fallback_selection =
+        match routing_helpers::get_merchant_default_config(&*state.store, &key_store.merchant_id)
+            .await
+            .change_context(errors::RoutingError::FallbackConfigFetchFailed)
+        {
+            Ok(fallback_config) => {
+                perform_kgraph_filtering_with_trace(
+                    state,
+                    key_store,
+                    merchant_last_modified,
+                    fallback_config,
+                    make_dsl_input(payment_data)?,
+                    eligible_connectors.as_ref(),
+                    routing_types::EligibilityStage::Fallback,
+                    record_eligibility,
+                    #[cfg(feature = "business_profile_routing")]
+                    profile_id,
+                )
+                .await
+            }
+            Err(error) => Err(error),
+        };
+
+    let (fallback_selection, mut fallback_eligibility) = fallback_selection.unwrap_or_default();
+    eligibility.append(&mut fallback_eligibility);
 
     final_selection.append(
         &mut fallback_selection
-            .unwrap_or_default()
             .iter()
             .filter(|&routable_connector_choice| {
                 !final_selection.contains(routable_connector_choice)
@@ -678,13 +1042,67 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
             .collect::<Vec<_>>(),
     );
 
+    let final_selection =
+        filter_connectors_with_open_circuit(state, final_selection, &mut eligibility).await;
+
     let final_selected_connectors = final_selection
         .iter()
         .map(|item| item.connector)
         .collect::<Vec<_>>();
     logger::debug!(final_selected_connectors_for_routing=?final_selected_connectors, "List of final selected connectors for routing");
 
-    Ok(final_selection)
+    Ok((final_selection, eligibility))
+}
+
+/// Leaves out the connectors whose circuit is open. The selection is kept as is if it would leave
+/// out every connector, as failing the payment upfront is no better than trying an unhealthy one.
+async fn filter_connectors_with_open_circuit(
+    state: &AppState,
+    selection: Vec<routing_types::RoutableConnectorChoice>,
+    eligibility: &mut [routing_types::ConnectorEligibilityTrace],
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let connectors = selection
+        .iter()
+        .map(|choice| {
+            #[cfg(feature = "connector_choice_mca_id")]
+            let merchant_connector_id = choice.merchant_connector_id.as_deref();
+            #[cfg(not(feature = "connector_choice_mca_id"))]
+            let merchant_connector_id = None;
+
+            (choice.connector.to_string(), merchant_connector_id)
+        })
+        .collect::<Vec<_>>();
+    let availability = connector_health::get_connectors_availability(
+        state,
+        &connectors
+            .iter()
+            .map(|(connector, merchant_connector_id)| (connector.as_str(), *merchant_connector_id))
+            .collect::<Vec<_>>(),
+    )
+    .await;
+
+    let mut available = Vec::with_capacity(selection.len());
+    let mut unavailable = Vec::new();
+    for (choice, is_available) in selection.iter().zip(availability) {
+        if is_available {
+            available.push(choice.clone());
+        } else {
+            unavailable.push(choice);
+        }
+    }
+
+    if available.is_empty() {
+        logger::warn!("The circuit of every eligible connector is open, ignoring the circuits");
+        return selection;
+    }
+
+    for trace in eligibility.iter_mut().filter(|trace| {
+        trace.ineligibility_reason.is_none() && unavailable.contains(&&trace.connector)
+    }) {
+        trace.ineligibility_reason = Some(routing_types::IneligibilityReason::CircuitOpen);
+    }
+
+    available
 }
 
 pub async fn perform_session_flow_routing(
@@ -789,6 +1207,12 @@ pub async fn perform_session_flow_routing(
             mandate_type: None,
             payment_type: None,
         },
+        time: get_time_input(
+            session_input.state,
+            &session_input.payment_intent.merchant_id,
+        )
+        .await,
+        issuer: None,
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -854,6 +1278,7 @@ async fn perform_session_routing_for_pm_type(
                     merchant_id,
                     algorithm_ref.timestamp,
                     algorithm_id,
+                    None,
                     #[cfg(feature = "business_profile_routing")]
                     session_pm_input.profile_id.clone(),
                 )
@@ -876,6 +1301,19 @@ async fn perform_session_routing_for_pm_type(
                         session_pm_input.backend_input.clone(),
                         interpreter,
                     )?,
+                    CachedAlgorithm::LeastCost(config) => {
+                        least_cost::perform_least_cost_routing(
+                            session_pm_input.state,
+                            session_pm_input.key_store,
+                            session_pm_input.merchant_last_modified,
+                            config,
+                            &session_pm_input.backend_input,
+                            #[cfg(feature = "business_profile_routing")]
+                            session_pm_input.profile_id.clone(),
+                        )
+                        .await?
+                        .0
+                    }
                 }
             } else {
                 routing_helpers::get_merchant_default_config(
@@ -948,3 +1386,148 @@ async fn perform_session_routing_for_pm_type(
 
     Ok(final_choice)
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_merchant_routing_timezone_falls_back_to_utc() {
+        use storage_impl::MockDb;
+
+        use crate::db::configs::ConfigInterface;
+
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        assert_eq!(
+            routing_helpers::get_merchant_routing_timezone(&db, "merchant_1").await,
+            chrono_tz::UTC
+        );
+
+        db.insert_config(diesel_models::configs::ConfigNew {
+            key: "routing_timezone_merchant_1".to_string(),
+            config: "Not/A_Timezone".to_string(),
+        })
+        .await
+        .expect("Failed to insert config");
+        assert_eq!(
+            routing_helpers::get_merchant_routing_timezone(&db, "merchant_1").await,
+            chrono_tz::UTC
+        );
+
+        db.insert_config(diesel_models::configs::ConfigNew {
+            key: "routing_timezone_merchant_2".to_string(),
+            config: "Asia/Kolkata".to_string(),
+        })
+        .await
+        .expect("Failed to insert config");
+        assert_eq!(
+            routing_helpers::get_merchant_routing_timezone(&db, "merchant_2").await,
+            chrono_tz::Asia::Kolkata
+        );
+    }
+
+    #[test]
+    fn test_make_time_input_in_merchant_timezone() {
+        // 2023-10-29T20:30:00Z, which is a Sunday evening in UTC
+        let timestamp = 1_698_611_400;
+
+        let utc = make_time_input(timestamp, chrono_tz::UTC).expect("UTC time input");
+        assert_eq!(
+            (utc.hour_of_day, utc.day_of_week, utc.day_of_month),
+            (20, 7, 29)
+        );
+
+        // Already Monday 02:00 in India
+        let kolkata =
+            make_time_input(timestamp, chrono_tz::Asia::Kolkata).expect("Kolkata time input");
+        assert_eq!(
+            (
+                kolkata.hour_of_day,
+                kolkata.day_of_week,
+                kolkata.day_of_month
+            ),
+            (2, 1, 30)
+        );
+    }
+
+    #[test]
+    fn test_canary_routing_arm_is_sticky_and_proportional() {
+        let canary = routing_types::RoutingCanaryConfig {
+            algorithm_id: "routing_candidate".to_string(),
+            percentage: 20,
+            stickiness: routing_types::RoutingCanaryStickiness::Customer,
+            started_at: 0,
+        };
+
+        let arms: Vec<_> = (0..10_000)
+            .map(|customer| get_canary_routing_arm(&canary, &format!("cus_{customer}")))
+            .collect();
+        let canary_count = arms
+            .iter()
+            .filter(|arm| **arm == storage_enums::RoutingArm::Canary)
+            .count();
+        assert!((1_700..2_300).contains(&canary_count), "{canary_count}");
+
+        for (customer, arm) in arms.iter().enumerate().take(100) {
+            assert_eq!(
+                get_canary_routing_arm(&canary, &format!("cus_{customer}")),
+                *arm
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_issuer_country() {
+        assert_eq!(
+            parse_issuer_country(" in "),
+            Some(api_enums::Country::India)
+        );
+        assert_eq!(parse_issuer_country("INDIA"), None);
+    }
+
+    #[test]
+    fn test_routing_trace_roundtrip() {
+        use euclid::backend::trace::{
+            ComparisonTrace, ComparisonTraceLogic, ProgramTrace, RuleTrace, StatementTrace,
+        };
+
+        let trace = routing_types::RoutingTrace {
+            algorithm_id: Some("routing_algorithm_1".to_string()),
+            routing_arm: Some(storage_enums::RoutingArm::Canary),
+            evaluation: Some(ProgramTrace {
+                rules: vec![RuleTrace {
+                    name: "high_value_cards".to_string(),
+                    statements: vec![StatementTrace {
+                        comparisons: vec![ComparisonTrace {
+                            key: "amount".to_string(),
+                            logic: ComparisonTraceLogic::AnyOf,
+                            expected: vec!["> 1000".to_string()],
+                            actual: vec!["500".to_string()],
+                            passed: false,
+                        }],
+                        nested: None,
+                        passed: false,
+                    }],
+                    matched: false,
+                }],
+                selected_rule: None,
+            }),
+            input: None,
+            selection: Vec::new(),
+            eligibility: Vec::new(),
+            connector_costs: Vec::new(),
+        };
+
+        let encoded = routing_helpers::encode_routing_trace(&trace).expect("encoded trace");
+        let decoded = routing_helpers::decode_routing_trace(&encoded).expect("decoded trace");
+
+        assert_eq!(decoded.algorithm_id, trace.algorithm_id);
+        assert_eq!(decoded.evaluation, trace.evaluation);
+        assert_eq!(decoded.routing_arm, trace.routing_arm);
+    }
+}
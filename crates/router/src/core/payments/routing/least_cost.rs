@@ -0,0 +1,411 @@
+use api_models::{
+    admin::{ConnectorFeeRegion, ConnectorFeeSchedule},
+    enums as api_enums,
+};
+use common_utils::static_cache::StaticCache;
+use error_stack::{IntoReport, ResultExt};
+use euclid::{backend::inputs as dsl_inputs, enums as euclid_enums};
+
+use super::RoutingResult;
+use crate::{
+    core::errors,
+    logger,
+    types::{api::routing as routing_types, domain, storage::enums as storage_enums},
+    utils::ValueExt,
+    AppState,
+};
+#[cfg(feature = "business_profile_routing")]
+use crate::{core::payments::helpers, utils::OptionExt};
+
+static FEE_SCHEDULE_CACHE: StaticCache<Vec<ConnectorAccountFees>> = StaticCache::new();
+
+/// The parts of a merchant connector account which least cost routing ranks connectors by
+struct ConnectorAccountFees {
+    connector_name: String,
+    #[cfg(feature = "connector_choice_mca_id")]
+    merchant_connector_id: String,
+    #[cfg(not(feature = "connector_choice_mca_id"))]
+    business_sub_label: Option<String>,
+    business_country: Option<api_enums::CountryAlpha2>,
+    business_label: Option<String>,
+    fee_schedule: Option<ConnectorFeeSchedule>,
+}
+
+/// Ranks the connectors of a least cost routing algorithm by the fee they would charge for the
+/// payment. Connectors whose account has no fee schedule, or no rule matching the payment, are
+/// ranked last in their configured order.
+pub async fn perform_least_cost_routing(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    config: &routing_types::LeastCostRoutingConfig,
+    backend_input: &dsl_inputs::BackendInput,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorCostTrace>,
+)> {
+    let connector_account_fees = get_connector_account_fees(
+        state,
+        key_store,
+        merchant_last_modified,
+        #[cfg(feature = "business_profile_routing")]
+        profile_id,
+    )
+    .await?;
+
+    let region = backend_input
+        .is_domestic()
+        .map(|is_domestic| match is_domestic {
+            euclid_enums::IsDomestic::True => ConnectorFeeRegion::Domestic,
+            euclid_enums::IsDomestic::False => ConnectorFeeRegion::CrossBorder,
+        });
+
+    Ok(rank_connectors_by_cost(config, |choice| {
+        find_connector_account_fees(&connector_account_fees, choice, backend_input)
+            .and_then(|account_fees| account_fees.fee_schedule.as_ref())
+            .and_then(|fee_schedule| {
+                fee_schedule.fee_for(
+                    backend_input.payment.amount,
+                    backend_input.payment.currency,
+                    backend_input.payment_method.payment_method_type,
+                    backend_input.payment_method.card_network.as_ref(),
+                    region,
+                )
+            })
+    }))
+}
+
+/// Provides the fee schedules of the merchant's connector accounts from a cache which, like the
+/// knowledge graph used for the eligibility analysis, is refreshed whenever the merchant account
+/// is modified, so that routing a payment doesn't list the accounts every time.
+async fn get_connector_account_fees(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RoutingResult<std::sync::Arc<Vec<ConnectorAccountFees>>> {
+    #[cfg(feature = "business_profile_routing")]
+    let key = {
+        let profile_id = profile_id
+            .clone()
+            .get_required_value("profile_id")
+            .change_context(errors::RoutingError::ProfileIdMissing)?;
+
+        format!("fee_schedules_{}_{profile_id}", key_store.merchant_id)
+    };
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    let key = format!("fee_schedules_{}", key_store.merchant_id);
+
+    let present = FEE_SCHEDULE_CACHE
+        .present(&key)
+        .into_report()
+        .change_context(errors::RoutingError::MerchantConnectorAccountsFetchFailed)
+        .attach_printable("Error checking presence of fee schedules in cache")?;
+
+    let expired = FEE_SCHEDULE_CACHE
+        .expired(&key, merchant_last_modified)
+        .into_report()
+        .change_context(errors::RoutingError::MerchantConnectorAccountsFetchFailed)
+        .attach_printable("Error checking expiry of fee schedules in cache")?;
+
+    if !present || expired {
+        let merchant_connector_accounts = state
+            .store
+            .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+                &key_store.merchant_id,
+                false,
+                key_store,
+            )
+            .await
+            .change_context(errors::RoutingError::MerchantConnectorAccountsFetchFailed)?
+            .into_iter()
+            .filter(|mca| mca.connector_type != storage_enums::ConnectorType::PaymentVas)
+            .collect::<Vec<_>>();
+
+        #[cfg(feature = "business_profile_routing")]
+        let merchant_connector_accounts =
+            helpers::filter_mca_based_on_business_profile(merchant_connector_accounts, profile_id);
+
+        let connector_account_fees = merchant_connector_accounts
+            .into_iter()
+            .map(|mca| ConnectorAccountFees {
+                fee_schedule: get_fee_schedule(&mca),
+                connector_name: mca.connector_name,
+                #[cfg(feature = "connector_choice_mca_id")]
+                merchant_connector_id: mca.merchant_connector_id,
+                #[cfg(not(feature = "connector_choice_mca_id"))]
+                business_sub_label: mca.business_sub_label,
+                business_country: mca.business_country,
+                business_label: mca.business_label,
+            })
+            .collect();
+
+        FEE_SCHEDULE_CACHE
+            .save(key.clone(), connector_account_fees, merchant_last_modified)
+            .into_report()
+            .change_context(errors::RoutingError::MerchantConnectorAccountsFetchFailed)
+            .attach_printable("Error saving fee schedules to cache")?;
+    }
+
+    FEE_SCHEDULE_CACHE
+        .retrieve(&key)
+        .into_report()
+        .change_context(errors::RoutingError::CacheMiss)
+        .attach_printable("Unable to retrieve fee schedules from cache")
+}
+
+fn rank_connectors_by_cost(
+    config: &routing_types::LeastCostRoutingConfig,
+    get_fee: impl Fn(&routing_types::RoutableConnectorChoice) -> Option<f64>,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::ConnectorCostTrace>,
+) {
+    let connector_costs = config
+        .connectors
+        .iter()
+        .map(|least_cost_connector| {
+            let fee = get_fee(&least_cost_connector.connector);
+            let authorization_rate = least_cost_connector.authorization_rate.unwrap_or(1.0);
+            routing_types::ConnectorCostTrace {
+                connector: least_cost_connector.connector.clone(),
+                fee,
+                effective_cost: fee
+                    .map(|fee| fee / authorization_rate.powf(config.authorization_rate_weight)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut ranked = connector_costs.clone();
+    // The sort is stable, so connectors with the same cost keep their configured order
+    ranked.sort_by(
+        |first, second| match (first.effective_cost, second.effective_cost) {
+            (Some(first_cost), Some(second_cost)) => first_cost.total_cmp(&second_cost),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        },
+    );
+
+    (
+        ranked
+            .into_iter()
+            .map(|connector_cost| connector_cost.connector)
+            .collect(),
+        connector_costs,
+    )
+}
+
+/// Finds the account a connector choice would be routed to. When several accounts match the
+/// choice, the one for the business country and label of the payment is preferred.
+fn find_connector_account_fees<'a>(
+    connector_account_fees: &'a [ConnectorAccountFees],
+    choice: &routing_types::RoutableConnectorChoice,
+    backend_input: &dsl_inputs::BackendInput,
+) -> Option<&'a ConnectorAccountFees> {
+    let connector_name = choice.connector.to_string();
+    let candidates = connector_account_fees
+        .iter()
+        .filter(|account_fees| account_fees.connector_name == connector_name)
+        .filter(|account_fees| {
+            #[cfg(feature = "connector_choice_mca_id")]
+            let is_chosen_account = choice
+                .merchant_connector_id
+                .as_ref()
+                .map_or(true, |mca_id| mca_id == &account_fees.merchant_connector_id);
+
+            #[cfg(not(feature = "connector_choice_mca_id"))]
+            let is_chosen_account = choice.sub_label.as_ref().map_or(true, |sub_label| {
+                account_fees.business_sub_label.as_ref() == Some(sub_label)
+            });
+
+            is_chosen_account
+        })
+        .collect::<Vec<_>>();
+
+    candidates
+        .iter()
+        .find(|account_fees| {
+            account_fees
+                .business_country
+                .map(euclid_enums::Country::from_alpha2)
+                == backend_input.payment.business_country
+                && account_fees.business_label == backend_input.payment.business_label
+        })
+        .or_else(|| candidates.first())
+        .copied()
+}
+
+fn get_fee_schedule(mca: &domain::MerchantConnectorAccount) -> Option<ConnectorFeeSchedule> {
+    mca.fee_schedule
+        .clone()
+        .map(|fee_schedule| fee_schedule.parse_value("ConnectorFeeSchedule"))
+        .transpose()
+        .map_err(|error| logger::error!(fee_schedule_parsing_error=?error))
+        .ok()
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::{admin::ConnectorFeeRule, routing::LeastCostConnector};
+
+    use super::*;
+    use crate::types::api::routing::api_enums;
+
+    fn choice(connector: api_enums::RoutableConnectors) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            #[cfg(feature = "backwards_compatibility")]
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            #[cfg(feature = "connector_choice_mca_id")]
+            merchant_connector_id: None,
+            #[cfg(not(feature = "connector_choice_mca_id"))]
+            sub_label: None,
+        }
+    }
+
+    fn fee_rule(fixed_fee: i64, percentage: f64) -> ConnectorFeeRule {
+        ConnectorFeeRule {
+            payment_method_type: None,
+            card_network: None,
+            region: None,
+            currency: None,
+            fixed_fee,
+            percentage,
+        }
+    }
+
+    #[test]
+    fn test_most_specific_fee_rule_is_applied() {
+        let fee_schedule = ConnectorFeeSchedule {
+            rules: vec![
+                fee_rule(30, 2.5),
+                ConnectorFeeRule {
+                    region: Some(ConnectorFeeRegion::CrossBorder),
+                    ..fee_rule(30, 4.5)
+                },
+                ConnectorFeeRule {
+                    currency: Some(api_enums::Currency::EUR),
+                    ..fee_rule(25, 1.5)
+                },
+            ],
+        };
+
+        let fee = |currency, region| {
+            fee_schedule.fee_for(
+                10_000,
+                currency,
+                Some(api_enums::PaymentMethodType::Credit),
+                None,
+                region,
+            )
+        };
+
+        assert_eq!(fee(api_enums::Currency::USD, None), Some(280.0));
+        assert_eq!(
+            fee(
+                api_enums::Currency::USD,
+                Some(ConnectorFeeRegion::CrossBorder)
+            ),
+            Some(480.0)
+        );
+        assert_eq!(fee(api_enums::Currency::EUR, None), Some(175.0));
+        // Equally specific rules, the first one wins
+        assert_eq!(
+            fee(
+                api_enums::Currency::EUR,
+                Some(ConnectorFeeRegion::CrossBorder)
+            ),
+            Some(480.0)
+        );
+
+        let card_only = ConnectorFeeSchedule {
+            rules: vec![ConnectorFeeRule {
+                payment_method_type: Some(api_enums::PaymentMethodType::Credit),
+                ..fee_rule(0, 1.0)
+            }],
+        };
+        assert_eq!(
+            card_only.fee_for(
+                10_000,
+                api_enums::Currency::USD,
+                Some(api_enums::PaymentMethodType::Debit),
+                None,
+                None,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_connectors_are_ranked_by_effective_cost() {
+        let config = routing_types::LeastCostRoutingConfig {
+            connectors: vec![
+                LeastCostConnector {
+                    connector: choice(api_enums::RoutableConnectors::Stripe),
+                    authorization_rate: Some(0.9),
+                },
+                LeastCostConnector {
+                    connector: choice(api_enums::RoutableConnectors::Adyen),
+                    authorization_rate: None,
+                },
+                LeastCostConnector {
+                    connector: choice(api_enums::RoutableConnectors::Checkout),
+                    authorization_rate: Some(0.5),
+                },
+                LeastCostConnector {
+                    connector: choice(api_enums::RoutableConnectors::Bluesnap),
+                    authorization_rate: None,
+                },
+            ],
+            authorization_rate_weight: 0.0,
+        };
+        let get_fee = |choice: &routing_types::RoutableConnectorChoice| match choice.connector {
+            api_enums::RoutableConnectors::Stripe => Some(300.0),
+            api_enums::RoutableConnectors::Adyen => Some(350.0),
+            api_enums::RoutableConnectors::Checkout => Some(200.0),
+            _ => None,
+        };
+
+        let (ranked, costs) = rank_connectors_by_cost(&config, get_fee);
+        let ranked = ranked
+            .into_iter()
+            .map(|choice| choice.connector)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Bluesnap,
+            ]
+        );
+        assert_eq!(costs.len(), 4);
+        assert_eq!(costs[3].effective_cost, None);
+
+        // Weighing the authorization rate fully ranks by fee per successful authorization
+        let config = routing_types::LeastCostRoutingConfig {
+            authorization_rate_weight: 1.0,
+            ..config
+        };
+        let (ranked, costs) = rank_connectors_by_cost(&config, get_fee);
+        let ranked = ranked
+            .into_iter()
+            .map(|choice| choice.connector)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                api_enums::RoutableConnectors::Stripe,
+                api_enums::RoutableConnectors::Adyen,
+                api_enums::RoutableConnectors::Checkout,
+                api_enums::RoutableConnectors::Bluesnap,
+            ]
+        );
+        assert_eq!(costs[2].effective_cost, Some(400.0));
+    }
+}
@@ -163,6 +163,7 @@ where
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow,
+        connector_fee_details: None,
     };
 
     Ok(router_data)
@@ -431,6 +432,8 @@ where
                 surcharge_amount,
                 tax_amount: payment_attempt.tax_amount,
             });
+    let fee_details =
+        Option::<api_models::payments::ConnectorFeeDetails>::foreign_from(&payment_attempt);
     let merchant_decision = payment_intent.merchant_decision.to_owned();
     let frm_message = payment_data.frm_message.map(FrmMessage::foreign_from);
 
@@ -692,6 +695,7 @@ where
                         .set_profile_id(payment_intent.profile_id)
                         .set_attempt_count(payment_intent.attempt_count)
                         .set_merchant_connector_id(payment_attempt.merchant_connector_id)
+                        .set_fee_details(fee_details)
                         .to_owned(),
                     headers,
                 ))
@@ -752,6 +756,7 @@ where
                 attempt_count: payment_intent.attempt_count,
                 payment_link: payment_link_data,
                 surcharge_details,
+                fee_details,
                 ..Default::default()
             },
             headers,
@@ -832,6 +837,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
     fn foreign_from(item: (storage::PaymentIntent, storage::PaymentAttempt)) -> Self {
         let pi = item.0;
         let pa = item.1;
+        let fee_details = Option::<api_models::payments::ConnectorFeeDetails>::foreign_from(&pa);
         Self {
             payment_id: Some(pi.payment_id),
             merchant_id: Some(pi.merchant_id),
@@ -856,6 +862,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             authentication_type: pa.authentication_type,
             connector_transaction_id: pa.connector_transaction_id,
             attempt_count: pi.attempt_count,
+            fee_details,
             ..Default::default()
         }
     }
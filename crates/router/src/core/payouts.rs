@@ -5,7 +5,7 @@ use api_models::enums as api_enums;
 use common_utils::{crypto::Encryptable, ext_traits::ValueExt};
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 use serde_json;
 
 use super::errors::{ConnectorErrorExt, StorageErrorExt};
@@ -21,6 +21,7 @@ use crate::{
         self,
         api::{self, payouts},
         domain, storage,
+        transformers::{ForeignFrom, ForeignInto},
     },
     utils::{self, OptionExt},
 };
@@ -254,7 +255,7 @@ pub async fn payouts_retrieve_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutRetrieveRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
-    let payout_data = make_payout_data(
+    let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
@@ -262,6 +263,33 @@ pub async fn payouts_retrieve_core(
     )
     .await?;
 
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+
+    // Sync with the connector only when explicitly requested and the payout is in flight
+    if req.force_sync.unwrap_or(false)
+        && helpers::should_call_payout_connector_sync(&payout_attempt)
+    {
+        // Form connector data
+        let connector_data = get_connector_data(
+            &state,
+            &merchant_account,
+            Some(payout_attempt.connector),
+            None,
+        )
+        .await?;
+
+        payout_data = sync_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable("Payout sync failed for given Payout request")?;
+    }
+
     response_handler(
         &state,
         &merchant_account,
@@ -344,6 +372,11 @@ pub async fn payouts_cancel_core(
         .attach_printable("Payout cancellation failed for given Payout request")?;
     }
 
+    utils::trigger_payouts_webhook(&state, &merchant_account, &payout_data)
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+
     response_handler(
         &state,
         &merchant_account,
@@ -418,6 +451,11 @@ pub async fn payouts_fulfill_core(
     .await
     .attach_printable("Payout fulfillment failed for given Payout request")?;
 
+    utils::trigger_payouts_webhook(&state, &merchant_account, &payout_data)
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+
     if helpers::is_payout_err_state(status) {
         return Err(report!(errors::ApiErrorResponse::PayoutFailed {
             data: Some(
@@ -463,6 +501,9 @@ pub async fn call_connector_payout(
             .get_required_value("payout_method_data")?,
         );
     }
+    if let Some(payout_method_data) = payout_data.payout_method_data.as_ref() {
+        validator::validate_payout_method_data(payout_method_data, payouts)?;
+    }
     if let Some(true) = req.confirm {
         // Eligibility flow
         if payouts.payout_type == storage_enums::PayoutType::Card
@@ -494,8 +535,12 @@ pub async fn call_connector_payout(
                 .attach_printable("Payout data provided is invalid"))
             },
         )?;
-        if payout_data.payouts.payout_type == storage_enums::PayoutType::Bank
-            && payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresCreation
+        if matches!(
+            payout_data.payouts.payout_type,
+            storage_enums::PayoutType::Bank
+                | storage_enums::PayoutType::Wallet
+                | storage_enums::PayoutType::InstantTransfer
+        ) && payout_data.payout_attempt.status == storage_enums::PayoutStatus::RequiresCreation
         {
             // Create customer flow
             *payout_data = create_recipient(
@@ -538,6 +583,11 @@ pub async fn call_connector_payout(
         .attach_printable("Payout fulfillment failed for given Payout request")?;
     }
 
+    utils::trigger_payouts_webhook(state, merchant_account, payout_data)
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+
     response_handler(
         state,
         merchant_account,
@@ -709,6 +759,14 @@ pub async fn check_payout_eligibility(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            if status == storage_enums::PayoutStatus::Pending {
+                // The connector has accepted the payout, failing to schedule its sync must not fail
+                // the payout
+                helpers::add_payout_sync_task_to_pt(&*state.store, &payout_data.payout_attempt)
+                    .await
+                    .map_err(|error| logger::error!(payout_sync_task_error=?error))
+                    .ok();
+            }
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -815,6 +873,14 @@ pub async fn create_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            if status == storage_enums::PayoutStatus::Pending {
+                // The connector has accepted the payout, failing to schedule its sync must not fail
+                // the payout
+                helpers::add_payout_sync_task_to_pt(&*state.store, &payout_data.payout_attempt)
+                    .await
+                    .map_err(|error| logger::error!(payout_sync_task_error=?error))
+                    .ok();
+            }
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1019,6 +1085,14 @@ pub async fn fulfill_payout(
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("Error updating payout_attempt in db")?;
+            if status == storage_enums::PayoutStatus::Pending {
+                // The connector has accepted the payout, failing to schedule its sync must not fail
+                // the payout
+                helpers::add_payout_sync_task_to_pt(&*state.store, &payout_data.payout_attempt)
+                    .await
+                    .map_err(|error| logger::error!(payout_sync_task_error=?error))
+                    .ok();
+            }
             if helpers::is_payout_err_state(status) {
                 return Err(report!(errors::ApiErrorResponse::PayoutFailed {
                     data: Some(
@@ -1051,6 +1125,95 @@ pub async fn fulfill_payout(
     Ok(payout_data.clone())
 }
 
+#[cfg(feature = "payouts")]
+pub async fn sync_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    req: &payouts::PayoutRequest,
+    connector_data: &api::PayoutConnectorData,
+    payout_data: &mut PayoutData,
+) -> RouterResult<PayoutData> {
+    // 1. Form Router data
+    let router_data = core_utils::construct_payout_router_data(
+        state,
+        &connector_data.connector_name.to_string(),
+        merchant_account,
+        key_store,
+        req,
+        payout_data,
+    )
+    .await?;
+
+    // 2. Fetch connector integration details
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PoSync,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // 3. Call connector service
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .to_payout_failed_response()?;
+
+    // 4. Process data returned by the connector
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let payout_id = &payout_attempt.payout_id;
+    match router_data_resp.response {
+        Ok(payout_response_data) => {
+            let status = payout_response_data
+                .status
+                .unwrap_or(payout_attempt.status.to_owned());
+            if status != payout_attempt.status {
+                let updated_payout_attempt =
+                    storage::payout_attempt::PayoutAttemptUpdate::StatusUpdate {
+                        connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+                        status,
+                        error_code: None,
+                        error_message: None,
+                        is_eligible: payout_attempt.is_eligible,
+                        last_modified_at: Some(common_utils::date_time::now()),
+                    };
+                payout_data.payout_attempt = db
+                    .update_payout_attempt_by_merchant_id_payout_id(
+                        merchant_id,
+                        payout_id,
+                        updated_payout_attempt,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error updating payout_attempt in db")?;
+
+                utils::trigger_payouts_webhook(state, merchant_account, payout_data)
+                    .await
+                    .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+                    .ok();
+            }
+        }
+        // A failed sync does not say anything about the payout itself, so the status is retained
+        Err(err) => Err(report!(errors::ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector_data.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        }))
+        .attach_printable("Failed to sync payout with the connector")?,
+    };
+
+    Ok(payout_data.clone())
+}
+
 #[cfg(feature = "payouts")]
 pub async fn response_handler(
     _state: &AppState,
@@ -1121,6 +1284,92 @@ pub async fn response_handler(
     Ok(services::ApplicationResponse::Json(response))
 }
 
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: payouts::PayoutListRequest,
+) -> RouterResponse<payouts::PayoutListResponse> {
+    let db = state.store.as_ref();
+    let limit = validator::validate_payout_list(req.limit)?;
+    let offset = req.offset.unwrap_or_default();
+
+    let payouts_list = db
+        .filter_payouts_by_constraints(&merchant_account.merchant_id, &req, limit, offset)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let data: Vec<payouts::PayoutCreateResponse> = payouts_list
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    let total_count = db
+        .get_total_count_of_payouts(&merchant_account.merchant_id, &req)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the total count of payouts")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payouts::PayoutListResponse {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[instrument(skip_all)]
+pub async fn payouts_filter_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: api_models::refunds::TimeRange,
+) -> RouterResponse<payouts::PayoutListFilters> {
+    let db = state.store.as_ref();
+    let filter_list = db
+        .filter_payouts_by_meta_constraints(&merchant_account.merchant_id, &req)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(filter_list))
+}
+
+#[cfg(feature = "payouts")]
+impl ForeignFrom<(storage::Payouts, storage::PayoutAttempt)> for payouts::PayoutCreateResponse {
+    fn foreign_from(item: (storage::Payouts, storage::PayoutAttempt)) -> Self {
+        let (payout, payout_attempt) = item;
+        Self {
+            payout_id: payout.payout_id,
+            merchant_id: payout.merchant_id,
+            amount: payout.amount,
+            currency: payout.destination_currency,
+            connector: Some(payout_attempt.connector),
+            payout_type: payout.payout_type,
+            billing: None,
+            customer_id: payout.customer_id,
+            auto_fulfill: payout.auto_fulfill,
+            email: None,
+            name: None,
+            phone: None,
+            phone_country_code: None,
+            client_secret: None,
+            return_url: payout.return_url,
+            business_country: payout_attempt.business_country,
+            business_label: payout_attempt.business_label,
+            description: payout.description,
+            entity_type: payout.entity_type,
+            recurring: payout.recurring,
+            metadata: payout.metadata,
+            status: payout_attempt.status,
+            error_message: payout_attempt.error_message,
+            error_code: payout_attempt.error_code,
+            profile_id: payout_attempt.profile_id,
+        }
+    }
+}
+
 // DB entries
 #[cfg(feature = "payouts")]
 pub async fn payout_create_db_entries(
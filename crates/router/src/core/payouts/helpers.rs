@@ -2,6 +2,7 @@ use common_utils::{errors::CustomResult, ext_traits::ValueExt};
 use diesel_models::encryption::Encryption;
 use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, PeekInterface, Secret};
+use scheduler::{db::process_tracker::ProcessTrackerExt, utils as pt_utils};
 
 use crate::{
     core::{
@@ -26,6 +27,7 @@ use crate::{
         transformers::ForeignFrom,
     },
     utils::{self, OptionExt},
+    workflows::payment_sync,
 };
 
 pub async fn make_payout_method_data<'a>(
@@ -154,40 +156,39 @@ pub async fn save_payout_data_to_locker(
                 api_enums::PaymentMethodType::Debit,
             )
         }
-        api_models::payouts::PayoutMethodData::Bank(bank) => {
-            let key = key_store.key.get_inner().peek();
-            let enc_data = async {
-                serde_json::to_value(payout_method_data.to_owned())
-                    .into_report()
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Unable to encode payout method data")
-                    .ok()
-                    .map(|v| {
-                        let secret: Secret<String> = Secret::new(v.to_string());
-                        secret
-                    })
-                    .async_lift(|inner| domain_types::encrypt_optional(inner, key))
-                    .await
-            }
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to encrypt payout method data")?
-            .map(Encryption::from)
-            .map(|e| e.into_inner())
-            .map_or(Err(errors::ApiErrorResponse::InternalServerError), |e| {
-                Ok(hex::encode(e.peek()))
-            })?;
-            let payload = StoreLockerReq::LockerGeneric(StoreGenericReq {
-                merchant_id: &merchant_account.merchant_id,
-                merchant_customer_id: payout_attempt.customer_id.to_owned(),
-                enc_data,
-            });
-            (
-                payload,
-                None,
-                api_enums::PaymentMethodType::foreign_from(bank.to_owned()),
+        api_models::payouts::PayoutMethodData::Bank(bank) => (
+            get_generic_locker_req(
+                payout_attempt,
+                payout_method_data,
+                merchant_account,
+                key_store,
             )
-        }
+            .await?,
+            None,
+            api_enums::PaymentMethodType::foreign_from(bank.to_owned()),
+        ),
+        api_models::payouts::PayoutMethodData::Wallet(wallet) => (
+            get_generic_locker_req(
+                payout_attempt,
+                payout_method_data,
+                merchant_account,
+                key_store,
+            )
+            .await?,
+            None,
+            api_enums::PaymentMethodType::foreign_from(wallet.to_owned()),
+        ),
+        api_models::payouts::PayoutMethodData::InstantTransfer(instant_transfer) => (
+            get_generic_locker_req(
+                payout_attempt,
+                payout_method_data,
+                merchant_account,
+                key_store,
+            )
+            .await?,
+            None,
+            api_enums::PaymentMethodType::foreign_from(instant_transfer.to_owned()),
+        ),
     };
     // Store payout method in locker
     let stored_resp = cards::call_to_locker_hs(state, &locker_req, &payout_attempt.customer_id)
@@ -254,6 +255,43 @@ pub async fn save_payout_data_to_locker(
     Ok(())
 }
 
+/// Locker request storing the encrypted payout method data of payout methods other than cards
+async fn get_generic_locker_req<'a>(
+    payout_attempt: &storage::payout_attempt::PayoutAttempt,
+    payout_method_data: &api::PayoutMethodData,
+    merchant_account: &'a domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<StoreLockerReq<'a>> {
+    let key = key_store.key.get_inner().peek();
+    let enc_data = async {
+        serde_json::to_value(payout_method_data.to_owned())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encode payout method data")
+            .ok()
+            .map(|v| {
+                let secret: Secret<String> = Secret::new(v.to_string());
+                secret
+            })
+            .async_lift(|inner| domain_types::encrypt_optional(inner, key))
+            .await
+    }
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt payout method data")?
+    .map(Encryption::from)
+    .map(|e| e.into_inner())
+    .map_or(Err(errors::ApiErrorResponse::InternalServerError), |e| {
+        Ok(hex::encode(e.peek()))
+    })?;
+
+    Ok(StoreLockerReq::LockerGeneric(StoreGenericReq {
+        merchant_id: &merchant_account.merchant_id,
+        merchant_customer_id: payout_attempt.customer_id.to_owned(),
+        enc_data,
+    }))
+}
+
 pub async fn get_or_create_customer_details(
     state: &AppState,
     customer_details: &CustomerDetails,
@@ -446,7 +484,81 @@ pub fn is_payout_err_state(status: api_enums::PayoutStatus) -> bool {
         api_enums::PayoutStatus::Cancelled
             | api_enums::PayoutStatus::Failed
             | api_enums::PayoutStatus::Ineligible
+            | api_enums::PayoutStatus::Expired
+            | api_enums::PayoutStatus::Reversed
+    )
+}
+
+pub fn should_call_payout_connector_sync(payout_attempt: &storage::PayoutAttempt) -> bool {
+    payout_attempt.status == api_enums::PayoutStatus::Pending
+        && !payout_attempt.connector_payout_id.is_empty()
+}
+
+/// Schedules the sync of a pending payout attempt. The task of an attempt that has been pending
+/// before is rescheduled, as the task id is derived from the attempt.
+pub async fn add_payout_sync_task_to_pt(
+    db: &dyn StorageInterface,
+    payout_attempt: &storage::PayoutAttempt,
+) -> RouterResult<()> {
+    let schedule_time = payment_sync::get_sync_process_schedule_time(
+        db,
+        &payout_attempt.connector,
+        &payout_attempt.merchant_id,
+        0,
     )
+    .await
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the schedule time for payout sync")?;
+
+    match schedule_time {
+        Some(schedule_time) => {
+            let tracking_data = storage::PayoutSyncWorkflow {
+                payout_id: payout_attempt.payout_id.to_owned(),
+                merchant_id: payout_attempt.merchant_id.to_owned(),
+                connector: payout_attempt.connector.to_owned(),
+            };
+            let runner = "PAYOUTS_SYNC_WORKFLOW";
+            let task = "PAYOUTS_SYNC";
+            let process_tracker_id = pt_utils::get_process_tracker_id(
+                runner,
+                task,
+                &payout_attempt.payout_attempt_id,
+                &payout_attempt.merchant_id,
+            );
+            let process_tracker_entry = <storage::ProcessTracker>::make_process_tracker_new(
+                process_tracker_id.clone(),
+                task,
+                runner,
+                tracking_data,
+                schedule_time,
+            )
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct payout sync process tracker task")?;
+
+            match db.insert_process(process_tracker_entry).await {
+                Ok(_) => Ok(()),
+                // The attempt has been pending before, so the task already exists and is
+                // rescheduled instead
+                Err(err) if err.current_context().is_db_unique_violation() => db
+                    .find_process_by_id(&process_tracker_id)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the payout sync task")?
+                    .get_required_value("process_tracker")?
+                    .reset(db.as_scheduler(), schedule_time)
+                    .await
+                    .into_report()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while resetting the payout sync task"),
+                Err(err) => Err(err)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while adding payout sync task to process tracker"),
+            }
+        }
+        None => Ok(()),
+    }
 }
 
 pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus) -> bool {
@@ -456,3 +568,70 @@ pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus
             | api_enums::PayoutStatus::RequiresPayoutMethodData,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use scheduler::ProcessTrackerInterface;
+    use storage_impl::MockDb;
+
+    use super::*;
+    use crate::types::storage::enums as storage_enums;
+
+    #[tokio::test]
+    async fn test_payout_sync_task_is_rescheduled_when_pending_again() {
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let payout_attempt = storage::PayoutAttempt {
+            payout_attempt_id: "payout_attempt_test".to_string(),
+            payout_id: "payout_test".to_string(),
+            merchant_id: "merchant_test".to_string(),
+            connector: "adyen".to_string(),
+            status: storage_enums::PayoutStatus::Pending,
+            ..Default::default()
+        };
+
+        // Pending after the eligibility check
+        add_payout_sync_task_to_pt(&db, &payout_attempt)
+            .await
+            .expect("Failed to schedule the payout sync");
+
+        let process_tracker_id = pt_utils::get_process_tracker_id(
+            "PAYOUTS_SYNC_WORKFLOW",
+            "PAYOUTS_SYNC",
+            &payout_attempt.payout_attempt_id,
+            &payout_attempt.merchant_id,
+        );
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .expect("Failed to fetch the payout sync task")
+            .expect("Payout sync task not scheduled");
+        db.update_process_tracker(
+            process.clone(),
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                retry_count: 3,
+                schedule_time: process
+                    .schedule_time
+                    .expect("Payout sync task without a schedule time"),
+            },
+        )
+        .await
+        .expect("Failed to update the payout sync task");
+
+        // Pending again after the fulfillment
+        add_payout_sync_task_to_pt(&db, &payout_attempt)
+            .await
+            .expect("Failed to reschedule the payout sync");
+
+        let process = db
+            .find_process_by_id(&process_tracker_id)
+            .await
+            .expect("Failed to fetch the payout sync task")
+            .expect("Payout sync task not scheduled");
+        assert_eq!(process.status, storage_enums::ProcessTrackerStatus::New);
+        assert_eq!(process.retry_count, 0);
+    }
+}
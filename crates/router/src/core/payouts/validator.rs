@@ -14,6 +14,10 @@ use crate::{
     utils,
 };
 
+pub const LOWER_LIMIT: i64 = 1;
+pub const UPPER_LIMIT: i64 = 100;
+pub const DEFAULT_LIMIT: i64 = 10;
+
 #[cfg(feature = "payouts")]
 #[instrument(skip(db))]
 pub async fn validate_uniqueness_of_payout_id_against_merchant_id(
@@ -111,3 +115,52 @@ pub async fn validate_create_request(
 
     Ok((payout_id, payout_method_data))
 }
+
+/// Validates the payout method data on below checks
+/// - payout method data is of the same kind as the payout_type of the payout
+/// - instant transfers are only requested in the currency settled by the chosen rail
+#[cfg(feature = "payouts")]
+pub fn validate_payout_method_data(
+    payout_method_data: &payouts::PayoutMethodData,
+    payout: &storage::Payouts,
+) -> RouterResult<()> {
+    let payout_type = payout_method_data.get_payout_type();
+    utils::when(payout_type != payout.payout_type, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payout_method_data of type {} cannot be used for a payout of type {}",
+                payout_type, payout.payout_type
+            ),
+        }))
+    })?;
+
+    if let payouts::PayoutMethodData::InstantTransfer(instant_transfer) = payout_method_data {
+        let rail_currency = instant_transfer.get_rail_currency();
+        utils::when(payout.destination_currency != rail_currency, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "instant transfer to the given account can only be made in {}, found {}",
+                    rail_currency, payout.destination_currency
+                ),
+            }))
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub fn validate_payout_list(limit: Option<i64>) -> RouterResult<i64> {
+    match limit {
+        Some(limit_val) => {
+            if !(LOWER_LIMIT..=UPPER_LIMIT).contains(&limit_val) {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("limit should be in between {LOWER_LIMIT} and {UPPER_LIMIT}"),
+                }))
+            } else {
+                Ok(limit_val)
+            }
+        }
+        None => Ok(DEFAULT_LIMIT),
+    }
+}
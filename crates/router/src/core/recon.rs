@@ -0,0 +1,456 @@
+pub mod helpers;
+pub mod parsers;
+
+use std::collections::BTreeMap;
+
+use api_models::{recon, refunds::TimeRange};
+use common_utils::ext_traits::ValueExt;
+use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
+
+use super::errors::{self, RouterResponse, RouterResult, StorageErrorExt};
+use crate::{
+    consts,
+    core::files,
+    routes::AppState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::Encode,
+};
+
+/// Number of recon records written to the database in a single statement
+const RECORD_INSERT_BATCH_SIZE: usize = 1000;
+const LOWER_LIMIT: i64 = 1;
+const UPPER_LIMIT: i64 = 100;
+const DEFAULT_LIMIT: i64 = 10;
+
+pub async fn recon_report_create_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: recon::ReconReportCreateRequest,
+) -> RouterResponse<recon::ReconReportResponse> {
+    let db = &*state.store;
+    if !merchant_account.is_recon_enabled {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Reconciliation is not enabled for this merchant account".to_string(),
+        })?
+    }
+
+    let settlement_report = match (req.file_id.clone(), req.connector_report_id) {
+        (Some(file_id), None) => {
+            let (settlement_report, _provider_file_id) =
+                files::helpers::retrieve_file_and_provider_file_id_from_file_id(
+                    &state,
+                    Some(file_id),
+                    &merchant_account,
+                    &key_store,
+                    api::FileDataRequired::Required,
+                )
+                .await?;
+            settlement_report
+                .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+                .into_report()
+                .attach_printable("Settlement report file data not found")?
+        }
+        (None, Some(connector_report_id)) => {
+            let merchant_connector_id = req.merchant_connector_id.ok_or(
+                errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "merchant_connector_id",
+                },
+            )?;
+            helpers::fetch_settlement_report(
+                &state,
+                &merchant_account,
+                &key_store,
+                req.connector,
+                &merchant_connector_id,
+                connector_report_id,
+            )
+            .await?
+        }
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of file_id or connector_report_id must be passed".to_string(),
+        })
+        .into_report()?,
+    };
+    let lines = parsers::parse_settlement_report(req.connector, &settlement_report)?;
+
+    let now = common_utils::date_time::now();
+    let report = db
+        .insert_recon_report(storage::ReconReportNew {
+            report_id: common_utils::generate_id(consts::ID_LENGTH, "recon"),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: req.connector.to_string(),
+            file_id: req.file_id,
+            status: enums::ReconReportStatus::Processing,
+            total_records: 0,
+            matched_records: 0,
+            unmatched_records: 0,
+            error_message: None,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert recon report")?;
+
+    let report_update = match reconcile_settlement_report(
+        db,
+        &merchant_account,
+        &report,
+        lines,
+        req.settlement_period,
+    )
+    .await
+    {
+        Ok(records) => {
+            let matched_records = records
+                .iter()
+                .filter(|record| record.outcome == enums::ReconOutcome::Matched)
+                .count();
+            storage::ReconReportUpdate::Completed {
+                total_records: get_record_count(records.len())?,
+                matched_records: get_record_count(matched_records)?,
+                unmatched_records: get_record_count(records.len() - matched_records)?,
+            }
+        }
+        Err(error) => {
+            logger::error!(recon_error=?error, report_id=%report.report_id);
+            storage::ReconReportUpdate::Failed {
+                error_message: error.current_context().to_string(),
+            }
+        }
+    };
+    let report = db
+        .update_recon_report(report, report_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update recon report")?;
+
+    let records = db
+        .filter_recon_records_by_merchant_id_report_id(
+            &merchant_account.merchant_id,
+            &report.report_id,
+            None,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recon records")?;
+
+    Ok(services::ApplicationResponse::Json(
+        build_recon_report_response(report, Some(records.as_slice()))?,
+    ))
+}
+
+pub async fn recon_report_retrieve_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    report_id: String,
+) -> RouterResponse<recon::ReconReportResponse> {
+    let db = &*state.store;
+    let report = db
+        .find_recon_report_by_merchant_id_report_id(&merchant_account.merchant_id, &report_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Recon report {report_id} does not exist"),
+        })?;
+    let records = db
+        .filter_recon_records_by_merchant_id_report_id(
+            &merchant_account.merchant_id,
+            &report.report_id,
+            None,
+            None,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recon records")?;
+
+    Ok(services::ApplicationResponse::Json(
+        build_recon_report_response(report, Some(records.as_slice()))?,
+    ))
+}
+
+pub async fn recon_report_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: recon::ReconReportListConstraints,
+) -> RouterResponse<recon::ReconReportListResponse> {
+    let (limit, offset) = validate_list_constraints(constraints.limit, constraints.offset)?;
+    let reports = state
+        .store
+        .list_recon_reports_by_merchant_id(&merchant_account.merchant_id, limit, offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list recon reports")?;
+    let data = reports
+        .into_iter()
+        .map(|report| build_recon_report_response(report, None))
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        recon::ReconReportListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+pub async fn recon_record_list_core(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    report_id: String,
+    constraints: recon::ReconRecordListConstraints,
+) -> RouterResponse<recon::ReconRecordListResponse> {
+    let db = &*state.store;
+    let (limit, offset) = validate_list_constraints(constraints.limit, constraints.offset)?;
+    let report = db
+        .find_recon_report_by_merchant_id_report_id(&merchant_account.merchant_id, &report_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Recon report {report_id} does not exist"),
+        })?;
+    let records = db
+        .filter_recon_records_by_merchant_id_report_id(
+            &merchant_account.merchant_id,
+            &report.report_id,
+            constraints.outcome,
+            Some(limit),
+            Some(offset),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recon records")?;
+    let data = records
+        .into_iter()
+        .map(build_recon_record_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(services::ApplicationResponse::Json(
+        recon::ReconRecordListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Matches every settlement report line against the stored payment attempt, refund or dispute,
+/// flags the settled payments which are absent from the report and persists the outcome of each
+async fn reconcile_settlement_report(
+    db: &dyn crate::db::StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    report: &storage::ReconReport,
+    lines: Vec<parsers::SettlementReportLine>,
+    settlement_period: Option<TimeRange>,
+) -> RouterResult<Vec<storage::ReconRecord>> {
+    let stored_transactions =
+        helpers::StoredTransactions::fetch(db, merchant_account, &report.connector, &lines).await?;
+    let missing_payments = stored_transactions
+        .find_payments_missing_from_report(
+            db,
+            merchant_account,
+            &report.connector,
+            settlement_period,
+            &lines,
+        )
+        .await?;
+
+    let mut records = Vec::with_capacity(lines.len() + missing_payments.len());
+    for line in lines {
+        let stored_transaction = stored_transactions.find(&line)?;
+        let mismatches = helpers::find_mismatches(&line, stored_transaction.as_ref());
+        let outcome = mismatches
+            .first()
+            .map_or(enums::ReconOutcome::Matched, |mismatch| mismatch.outcome);
+
+        records.push(storage::ReconRecord {
+            record_id: common_utils::generate_id(consts::ID_LENGTH, "reconrec"),
+            report_id: report.report_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: report.connector.clone(),
+            settlement_batch_id: line.settlement_batch_id,
+            transaction_type: line.transaction_type,
+            connector_transaction_id: line.connector_transaction_id,
+            reference_id: stored_transaction
+                .as_ref()
+                .map(|stored_transaction| stored_transaction.reference_id.clone()),
+            reported_amount: Some(line.gross_amount),
+            reported_currency: Some(line.currency.to_string()),
+            reported_fee: line.fee,
+            reported_net_amount: line.net_amount,
+            reported_status: line.connector_status,
+            expected_amount: stored_transaction
+                .as_ref()
+                .map(|stored_transaction| stored_transaction.amount),
+            expected_currency: stored_transaction
+                .and_then(|stored_transaction| stored_transaction.currency),
+            outcome,
+            mismatch_details: encode_mismatches(mismatches)?,
+            created_at: common_utils::date_time::now(),
+        });
+    }
+
+    for payment_attempt in missing_payments {
+        let stored_transaction = helpers::StoredTransaction::from(&payment_attempt);
+        let connector_transaction_id = payment_attempt.connector_transaction_id.unwrap_or_default();
+        let mismatches = vec![recon::ReconMismatch {
+            outcome: enums::ReconOutcome::MissingFromReport,
+            expected: Some(connector_transaction_id.clone()),
+            reported: None,
+        }];
+
+        records.push(storage::ReconRecord {
+            record_id: common_utils::generate_id(consts::ID_LENGTH, "reconrec"),
+            report_id: report.report_id.clone(),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: report.connector.clone(),
+            settlement_batch_id: None,
+            transaction_type: enums::ReconTransactionType::Payment,
+            connector_transaction_id,
+            reference_id: Some(stored_transaction.reference_id),
+            reported_amount: None,
+            reported_currency: None,
+            reported_fee: None,
+            reported_net_amount: None,
+            reported_status: None,
+            expected_amount: Some(stored_transaction.amount),
+            expected_currency: stored_transaction.currency,
+            outcome: enums::ReconOutcome::MissingFromReport,
+            mismatch_details: encode_mismatches(mismatches)?,
+            created_at: common_utils::date_time::now(),
+        });
+    }
+
+    db.insert_recon_records(records.clone(), RECORD_INSERT_BATCH_SIZE)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert recon records")?;
+
+    Ok(records)
+}
+
+fn encode_mismatches(
+    mismatches: Vec<recon::ReconMismatch>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if mismatches.is_empty() {
+        return Ok(None);
+    }
+    Encode::<Vec<recon::ReconMismatch>>::encode_to_value(&mismatches)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize recon mismatches")
+        .map(Some)
+}
+
+fn validate_list_constraints(limit: Option<i64>, offset: Option<i64>) -> RouterResult<(i64, i64)> {
+    let limit = match limit {
+        Some(limit) if !(LOWER_LIMIT..=UPPER_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between {LOWER_LIMIT} and {UPPER_LIMIT}"),
+            })
+            .into_report()?
+        }
+        Some(limit) => limit,
+        None => DEFAULT_LIMIT,
+    };
+    Ok((limit, offset.unwrap_or_default()))
+}
+
+fn get_record_count(count: usize) -> RouterResult<i32> {
+    i32::try_from(count)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Recon record count does not fit in i32")
+}
+
+fn get_mismatches(record: &storage::ReconRecord) -> RouterResult<Vec<recon::ReconMismatch>> {
+    record
+        .mismatch_details
+        .clone()
+        .map(|mismatch_details| {
+            mismatch_details
+                .parse_value::<Vec<recon::ReconMismatch>>("ReconMismatch")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+fn build_settlement_batch_summaries(
+    records: &[storage::ReconRecord],
+) -> RouterResult<Vec<recon::SettlementBatchSummary>> {
+    let mut summaries = BTreeMap::<Option<String>, recon::SettlementBatchSummary>::new();
+    for record in records {
+        let summary = summaries
+            .entry(record.settlement_batch_id.clone())
+            .or_insert_with(|| recon::SettlementBatchSummary {
+                settlement_batch_id: record.settlement_batch_id.clone(),
+                ..Default::default()
+            });
+        summary.total_records += 1;
+        if record.outcome == enums::ReconOutcome::Matched {
+            summary.matched_records += 1;
+        } else {
+            summary.unmatched_records += 1;
+        }
+
+        for mismatch in get_mismatches(record)? {
+            match mismatch.outcome {
+                enums::ReconOutcome::Matched => {}
+                enums::ReconOutcome::MissingRecord => summary.missing_records += 1,
+                enums::ReconOutcome::MissingFromReport => summary.missing_from_report += 1,
+                enums::ReconOutcome::CurrencyMismatch => summary.currency_mismatches += 1,
+                enums::ReconOutcome::AmountMismatch => summary.amount_mismatches += 1,
+                enums::ReconOutcome::StatusMismatch => summary.status_mismatches += 1,
+                enums::ReconOutcome::FeeMismatch => summary.fee_mismatches += 1,
+            }
+        }
+    }
+    Ok(summaries.into_values().collect())
+}
+
+fn build_recon_report_response(
+    report: storage::ReconReport,
+    records: Option<&[storage::ReconRecord]>,
+) -> RouterResult<recon::ReconReportResponse> {
+    Ok(recon::ReconReportResponse {
+        settlement_batches: records.map(build_settlement_batch_summaries).transpose()?,
+        report_id: report.report_id,
+        connector: report.connector,
+        file_id: report.file_id,
+        status: report.status,
+        total_records: report.total_records,
+        matched_records: report.matched_records,
+        unmatched_records: report.unmatched_records,
+        error_message: report.error_message,
+        created_at: report.created_at,
+        modified_at: report.modified_at,
+    })
+}
+
+fn build_recon_record_response(
+    record: storage::ReconRecord,
+) -> RouterResult<recon::ReconRecordResponse> {
+    Ok(recon::ReconRecordResponse {
+        mismatches: get_mismatches(&record)?,
+        record_id: record.record_id,
+        report_id: record.report_id,
+        settlement_batch_id: record.settlement_batch_id,
+        transaction_type: record.transaction_type,
+        connector_transaction_id: record.connector_transaction_id,
+        reference_id: record.reference_id,
+        reported_amount: record.reported_amount,
+        reported_currency: record.reported_currency,
+        reported_fee: record.reported_fee,
+        reported_net_amount: record.reported_net_amount,
+        reported_status: record.reported_status,
+        expected_amount: record.expected_amount,
+        expected_currency: record.expected_currency,
+        outcome: record.outcome,
+    })
+}
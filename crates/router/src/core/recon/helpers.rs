@@ -0,0 +1,517 @@
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+};
+
+use api_models::{enums as api_enums, recon::ReconMismatch, refunds::TimeRange};
+use common_utils::errors::CustomResult;
+use error_stack::{IntoReport, ResultExt};
+
+use super::parsers::SettlementReportLine;
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        files, payments, utils,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Number of references looked up in a single query
+const LOOKUP_BATCH_SIZE: usize = 1000;
+
+/// Attempt statuses of payments which the connector is expected to settle
+const SETTLED_ATTEMPT_STATUSES: [enums::AttemptStatus; 2] = [
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::PartialCharged,
+];
+
+/// The payment attempt, refund or dispute a settlement report line refers to
+#[derive(Clone, Debug)]
+pub struct StoredTransaction {
+    /// The payment, refund or dispute id
+    pub reference_id: String,
+    pub amount: i64,
+    pub currency: Option<String>,
+    pub status: String,
+    /// Whether the stored status agrees with the transaction having been settled
+    pub is_settled: bool,
+    /// The processing fee reported by the connector while processing the transaction
+    pub processing_fee: Option<i64>,
+}
+
+impl From<&diesel_models::PaymentAttempt> for StoredTransaction {
+    fn from(payment_attempt: &diesel_models::PaymentAttempt) -> Self {
+        Self {
+            reference_id: payment_attempt.payment_id.clone(),
+            amount: payment_attempt
+                .amount_to_capture
+                .unwrap_or(payment_attempt.amount),
+            currency: payment_attempt
+                .currency
+                .map(|currency| currency.to_string()),
+            status: payment_attempt.status.to_string(),
+            is_settled: SETTLED_ATTEMPT_STATUSES.contains(&payment_attempt.status),
+            processing_fee: payment_attempt.processing_fee,
+        }
+    }
+}
+
+/// The payment attempts, refunds and disputes referred to by a settlement report, looked up in
+/// batches before the lines are matched
+#[derive(Debug, Default)]
+pub struct StoredTransactions {
+    /// Payment attempts by connector transaction id
+    payment_attempts: HashMap<String, diesel_models::PaymentAttempt>,
+    /// Refunds by connector refund id
+    refunds: HashMap<String, storage::Refund>,
+    /// Disputes by payment id
+    disputes: HashMap<String, Vec<storage::Dispute>>,
+}
+
+impl StoredTransactions {
+    pub async fn fetch(
+        db: &dyn StorageInterface,
+        merchant_account: &domain::MerchantAccount,
+        connector: &str,
+        lines: &[SettlementReportLine],
+    ) -> RouterResult<Self> {
+        let merchant_id = merchant_account.merchant_id.as_str();
+
+        let connector_payment_ids = lines
+            .iter()
+            .filter_map(|line| match line.transaction_type {
+                enums::ReconTransactionType::Payment => {
+                    Some(line.connector_transaction_id.as_str())
+                }
+                enums::ReconTransactionType::Dispute => Some(get_connector_payment_id(line)),
+                enums::ReconTransactionType::Refund => None,
+            })
+            .collect::<HashSet<_>>();
+        let payment_attempts = find_in_batches(connector_payment_ids, |connector_txn_ids| {
+            db.find_payment_attempts_by_merchant_id_connector_txn_ids_connector(
+                merchant_id,
+                connector_txn_ids,
+                connector,
+            )
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the payment attempts for recon")?
+        .into_iter()
+        .filter_map(|payment_attempt| {
+            payment_attempt
+                .connector_transaction_id
+                .clone()
+                .map(|connector_transaction_id| (connector_transaction_id, payment_attempt))
+        })
+        .collect::<HashMap<_, _>>();
+
+        let connector_refund_ids = lines
+            .iter()
+            .filter(|line| line.transaction_type == enums::ReconTransactionType::Refund)
+            .map(|line| line.connector_transaction_id.as_str())
+            .collect::<HashSet<_>>();
+        let refunds = find_in_batches(connector_refund_ids, |connector_refund_ids| {
+            db.find_refunds_by_merchant_id_connector_refund_ids_connector(
+                merchant_id,
+                connector_refund_ids,
+                connector,
+            )
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the refunds for recon")?
+        .into_iter()
+        .filter_map(|refund| {
+            refund
+                .connector_refund_id
+                .clone()
+                .map(|connector_refund_id| (connector_refund_id, refund))
+        })
+        .collect::<HashMap<_, _>>();
+
+        // Disputes are located through the payment they were raised against
+        let disputed_payment_ids = lines
+            .iter()
+            .filter(|line| line.transaction_type == enums::ReconTransactionType::Dispute)
+            .filter_map(|line| payment_attempts.get(get_connector_payment_id(line)))
+            .map(|payment_attempt| payment_attempt.payment_id.as_str())
+            .collect::<HashSet<_>>();
+        let mut disputes = HashMap::<String, Vec<storage::Dispute>>::new();
+        for dispute in find_in_batches(disputed_payment_ids, |payment_ids| {
+            db.find_disputes_by_merchant_id_payment_ids(merchant_id, payment_ids)
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the disputes for recon")?
+        {
+            disputes
+                .entry(dispute.payment_id.clone())
+                .or_default()
+                .push(dispute);
+        }
+
+        Ok(Self {
+            payment_attempts,
+            refunds,
+            disputes,
+        })
+    }
+
+    /// Returns the payment attempt, refund or dispute a settlement report line refers to
+    pub fn find(&self, line: &SettlementReportLine) -> RouterResult<Option<StoredTransaction>> {
+        match line.transaction_type {
+            enums::ReconTransactionType::Payment => Ok(self
+                .payment_attempts
+                .get(&line.connector_transaction_id)
+                .map(StoredTransaction::from)),
+            enums::ReconTransactionType::Refund => Ok(self
+                .refunds
+                .get(&line.connector_transaction_id)
+                .map(|refund| StoredTransaction {
+                    reference_id: refund.refund_id.clone(),
+                    amount: refund.refund_amount,
+                    currency: Some(refund.currency.to_string()),
+                    status: refund.refund_status.to_string(),
+                    is_settled: refund.refund_status == enums::RefundStatus::Success,
+                    processing_fee: refund.processing_fee,
+                })),
+            enums::ReconTransactionType::Dispute => {
+                let disputes = self
+                    .payment_attempts
+                    .get(get_connector_payment_id(line))
+                    .and_then(|payment_attempt| self.disputes.get(&payment_attempt.payment_id))
+                    .map(Vec::as_slice)
+                    .unwrap_or_default();
+                let dispute = disputes
+                    .iter()
+                    .find(|dispute| dispute.connector_dispute_id == line.connector_transaction_id)
+                    .or_else(|| disputes.iter().max_by_key(|dispute| dispute.created_at));
+                dispute
+                    .map(|dispute| {
+                        Ok(StoredTransaction {
+                            reference_id: dispute.dispute_id.clone(),
+                            amount: dispute
+                                .amount
+                                .parse::<i64>()
+                                .into_report()
+                                .change_context(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable("Failed to parse the dispute amount")?,
+                            currency: Some(dispute.currency.clone()),
+                            status: dispute.dispute_status.to_string(),
+                            is_settled: dispute.dispute_status
+                                != enums::DisputeStatus::DisputeCancelled,
+                            processing_fee: None,
+                        })
+                    })
+                    .transpose()
+            }
+        }
+    }
+
+    /// Finds the successful payments processed through the connector in the settlement period
+    /// which are absent from the settlement report. Without a settlement period, the period
+    /// spanned by the payments found in the report is checked.
+    pub async fn find_payments_missing_from_report(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_account: &domain::MerchantAccount,
+        connector: &str,
+        settlement_period: Option<TimeRange>,
+        lines: &[SettlementReportLine],
+    ) -> RouterResult<Vec<diesel_models::PaymentAttempt>> {
+        let reported_payment_ids = lines
+            .iter()
+            .filter(|line| line.transaction_type == enums::ReconTransactionType::Payment)
+            .map(|line| line.connector_transaction_id.as_str())
+            .collect::<HashSet<_>>();
+        let settlement_period = settlement_period
+            .map(|settlement_period| {
+                (
+                    settlement_period.start_time,
+                    settlement_period
+                        .end_time
+                        .unwrap_or_else(common_utils::date_time::now),
+                )
+            })
+            .or_else(|| {
+                let created_at = reported_payment_ids
+                    .iter()
+                    .filter_map(|connector_txn_id| self.payment_attempts.get(*connector_txn_id))
+                    .map(|payment_attempt| payment_attempt.created_at);
+                created_at.clone().min().zip(created_at.max())
+            });
+        let (start_time, end_time) = match settlement_period {
+            Some(settlement_period) => settlement_period,
+            None => return Ok(Vec::new()),
+        };
+
+        let payment_attempts = db
+            .find_payment_attempts_by_merchant_id_connector_statuses_time_range(
+                &merchant_account.merchant_id,
+                connector,
+                SETTLED_ATTEMPT_STATUSES.to_vec(),
+                start_time,
+                end_time,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching the settled payment attempts for recon")?;
+        Ok(payment_attempts
+            .into_iter()
+            .filter(|payment_attempt| {
+                payment_attempt
+                    .connector_transaction_id
+                    .as_deref()
+                    .map_or(false, |connector_txn_id| {
+                        !reported_payment_ids.contains(connector_txn_id)
+                    })
+            })
+            .collect())
+    }
+}
+
+/// Connector reference of the payment a settlement report line belongs to
+fn get_connector_payment_id(line: &SettlementReportLine) -> &str {
+    match line.transaction_type {
+        enums::ReconTransactionType::Payment => &line.connector_transaction_id,
+        enums::ReconTransactionType::Refund | enums::ReconTransactionType::Dispute => line
+            .connector_payment_id
+            .as_deref()
+            .unwrap_or(&line.connector_transaction_id),
+    }
+}
+
+/// Looks up the given references in batches of [`LOOKUP_BATCH_SIZE`], to keep the number of
+/// bind parameters of every query bounded
+async fn find_in_batches<T, F, Fut>(
+    ids: HashSet<&str>,
+    find: F,
+) -> CustomResult<Vec<T>, errors::StorageError>
+where
+    F: Fn(Vec<String>) -> Fut,
+    Fut: Future<Output = CustomResult<Vec<T>, errors::StorageError>>,
+{
+    let ids = ids.into_iter().map(str::to_owned).collect::<Vec<_>>();
+    let mut found = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(LOOKUP_BATCH_SIZE) {
+        found.extend(find(chunk.to_vec()).await?);
+    }
+    Ok(found)
+}
+
+/// Fetches a settlement report from the connector with the credentials of the merchant
+/// connector account
+pub async fn fetch_settlement_report(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector: api_enums::Connector,
+    merchant_connector_id: &str,
+    connector_report_id: String,
+) -> RouterResult<Vec<u8>> {
+    // Only connectors whose file retrieval returns the contents of the report are supported
+    match connector {
+        api_enums::Connector::Stripe => {}
+        _ => Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Fetching settlement reports from {connector}"),
+        })
+        .into_report()?,
+    }
+
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.to_string(),
+        })?;
+    let connector_name = connector.to_string();
+    if merchant_connector_account.connector_name != connector_name {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("merchant_connector_id does not belong to {connector}"),
+        })
+        .into_report()?
+    }
+
+    let router_data = utils::construct_retrieve_file_router_data_for_connector_account(
+        merchant_account,
+        &payments::helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account),
+        &connector_name,
+        connector_report_id,
+    )?;
+    files::helpers::call_connector_retrieve_file(
+        state,
+        &connector_name,
+        Some(merchant_connector_id.to_string()),
+        &router_data,
+    )
+    .await
+}
+
+/// Compares a settlement report line with the stored transaction. Missing records, currency,
+/// amount, status and fee mismatches are reported in that order of precedence. Reported fees are
+/// checked against the fee stored from the connector response when available, and against the
+/// gross and net amounts of the line otherwise.
+pub fn find_mismatches(
+    line: &SettlementReportLine,
+    stored_transaction: Option<&StoredTransaction>,
+) -> Vec<ReconMismatch> {
+    let stored_transaction = match stored_transaction {
+        Some(stored_transaction) => stored_transaction,
+        None => {
+            return vec![ReconMismatch {
+                outcome: enums::ReconOutcome::MissingRecord,
+                expected: None,
+                reported: Some(line.connector_transaction_id.clone()),
+            }]
+        }
+    };
+
+    let mut mismatches = Vec::new();
+    let reported_currency = line.currency.to_string();
+    if stored_transaction.currency.as_ref() != Some(&reported_currency) {
+        mismatches.push(ReconMismatch {
+            outcome: enums::ReconOutcome::CurrencyMismatch,
+            expected: stored_transaction.currency.clone(),
+            reported: Some(reported_currency),
+        });
+    }
+
+    // Debits such as refunds and chargebacks are booked as negative amounts
+    if stored_transaction.amount != line.gross_amount.abs() {
+        mismatches.push(ReconMismatch {
+            outcome: enums::ReconOutcome::AmountMismatch,
+            expected: Some(stored_transaction.amount.to_string()),
+            reported: Some(line.gross_amount.abs().to_string()),
+        });
+    }
+
+    if !stored_transaction.is_settled {
+        mismatches.push(ReconMismatch {
+            outcome: enums::ReconOutcome::StatusMismatch,
+            expected: Some(stored_transaction.status.clone()),
+            reported: line.connector_status.clone(),
+        });
+    }
+
+    if let (Some(fee), Some(processing_fee)) = (line.fee, stored_transaction.processing_fee) {
+        if fee.abs() != processing_fee.abs() {
+            mismatches.push(ReconMismatch {
+                outcome: enums::ReconOutcome::FeeMismatch,
+                expected: Some(processing_fee.abs().to_string()),
+                reported: Some(fee.abs().to_string()),
+            });
+        }
+    } else if let (Some(fee), Some(net_amount)) = (line.fee, line.net_amount) {
+        if line.gross_amount - fee != net_amount {
+            mismatches.push(ReconMismatch {
+                outcome: enums::ReconOutcome::FeeMismatch,
+                expected: Some((line.gross_amount - fee).to_string()),
+                reported: Some(net_amount.to_string()),
+            });
+        }
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settlement_line() -> SettlementReportLine {
+        SettlementReportLine {
+            settlement_batch_id: Some("po_1".to_string()),
+            transaction_type: enums::ReconTransactionType::Refund,
+            connector_transaction_id: "re_1".to_string(),
+            connector_payment_id: Some("pi_1".to_string()),
+            currency: enums::Currency::USD,
+            gross_amount: -2000,
+            fee: Some(0),
+            net_amount: Some(-2000),
+            connector_status: Some("refund".to_string()),
+        }
+    }
+
+    fn stored_transaction() -> StoredTransaction {
+        StoredTransaction {
+            reference_id: "ref_1".to_string(),
+            amount: 2000,
+            currency: Some("USD".to_string()),
+            status: "success".to_string(),
+            is_settled: true,
+            processing_fee: None,
+        }
+    }
+
+    #[test]
+    fn test_matched_line_has_no_mismatches() {
+        assert!(find_mismatches(&settlement_line(), Some(&stored_transaction())).is_empty());
+    }
+
+    #[test]
+    fn test_missing_record() {
+        let mismatches = find_mismatches(&settlement_line(), None);
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].outcome, enums::ReconOutcome::MissingRecord);
+    }
+
+    #[test]
+    fn test_mismatches_are_ordered_by_precedence() {
+        let line = SettlementReportLine {
+            net_amount: Some(-2100),
+            ..settlement_line()
+        };
+        let stored_transaction = StoredTransaction {
+            amount: 1500,
+            currency: Some("EUR".to_string()),
+            is_settled: false,
+            ..stored_transaction()
+        };
+
+        let outcomes: Vec<_> = find_mismatches(&line, Some(&stored_transaction))
+            .into_iter()
+            .map(|mismatch| mismatch.outcome)
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                enums::ReconOutcome::CurrencyMismatch,
+                enums::ReconOutcome::AmountMismatch,
+                enums::ReconOutcome::StatusMismatch,
+                enums::ReconOutcome::FeeMismatch,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fee_mismatch_against_stored_processing_fee() {
+        let line = SettlementReportLine {
+            fee: Some(-59),
+            net_amount: Some(-1941),
+            ..settlement_line()
+        };
+        let stored_transaction = StoredTransaction {
+            processing_fee: Some(32),
+            ..stored_transaction()
+        };
+
+        let mismatches = find_mismatches(&line, Some(&stored_transaction));
+
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].outcome, enums::ReconOutcome::FeeMismatch);
+        assert_eq!(mismatches[0].expected.as_deref(), Some("32"));
+    }
+}
@@ -0,0 +1,398 @@
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use common_utils::errors::CustomResult;
+use error_stack::IntoReport;
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{core::errors, types::storage::enums};
+
+/// A settlement report line which refers to a payment, refund or dispute
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SettlementReportLine {
+    /// The settlement batch or payout reference the line was settled in
+    pub settlement_batch_id: Option<String>,
+    pub transaction_type: enums::ReconTransactionType,
+    /// Connector reference of the payment, refund or dispute
+    pub connector_transaction_id: String,
+    /// Connector reference of the payment which a refund or dispute belongs to
+    pub connector_payment_id: Option<String>,
+    pub currency: enums::Currency,
+    /// Signed gross amount as booked by the connector, in the lowest denomination of the currency
+    pub gross_amount: i64,
+    pub fee: Option<i64>,
+    pub net_amount: Option<i64>,
+    pub connector_status: Option<String>,
+}
+
+trait SettlementReportRow: DeserializeOwned {
+    /// Returns `None` for lines which do not refer to a payment, refund or dispute, such as
+    /// connector fees, balance transfers and payouts
+    fn try_into_line(self) -> Result<Option<SettlementReportLine>, String>;
+}
+
+pub fn parse_settlement_report(
+    connector: api_enums::Connector,
+    report: &[u8],
+) -> CustomResult<Vec<SettlementReportLine>, errors::ApiErrorResponse> {
+    match connector {
+        api_enums::Connector::Adyen => parse_rows::<AdyenSettlementDetailRow>(report),
+        api_enums::Connector::Checkout => parse_rows::<CheckoutFinancialActionRow>(report),
+        api_enums::Connector::Stripe => parse_rows::<StripePayoutReconciliationRow>(report),
+        _ => Err(errors::ApiErrorResponse::NotSupported {
+            message: format!("Settlement reports from {connector}"),
+        })
+        .into_report(),
+    }
+}
+
+fn parse_rows<R: SettlementReportRow>(
+    report: &[u8],
+) -> CustomResult<Vec<SettlementReportLine>, errors::ApiErrorResponse> {
+    csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(report)
+        .deserialize::<R>()
+        .enumerate()
+        .filter_map(|(index, row)| {
+            // The first line of the report holds the column headers
+            let line_number = index + 2;
+            row.map_err(|error| error.to_string())
+                .and_then(R::try_into_line)
+                .map_err(|reason| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Invalid settlement report line {line_number}: {reason}"),
+                })
+                .into_report()
+                .transpose()
+        })
+        .collect()
+}
+
+fn parse_currency(currency: &str) -> Result<enums::Currency, String> {
+    enums::Currency::from_str(&currency.to_uppercase())
+        .map_err(|_| format!("unknown currency `{currency}`"))
+}
+
+/// Converts a decimal amount in the base unit of the currency to its lowest denomination,
+/// without going through floating point
+fn to_minor_unit(amount: &str, currency: enums::Currency) -> Result<i64, String> {
+    let exponent = if currency.is_zero_decimal_currency() {
+        0
+    } else if currency.is_three_decimal_currency() {
+        3
+    } else {
+        2
+    };
+    let invalid_amount = || format!("invalid amount `{amount}`");
+
+    let (is_negative, digits) = match amount.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, amount),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    let fraction = fraction.trim_end_matches('0');
+    if whole.is_empty()
+        || fraction.len() > exponent
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid_amount());
+    }
+
+    let value = format!("{whole}{fraction:0<exponent$}")
+        .parse::<i64>()
+        .map_err(|_| invalid_amount())?;
+    Ok(if is_negative { -value } else { value })
+}
+
+/// Treats a missing or empty amount column as zero
+fn to_minor_unit_or_zero(
+    amount: Option<&String>,
+    currency: enums::Currency,
+) -> Result<i64, String> {
+    amount
+        .filter(|amount| !amount.is_empty())
+        .map_or(Ok(0), |amount| to_minor_unit(amount, currency))
+}
+
+/// A line of the Stripe itemized payout reconciliation report
+#[derive(Debug, Deserialize)]
+struct StripePayoutReconciliationRow {
+    automatic_payout_id: Option<String>,
+    reporting_category: String,
+    currency: String,
+    gross: String,
+    fee: String,
+    net: String,
+    source_id: Option<String>,
+    payment_intent_id: Option<String>,
+    charge_id: Option<String>,
+}
+
+impl SettlementReportRow for StripePayoutReconciliationRow {
+    fn try_into_line(self) -> Result<Option<SettlementReportLine>, String> {
+        let transaction_type = match self.reporting_category.as_str() {
+            "charge" => enums::ReconTransactionType::Payment,
+            "refund" => enums::ReconTransactionType::Refund,
+            "dispute" => enums::ReconTransactionType::Dispute,
+            _ => return Ok(None),
+        };
+        // Payments are stored against the payment intent id
+        let connector_payment_id = self.payment_intent_id.or(self.charge_id);
+        let connector_transaction_id = match transaction_type {
+            enums::ReconTransactionType::Payment => connector_payment_id.clone(),
+            enums::ReconTransactionType::Refund | enums::ReconTransactionType::Dispute => {
+                self.source_id
+            }
+        }
+        .ok_or_else(|| "missing transaction reference".to_string())?;
+        let currency = parse_currency(&self.currency)?;
+
+        Ok(Some(SettlementReportLine {
+            settlement_batch_id: self.automatic_payout_id,
+            transaction_type,
+            connector_transaction_id,
+            connector_payment_id,
+            currency,
+            gross_amount: to_minor_unit(&self.gross, currency)?,
+            fee: Some(to_minor_unit(&self.fee, currency)?),
+            net_amount: Some(to_minor_unit(&self.net, currency)?),
+            connector_status: Some(self.reporting_category),
+        }))
+    }
+}
+
+/// A line of the Adyen settlement details report
+#[derive(Debug, Deserialize)]
+struct AdyenSettlementDetailRow {
+    #[serde(rename = "Psp Reference")]
+    psp_reference: String,
+    #[serde(rename = "Modification Reference", default)]
+    modification_reference: Option<String>,
+    #[serde(rename = "Type")]
+    record_type: String,
+    #[serde(rename = "Gross Currency")]
+    gross_currency: Option<String>,
+    #[serde(rename = "Gross Debit (GC)", default)]
+    gross_debit: Option<String>,
+    #[serde(rename = "Gross Credit (GC)", default)]
+    gross_credit: Option<String>,
+    #[serde(rename = "Net Currency", default)]
+    net_currency: Option<String>,
+    #[serde(rename = "Net Debit (NC)", default)]
+    net_debit: Option<String>,
+    #[serde(rename = "Net Credit (NC)", default)]
+    net_credit: Option<String>,
+    #[serde(rename = "Commission (NC)", default)]
+    commission: Option<String>,
+    #[serde(rename = "Markup (NC)", default)]
+    markup: Option<String>,
+    #[serde(rename = "Scheme Fees (NC)", default)]
+    scheme_fees: Option<String>,
+    #[serde(rename = "Interchange (NC)", default)]
+    interchange: Option<String>,
+    #[serde(rename = "Batch Number", default)]
+    batch_number: Option<String>,
+}
+
+impl SettlementReportRow for AdyenSettlementDetailRow {
+    fn try_into_line(self) -> Result<Option<SettlementReportLine>, String> {
+        let transaction_type = match self.record_type.as_str() {
+            "Settled" | "SettledBulk" => enums::ReconTransactionType::Payment,
+            "Refunded" | "RefundedBulk" => enums::ReconTransactionType::Refund,
+            "Chargeback" => enums::ReconTransactionType::Dispute,
+            _ => return Ok(None),
+        };
+        // Refunds are stored against the psp reference of the modification, while payments and
+        // chargebacks carry the psp reference of the payment
+        let connector_transaction_id = match transaction_type {
+            enums::ReconTransactionType::Refund => self
+                .modification_reference
+                .ok_or_else(|| "missing modification reference".to_string())?,
+            enums::ReconTransactionType::Payment | enums::ReconTransactionType::Dispute => {
+                self.psp_reference.clone()
+            }
+        };
+        let currency = parse_currency(
+            self.gross_currency
+                .as_deref()
+                .ok_or_else(|| "missing gross currency".to_string())?,
+        )?;
+        let gross_amount = to_minor_unit_or_zero(self.gross_credit.as_ref(), currency)?
+            - to_minor_unit_or_zero(self.gross_debit.as_ref(), currency)?;
+
+        // Fees and the net amount can only be checked when they are booked in the gross currency
+        let (fee, net_amount) = match self.net_currency.as_deref().map(parse_currency) {
+            Some(Ok(net_currency)) if net_currency == currency => {
+                let fee = [
+                    &self.commission,
+                    &self.markup,
+                    &self.scheme_fees,
+                    &self.interchange,
+                ]
+                .into_iter()
+                .map(|fee| to_minor_unit_or_zero(fee.as_ref(), currency))
+                .sum::<Result<i64, String>>()?;
+                let net_amount = to_minor_unit_or_zero(self.net_credit.as_ref(), currency)?
+                    - to_minor_unit_or_zero(self.net_debit.as_ref(), currency)?;
+                (Some(fee), Some(net_amount))
+            }
+            _ => (None, None),
+        };
+
+        Ok(Some(SettlementReportLine {
+            settlement_batch_id: self.batch_number,
+            transaction_type,
+            connector_transaction_id,
+            connector_payment_id: Some(self.psp_reference),
+            currency,
+            gross_amount,
+            fee,
+            net_amount,
+            connector_status: Some(self.record_type),
+        }))
+    }
+}
+
+/// A line of the Checkout.com financial actions report
+#[derive(Debug, Deserialize)]
+struct CheckoutFinancialActionRow {
+    #[serde(rename = "Payout ID", default)]
+    payout_id: Option<String>,
+    #[serde(rename = "Action Type")]
+    action_type: String,
+    #[serde(rename = "Payment ID")]
+    payment_id: String,
+    #[serde(rename = "Action ID", default)]
+    action_id: Option<String>,
+    #[serde(rename = "Processing Currency")]
+    processing_currency: String,
+    #[serde(rename = "Gross In Processing Currency")]
+    gross: String,
+    #[serde(rename = "Total Fees In Processing Currency", default)]
+    fees: Option<String>,
+    #[serde(rename = "Net In Processing Currency", default)]
+    net: Option<String>,
+}
+
+impl SettlementReportRow for CheckoutFinancialActionRow {
+    fn try_into_line(self) -> Result<Option<SettlementReportLine>, String> {
+        let transaction_type = match self.action_type.as_str() {
+            "Capture" => enums::ReconTransactionType::Payment,
+            "Refund" => enums::ReconTransactionType::Refund,
+            "Chargeback" => enums::ReconTransactionType::Dispute,
+            _ => return Ok(None),
+        };
+        let connector_transaction_id = match transaction_type {
+            enums::ReconTransactionType::Refund => self
+                .action_id
+                .ok_or_else(|| "missing action id".to_string())?,
+            enums::ReconTransactionType::Payment | enums::ReconTransactionType::Dispute => {
+                self.payment_id.clone()
+            }
+        };
+        let currency = parse_currency(&self.processing_currency)?;
+        let fee = self
+            .fees
+            .as_ref()
+            .map(|fees| to_minor_unit_or_zero(Some(fees), currency))
+            .transpose()?;
+        let net_amount = self
+            .net
+            .as_ref()
+            .map(|net| to_minor_unit_or_zero(Some(net), currency))
+            .transpose()?;
+
+        Ok(Some(SettlementReportLine {
+            settlement_batch_id: self.payout_id,
+            transaction_type,
+            connector_transaction_id,
+            connector_payment_id: Some(self.payment_id),
+            currency,
+            gross_amount: to_minor_unit(&self.gross, currency)?,
+            fee,
+            net_amount,
+            connector_status: Some(self.action_type),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_to_minor_unit() {
+        assert_eq!(to_minor_unit("10.5", enums::Currency::USD), Ok(1050));
+        assert_eq!(to_minor_unit("-0.07", enums::Currency::EUR), Ok(-7));
+        assert_eq!(to_minor_unit("1200", enums::Currency::JPY), Ok(1200));
+        assert_eq!(to_minor_unit("1.234", enums::Currency::KWD), Ok(1234));
+        assert_eq!(to_minor_unit("10.500", enums::Currency::USD), Ok(1050));
+        assert!(to_minor_unit("10.555", enums::Currency::USD).is_err());
+        assert!(to_minor_unit("1,000.00", enums::Currency::USD).is_err());
+    }
+
+    #[test]
+    fn test_parse_stripe_report() {
+        let report = "automatic_payout_id,balance_transaction_id,reporting_category,currency,gross,fee,net,source_id,payment_intent_id,charge_id\n\
+            po_1,txn_1,charge,usd,100.00,3.20,96.80,ch_1,pi_1,ch_1\n\
+            po_1,txn_2,refund,usd,-20.00,0.00,-20.00,re_1,pi_1,ch_1\n\
+            po_1,txn_3,payout,usd,-76.80,0.00,-76.80,po_1,,\n";
+
+        let lines =
+            parse_settlement_report(api_enums::Connector::Stripe, report.as_bytes()).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0].transaction_type,
+            enums::ReconTransactionType::Payment
+        );
+        assert_eq!(lines[0].connector_transaction_id, "pi_1");
+        assert_eq!(lines[0].gross_amount, 10000);
+        assert_eq!(lines[0].fee, Some(320));
+        assert_eq!(lines[0].net_amount, Some(9680));
+        assert_eq!(
+            lines[1].transaction_type,
+            enums::ReconTransactionType::Refund
+        );
+        assert_eq!(lines[1].connector_transaction_id, "re_1");
+        assert_eq!(lines[1].connector_payment_id.as_deref(), Some("pi_1"));
+        assert_eq!(lines[1].settlement_batch_id.as_deref(), Some("po_1"));
+    }
+
+    #[test]
+    fn test_parse_adyen_report() {
+        let report = "Psp Reference,Modification Reference,Type,Gross Currency,Gross Debit (GC),Gross Credit (GC),Net Currency,Net Debit (NC),Net Credit (NC),Commission (NC),Markup (NC),Scheme Fees (NC),Interchange (NC),Batch Number\n\
+            8816,8816,Settled,EUR,,10.00,EUR,,9.70,0.10,0.05,0.05,0.10,42\n\
+            8816,8817,Refunded,EUR,5.00,,EUR,5.00,,,,,,42\n\
+            ,,Fee,EUR,,,EUR,1.00,,,,,,42\n";
+
+        let lines =
+            parse_settlement_report(api_enums::Connector::Adyen, report.as_bytes()).unwrap();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].gross_amount, 1000);
+        assert_eq!(lines[0].fee, Some(30));
+        assert_eq!(lines[0].net_amount, Some(970));
+        assert_eq!(lines[1].connector_transaction_id, "8817");
+        assert_eq!(lines[1].gross_amount, -500);
+    }
+
+    #[test]
+    fn test_parse_report_with_invalid_line() {
+        let report = "Payout ID,Action Type,Payment ID,Action ID,Processing Currency,Gross In Processing Currency\n\
+            pyt_1,Capture,pay_1,act_1,XYZ,10.00\n";
+
+        let error =
+            parse_settlement_report(api_enums::Connector::Checkout, report.as_bytes()).unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { message }
+                if message == "Invalid settlement report line 2: unknown currency `XYZ`"
+        ));
+    }
+}
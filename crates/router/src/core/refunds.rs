@@ -1,3 +1,5 @@
+#[cfg(feature = "payouts")]
+pub mod payout_fallback;
 pub mod validator;
 
 use common_utils::ext_traits::AsyncExt;
@@ -221,12 +223,18 @@ pub async fn trigger_refund_to_gateway(
                     )],
                 )
             }
+            let fee_details = router_data_res.connector_fee_details.unwrap_or_default();
             storage::RefundUpdate::Update {
                 connector_refund_id: response.connector_refund_id,
                 refund_status: response.refund_status,
                 sent_to_gateway: true,
                 refund_error_message: None,
                 refund_arn: "".to_string(),
+                processing_fee: fee_details.processing_fee,
+                interchange_fee: fee_details.interchange_fee,
+                fx_markup: fee_details.fx_markup,
+                net_amount: fee_details.net_amount,
+                settlement_currency: fee_details.settlement_currency,
                 updated_by: storage_scheme.to_string(),
             }
         }
@@ -247,6 +255,21 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+
+    #[cfg(feature = "payouts")]
+    let response = if response.refund_status == enums::RefundStatus::Failure {
+        payout_fallback::trigger_refund_via_payout(
+            state,
+            merchant_account,
+            key_store,
+            payment_intent,
+            response,
+        )
+        .await?
+    } else {
+        response
+    };
+
     Ok(response)
 }
 
@@ -352,7 +375,8 @@ pub async fn refund_retrieve_core(
 fn should_call_refund(refund: &diesel_models::refund::Refund, force_sync: bool) -> bool {
     // This implies, we cannot perform a refund sync & `the connector_refund_id`
     // doesn't exist
-    let predicate1 = refund.connector_refund_id.is_some();
+    let predicate1 = refund.connector_refund_id.is_some()
+        || refund.refund_method == Some(enums::RefundMethod::Payout);
 
     // This allows refund sync at connector level if force_sync is enabled, or
     // checks if the refund has failed
@@ -376,6 +400,11 @@ pub async fn sync_refund_with_gateway(
     refund: &storage::Refund,
     creds_identifier: Option<String>,
 ) -> RouterResult<storage::Refund> {
+    #[cfg(feature = "payouts")]
+    if refund.refund_method == Some(enums::RefundMethod::Payout) {
+        return payout_fallback::sync_refund_with_payout(state, merchant_account, refund).await;
+    }
+
     let connector_id = refund.connector.to_string();
     let connector: api::ConnectorData = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
@@ -443,14 +472,22 @@ pub async fn sync_refund_with_gateway(
             refund_error_code: Some(error_message.code),
             updated_by: storage_scheme.to_string(),
         },
-        Ok(response) => storage::RefundUpdate::Update {
-            connector_refund_id: response.connector_refund_id,
-            refund_status: response.refund_status,
-            sent_to_gateway: true,
-            refund_error_message: None,
-            refund_arn: "".to_string(),
-            updated_by: storage_scheme.to_string(),
-        },
+        Ok(response) => {
+            let fee_details = router_data_res.connector_fee_details.unwrap_or_default();
+            storage::RefundUpdate::Update {
+                connector_refund_id: response.connector_refund_id,
+                refund_status: response.refund_status,
+                sent_to_gateway: true,
+                refund_error_message: None,
+                refund_arn: "".to_string(),
+                processing_fee: fee_details.processing_fee,
+                interchange_fee: fee_details.interchange_fee,
+                fx_markup: fee_details.fx_markup,
+                net_amount: fee_details.net_amount,
+                settlement_currency: fee_details.settlement_currency,
+                updated_by: storage_scheme.to_string(),
+            }
+        }
     };
 
     let response = state
@@ -468,6 +505,24 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+
+    // A refund which was accepted by the connector can still fail later on
+    #[cfg(feature = "payouts")]
+    let response = if response.refund_status == enums::RefundStatus::Failure
+        && refund.refund_status != enums::RefundStatus::Failure
+    {
+        payout_fallback::trigger_refund_via_payout(
+            state,
+            merchant_account,
+            key_store,
+            payment_intent,
+            response,
+        )
+        .await?
+    } else {
+        response
+    };
+
     Ok(response)
 }
 
@@ -716,6 +771,8 @@ pub async fn refund_filter_list(
 impl ForeignFrom<storage::Refund> for api::RefundResponse {
     fn foreign_from(refund: storage::Refund) -> Self {
         let refund = refund;
+        let fee_details =
+            Option::<api_models::payments::ConnectorFeeDetails>::foreign_from(&refund);
         Self {
             payment_id: refund.payment_id,
             refund_id: refund.refund_id,
@@ -730,6 +787,9 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             created_at: Some(refund.created_at),
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
+            fee_details,
+            refund_method: refund.refund_method.unwrap_or_default(),
+            payout_id: refund.payout_id,
         }
     }
 }
@@ -0,0 +1,431 @@
+use api_models::refunds::RefundPayoutFallbackConfig;
+use common_utils::ext_traits::StringExt;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        payments::helpers as payment_helpers,
+        payouts,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// Pays out a refund which failed at the connector to a saved payment method of the customer,
+/// when the merchant has configured the fallback for the error returned by the connector.
+/// The refund is returned unchanged if the fallback is not applicable or could not be initiated.
+#[instrument(skip_all)]
+pub async fn trigger_refund_via_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let fallback_config =
+        get_refund_payout_fallback_config(&*state.store, &merchant_account.merchant_id).await;
+    let is_eligible = fallback_config
+        .as_ref()
+        .map(|config| is_eligible_for_payout_fallback(config, &refund))
+        .unwrap_or(false);
+    if !is_eligible {
+        return Ok(refund);
+    }
+
+    let payout_data = match create_payout_for_refund(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        &refund,
+    )
+    .await
+    {
+        Ok(Some(payout_data)) => payout_data,
+        Ok(None) => {
+            logger::info!(
+                refund_id = %refund.refund_id,
+                "no saved payout method found for refund payout fallback"
+            );
+            return Ok(refund);
+        }
+        Err(error) => {
+            logger::error!(?error, refund_id = %refund.refund_id, "refund payout fallback failed");
+            return Ok(refund);
+        }
+    };
+
+    let refund_update = storage::RefundUpdate::RefundMethodUpdate {
+        refund_method: enums::RefundMethod::Payout,
+        payout_id: Some(payout_data.payouts.payout_id),
+        refund_status: get_refund_status_from_payout_status(payout_data.payout_attempt.status),
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    state
+        .store
+        .update_refund(
+            refund.to_owned(),
+            refund_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while linking payout to refund: refund_id: {}",
+                refund.refund_id
+            )
+        })
+}
+
+/// Updates the status of a refund which is paid out, from the status of the linked payout
+#[instrument(skip_all)]
+pub async fn sync_refund_with_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    refund: &storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let payout_id = refund
+        .payout_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing payout_id in a refund paid out through a payout")?;
+
+    let payout_attempt = state
+        .store
+        .find_payout_attempt_by_merchant_id_payout_id(&merchant_account.merchant_id, payout_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
+
+    let refund_status = get_refund_status_from_payout_status(payout_attempt.status);
+    if refund_status == refund.refund_status {
+        return Ok(refund.to_owned());
+    }
+
+    let refund_update = storage::RefundUpdate::RefundMethodUpdate {
+        refund_method: enums::RefundMethod::Payout,
+        payout_id: Some(payout_id.to_owned()),
+        refund_status,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    state
+        .store
+        .update_refund(
+            refund.to_owned(),
+            refund_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+        .attach_printable_lazy(|| {
+            format!(
+                "Unable to update refund with refund_id: {}",
+                refund.refund_id
+            )
+        })
+}
+
+async fn get_refund_payout_fallback_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> Option<RefundPayoutFallbackConfig> {
+    let config = db
+        .find_config_by_key(&format!("refund_payout_fallback_{merchant_id}"))
+        .await
+        .ok()?;
+    config
+        .config
+        .parse_struct("RefundPayoutFallbackConfig")
+        .map_err(|error| logger::warn!(?error, "error while parsing refund payout fallback config"))
+        .ok()
+}
+
+fn is_eligible_for_payout_fallback(
+    config: &RefundPayoutFallbackConfig,
+    refund: &storage::Refund,
+) -> bool {
+    let is_failed_at_connector = refund.refund_status == enums::RefundStatus::Failure
+        && refund.refund_method != Some(enums::RefundMethod::Payout);
+    let is_permanent_failure = refund
+        .refund_error_code
+        .as_ref()
+        .map(|error_code| config.error_codes.contains(error_code))
+        .unwrap_or(false);
+
+    is_failed_at_connector && is_permanent_failure
+}
+
+fn get_refund_status_from_payout_status(payout_status: enums::PayoutStatus) -> enums::RefundStatus {
+    match payout_status {
+        enums::PayoutStatus::Success => enums::RefundStatus::Success,
+        enums::PayoutStatus::Failed
+        | enums::PayoutStatus::Cancelled
+        | enums::PayoutStatus::Ineligible
+        | enums::PayoutStatus::Expired
+        | enums::PayoutStatus::Reversed => enums::RefundStatus::Failure,
+        enums::PayoutStatus::Pending
+        | enums::PayoutStatus::RequiresCreation
+        | enums::PayoutStatus::RequiresPayoutMethodData
+        | enums::PayoutStatus::RequiresFulfillment => enums::RefundStatus::Pending,
+    }
+}
+
+async fn create_payout_for_refund(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: &storage::Refund,
+) -> RouterResult<Option<payouts::PayoutData>> {
+    let customer_id = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => customer_id,
+        None => return Ok(None),
+    };
+    let payout_method_data = match get_saved_payout_method_data(
+        state,
+        merchant_account,
+        key_store,
+        customer_id,
+    )
+    .await?
+    {
+        Some(payout_method_data) => payout_method_data,
+        None => return Ok(None),
+    };
+
+    let billing = payment_helpers::create_or_find_address_for_payment_by_request(
+        &*state.store,
+        None,
+        payment_intent.billing_address_id.as_deref(),
+        &merchant_account.merchant_id,
+        Some(customer_id),
+        key_store,
+        &payment_intent.payment_id,
+        merchant_account.storage_scheme,
+    )
+    .await?
+    .as_ref()
+    .map(api::Address::from);
+
+    let req = api::PayoutCreateRequest {
+        amount: Some(refund.refund_amount.into()),
+        currency: Some(refund.currency),
+        confirm: Some(true),
+        payout_type: Some(payout_method_data.get_payout_type()),
+        payout_method_data: Some(payout_method_data),
+        billing,
+        customer_id: Some(customer_id.to_owned()),
+        auto_fulfill: Some(true),
+        business_country: payment_intent.business_country,
+        business_label: payment_intent.business_label.to_owned(),
+        description: Some(format!("Payout for refund {}", refund.refund_id)),
+        profile_id: refund.profile_id.to_owned(),
+        ..Default::default()
+    };
+
+    let connector_data = payouts::get_connector_data(state, merchant_account, None, None).await?;
+    let (payout_id, _) =
+        payouts::validator::validate_create_request(state, merchant_account, &req).await?;
+    let mut payout_data = payouts::payout_create_db_entries(
+        state,
+        merchant_account,
+        key_store,
+        &req,
+        &payout_id,
+        &connector_data.connector_name,
+        None,
+    )
+    .await?;
+
+    // The refund is linked to the payout even if the connector call fails, so that the failure
+    // of the payout is reflected in the status of the refund
+    payouts::call_connector_payout(
+        state,
+        merchant_account,
+        key_store,
+        &req,
+        connector_data,
+        &mut payout_data,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, %payout_id, "payout for refund failed"))
+    .ok();
+
+    Ok(Some(payout_data))
+}
+
+/// Fetches the most recently saved card or bank account of the customer from the locker, in the
+/// format required for making a payout to it
+async fn get_saved_payout_method_data(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+) -> RouterResult<Option<api::PayoutMethodData>> {
+    let mut payment_methods = state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_list(
+            customer_id,
+            &merchant_account.merchant_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch saved payment methods of the customer")?;
+    payment_methods.sort_by(|pm1, pm2| pm2.created_at.cmp(&pm1.created_at));
+
+    for payment_method in payment_methods {
+        match payment_method.payment_method {
+            enums::PaymentMethod::Card => {
+                let card = cards::get_card_from_locker(
+                    state,
+                    customer_id,
+                    &merchant_account.merchant_id,
+                    &payment_method.payment_method_id,
+                )
+                .await?;
+                // Card payouts cannot be made without the name of the card holder
+                if let Some(card_holder_name) = card.name_on_card {
+                    return Ok(Some(api::PayoutMethodData::Card(api::CardPayout {
+                        card_number: card.card_number,
+                        expiry_month: card.card_exp_month,
+                        expiry_year: card.card_exp_year,
+                        card_holder_name,
+                    })));
+                }
+            }
+            enums::PaymentMethod::BankTransfer => {
+                let payout_method = cards::get_payment_method_from_hs_locker(
+                    state,
+                    key_store,
+                    customer_id,
+                    &merchant_account.merchant_id,
+                    &payment_method.payment_method_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error getting payment method from locker")?;
+                let payout_method_data: api::PayoutMethodData = payout_method
+                    .peek()
+                    .to_string()
+                    .parse_struct("PayoutMethodData")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                if matches!(
+                    payout_method_data,
+                    api::PayoutMethodData::Bank(_) | api::PayoutMethodData::InstantTransfer(_)
+                ) {
+                    return Ok(Some(payout_method_data));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_failed_refund(refund_error_code: Option<&str>) -> storage::Refund {
+        storage::Refund {
+            id: 1,
+            internal_reference_id: "refid_123".to_string(),
+            refund_id: "ref_123".to_string(),
+            payment_id: "pay_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            connector_transaction_id: "txn_123".to_string(),
+            connector: "stripe".to_string(),
+            connector_refund_id: None,
+            external_reference_id: None,
+            refund_type: enums::RefundType::RegularRefund,
+            total_amount: 1000,
+            currency: enums::Currency::USD,
+            refund_amount: 1000,
+            refund_status: enums::RefundStatus::Failure,
+            sent_to_gateway: true,
+            refund_error_message: None,
+            metadata: None,
+            refund_arn: None,
+            created_at: common_utils::date_time::now(),
+            updated_at: common_utils::date_time::now(),
+            description: None,
+            attempt_id: "pay_123_1".to_string(),
+            refund_reason: None,
+            refund_error_code: refund_error_code.map(ToString::to_string),
+            profile_id: None,
+            updated_by: "postgres_only".to_string(),
+            merchant_connector_id: None,
+            processing_fee: None,
+            interchange_fee: None,
+            fx_markup: None,
+            net_amount: None,
+            settlement_currency: None,
+            refund_method: None,
+            payout_id: None,
+        }
+    }
+
+    #[test]
+    fn test_is_eligible_for_payout_fallback() {
+        let config = RefundPayoutFallbackConfig {
+            error_codes: vec!["account_closed".to_string()],
+        };
+        assert!(is_eligible_for_payout_fallback(
+            &config,
+            &get_failed_refund(Some("account_closed"))
+        ));
+        assert!(!is_eligible_for_payout_fallback(
+            &config,
+            &get_failed_refund(Some("rate_limit"))
+        ));
+        assert!(!is_eligible_for_payout_fallback(
+            &config,
+            &get_failed_refund(None)
+        ));
+
+        let mut paid_out_refund = get_failed_refund(Some("account_closed"));
+        paid_out_refund.refund_method = Some(enums::RefundMethod::Payout);
+        assert!(!is_eligible_for_payout_fallback(&config, &paid_out_refund));
+
+        let mut pending_refund = get_failed_refund(Some("account_closed"));
+        pending_refund.refund_status = enums::RefundStatus::Pending;
+        assert!(!is_eligible_for_payout_fallback(&config, &pending_refund));
+
+        let config = RefundPayoutFallbackConfig::default();
+        assert!(!is_eligible_for_payout_fallback(
+            &config,
+            &get_failed_refund(Some("rate_limit"))
+        ));
+
+        assert!(!is_eligible_for_payout_fallback(
+            &config,
+            &get_failed_refund(None)
+        ));
+    }
+
+    #[test]
+    fn test_get_refund_status_from_payout_status() {
+        assert_eq!(
+            get_refund_status_from_payout_status(enums::PayoutStatus::Success),
+            enums::RefundStatus::Success
+        );
+        assert_eq!(
+            get_refund_status_from_payout_status(enums::PayoutStatus::Reversed),
+            enums::RefundStatus::Failure
+        );
+        assert_eq!(
+            get_refund_status_from_payout_status(enums::PayoutStatus::RequiresFulfillment),
+            enums::RefundStatus::Pending
+        );
+    }
+}
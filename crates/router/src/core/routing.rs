@@ -8,26 +8,29 @@ use api_models::routing::{RoutingRetrieveLinkQuery, RoutingRetrieveQuery};
 use common_utils::ext_traits::{Encode, StringExt};
 #[cfg(not(feature = "business_profile_routing"))]
 use diesel_models::configs;
+use diesel_models::enums as storage_enums;
 #[cfg(feature = "business_profile_routing")]
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::{IntoReport, ResultExt};
+use router_env::logger;
 use rustc_hash::FxHashSet;
 
 #[cfg(feature = "business_profile_routing")]
 use crate::core::utils::validate_and_get_business_profile;
 #[cfg(feature = "business_profile_routing")]
-use crate::types::transformers::{ForeignInto, ForeignTryInto};
+use crate::types::transformers::ForeignTryInto;
 use crate::{
     consts,
-    core::errors::{RouterResponse, StorageErrorExt},
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
     routes::AppState,
-    types::domain,
+    services::api as service_api,
+    types::{domain, storage, transformers::ForeignInto},
     utils::{self, OptionExt, ValueExt},
 };
-#[cfg(not(feature = "business_profile_routing"))]
-use crate::{core::errors, services::api as service_api, types::storage};
-#[cfg(feature = "business_profile_routing")]
-use crate::{errors, services::api as service_api};
+
+/// Scheduler runner that activates routing algorithms at a later time
+pub const ROUTING_ACTIVATION_WORKFLOW: &str = "ROUTING_ACTIVATION_WORKFLOW";
 
 pub async fn retrieve_merchant_routing_dictionary(
     state: AppState,
@@ -71,7 +74,7 @@ pub async fn create_routing_config(
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     request: routing_types::RoutingConfigRequest,
-) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+) -> RouterResponse<routing_types::RoutingConfigCreateResponse> {
     let db = state.store.as_ref();
 
     let name = request
@@ -96,6 +99,8 @@ pub async fn create_routing_config(
         })
         .attach_printable("Algorithm of config not given")?;
 
+    let analysis_warnings = helpers::get_routing_algorithm_warnings(&algorithm);
+
     let algorithm_id = common_utils::generate_id(
         consts::ROUTING_CONFIG_ID_LENGTH,
         &format!("routing_{}", &merchant_account.merchant_id),
@@ -134,15 +139,59 @@ pub async fn create_routing_config(
             algorithm_data: serde_json::json!(algorithm),
             created_at: timestamp,
             modified_at: timestamp,
+            version: 1,
+            created_by: request.created_by,
+            comment: request.comment,
+        };
+
+        // Concurrent requests can pick the same version, the unique constraint on the profile,
+        // name and version lets only one of them through and the others retry with the next
+        // version
+        let mut retries_left = consts::ROUTING_ALGORITHM_VERSION_CONFLICT_RETRIES;
+        let record = loop {
+            let version = db
+                .list_routing_algorithm_metadata_by_profile_id_name(&algo.profile_id, &name)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the versions of the routing algorithm")?
+                .iter()
+                .map(|metadata| metadata.version)
+                .max()
+                .map_or(1, |latest_version| latest_version + 1);
+
+            match db
+                .insert_routing_algorithm(RoutingAlgorithm {
+                    version,
+                    ..algo.clone()
+                })
+                .await
+            {
+                Ok(record) => break record,
+                Err(error)
+                    if error.current_context().is_db_unique_violation() && retries_left > 0 =>
+                {
+                    logger::warn!(
+                        ?error,
+                        version,
+                        "Routing algorithm version already exists, retrying"
+                    );
+                    retries_left -= 1;
+                }
+                Err(error) => {
+                    return Err(error)
+                        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+                }
+            }
         };
-        let record = db
-            .insert_routing_algorithm(algo)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
         let new_record = record.foreign_into();
 
-        Ok(service_api::ApplicationResponse::Json(new_record))
+        Ok(service_api::ApplicationResponse::Json(
+            routing_types::RoutingConfigCreateResponse {
+                record: new_record,
+                analysis_warnings,
+            },
+        ))
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -160,31 +209,6 @@ pub async fn create_routing_config(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
             .unwrap_or_default();
-        let mut merchant_dictionary =
-            helpers::get_merchant_routing_dictionary(db, &merchant_account.merchant_id).await?;
-
-        utils::when(
-            merchant_dictionary.records.len() >= consts::MAX_ROUTING_CONFIGS_PER_MERCHANT,
-            || {
-                Err(errors::ApiErrorResponse::PreconditionFailed {
-            message: format!("Reached the maximum number of routing configs ({}), please delete some to create new ones", consts::MAX_ROUTING_CONFIGS_PER_MERCHANT),
-        })
-        .into_report()
-            },
-        )?;
-        let timestamp = common_utils::date_time::now_unix_timestamp();
-        let records_are_empty = merchant_dictionary.records.is_empty();
-
-        let new_record = routing_types::RoutingDictionaryRecord {
-            id: algorithm_id.clone(),
-            name: name.clone(),
-            kind: algorithm.get_kind(),
-            description: description.clone(),
-            created_at: timestamp,
-            modified_at: timestamp,
-        };
-        merchant_dictionary.records.push(new_record.clone());
-
         let new_algorithm_config = configs::ConfigNew {
             key: algorithm_id.clone(),
             config: algorithm_str,
@@ -195,20 +219,93 @@ pub async fn create_routing_config(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to save new routing algorithm config to DB")?;
 
+        // The dictionary is only saved if no other request changed it in the meantime, which
+        // keeps the version of the algorithm unique for its name
+        let mut retries_left = consts::ROUTING_ALGORITHM_VERSION_CONFLICT_RETRIES;
+        let (new_record, records_are_empty) = loop {
+            let (mut merchant_dictionary, current_dictionary) =
+                helpers::get_merchant_routing_dictionary_for_update(
+                    db,
+                    &merchant_account.merchant_id,
+                )
+                .await?;
+
+            utils::when(
+                merchant_dictionary.records.len() >= consts::MAX_ROUTING_CONFIGS_PER_MERCHANT,
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Reached the maximum number of routing configs ({}), please delete some to create new ones", consts::MAX_ROUTING_CONFIGS_PER_MERCHANT),
+            })
+            .into_report()
+                },
+            )?;
+            let timestamp = common_utils::date_time::now_unix_timestamp();
+            let records_are_empty = merchant_dictionary.records.is_empty();
+            let version = merchant_dictionary
+                .records
+                .iter()
+                .filter(|record| record.name == name)
+                .map(|record| record.version)
+                .max()
+                .map_or(1, |latest_version| latest_version + 1);
+
+            let new_record = routing_types::RoutingDictionaryRecord {
+                id: algorithm_id.clone(),
+                name: name.clone(),
+                kind: algorithm.get_kind(),
+                description: description.clone(),
+                created_at: timestamp,
+                modified_at: timestamp,
+                version,
+                created_by: request.created_by.clone(),
+                comment: request.comment.clone(),
+            };
+            merchant_dictionary.records.push(new_record.clone());
+            if records_are_empty {
+                merchant_dictionary.active_id = Some(algorithm_id.clone());
+            }
+
+            if helpers::update_merchant_routing_dictionary_if_unchanged(
+                db,
+                &merchant_account.merchant_id,
+                &current_dictionary,
+                merchant_dictionary,
+            )
+            .await?
+            {
+                break (new_record, records_are_empty);
+            }
+
+            utils::when(retries_left == 0, || {
+                Err(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Routing dictionary kept changing while saving a new version")
+            })?;
+            logger::warn!(version, "Routing dictionary changed while saving, retrying");
+            retries_left -= 1;
+        };
+
         if records_are_empty {
-            merchant_dictionary.active_id = Some(algorithm_id.clone());
-            algorithm_ref.update_algorithm_id(algorithm_id);
+            algorithm_ref.update_algorithm_id(algorithm_id.clone());
             helpers::update_merchant_active_algorithm_ref(db, &key_store, algorithm_ref).await?;
+            helpers::insert_routing_activation_audit(
+                db,
+                &merchant_account.merchant_id,
+                None,
+                Some(algorithm_id),
+                None,
+                storage_enums::RoutingActivationAction::Activate,
+                new_record.created_by.clone(),
+            )
+            .await?;
         }
 
-        helpers::update_merchant_routing_dictionary(
-            db,
-            &merchant_account.merchant_id,
-            merchant_dictionary,
-        )
-        .await?;
-
-        Ok(service_api::ApplicationResponse::Json(new_record))
+        Ok(service_api::ApplicationResponse::Json(
+            routing_types::RoutingConfigCreateResponse {
+                record: new_record,
+                analysis_warnings,
+            },
+        ))
     }
 }
 
@@ -218,7 +315,29 @@ pub async fn link_routing_config(
     #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
     algorithm_id: String,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
-    let db = state.store.as_ref();
+    activate_routing_algorithm(
+        state.store.as_ref(),
+        &merchant_account,
+        #[cfg(not(feature = "business_profile_routing"))]
+        &key_store,
+        algorithm_id,
+        storage_enums::RoutingActivationAction::Activate,
+        None,
+    )
+    .await
+    .map(service_api::ApplicationResponse::Json)
+}
+
+/// Makes the given algorithm the active routing algorithm, and records the change in the
+/// activation audit trail
+pub async fn activate_routing_algorithm(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: &domain::MerchantKeyStore,
+    algorithm_id: String,
+    action: storage_enums::RoutingActivationAction,
+    created_by: Option<String>,
+) -> RouterResult<routing_types::RoutingDictionaryRecord> {
     #[cfg(feature = "business_profile_routing")]
     {
         let routing_algorithm = db
@@ -259,13 +378,23 @@ pub async fn link_routing_config(
             },
         )?;
 
-        routing_ref.update_algorithm_id(algorithm_id);
+        let previous_algorithm_id = routing_ref.algorithm_id.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_business_profile_active_algorithm_ref(db, business_profile, routing_ref)
             .await?;
 
-        Ok(service_api::ApplicationResponse::Json(
-            routing_algorithm.foreign_into(),
-        ))
+        helpers::insert_routing_activation_audit(
+            db,
+            &merchant_account.merchant_id,
+            Some(routing_algorithm.profile_id.clone()),
+            Some(algorithm_id),
+            previous_algorithm_id,
+            action,
+            created_by,
+        )
+        .await?;
+
+        Ok(routing_algorithm.foreign_into())
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -303,16 +432,28 @@ pub async fn link_routing_config(
         record.modified_at = modified_at;
         merchant_dictionary.active_id = Some(record.id.clone());
         let response = record.clone();
-        routing_ref.update_algorithm_id(algorithm_id);
+        let previous_algorithm_id = routing_ref.algorithm_id.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_merchant_routing_dictionary(
             db,
             &merchant_account.merchant_id,
             merchant_dictionary,
         )
         .await?;
-        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
+        helpers::update_merchant_active_algorithm_ref(db, key_store, routing_ref).await?;
 
-        Ok(service_api::ApplicationResponse::Json(response))
+        helpers::insert_routing_activation_audit(
+            db,
+            &merchant_account.merchant_id,
+            None,
+            Some(algorithm_id),
+            previous_algorithm_id,
+            action,
+            created_by,
+        )
+        .await?;
+
+        Ok(response)
     }
 }
 
@@ -321,12 +462,21 @@ pub async fn retrieve_routing_config(
     merchant_account: domain::MerchantAccount,
     algorithm_id: String,
 ) -> RouterResponse<routing_types::MerchantRoutingAlgorithm> {
-    let db = state.store.as_ref();
+    get_merchant_routing_algorithm(state.store.as_ref(), &merchant_account, &algorithm_id)
+        .await
+        .map(service_api::ApplicationResponse::Json)
+}
+
+async fn get_merchant_routing_algorithm(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    algorithm_id: &str,
+) -> RouterResult<routing_types::MerchantRoutingAlgorithm> {
     #[cfg(feature = "business_profile_routing")]
     {
         let routing_algorithm = db
             .find_routing_algorithm_by_algorithm_id_merchant_id(
-                &algorithm_id,
+                algorithm_id,
                 &merchant_account.merchant_id,
             )
             .await
@@ -341,11 +491,10 @@ pub async fn retrieve_routing_config(
         .get_required_value("BusinessProfile")
         .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-        let response = routing_algorithm
+        routing_algorithm
             .foreign_try_into()
             .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("unable to parse routing algorithm")?;
-        Ok(service_api::ApplicationResponse::Json(response))
+            .attach_printable("unable to parse routing algorithm")
     }
 
     #[cfg(not(feature = "business_profile_routing"))]
@@ -362,7 +511,7 @@ pub async fn retrieve_routing_config(
             .attach_printable("Algorithm with the given ID not found in the merchant dictionary")?;
 
         let algorithm_config = db
-            .find_config_by_key(&algorithm_id)
+            .find_config_by_key(algorithm_id)
             .await
             .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
             .attach_printable("Routing config not found in DB")?;
@@ -373,18 +522,270 @@ pub async fn retrieve_routing_config(
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error deserializing routing algorithm config")?;
 
-        let response = routing_types::MerchantRoutingAlgorithm {
+        Ok(routing_types::MerchantRoutingAlgorithm {
             id: record.id,
             name: record.name,
             description: record.description,
             algorithm,
             created_at: record.created_at,
             modified_at: record.modified_at,
-        };
+            version: record.version,
+            created_by: record.created_by,
+            comment: record.comment,
+        })
+    }
+}
 
-        Ok(service_api::ApplicationResponse::Json(response))
+pub async fn diff_routing_configs(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    algorithm_id: String,
+    other_algorithm_id: String,
+) -> RouterResponse<routing_types::RoutingAlgorithmDiffResponse> {
+    let db = state.store.as_ref();
+    let from = get_merchant_routing_algorithm(db, &merchant_account, &algorithm_id).await?;
+    let to = get_merchant_routing_algorithm(db, &merchant_account, &other_algorithm_id).await?;
+
+    let changes = helpers::get_routing_algorithm_changes(&from.algorithm, &to.algorithm)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingAlgorithmDiffResponse {
+            from: from.foreign_into(),
+            to: to.foreign_into(),
+            changes,
+        },
+    ))
+}
+
+/// Lists every version of the algorithm with the same name as the given algorithm, latest
+/// version first
+pub async fn list_routing_config_versions(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    algorithm_id: String,
+) -> RouterResponse<Vec<routing_types::RoutingDictionaryRecord>> {
+    let db = state.store.as_ref();
+    #[cfg(feature = "business_profile_routing")]
+    {
+        let routing_algorithm = db
+            .find_routing_algorithm_by_algorithm_id_merchant_id(
+                &algorithm_id,
+                &merchant_account.merchant_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+        let versions = db
+            .list_routing_algorithm_metadata_by_profile_id_name(
+                &routing_algorithm.profile_id,
+                &routing_algorithm.name,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the versions of the routing algorithm")?
+            .into_iter()
+            .map(|metadata| routing_types::RoutingDictionaryRecord {
+                id: metadata.algorithm_id,
+                profile_id: routing_algorithm.profile_id.clone(),
+                name: metadata.name,
+                kind: metadata.kind.foreign_into(),
+                description: metadata.description.unwrap_or_default(),
+                created_at: metadata.created_at.assume_utc().unix_timestamp(),
+                modified_at: metadata.modified_at.assume_utc().unix_timestamp(),
+                version: metadata.version,
+                created_by: metadata.created_by,
+                comment: metadata.comment,
+            })
+            .collect();
+
+        Ok(service_api::ApplicationResponse::Json(versions))
+    }
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        let merchant_dictionary =
+            helpers::get_merchant_routing_dictionary(db, &merchant_account.merchant_id).await?;
+
+        let name = merchant_dictionary
+            .records
+            .iter()
+            .find(|rec| rec.id == algorithm_id)
+            .map(|rec| rec.name.clone())
+            .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+            .into_report()
+            .attach_printable("Algorithm with the given ID not found in the merchant dictionary")?;
+
+        let mut versions: Vec<_> = merchant_dictionary
+            .records
+            .into_iter()
+            .filter(|rec| rec.name == name)
+            .collect();
+        versions.sort_by(|first, second| second.version.cmp(&first.version));
+
+        Ok(service_api::ApplicationResponse::Json(versions))
     }
 }
+
+/// Activates the routing algorithm that was active before the latest activation change
+pub async fn rollback_routing_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingRollbackRequest,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let db = state.store.as_ref();
+
+    #[cfg(feature = "business_profile_routing")]
+    let profile_id = Some(
+        request
+            .profile_id
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })
+            .attach_printable("Profile_id not provided")?,
+    );
+    #[cfg(not(feature = "business_profile_routing"))]
+    let profile_id: Option<String> = None;
+
+    let latest_change = db
+        .list_routing_activation_audit_by_merchant_id(
+            &merchant_account.merchant_id,
+            profile_id.as_deref(),
+            consts::ROUTING_ACTIVATION_AUDIT_DEFAULT_LIMIT,
+            0,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing activation audit trail")?
+        .into_iter()
+        // Canary rollouts never change the active algorithm
+        .find(|change| {
+            !matches!(
+                change.action,
+                storage_enums::RoutingActivationAction::CanaryStart
+                    | storage_enums::RoutingActivationAction::CanaryAbort
+            )
+        });
+
+    let previous_algorithm_id = latest_change
+        .and_then(|change| change.previous_algorithm_id)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No previously active routing algorithm to roll back to".to_string(),
+        })
+        .into_report()?;
+
+    activate_routing_algorithm(
+        db,
+        &merchant_account,
+        #[cfg(not(feature = "business_profile_routing"))]
+        &key_store,
+        previous_algorithm_id,
+        storage_enums::RoutingActivationAction::Rollback,
+        request.created_by,
+    )
+    .await
+    .map(service_api::ApplicationResponse::Json)
+}
+
+/// Schedules the activation of a routing algorithm at a later time through the scheduler
+pub async fn schedule_routing_config_activation(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    algorithm_id: String,
+    request: routing_types::RoutingActivationScheduleRequest,
+) -> RouterResponse<routing_types::RoutingActivationScheduleResponse> {
+    let db = state.store.as_ref();
+
+    utils::when(
+        request.activate_at <= common_utils::date_time::now(),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "activate_at must be in the future".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    // Ensures that the algorithm exists and belongs to the merchant
+    get_merchant_routing_algorithm(db, &merchant_account, &algorithm_id).await?;
+
+    let tracking_data = storage::RoutingActivationTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        algorithm_id: algorithm_id.clone(),
+        created_by: request.created_by,
+    };
+    let tracking_data = serde_json::to_value(tracking_data)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing activation tracking data")?;
+
+    let runner = ROUTING_ACTIVATION_WORKFLOW;
+    let task = "ACTIVATE_ROUTING_ALGORITHM";
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!(
+            "{runner}_{task}_{algorithm_id}_{}",
+            request.activate_at.assume_utc().unix_timestamp()
+        ),
+        name: Some(String::from(task)),
+        tag: vec![String::from("ROUTING")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(request.activate_at),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: storage_enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Activation of the algorithm is already scheduled at the given time"
+                .to_string(),
+        })
+        .attach_printable("Failed to insert the routing activation task in process_tracker")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingActivationScheduleResponse {
+            algorithm_id,
+            activate_at: request.activate_at,
+            task_id: process.id,
+        },
+    ))
+}
+
+pub async fn retrieve_routing_activation_audit(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    query_params: routing_types::RoutingActivationAuditQuery,
+) -> RouterResponse<Vec<routing_types::RoutingActivationAuditRecord>> {
+    let records = state
+        .store
+        .list_routing_activation_audit_by_merchant_id(
+            &merchant_account.merchant_id,
+            query_params.profile_id.as_deref(),
+            query_params
+                .limit
+                .map(i64::from)
+                .unwrap_or(consts::ROUTING_ACTIVATION_AUDIT_DEFAULT_LIMIT),
+            query_params.offset.map(i64::from).unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing activation audit trail")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(service_api::ApplicationResponse::Json(records))
+}
+
 pub async fn unlink_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -427,6 +828,7 @@ pub async fn unlink_routing_config(
                                 timestamp,
                                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
                                 surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                                canary: None,
                             };
 
                         let record = db
@@ -443,6 +845,16 @@ pub async fn unlink_routing_config(
                             routing_algorithm,
                         )
                         .await?;
+                        helpers::insert_routing_activation_audit(
+                            db,
+                            &merchant_account.merchant_id,
+                            Some(profile_id),
+                            None,
+                            Some(algorithm_id),
+                            storage_enums::RoutingActivationAction::Deactivate,
+                            request.created_by,
+                        )
+                        .await?;
                         Ok(service_api::ApplicationResponse::Json(response))
                     }
                     None => Err(errors::ApiErrorResponse::PreconditionFailed {
@@ -485,6 +897,7 @@ pub async fn unlink_routing_config(
                 timestamp,
                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
                 surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                canary: None,
             };
 
         let active_algorithm_id = merchant_dictionary
@@ -551,6 +964,17 @@ pub async fn unlink_routing_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
 
+        helpers::insert_routing_activation_audit(
+            db,
+            &merchant_account.merchant_id,
+            None,
+            None,
+            Some(active_algorithm_id),
+            storage_enums::RoutingActivationAction::Deactivate,
+            None,
+        )
+        .await?;
+
         Ok(service_api::ApplicationResponse::Json(response))
     }
 }
@@ -612,6 +1036,43 @@ pub async fn retrieve_default_routing_config(
         .map(service_api::ApplicationResponse::Json)
 }
 
+pub async fn retrieve_routing_timezone_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<routing_types::RoutingTimezoneConfig> {
+    let db = state.store.as_ref();
+    let timezone = helpers::get_merchant_routing_timezone(db, &merchant_account.merchant_id).await;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingTimezoneConfig {
+            timezone: timezone.name().to_string(),
+        },
+    ))
+}
+
+pub async fn update_routing_timezone_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: routing_types::RoutingTimezoneConfig,
+) -> RouterResponse<routing_types::RoutingTimezoneConfig> {
+    let db = state.store.as_ref();
+    let timezone = request
+        .timezone
+        .parse::<chrono_tz::Tz>()
+        .map_err(|_| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("'{}' is not a valid IANA timezone", request.timezone),
+        })
+        .into_report()?;
+
+    helpers::update_merchant_routing_timezone(db, &merchant_account.merchant_id, timezone).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingTimezoneConfig {
+            timezone: timezone.name().to_string(),
+        },
+    ))
+}
+
 pub async fn retrieve_linked_routing_config(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -699,6 +1160,9 @@ pub async fn retrieve_linked_routing_config(
                 algorithm: the_algorithm,
                 created_at: record.created_at,
                 modified_at: record.modified_at,
+                version: record.version,
+                created_by: record.created_by,
+                comment: record.comment,
             })
         } else {
             None
@@ -711,3 +1175,329 @@ pub async fn retrieve_linked_routing_config(
         Ok(service_api::ApplicationResponse::Json(response))
     }
 }
+
+/// The routing algorithm ref of a business profile, or of the merchant account when routing is
+/// not configured per profile
+struct RoutingRefScope {
+    #[cfg(feature = "business_profile_routing")]
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    profile_id: Option<String>,
+    routing_ref: routing_types::RoutingAlgorithmRef,
+}
+
+async fn find_routing_ref_scope(
+    #[cfg_attr(not(feature = "business_profile_routing"), allow(unused_variables))]
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    #[cfg(feature = "business_profile_routing")] profile_id: Option<String>,
+) -> RouterResult<RoutingRefScope> {
+    #[cfg(feature = "business_profile_routing")]
+    {
+        let profile_id = profile_id
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })
+            .attach_printable("Profile_id not provided")?;
+
+        let business_profile =
+            validate_and_get_business_profile(db, Some(&profile_id), &merchant_account.merchant_id)
+                .await?
+                .get_required_value("BusinessProfile")
+                .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.clone(),
+                })?;
+
+        let routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+            .unwrap_or_default();
+
+        Ok(RoutingRefScope {
+            business_profile,
+            profile_id: Some(profile_id),
+            routing_ref,
+        })
+    }
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        let routing_ref: routing_types::RoutingAlgorithmRef = merchant_account
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref from merchant account")?
+            .unwrap_or_default();
+
+        Ok(RoutingRefScope {
+            profile_id: None,
+            routing_ref,
+        })
+    }
+}
+
+async fn save_routing_ref_scope(
+    db: &dyn StorageInterface,
+    scope: RoutingRefScope,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    #[cfg(feature = "business_profile_routing")]
+    {
+        helpers::update_business_profile_active_algorithm_ref(
+            db,
+            scope.business_profile,
+            scope.routing_ref,
+        )
+        .await
+    }
+
+    #[cfg(not(feature = "business_profile_routing"))]
+    {
+        helpers::update_merchant_active_algorithm_ref(db, key_store, scope.routing_ref).await
+    }
+}
+
+/// Starts routing a percentage of the payments with the given algorithm, while the active
+/// algorithm keeps routing the rest. Sending a new percentage for the algorithm already in
+/// rollout adjusts the rollout without resetting it.
+pub async fn start_routing_canary(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
+    algorithm_id: String,
+    request: routing_types::RoutingCanaryStartRequest,
+) -> RouterResponse<routing_types::RoutingCanaryConfig> {
+    let db = state.store.as_ref();
+
+    utils::when(!(1..=99).contains(&request.percentage), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "percentage must be between 1 and 99".to_string(),
+        })
+        .into_report()
+    })?;
+
+    // Ensures that the algorithm exists and belongs to the merchant
+    #[cfg_attr(not(feature = "business_profile_routing"), allow(unused_variables))]
+    let candidate = get_merchant_routing_algorithm(db, &merchant_account, &algorithm_id).await?;
+
+    let mut scope = find_routing_ref_scope(
+        db,
+        &merchant_account,
+        #[cfg(feature = "business_profile_routing")]
+        Some(candidate.profile_id),
+    )
+    .await?;
+
+    let active_algorithm_id = scope
+        .routing_ref
+        .algorithm_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A canary rollout needs an active algorithm to compare against".to_string(),
+        })
+        .into_report()?;
+
+    utils::when(active_algorithm_id == algorithm_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Algorithm is already active".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let started_at = match &scope.routing_ref.canary {
+        Some(canary) if canary.algorithm_id == algorithm_id => canary.started_at,
+        Some(canary) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Algorithm {} is already being rolled out, promote or abort it first",
+                canary.algorithm_id
+            ),
+        })
+        .into_report()?,
+        None => common_utils::date_time::now_unix_timestamp(),
+    };
+
+    let canary = routing_types::RoutingCanaryConfig {
+        algorithm_id: algorithm_id.clone(),
+        percentage: request.percentage,
+        stickiness: request.stickiness,
+        started_at,
+    };
+    let profile_id = scope.profile_id.clone();
+    scope.routing_ref.update_canary(Some(canary.clone()));
+    save_routing_ref_scope(
+        db,
+        scope,
+        #[cfg(not(feature = "business_profile_routing"))]
+        &key_store,
+    )
+    .await?;
+
+    helpers::insert_routing_activation_audit(
+        db,
+        &merchant_account.merchant_id,
+        profile_id,
+        Some(algorithm_id),
+        Some(active_algorithm_id),
+        storage_enums::RoutingActivationAction::CanaryStart,
+        request.created_by,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(canary))
+}
+
+/// Activates the candidate algorithm of the canary rollout for all payments
+pub async fn promote_routing_canary(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingCanaryActionRequest,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    let db = state.store.as_ref();
+    let scope = find_routing_ref_scope(
+        db,
+        &merchant_account,
+        #[cfg(feature = "business_profile_routing")]
+        request.profile_id,
+    )
+    .await?;
+
+    let canary = scope
+        .routing_ref
+        .canary
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No canary rollout is in progress".to_string(),
+        })
+        .into_report()?;
+
+    activate_routing_algorithm(
+        db,
+        &merchant_account,
+        #[cfg(not(feature = "business_profile_routing"))]
+        &key_store,
+        canary.algorithm_id,
+        storage_enums::RoutingActivationAction::CanaryPromote,
+        request.created_by,
+    )
+    .await
+    .map(service_api::ApplicationResponse::Json)
+}
+
+/// Stops the canary rollout, so that the active algorithm routes all payments again
+pub async fn abort_routing_canary(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    #[cfg(not(feature = "business_profile_routing"))] key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingCanaryActionRequest,
+) -> RouterResponse<routing_types::RoutingCanaryConfig> {
+    let db = state.store.as_ref();
+    let mut scope = find_routing_ref_scope(
+        db,
+        &merchant_account,
+        #[cfg(feature = "business_profile_routing")]
+        request.profile_id,
+    )
+    .await?;
+
+    let canary = scope
+        .routing_ref
+        .canary
+        .clone()
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No canary rollout is in progress".to_string(),
+        })
+        .into_report()?;
+    let active_algorithm_id = scope.routing_ref.algorithm_id.clone();
+    let profile_id = scope.profile_id.clone();
+
+    scope.routing_ref.update_canary(None);
+    save_routing_ref_scope(
+        db,
+        scope,
+        #[cfg(not(feature = "business_profile_routing"))]
+        &key_store,
+    )
+    .await?;
+
+    helpers::insert_routing_activation_audit(
+        db,
+        &merchant_account.merchant_id,
+        profile_id,
+        active_algorithm_id,
+        Some(canary.algorithm_id.clone()),
+        storage_enums::RoutingActivationAction::CanaryAbort,
+        request.created_by,
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(canary))
+}
+
+/// Compares the authorization rates of the two arms of the canary rollout since it started
+pub async fn retrieve_routing_canary(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    #[cfg_attr(not(feature = "business_profile_routing"), allow(unused_variables))]
+    query_params: routing_types::RoutingCanaryQuery,
+) -> RouterResponse<routing_types::RoutingCanaryStatusResponse> {
+    let db = state.store.as_ref();
+    let scope = find_routing_ref_scope(
+        db,
+        &merchant_account,
+        #[cfg(feature = "business_profile_routing")]
+        query_params.profile_id,
+    )
+    .await?;
+
+    let (active_algorithm_id, canary) = scope
+        .routing_ref
+        .algorithm_id
+        .zip(scope.routing_ref.canary)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No canary rollout is in progress".to_string(),
+        })
+        .into_report()?;
+
+    let started_at = time::OffsetDateTime::from_unix_timestamp(canary.started_at)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid start time of the canary rollout")?;
+
+    let arm_aggregates = db
+        .get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+            &merchant_account.merchant_id,
+            vec![active_algorithm_id.clone(), canary.algorithm_id.clone()],
+            time::PrimitiveDateTime::new(started_at.date(), started_at.time()),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing arms of payment attempts")?;
+
+    let arms = vec![
+        helpers::get_routing_arm_stats(
+            storage_enums::RoutingArm::Active,
+            active_algorithm_id.clone(),
+            &arm_aggregates,
+        ),
+        helpers::get_routing_arm_stats(
+            storage_enums::RoutingArm::Canary,
+            canary.algorithm_id.clone(),
+            &arm_aggregates,
+        ),
+    ];
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingCanaryStatusResponse {
+            active_algorithm_id,
+            canary,
+            arms,
+        },
+    ))
+}
@@ -2,18 +2,27 @@
 //!
 //! Functions that are used to perform the retrieval of merchant's
 //! routing dict, configs, defaults
+use std::io::{Read, Write};
+
 use api_models::routing as routing_types;
 use common_utils::ext_traits::Encode;
 use diesel_models::{
     business_profile::{BusinessProfile, BusinessProfileUpdateInternal},
-    configs,
+    configs, enums as storage_enums,
+};
+use error_stack::{IntoReport, ResultExt};
+use euclid::{
+    dssa::{analyzer, types::AnalysisWarning},
+    frontend::ast,
 };
-use error_stack::ResultExt;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use rustc_hash::FxHashSet;
 
 use crate::{
+    consts,
     core::errors::{self, RouterResult},
     db::StorageInterface,
+    logger,
     types::{domain, storage},
     utils::{self, StringExt},
 };
@@ -68,6 +77,38 @@ pub async fn get_merchant_routing_dictionary(
     }
 }
 
+/// Fetches the routing dictionary of the merchant from the database rather than the cache, along
+/// with the stored config it was read from, so that it can be updated with
+/// [`update_merchant_routing_dictionary_if_unchanged`]
+pub async fn get_merchant_routing_dictionary_for_update(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<(routing_types::RoutingDictionary, String)> {
+    let key = get_routing_dictionary_key(merchant_id);
+    let config = match db.find_config_by_key_from_db(&key).await {
+        Ok(config) => config,
+        Err(e) if e.current_context().is_db_not_found() => {
+            // Creates the dictionary of the merchant
+            get_merchant_routing_dictionary(db, merchant_id).await?;
+            db.find_config_by_key_from_db(&key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error fetching routing dictionary for merchant")?
+        }
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Error fetching routing dictionary for merchant")?,
+    };
+
+    let dictionary = config
+        .config
+        .parse_struct("RoutingDictionary")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Merchant routing dictionary has invalid structure")?;
+
+    Ok((dictionary, config.config))
+}
+
 /// Provides us with all the configured configs of the Merchant in the ascending time configured
 /// manner and chooses the first of them
 pub async fn get_merchant_default_config(
@@ -164,6 +205,31 @@ pub async fn update_merchant_routing_dictionary(
     Ok(())
 }
 
+/// Saves the routing dictionary of the merchant only if it was not changed since it was fetched
+/// with [`get_merchant_routing_dictionary_for_update`]. Returns whether the dictionary was saved.
+pub async fn update_merchant_routing_dictionary_if_unchanged(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    current_dictionary: &str,
+    dictionary: routing_types::RoutingDictionary,
+) -> RouterResult<bool> {
+    let key = get_routing_dictionary_key(merchant_id);
+    let dictionary_str =
+        Encode::<routing_types::RoutingDictionary>::encode_to_string_of_json(&dictionary)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to serialize routing dictionary during update")?;
+
+    let config_update = configs::ConfigUpdate::Update {
+        config: Some(dictionary_str),
+    };
+
+    db.update_config_by_key_if_unchanged(&key, current_dictionary, config_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error saving routing dictionary to DB")
+        .map(|config| config.is_some())
+}
+
 pub async fn update_routing_algorithm(
     db: &dyn StorageInterface,
     algorithm_id: String,
@@ -255,6 +321,7 @@ pub async fn update_business_profile_active_algorithm_ref(
         applepay_verified_domains: None,
         modified_at: None,
         is_recon_enabled: None,
+        webhook_signing_keys: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -331,6 +398,96 @@ pub async fn update_merchant_connector_agnostic_mandate_config(
     Ok(mandate_config)
 }
 
+/// Provides the timezone in which the time based keys of the merchant's routing rules are
+/// evaluated. It is read on every routed payment, so it goes through the config cache, with UTC
+/// stored as the default for merchants which haven't configured one. A timezone which can't be
+/// read falls back to UTC, as routing the payment matters more than the time based rules.
+pub async fn get_merchant_routing_timezone(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> chrono_tz::Tz {
+    let key = get_routing_timezone_key(merchant_id);
+
+    db.find_config_by_key_unwrap_or(&key, Some(chrono_tz::UTC.name().to_string()))
+        .await
+        .map_err(|error| logger::error!(routing_timezone_error=?error))
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse::<chrono_tz::Tz>()
+                .map_err(|error| logger::error!(routing_timezone_parse_error=?error))
+                .ok()
+        })
+        .unwrap_or(chrono_tz::UTC)
+}
+
+pub async fn update_merchant_routing_timezone(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    timezone: chrono_tz::Tz,
+) -> RouterResult<()> {
+    let key = get_routing_timezone_key(merchant_id);
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(timezone.name().to_string()),
+                },
+            )
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("error updating routing timezone for merchant in db"),
+
+        Err(e) if e.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew {
+                key,
+                config: timezone.name().to_string(),
+            })
+            .await
+            .map(|_| ())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("error inserting routing timezone for merchant in db"),
+
+        Err(e) => Err(e)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("error fetching routing timezone for merchant from db"),
+    }
+}
+
+/// Serializes the routing trace of a payment attempt and compresses it for storage
+pub fn encode_routing_trace(trace: &routing_types::RoutingTrace) -> RouterResult<Vec<u8>> {
+    let serialized = serde_json::to_vec(trace)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error serializing routing trace")?;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&serialized)
+        .and_then(|_| encoder.finish())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error compressing routing trace")
+}
+
+pub fn decode_routing_trace(encoded: &[u8]) -> RouterResult<routing_types::RoutingTrace> {
+    let mut serialized = Vec::new();
+    GzDecoder::new(encoded)
+        .read_to_end(&mut serialized)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error decompressing routing trace")?;
+
+    serde_json::from_slice(&serialized)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error deserializing routing trace")
+}
+
 pub async fn validate_connectors_in_routing_config(
     db: &dyn StorageInterface,
     key_store: &domain::MerchantKeyStore,
@@ -422,6 +579,12 @@ pub async fn validate_connectors_in_routing_config(
             }
         }
 
+        routing_types::RoutingAlgorithm::LeastCost(config) => {
+            for least_cost_connector in &config.connectors {
+                check_connector_choice(&least_cost_connector.connector)?;
+            }
+        }
+
         routing_types::RoutingAlgorithm::Advanced(program) => {
             let check_connector_selection =
                 |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
@@ -453,6 +616,175 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Analyses how the rules of an advanced routing algorithm interact with one another. The
+/// warnings are only advisory, so an algorithm that cannot be analysed yields no warnings.
+pub fn get_routing_algorithm_warnings(
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> Vec<AnalysisWarning> {
+    match routing_algorithm {
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            ast::lowering::lower_program(program.clone())
+                .map(|dir_program| analyzer::analyze_rule_interactions(&dir_program))
+                .map_err(|error| {
+                    logger::warn!(?error, "Failed to analyze the rules of routing algorithm")
+                })
+                .unwrap_or_default()
+        }
+
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_)
+        | routing_types::RoutingAlgorithm::LeastCost(_) => Vec::new(),
+    }
+}
+
+/// Computes the structural changes between two routing algorithms. Advanced algorithms are
+/// compared rule by rule, any other pair of algorithms is reported as a whole if it differs.
+pub fn get_routing_algorithm_changes(
+    from: &routing_types::RoutingAlgorithm,
+    to: &routing_types::RoutingAlgorithm,
+) -> RouterResult<routing_types::RoutingAlgorithmChanges> {
+    if let (
+        routing_types::RoutingAlgorithm::Advanced(from_program),
+        routing_types::RoutingAlgorithm::Advanced(to_program),
+    ) = (from, to)
+    {
+        let program_diff = ast::diff::diff_programs(from_program, to_program);
+        return Ok(if program_diff.is_empty() {
+            routing_types::RoutingAlgorithmChanges::Unchanged
+        } else {
+            routing_types::RoutingAlgorithmChanges::Program(program_diff)
+        });
+    }
+
+    let from_value = Encode::<routing_types::RoutingAlgorithm>::encode_to_value(from)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing algorithm")?;
+    let to_value = Encode::<routing_types::RoutingAlgorithm>::encode_to_value(to)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing algorithm")?;
+
+    Ok(if from_value == to_value {
+        routing_types::RoutingAlgorithmChanges::Unchanged
+    } else {
+        routing_types::RoutingAlgorithmChanges::Replaced {
+            from: from.clone(),
+            to: to.clone(),
+        }
+    })
+}
+
+/// Records a change of the active routing algorithm in the activation audit trail
+pub async fn insert_routing_activation_audit(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    profile_id: Option<String>,
+    algorithm_id: Option<String>,
+    previous_algorithm_id: Option<String>,
+    action: storage_enums::RoutingActivationAction,
+    created_by: Option<String>,
+) -> RouterResult<()> {
+    let audit = storage::RoutingActivationAuditNew {
+        audit_id: common_utils::generate_id(consts::ID_LENGTH, "routing_audit"),
+        merchant_id: merchant_id.to_string(),
+        profile_id,
+        algorithm_id,
+        previous_algorithm_id,
+        action,
+        created_by,
+        created_at: common_utils::date_time::now(),
+    };
+
+    db.insert_routing_activation_audit(audit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the routing activation change")?;
+
+    Ok(())
+}
+
+/// Aggregates the authorization outcomes of the payment attempts routed through one arm of a
+/// canary rollout, in total and per day
+pub fn get_routing_arm_stats(
+    arm: storage_enums::RoutingArm,
+    algorithm_id: String,
+    aggregates: &[storage::PaymentAttemptRoutingArmAggregate],
+) -> routing_types::RoutingArmStats {
+    let mut totals = routing_types::RoutingArmCounts::default();
+    let mut daily =
+        std::collections::BTreeMap::<time::Date, routing_types::RoutingArmCounts>::new();
+
+    for aggregate in aggregates.iter().filter(|aggregate| {
+        aggregate.routing_arm == Some(arm)
+            && aggregate.routing_algorithm_id.as_deref() == Some(algorithm_id.as_str())
+    }) {
+        let attempt_count = u64::try_from(aggregate.attempt_count).unwrap_or_default();
+        let day = daily.entry(aggregate.date).or_default();
+        for counts in [&mut totals, day] {
+            add_attempts_to_arm_counts(counts, aggregate.status, attempt_count);
+        }
+    }
+
+    routing_types::RoutingArmStats {
+        arm,
+        algorithm_id,
+        totals: with_authorization_rate(totals),
+        daily: daily
+            .into_iter()
+            .map(|(date, counts)| routing_types::RoutingArmDailyStats {
+                date: date.to_string(),
+                counts: with_authorization_rate(counts),
+            })
+            .collect(),
+    }
+}
+
+fn add_attempts_to_arm_counts(
+    counts: &mut routing_types::RoutingArmCounts,
+    status: storage_enums::AttemptStatus,
+    attempt_count: u64,
+) {
+    counts.total_attempts += attempt_count;
+    match status {
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::CaptureInitiated
+        | storage_enums::AttemptStatus::CaptureFailed
+        | storage_enums::AttemptStatus::Voided
+        | storage_enums::AttemptStatus::VoidInitiated
+        | storage_enums::AttemptStatus::VoidFailed
+        | storage_enums::AttemptStatus::AutoRefunded
+        | storage_enums::AttemptStatus::CodInitiated => counts.authorized_attempts += attempt_count,
+        storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::AuthenticationFailed
+        | storage_enums::AttemptStatus::RouterDeclined
+        | storage_enums::AttemptStatus::Failure => counts.declined_attempts += attempt_count,
+        storage_enums::AttemptStatus::Started
+        | storage_enums::AttemptStatus::AuthenticationPending
+        | storage_enums::AttemptStatus::AuthenticationSuccessful
+        | storage_enums::AttemptStatus::Authorizing
+        | storage_enums::AttemptStatus::Unresolved
+        | storage_enums::AttemptStatus::Pending
+        | storage_enums::AttemptStatus::PaymentMethodAwaited
+        | storage_enums::AttemptStatus::ConfirmationAwaited
+        | storage_enums::AttemptStatus::DeviceDataCollectionPending => {}
+    }
+}
+
+/// Attempts which are still in progress are left out of the authorization rate
+#[allow(clippy::as_conversions)]
+fn with_authorization_rate(
+    mut counts: routing_types::RoutingArmCounts,
+) -> routing_types::RoutingArmCounts {
+    let completed_attempts = counts.authorized_attempts + counts.declined_attempts;
+    if completed_attempts > 0 {
+        counts.authorization_rate =
+            Some(counts.authorized_attempts as f64 / completed_attempts as f64);
+    }
+    counts
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -470,6 +802,12 @@ pub fn get_pg_agnostic_mandate_config_key(merchant_id: &str) -> String {
 pub fn get_default_config_key(merchant_id: &str) -> String {
     format!("routing_default_{merchant_id}")
 }
+/// Provides the identifier for the specific merchant's routing timezone
+#[inline(always)]
+pub fn get_routing_timezone_key(merchant_id: &str) -> String {
+    format!("routing_timezone_{merchant_id}")
+}
+
 pub fn get_payment_config_routing_id(merchant_id: &str) -> String {
     format!("payment_config_id_{merchant_id}")
 }
@@ -1,11 +1,11 @@
 use api_models::routing::{
-    MerchantRoutingAlgorithm, RoutingAlgorithm as Algorithm, RoutingAlgorithmKind,
-    RoutingDictionaryRecord,
+    MerchantRoutingAlgorithm, RoutingActivationAuditRecord, RoutingAlgorithm as Algorithm,
+    RoutingAlgorithmKind, RoutingDictionaryRecord,
 };
 use common_utils::ext_traits::ValueExt;
 use diesel_models::{
     enums as storage_enums,
-    routing_algorithm::{RoutingAlgorithm, RoutingProfileMetadata},
+    routing_algorithm::{RoutingActivationAudit, RoutingAlgorithm, RoutingProfileMetadata},
 };
 
 use crate::{
@@ -25,6 +25,9 @@ impl ForeignFrom<RoutingProfileMetadata> for RoutingDictionaryRecord {
             description: value.description.unwrap_or_default(),
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
+            version: value.version,
+            created_by: value.created_by,
+            comment: value.comment,
         }
     }
 }
@@ -40,6 +43,9 @@ impl ForeignFrom<RoutingAlgorithm> for RoutingDictionaryRecord {
             description: value.description.unwrap_or_default(),
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
+            version: value.version,
+            created_by: value.created_by,
+            comment: value.comment,
         }
     }
 }
@@ -59,10 +65,45 @@ impl ForeignTryFrom<RoutingAlgorithm> for MerchantRoutingAlgorithm {
                 .parse_value::<Algorithm>("RoutingAlgorithm")?,
             created_at: value.created_at.assume_utc().unix_timestamp(),
             modified_at: value.modified_at.assume_utc().unix_timestamp(),
+            version: value.version,
+            created_by: value.created_by,
+            comment: value.comment,
         })
     }
 }
 
+impl ForeignFrom<MerchantRoutingAlgorithm> for RoutingDictionaryRecord {
+    fn foreign_from(value: MerchantRoutingAlgorithm) -> Self {
+        Self {
+            id: value.id,
+            #[cfg(feature = "business_profile_routing")]
+            profile_id: value.profile_id,
+            name: value.name,
+            kind: value.algorithm.get_kind(),
+            description: value.description,
+            created_at: value.created_at,
+            modified_at: value.modified_at,
+            version: value.version,
+            created_by: value.created_by,
+            comment: value.comment,
+        }
+    }
+}
+
+impl ForeignFrom<RoutingActivationAudit> for RoutingActivationAuditRecord {
+    fn foreign_from(value: RoutingActivationAudit) -> Self {
+        Self {
+            audit_id: value.audit_id,
+            profile_id: value.profile_id,
+            algorithm_id: value.algorithm_id,
+            previous_algorithm_id: value.previous_algorithm_id,
+            action: value.action,
+            created_by: value.created_by,
+            created_at: value.created_at.assume_utc().unix_timestamp(),
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
     fn foreign_from(value: storage_enums::RoutingAlgorithmKind) -> Self {
         match value {
@@ -70,6 +111,7 @@ impl ForeignFrom<storage_enums::RoutingAlgorithmKind> for RoutingAlgorithmKind {
             storage_enums::RoutingAlgorithmKind::Priority => Self::Priority,
             storage_enums::RoutingAlgorithmKind::VolumeSplit => Self::VolumeSplit,
             storage_enums::RoutingAlgorithmKind::Advanced => Self::Advanced,
+            storage_enums::RoutingAlgorithmKind::LeastCost => Self::LeastCost,
         }
     }
 }
@@ -81,6 +123,7 @@ impl ForeignFrom<RoutingAlgorithmKind> for storage_enums::RoutingAlgorithmKind {
             RoutingAlgorithmKind::Priority => Self::Priority,
             RoutingAlgorithmKind::VolumeSplit => Self::VolumeSplit,
             RoutingAlgorithmKind::Advanced => Self::Advanced,
+            RoutingAlgorithmKind::LeastCost => Self::LeastCost,
         }
     }
 }
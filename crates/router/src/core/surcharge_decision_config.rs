@@ -0,0 +1,179 @@
+use api_models::{
+    routing::RoutingAlgorithmRef,
+    surcharge_decision_configs::{
+        SurchargeDecisionConfigs, SurchargeDecisionManagerRecord, SurchargeDecisionManagerRequest,
+        SurchargeDecisionManagerResponse,
+    },
+};
+use common_utils::ext_traits::{Encode, StringExt, ValueExt};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use euclid::{
+    backend::{EuclidBackend, VirInterpreterBackend},
+    frontend::ast,
+};
+
+use super::routing::helpers::{
+    get_payment_method_surcharge_routing_id, update_merchant_active_algorithm_ref,
+};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::AppState,
+    services::api as service_api,
+    types::domain,
+    utils::OptionExt,
+};
+
+pub async fn upsert_surcharge_decision_config(
+    state: AppState,
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+    request: SurchargeDecisionManagerRequest,
+) -> RouterResponse<SurchargeDecisionManagerRecord> {
+    let db = state.store.as_ref();
+    let name = request.name;
+    let program = request.algorithm;
+    let merchant_surcharge_configs = request.merchant_surcharge_configs;
+
+    if let Some(ref program) = program {
+        validate_surcharge_program(program)?;
+    }
+
+    let key = get_payment_method_surcharge_routing_id(&merchant_account.merchant_id);
+    let timestamp = common_utils::date_time::now_unix_timestamp();
+
+    let existing_record: Option<SurchargeDecisionManagerRecord> =
+        match db.find_config_by_key(&key).await {
+            Ok(config) => Some(
+                config
+                    .config
+                    .parse_struct("SurchargeDecisionManagerRecord")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Unable to parse the stored surcharge decision config")?,
+            ),
+            Err(err) if err.current_context().is_db_not_found() => None,
+            Err(err) => Err(err)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error fetching the surcharge decision config")?,
+        };
+
+    let is_new_record = existing_record.is_none();
+    let new_record = match existing_record {
+        Some(record) => SurchargeDecisionManagerRecord {
+            name: name.unwrap_or(record.name),
+            merchant_surcharge_configs,
+            algorithm: program.unwrap_or(record.algorithm),
+            created_at: record.created_at,
+            modified_at: timestamp,
+        },
+        None => SurchargeDecisionManagerRecord {
+            name: name.get_required_value("name")?,
+            merchant_surcharge_configs,
+            algorithm: program.get_required_value("algorithm")?,
+            created_at: timestamp,
+            modified_at: timestamp,
+        },
+    };
+
+    let serialized_record =
+        Encode::<SurchargeDecisionManagerRecord>::encode_to_string_of_json(&new_record)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to serialize the surcharge decision config")?;
+
+    if is_new_record {
+        db.insert_config(configs::ConfigNew {
+            key: key.clone(),
+            config: serialized_record,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error saving the surcharge decision config")?;
+    } else {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_record),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating the surcharge decision config")?;
+    }
+
+    let mut algo_id = get_merchant_routing_algorithm_ref(&merchant_account)?;
+    algo_id.update_surcharge_config_id(key);
+    update_merchant_active_algorithm_ref(db, &key_store, algo_id)
+        .await
+        .attach_printable("Failed to update the merchant routing algorithm ref")?;
+
+    Ok(service_api::ApplicationResponse::Json(new_record))
+}
+
+pub async fn retrieve_surcharge_decision_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<SurchargeDecisionManagerResponse> {
+    let key = get_payment_method_surcharge_routing_id(&merchant_account.merchant_id);
+    let record = state
+        .store
+        .find_config_by_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Surcharge decision config not found".to_string(),
+        })?
+        .config
+        .parse_struct("SurchargeDecisionManagerRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse the stored surcharge decision config")?;
+
+    Ok(service_api::ApplicationResponse::Json(record))
+}
+
+pub async fn delete_surcharge_decision_config(
+    state: AppState,
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let key = get_payment_method_surcharge_routing_id(&merchant_account.merchant_id);
+
+    let mut algo_id = get_merchant_routing_algorithm_ref(&merchant_account)?;
+    algo_id.surcharge_config_algo_id = None;
+    algo_id.timestamp = common_utils::date_time::now_unix_timestamp();
+    update_merchant_active_algorithm_ref(db, &key_store, algo_id)
+        .await
+        .attach_printable("Failed to update the merchant routing algorithm ref")?;
+
+    db.delete_config_by_key(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Surcharge decision config not found".to_string(),
+        })
+        .attach_printable("Error deleting the surcharge decision config")?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+fn validate_surcharge_program(
+    program: &ast::Program<SurchargeDecisionConfigs>,
+) -> RouterResult<()> {
+    VirInterpreterBackend::with_program(program.clone())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid surcharge decision program".to_string(),
+        })
+        .map(|_| ())
+}
+
+fn get_merchant_routing_algorithm_ref(
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<RoutingAlgorithmRef> {
+    Ok(merchant_account
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Could not decode the routing algorithm ref")?
+        .unwrap_or_default())
+}
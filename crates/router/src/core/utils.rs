@@ -186,6 +186,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
 
     Ok(router_data)
@@ -337,6 +338,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
 
     Ok(router_data)
@@ -566,6 +568,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
@@ -653,6 +656,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
@@ -746,6 +750,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
@@ -836,6 +841,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
@@ -868,7 +874,29 @@ pub async fn construct_retrieve_file_router_data<'a>(
         file_metadata.merchant_connector_id.as_ref(),
     )
     .await?;
+    let provider_file_id = file_metadata
+        .provider_file_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Missing provider file id")?;
+
+    construct_retrieve_file_router_data_for_connector_account(
+        merchant_account,
+        &merchant_connector_account,
+        connector_id,
+        provider_file_id,
+    )
+}
 
+/// Constructs the router data to download the file `provider_file_id` from the connector with
+/// the credentials of the given merchant connector account
+pub fn construct_retrieve_file_router_data_for_connector_account(
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+    connector_id: &str,
+    provider_file_id: String,
+) -> RouterResult<types::RetrieveFileRouterData> {
     let test_mode: Option<bool> = merchant_connector_account.is_test_mode_on();
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .get_connector_account_details()
@@ -892,14 +920,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         auth_type: diesel_models::enums::AuthenticationType::default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         amount_captured: None,
-        request: types::RetrieveFileRequestData {
-            provider_file_id: file_metadata
-                .provider_file_id
-                .clone()
-                .ok_or(errors::ApiErrorResponse::InternalServerError)
-                .into_report()
-                .attach_printable("Missing provider file id")?,
-        },
+        request: types::RetrieveFileRequestData { provider_file_id },
         response: Err(types::ErrorResponse::default()),
         access_token: None,
         session_token: None,
@@ -919,6 +940,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
@@ -60,6 +60,7 @@ pub async fn check_existence_and_add_domain_to_db(
         applepay_verified_domains: Some(already_verified_domains.clone()),
         pm_auth_config: None,
         connector_label: None,
+        fee_schedule: None,
     };
     state
         .store
@@ -1,3 +1,7 @@
+pub mod endpoints;
+pub mod incoming;
+pub mod signing;
+mod source_verification;
 pub mod types;
 pub mod utils;
 
@@ -7,14 +11,16 @@ use api_models::{
     payments::HeaderPayload,
     webhooks::{self, WebhookResponseTracker},
 };
-use common_utils::errors::ReportSwitchExt;
+use common_utils::{errors::ReportSwitchExt, webhook_signature};
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeInterface;
+use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing};
 
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
     consts,
     core::{
@@ -49,9 +55,9 @@ pub async fn payments_incoming_webhook_flow<
     business_profile: diesel_models::business_profile::BusinessProfile,
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
-    source_verified: bool,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
-    let consume_or_trigger_flow = if source_verified {
+    let consume_or_trigger_flow = if source_verification_status.is_verified() {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
     } else {
         payments::CallConnectorAction::Trigger
@@ -165,6 +171,7 @@ pub async fn payments_incoming_webhook_flow<
                     payment_id.clone(),
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                    Some(source_verification_status),
                 )
                 .await?;
             };
@@ -189,7 +196,7 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
     connector_name: &str,
-    source_verified: bool,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
     event_type: api_models::webhooks::IncomingWebhookEvent,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     let db = &*state.store;
@@ -222,7 +229,7 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     };
     let refund_id = refund.refund_id.to_owned();
     //if source verified then update refund status else trigger refund sync
-    let updated_refund = if source_verified {
+    let updated_refund = if source_verification_status.is_verified() {
         let refund_update = storage::RefundUpdate::StatusUpdate {
             connector_refund_id: None,
             sent_to_gateway: true,
@@ -233,19 +240,46 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 .attach_printable("failed refund status mapping from event type")?,
             updated_by: merchant_account.storage_scheme.to_string(),
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| {
-            format!(
-                "Failed while updating refund: refund_id: {}",
-                refund_id.to_owned()
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
             )
-        })?
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while updating refund: refund_id: {}",
+                    refund_id.to_owned()
+                )
+            })?;
+
+        #[cfg(feature = "payouts")]
+        let updated_refund = if updated_refund.refund_status == enums::RefundStatus::Failure
+            && refund.refund_status != enums::RefundStatus::Failure
+        {
+            let payment_intent = db
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &updated_refund.payment_id,
+                    &merchant_account.merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+            refunds::payout_fallback::trigger_refund_via_payout(
+                &state,
+                &merchant_account,
+                &key_store,
+                &payment_intent,
+                updated_refund,
+            )
+            .await?
+        } else {
+            updated_refund
+        };
+
+        updated_refund
     } else {
         refunds::refund_retrieve_core(
             state.clone(),
@@ -281,6 +315,7 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             refund_id,
             enums::EventObjectType::RefundDetails,
             api::OutgoingWebhookContent::RefundDetails(refund_response),
+            Some(source_verification_status),
         )
         .await?;
     }
@@ -292,6 +327,127 @@ pub async fn refunds_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     })
 }
 
+#[cfg(feature = "payouts")]
+pub async fn payouts_incoming_webhook_flow<W: types::OutgoingWebhookType>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    key_store: domain::MerchantKeyStore,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
+    event_type: api_models::webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let db = &*state.store;
+    //find payout attempt by payout id or connector payout id
+    let payout_attempt = match webhook_details.object_reference_id {
+        api_models::webhooks::ObjectReferenceId::PayoutId(payout_id_type) => match payout_id_type {
+            api_models::webhooks::PayoutIdType::PayoutId(id) => db
+                .find_payout_attempt_by_merchant_id_payout_id(&merchant_account.merchant_id, &id)
+                .await
+                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable_lazy(|| "Failed fetching the payout attempt")?,
+            api_models::webhooks::PayoutIdType::ConnectorPayoutId(id) => db
+                .find_payout_attempt_by_merchant_id_connector_payout_id(
+                    &merchant_account.merchant_id,
+                    &id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable_lazy(|| "Failed fetching the payout attempt")?,
+        },
+        _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .into_report()
+            .attach_printable("received a non-payout id when processing payout webhooks")?,
+    };
+    let payout_id = payout_attempt.payout_id.to_owned();
+    let request =
+        api::payouts::PayoutRequest::PayoutRetrieveRequest(api::payouts::PayoutRetrieveRequest {
+            payout_id: payout_id.to_owned(),
+            force_sync: Some(true),
+        });
+    let mut payout_data =
+        payouts::make_payout_data(&state, &merchant_account, &key_store, &request).await?;
+
+    //if source verified then update payout status else trigger payout sync
+    if source_verification_status.is_verified() {
+        let status: enums::PayoutStatus = event_type
+            .foreign_try_into()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed payout status mapping from event type")?;
+        let payout_attempt_update = storage::PayoutAttemptUpdate::StatusUpdate {
+            connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+            status,
+            error_message: None,
+            error_code: None,
+            is_eligible: payout_attempt.is_eligible,
+            last_modified_at: Some(common_utils::date_time::now()),
+        };
+        payout_data.payout_attempt = db
+            .update_payout_attempt_by_merchant_id_payout_id(
+                &merchant_account.merchant_id,
+                &payout_id,
+                payout_attempt_update,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating payout attempt: payout_id: {payout_id}")
+            })?;
+    } else {
+        let connector_data = payouts::get_connector_data(
+            &state,
+            &merchant_account,
+            Some(payout_attempt.connector.to_owned()),
+            None,
+        )
+        .await?;
+        payout_data = payouts::sync_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &request,
+            &connector_data,
+            &mut payout_data,
+        )
+        .await
+        .attach_printable_lazy(|| format!("Failed while syncing payout: payout_id: {payout_id}"))?;
+    }
+    let updated_status = payout_data.payout_attempt.status;
+    let event_type: Option<enums::EventType> = updated_status.foreign_into();
+
+    // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
+    if let Some(outgoing_event_type) = event_type {
+        let payout_response =
+            match payouts::response_handler(&state, &merchant_account, &request, &payout_data)
+                .await?
+            {
+                services::ApplicationResponse::Json(payout_response) => payout_response,
+                _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                    .into_report()
+                    .attach_printable("received non-json response from payouts core")?,
+            };
+        create_event_and_trigger_outgoing_webhook::<W>(
+            state,
+            merchant_account,
+            business_profile,
+            outgoing_event_type,
+            enums::EventClass::Payouts,
+            None,
+            payout_id.to_owned(),
+            enums::EventObjectType::PayoutDetails,
+            api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_response)),
+            Some(source_verification_status),
+        )
+        .await?;
+    }
+
+    Ok(WebhookResponseTracker::Payout {
+        payout_id,
+        status: updated_status,
+    })
+}
+
 pub async fn get_payment_attempt_from_object_reference_id(
     state: &AppState,
     object_reference_id: api_models::webhooks::ObjectReferenceId,
@@ -412,10 +568,10 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     merchant_account: domain::MerchantAccount,
     business_profile: diesel_models::business_profile::BusinessProfile,
     webhook_details: api::IncomingWebhookDetails,
-    source_verified: bool,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
     event_type: api_models::webhooks::IncomingWebhookEvent,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
-    if source_verified {
+    if source_verification_status.is_verified() {
         let db = &*state.store;
         let mandate = match webhook_details.object_reference_id {
             webhooks::ObjectReferenceId::MandateId(webhooks::MandateIdType::MandateId(
@@ -469,6 +625,7 @@ pub async fn mandates_incoming_webhook_flow<W: types::OutgoingWebhookType>(
                 updated_mandate.mandate_id.clone(),
                 enums::EventObjectType::MandateDetails,
                 api::OutgoingWebhookContent::MandateDetails(mandates_response),
+                Some(source_verification_status),
             )
             .await?;
         }
@@ -489,13 +646,13 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
     merchant_account: domain::MerchantAccount,
     business_profile: diesel_models::business_profile::BusinessProfile,
     webhook_details: api::IncomingWebhookDetails,
-    source_verified: bool,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
     connector: &(dyn api::Connector + Sync),
     request_details: &api::IncomingWebhookRequestDetails<'_>,
     event_type: api_models::webhooks::IncomingWebhookEvent,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     metrics::INCOMING_DISPUTE_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
-    if source_verified {
+    if source_verification_status.is_verified() {
         let db = &*state.store;
         let dispute_details = connector.get_dispute_details(request_details).switch()?;
         let payment_attempt = get_payment_attempt_from_object_reference_id(
@@ -535,6 +692,7 @@ pub async fn disputes_incoming_webhook_flow<W: types::OutgoingWebhookType>(
             dispute_object.dispute_id.clone(),
             enums::EventObjectType::DisputeDetails,
             api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+            Some(source_verification_status),
         )
         .await?;
         metrics::INCOMING_DISPUTE_WEBHOOK_MERCHANT_NOTIFIED_METRIC.add(&metrics::CONTEXT, 1, &[]);
@@ -555,9 +713,9 @@ async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType, Ctx: PaymentM
     business_profile: diesel_models::business_profile::BusinessProfile,
     key_store: domain::MerchantKeyStore,
     webhook_details: api::IncomingWebhookDetails,
-    source_verified: bool,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
-    let response = if source_verified {
+    let response = if source_verification_status.is_verified() {
         let payment_attempt = get_payment_attempt_from_object_reference_id(
             &state,
             webhook_details.object_reference_id,
@@ -614,6 +772,7 @@ async fn bank_transfer_webhook_flow<W: types::OutgoingWebhookType, Ctx: PaymentM
                     payment_id.clone(),
                     enums::EventObjectType::PaymentDetails,
                     api::OutgoingWebhookContent::PaymentDetails(payments_response),
+                    Some(source_verification_status),
                 )
                 .await?;
             }
@@ -640,37 +799,44 @@ pub async fn create_event_and_trigger_appropriate_outgoing_webhook(
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    match merchant_account.get_compatible_connector() {
-        #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => {
-            create_event_and_trigger_outgoing_webhook::<stripe_webhooks::StripeOutgoingWebhook>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                event_type,
-                event_class,
-                intent_reference_id,
-                primary_object_id,
-                primary_object_type,
-                content,
-            )
-            .await
-        }
-        _ => {
-            create_event_and_trigger_outgoing_webhook::<api_models::webhooks::OutgoingWebhook>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                event_type,
-                event_class,
-                intent_reference_id,
-                primary_object_id,
-                primary_object_type,
-                content,
-            )
-            .await
-        }
-    }
+    create_event_and_trigger_outgoing_webhook::<api::OutgoingWebhook>(
+        state,
+        merchant_account,
+        business_profile,
+        event_type,
+        event_class,
+        intent_reference_id,
+        primary_object_id,
+        primary_object_type,
+        content,
+        None,
+    )
+    .await
+}
+
+/// Returns the format in which the merchant receives outgoing webhooks. The format in the webhook
+/// details of the business profile takes precedence, merchants with Stripe as the compatible
+/// connector receive Stripe events otherwise.
+fn get_outgoing_webhook_format(
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+) -> Option<api_models::enums::OutgoingWebhookFormat> {
+    business_profile
+        .webhook_details
+        .clone()
+        .and_then(|webhook_details| {
+            webhook_details
+                .parse_value::<api::WebhookDetails>("WebhookDetails")
+                .map_err(|error| logger::error!(webhook_details_parsing_failure=?error))
+                .ok()
+        })
+        .and_then(|webhook_details| webhook_details.webhook_format)
+        .or_else(|| match merchant_account.get_compatible_connector() {
+            Some(api_models::enums::Connector::Stripe) => {
+                Some(api_models::enums::OutgoingWebhookFormat::Stripe)
+            }
+            _ => None,
+        })
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -685,6 +851,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
     primary_object_id: String,
     primary_object_type: enums::EventObjectType,
     content: api::OutgoingWebhookContent,
+    source_verification_status: Option<enums::WebhookSourceVerificationStatus>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let event_id = format!("{primary_object_id}_{}", event_type);
     let new_event = storage::EventNew {
@@ -695,6 +862,7 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
         intent_reference_id,
         primary_object_id,
         primary_object_type,
+        source_verification_status,
     };
 
     let event_insert_result = state.store.insert_event(new_event).await;
@@ -723,14 +891,34 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
             timestamp: event.created_at,
         };
 
+        let webhook_format = get_outgoing_webhook_format(&merchant_account, &business_profile);
+
         // Using a tokio spawn here and not arbiter because not all caller of this function
         // may have an actix arbiter
         tokio::spawn(async move {
-            let result =
-                trigger_webhook_to_merchant::<W>(business_profile, outgoing_webhook, &state).await;
-
-            if let Err(e) = result {
-                logger::error!(?e);
+            // The format of the merchant takes precedence over the one of the flow, which
+            // depends on the route the incoming webhook was received at
+            match webhook_format {
+                #[cfg(feature = "stripe")]
+                Some(api_models::enums::OutgoingWebhookFormat::Stripe) => {
+                    trigger_outgoing_webhooks::<stripe_webhooks::StripeOutgoingWebhook>(
+                        business_profile,
+                        outgoing_webhook,
+                        &state,
+                    )
+                    .await
+                }
+                Some(api_models::enums::OutgoingWebhookFormat::Standard) => {
+                    trigger_outgoing_webhooks::<api::OutgoingWebhook>(
+                        business_profile,
+                        outgoing_webhook,
+                        &state,
+                    )
+                    .await
+                }
+                _ => {
+                    trigger_outgoing_webhooks::<W>(business_profile, outgoing_webhook, &state).await
+                }
             }
         });
     }
@@ -738,6 +926,65 @@ pub async fn create_event_and_trigger_outgoing_webhook<W: types::OutgoingWebhook
     Ok(())
 }
 
+/// Sends the webhook to the webhook endpoints of the business profile subscribed to the event
+/// type. The webhook url of the business profile keeps receiving every event, unless the
+/// merchant has moved to webhook endpoints and removed it.
+async fn trigger_outgoing_webhooks<W: types::OutgoingWebhookType>(
+    business_profile: diesel_models::business_profile::BusinessProfile,
+    webhook: api::OutgoingWebhook,
+    state: &AppState,
+) {
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_profile_id(&business_profile.profile_id)
+        .await
+        .map_err(|error| logger::error!(webhook_endpoints_fetch_failure=?error))
+        .unwrap_or_default();
+
+    let has_webhook_url = business_profile
+        .webhook_details
+        .clone()
+        .and_then(|webhook_details| {
+            webhook_details
+                .parse_value::<api::WebhookDetails>("WebhookDetails")
+                .ok()
+        })
+        .and_then(|webhook_details| webhook_details.webhook_url)
+        .is_some();
+
+    let enabled_webhook_endpoints = webhook_endpoints
+        .iter()
+        .filter(|webhook_endpoint| {
+            endpoints::is_event_enabled(webhook_endpoint, webhook.event_type)
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if has_webhook_url || webhook_endpoints.is_empty() {
+        let result =
+            trigger_webhook_to_merchant::<W>(business_profile.clone(), webhook.clone(), state)
+                .await;
+
+        if let Err(e) = result {
+            logger::error!(?e);
+        }
+    }
+
+    if !enabled_webhook_endpoints.is_empty() {
+        let result = endpoints::trigger_webhook_to_endpoints::<W>(
+            state,
+            &business_profile,
+            enabled_webhook_endpoints,
+            webhook,
+        )
+        .await;
+
+        if let Err(e) = result {
+            logger::error!(?e);
+        }
+    }
+}
+
 pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
     business_profile: diesel_models::business_profile::BusinessProfile,
     webhook: api::OutgoingWebhook,
@@ -745,6 +992,7 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_details_json = business_profile
         .webhook_details
+        .clone()
         .get_required_value("webhook_details")
         .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)?;
 
@@ -782,7 +1030,7 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         W::add_webhook_header(&mut header, signature)
     }
 
-    let request = services::RequestBuilder::new()
+    let mut request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
         .attach_default_headers()
@@ -790,6 +1038,16 @@ pub async fn trigger_webhook_to_merchant<W: types::OutgoingWebhookType>(
         .body(Some(transformed_outgoing_webhook_string))
         .build();
 
+    let versioned_signature = match request.payload.as_ref() {
+        Some(payload) => {
+            signing::get_webhook_signature_header(state, &business_profile, payload.peek()).await?
+        }
+        None => None,
+    };
+    if let Some(signature) = versioned_signature {
+        request.add_header(webhook_signature::SIGNATURE_HEADER, signature.into());
+    }
+
     let response = state
         .api_client
         .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
@@ -854,13 +1112,21 @@ pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodR
     connector_name_or_mca_id: &str,
     body: actix_web::web::Bytes,
 ) -> RouterResponse<serde_json::Value> {
+    let request_details = api::IncomingWebhookRequestDetails {
+        method: req.method().clone(),
+        uri: req.uri().clone(),
+        headers: req.headers(),
+        query_params: req.query_string().to_string(),
+        body: &body,
+    };
+
     let (application_response, _webhooks_response_tracker) = webhooks_core::<W, Ctx>(
         state,
-        req,
+        request_details,
         merchant_account,
         key_store,
         connector_name_or_mca_id,
-        body,
+        false,
     )
     .await?;
 
@@ -868,14 +1134,13 @@ pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodR
 }
 
 #[instrument(skip_all)]
-
 pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
     state: AppState,
-    req: &actix_web::HttpRequest,
+    request_details: api::IncomingWebhookRequestDetails<'_>,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
     connector_name_or_mca_id: &str,
-    body: actix_web::web::Bytes,
+    is_reprocessing: bool,
 ) -> errors::RouterResult<(
     services::ApplicationResponse<serde_json::Value>,
     WebhookResponseTracker,
@@ -888,14 +1153,6 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             merchant_account.merchant_id.clone(),
         )],
     );
-    let mut request_details = api::IncomingWebhookRequestDetails {
-        method: req.method().clone(),
-        uri: req.uri().clone(),
-        headers: req.headers(),
-        query_params: req.query_string().to_string(),
-        body: &body,
-    };
-
     // Fetch the merchant connector account to get the webhooks source secret
     // `webhooks source secret` is a secret shared between the merchant and connector
     // This is used for source verification and webhooks integrity
@@ -911,6 +1168,7 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
     let connector_name = merchant_connector_account.clone().connector_name;
 
     let connector = connector.connector;
+    let received_body = request_details.body;
 
     let decoded_body = connector
         .decode_webhook_body(
@@ -922,7 +1180,10 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
         .switch()
         .attach_printable("There was an error in incoming webhook body decoding")?;
 
-    request_details.body = &decoded_body;
+    let request_details = api::IncomingWebhookRequestDetails {
+        body: &decoded_body,
+        ..request_details
+    };
 
     let event_type = match connector
         .get_webhook_event_type(&request_details)
@@ -998,66 +1259,29 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             .attach_printable_lazy(|| {
                 format!("unable to parse connector name {connector_name:?}")
             })?;
-        let connectors_with_source_verification_call = &state.conf.webhook_source_verification_call;
+        let source_verification_status = source_verification::verify_webhook_source(
+            &state,
+            *connector,
+            connector_enum,
+            &merchant_account,
+            merchant_connector_account.clone(),
+            &connector_name,
+            &request_details,
+        )
+        .await?;
 
-        let source_verified = if connectors_with_source_verification_call
-            .connectors_with_webhook_source_verification_call
-            .contains(&connector_enum)
+        if !source_verification_status.is_verified()
+            && (connector.is_webhook_source_verification_mandatory()
+                || source_verification::is_source_verification_enforced(
+                    &merchant_connector_account,
+                )?)
         {
-            connector
-                .verify_webhook_source_verification_call(
-                    &state,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    &connector_name,
-                    &request_details,
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        } else {
-            connector
-                .verify_webhook_source(
-                    &request_details,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    connector_name.as_str(),
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        };
-
-        if source_verified {
-            metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
-                &metrics::CONTEXT,
-                1,
-                &[metrics::KeyValue::new(
-                    MERCHANT_ID,
-                    merchant_account.merchant_id.clone(),
-                )],
-            );
-        } else if connector.is_webhook_source_verification_mandatory() {
-            // if webhook consumption is mandatory for connector, fail webhook
-            // so that merchant can retrigger it after updating merchant_secret
+            // if webhook source verification is mandatory for the connector or enforced by the
+            // merchant, fail webhook so that merchant can retrigger it after updating merchant_secret
             return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
         }
 
-        logger::info!(source_verified=?source_verified);
+        logger::info!(source_verification_status=?source_verification_status);
 
         let event_object = connector
             .get_webhook_resource_object(&request_details)
@@ -1088,72 +1312,150 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
                 id: profile_id.to_string(),
             })?;
 
-        match flow_type {
-            api::WebhookFlow::Payment => payments_incoming_webhook_flow::<W, Ctx>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
+        // A reprocessed webhook has been received before, and its signature timestamp is stale
+        let replay_check = if is_reprocessing {
+            source_verification::WebhookReplayCheck::Fresh(None)
+        } else {
+            source_verification::check_webhook_replay(
+                &state,
+                *connector,
+                &merchant_account.merchant_id,
+                &connector_name,
+                &request_details,
+                source_verification_status,
             )
-            .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            .await?
+        };
+        let replay_protection_key = match replay_check {
+            source_verification::WebhookReplayCheck::Fresh(replay_protection_key) => {
+                replay_protection_key
+            }
+            source_verification::WebhookReplayCheck::Duplicate => {
+                let response = connector
+                    .get_webhook_api_response(&request_details)
+                    .switch()
+                    .attach_printable("Failed while early return in case of duplicate webhook")?;
 
-            api::WebhookFlow::Refund => refunds_incoming_webhook_flow::<W>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                connector_name.as_str(),
-                source_verified,
-                event_type,
-            )
-            .await
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+                return Ok((response, WebhookResponseTracker::NoEffect));
+            }
+            source_verification::WebhookReplayCheck::Stale => {
+                return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+            }
+        };
 
-            api::WebhookFlow::Dispute => disputes_incoming_webhook_flow::<W>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                webhook_details,
-                source_verified,
-                *connector,
+        // A reprocessed webhook is stored already
+        let incoming_webhook = if is_reprocessing {
+            None
+        } else {
+            incoming::store_incoming_webhook(
+                &state,
+                &merchant_account,
+                &key_store,
+                connector_name_or_mca_id,
                 &request_details,
-                event_type,
+                received_body,
+                source_verification_status,
             )
             .await
-            .attach_printable("Incoming webhook flow for disputes failed")?,
+        };
 
-            api::WebhookFlow::BankTransfer => bank_transfer_webhook_flow::<W, Ctx>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                key_store,
-                webhook_details,
-                source_verified,
-            )
-            .await
-            .attach_printable("Incoming bank-transfer webhook flow failed")?,
+        let flow_result: errors::RouterResult<WebhookResponseTracker> = async {
+            Ok(match flow_type {
+                api::WebhookFlow::Payment => payments_incoming_webhook_flow::<W, Ctx>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verification_status,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for payments failed")?,
 
-            api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+                api::WebhookFlow::Refund => refunds_incoming_webhook_flow::<W>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    connector_name.as_str(),
+                    source_verification_status,
+                    event_type,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for refunds failed")?,
 
-            api::WebhookFlow::Mandate => mandates_incoming_webhook_flow::<W>(
-                state.clone(),
-                merchant_account,
-                business_profile,
-                webhook_details,
-                source_verified,
-                event_type,
-            )
-            .await
-            .attach_printable("Incoming webhook flow for mandates failed")?,
+                api::WebhookFlow::Dispute => disputes_incoming_webhook_flow::<W>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    webhook_details,
+                    source_verification_status,
+                    *connector,
+                    &request_details,
+                    event_type,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for disputes failed")?,
 
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .into_report()
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+                api::WebhookFlow::BankTransfer => bank_transfer_webhook_flow::<W, Ctx>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verification_status,
+                )
+                .await
+                .attach_printable("Incoming bank-transfer webhook flow failed")?,
+
+                api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+
+                #[cfg(feature = "payouts")]
+                api::WebhookFlow::Payout => payouts_incoming_webhook_flow::<W>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    webhook_details,
+                    source_verification_status,
+                    event_type,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for payouts failed")?,
+
+                api::WebhookFlow::Mandate => mandates_incoming_webhook_flow::<W>(
+                    state.clone(),
+                    merchant_account,
+                    business_profile,
+                    webhook_details,
+                    source_verification_status,
+                    event_type,
+                )
+                .await
+                .attach_printable("Incoming webhook flow for mandates failed")?,
+
+                _ => Err(errors::ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+            })
+        }
+        .await;
+
+        if let (Err(_), Some(replay_protection_key)) = (&flow_result, &replay_protection_key) {
+            source_verification::release_replay_protection_key(&state, replay_protection_key).await;
         }
+
+        if let Some(incoming_webhook) = incoming_webhook {
+            incoming::update_incoming_webhook_outcome(
+                &state,
+                incoming_webhook,
+                flow_result.as_ref(),
+            )
+            .await;
+        }
+
+        flow_result?
     } else {
         metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
             &metrics::CONTEXT,
@@ -0,0 +1,668 @@
+use std::collections::HashMap;
+
+use api_models::webhook_endpoints as endpoint_types;
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+    webhook_signature::{self, WebhookSignatureAlgorithm, WebhookSignatureHeader},
+};
+use diesel_models::business_profile::BusinessProfile;
+use error_stack::{IntoReport, ResultExt};
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::{signing, types::OutgoingWebhookType, MERCHANT_ID, OUTGOING_WEBHOOK_TIMEOUT_SECS};
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        metrics,
+    },
+    headers, logger,
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{
+        self as router_types, api,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+const WEBHOOK_ENDPOINT_ID_PREFIX: &str = "whe";
+const WEBHOOK_DELIVERY_ID_PREFIX: &str = "whd";
+const LOWER_LIMIT: i64 = 1;
+const UPPER_LIMIT: i64 = 100;
+const DEFAULT_LIMIT: i64 = 10;
+
+/// Headers set on every outgoing webhook, which cannot be overridden by the custom headers
+const RESERVED_HEADERS: [&str; 5] = [
+    "content-type",
+    "via",
+    headers::X_WEBHOOK_SIGNATURE,
+    headers::STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE,
+    webhook_signature::SIGNATURE_HEADER,
+];
+
+fn get_enabled_events(
+    webhook_endpoint: &storage::WebhookEndpoint,
+) -> CustomResult<Option<Vec<enums::EventType>>, errors::ParsingError> {
+    webhook_endpoint
+        .enabled_events
+        .clone()
+        .map(|enabled_events| enabled_events.parse_value("Vec<EventType>"))
+        .transpose()
+}
+
+fn get_headers(
+    webhook_endpoint: &storage::WebhookEndpoint,
+) -> CustomResult<HashMap<String, Secret<String>>, errors::ParsingError> {
+    webhook_endpoint
+        .headers
+        .clone()
+        .map(|headers| {
+            headers
+                .expose()
+                .parse_value("HashMap<String, Secret<String>>")
+        })
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Checks whether the webhook endpoint is enabled and subscribed to the event type
+pub fn is_event_enabled(
+    webhook_endpoint: &storage::WebhookEndpoint,
+    event_type: enums::EventType,
+) -> bool {
+    if webhook_endpoint.disabled {
+        return false;
+    }
+
+    match get_enabled_events(webhook_endpoint) {
+        Ok(enabled_events) => {
+            enabled_events.map_or(true, |enabled_events| enabled_events.contains(&event_type))
+        }
+        Err(error) => {
+            logger::error!(
+                webhook_endpoint_id = %webhook_endpoint.endpoint_id,
+                enabled_events_parsing_failure = ?error
+            );
+            false
+        }
+    }
+}
+
+fn validate_headers(headers: &HashMap<String, Secret<String>>) -> RouterResult<()> {
+    for (name, value) in headers {
+        if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+            || reqwest::header::HeaderValue::from_str(value.peek()).is_err()
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Custom header {name} is not a valid HTTP header"),
+            })?
+        }
+
+        if RESERVED_HEADERS
+            .iter()
+            .any(|reserved_header| reserved_header.eq_ignore_ascii_case(name))
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Custom header {name} is set on every webhook"),
+            })?
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_enabled_events(enabled_events: Vec<enums::EventType>) -> RouterResult<serde_json::Value> {
+    if enabled_events.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "enabled_events should contain at least one event type".to_string(),
+        })?
+    }
+
+    Encode::<Vec<enums::EventType>>::encode_to_value(&enabled_events)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the enabled events of the webhook endpoint")
+}
+
+fn encode_headers(
+    headers: HashMap<String, Secret<String>>,
+) -> RouterResult<common_utils::pii::SecretSerdeValue> {
+    validate_headers(&headers)?;
+
+    Encode::<HashMap<String, Secret<String>>>::encode_to_value(&headers)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the headers of the webhook endpoint")
+        .map(Secret::new)
+}
+
+fn get_webhook_endpoint_response(
+    webhook_endpoint: storage::WebhookEndpoint,
+    include_secret: bool,
+) -> RouterResult<endpoint_types::WebhookEndpointResponse> {
+    let enabled_events = get_enabled_events(&webhook_endpoint)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the enabled events of the webhook endpoint")?;
+    let mut headers = get_headers(&webhook_endpoint)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the headers of the webhook endpoint")?
+        .into_keys()
+        .collect::<Vec<_>>();
+    headers.sort();
+
+    Ok(endpoint_types::WebhookEndpointResponse {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        profile_id: webhook_endpoint.profile_id,
+        url: webhook_endpoint.url,
+        description: webhook_endpoint.description,
+        enabled_events,
+        headers,
+        secret: include_secret.then_some(webhook_endpoint.secret),
+        disabled: webhook_endpoint.disabled,
+        created_at: webhook_endpoint.created_at,
+        modified_at: webhook_endpoint.modified_at,
+    })
+}
+
+async fn find_webhook_endpoint(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+) -> RouterResult<storage::WebhookEndpoint> {
+    let business_profile = signing::find_business_profile(state, merchant_id, profile_id).await?;
+
+    state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(&business_profile.profile_id, endpoint_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook endpoint {endpoint_id} does not exist"),
+        })
+}
+
+pub async fn create_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: endpoint_types::WebhookEndpointCreateRequest,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let business_profile = signing::find_business_profile(&state, merchant_id, profile_id).await?;
+    let current_time = date_time::now();
+
+    let webhook_endpoint = storage::WebhookEndpointNew {
+        endpoint_id: utils::generate_id(consts::ID_LENGTH, WEBHOOK_ENDPOINT_ID_PREFIX),
+        merchant_id: business_profile.merchant_id,
+        profile_id: business_profile.profile_id,
+        url: request.url.to_string(),
+        description: request.description,
+        enabled_events: request
+            .enabled_events
+            .map(encode_enabled_events)
+            .transpose()?,
+        headers: request.headers.map(encode_headers).transpose()?,
+        secret: Secret::new(signing::generate_hmac_secret()),
+        disabled: false,
+        created_at: current_time,
+        modified_at: current_time,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .insert_webhook_endpoint(webhook_endpoint)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook endpoint")?;
+
+    get_webhook_endpoint_response(webhook_endpoint, true).map(ApplicationResponse::Json)
+}
+
+pub async fn list_webhook_endpoints(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<Vec<endpoint_types::WebhookEndpointResponse>> {
+    let business_profile = signing::find_business_profile(&state, merchant_id, &profile_id).await?;
+
+    state
+        .store
+        .list_webhook_endpoints_by_profile_id(&business_profile.profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook endpoints")?
+        .into_iter()
+        .map(|webhook_endpoint| get_webhook_endpoint_response(webhook_endpoint, false))
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn retrieve_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: String,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, merchant_id, profile_id, &endpoint_id).await?;
+
+    get_webhook_endpoint_response(webhook_endpoint, false).map(ApplicationResponse::Json)
+}
+
+pub async fn update_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    request: endpoint_types::WebhookEndpointUpdateRequest,
+) -> RouterResponse<endpoint_types::WebhookEndpointResponse> {
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, merchant_id, profile_id, endpoint_id).await?;
+
+    let endpoint_update = storage::WebhookEndpointUpdate::Update {
+        url: request.url.map(|url| url.to_string()),
+        description: request.description,
+        enabled_events: request
+            .enabled_events
+            .map(encode_enabled_events)
+            .transpose()?,
+        headers: request.headers.map(encode_headers).transpose()?,
+        disabled: request.disabled,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint(webhook_endpoint, endpoint_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update webhook endpoint")?;
+
+    get_webhook_endpoint_response(webhook_endpoint, false).map(ApplicationResponse::Json)
+}
+
+pub async fn delete_webhook_endpoint(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: String,
+) -> RouterResponse<bool> {
+    let business_profile = signing::find_business_profile(&state, merchant_id, profile_id).await?;
+
+    state
+        .store
+        .delete_webhook_endpoint_by_profile_id_endpoint_id(
+            &business_profile.profile_id,
+            &endpoint_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook endpoint {endpoint_id} does not exist"),
+        })
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn list_webhook_deliveries(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    endpoint_id: &str,
+    constraints: endpoint_types::WebhookDeliveryListConstraints,
+) -> RouterResponse<endpoint_types::WebhookDeliveryListResponse> {
+    let limit = match constraints.limit {
+        Some(limit) if !(LOWER_LIMIT..=UPPER_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between {LOWER_LIMIT} and {UPPER_LIMIT}"),
+            })
+            .into_report()?
+        }
+        Some(limit) => limit,
+        None => DEFAULT_LIMIT,
+    };
+    let webhook_endpoint =
+        find_webhook_endpoint(&state, merchant_id, profile_id, endpoint_id).await?;
+
+    let data = state
+        .store
+        .list_webhook_deliveries_by_endpoint_id(
+            &webhook_endpoint.endpoint_id,
+            limit,
+            constraints.offset.unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list webhook deliveries")?
+        .into_iter()
+        .map(|delivery| endpoint_types::WebhookDeliveryResponse {
+            delivery_id: delivery.delivery_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type,
+            endpoint_id: delivery.endpoint_id,
+            status: delivery.status,
+            response_status_code: delivery.response_status_code,
+            error_message: delivery.error_message,
+            created_at: delivery.created_at,
+            modified_at: delivery.modified_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        endpoint_types::WebhookDeliveryListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Builds the webhook request for the endpoint. The webhook is signed with the secret of the
+/// endpoint, both in the format of the outgoing webhook and in the versioned format.
+fn build_webhook_request<W: OutgoingWebhookType>(
+    webhook_endpoint: &storage::WebhookEndpoint,
+    transformed_outgoing_webhook: &W,
+) -> CustomResult<services::Request, errors::WebhooksFlowError> {
+    let secret = webhook_endpoint.secret.peek();
+
+    let mut header = get_headers(webhook_endpoint)
+        .change_context(errors::WebhooksFlowError::MerchantWebhookDetailsNotFound)
+        .attach_printable("Failed to parse the headers of the webhook endpoint")?
+        .into_iter()
+        .map(|(name, value)| (name, value.expose().into_masked()))
+        .collect::<Vec<_>>();
+    header.push((
+        reqwest::header::CONTENT_TYPE.to_string(),
+        "application/json".into(),
+    ));
+
+    if let Some(signature) =
+        transformed_outgoing_webhook.get_outgoing_webhooks_signature(Some(secret.clone()))?
+    {
+        W::add_webhook_header(&mut header, signature)
+    }
+
+    let webhook_body = router_types::RequestBody::log_and_get_request_body(
+        transformed_outgoing_webhook,
+        Encode::<serde_json::Value>::encode_to_string_of_json,
+    )
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+    .attach_printable("There was an issue when encoding the outgoing webhook body")?;
+
+    let mut request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&webhook_endpoint.url)
+        .attach_default_headers()
+        .headers(header)
+        .body(Some(webhook_body))
+        .build();
+
+    let versioned_signature = request
+        .payload
+        .as_ref()
+        .map(|payload| {
+            WebhookSignatureHeader::sign(
+                payload.peek().as_bytes(),
+                date_time::now_unix_timestamp(),
+                [(WebhookSignatureAlgorithm::HmacSha256, secret.as_bytes())],
+            )
+        })
+        .transpose()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the outgoing webhook")?;
+    if let Some(signature) = versioned_signature {
+        request.add_header(
+            webhook_signature::SIGNATURE_HEADER,
+            signature.to_string().into(),
+        );
+    }
+
+    Ok(request)
+}
+
+#[instrument(skip_all, fields(webhook_endpoint_id = %webhook_endpoint.endpoint_id))]
+async fn trigger_webhook_to_endpoint<W: OutgoingWebhookType>(
+    state: &AppState,
+    webhook_endpoint: &storage::WebhookEndpoint,
+    webhook: &api::OutgoingWebhook,
+    transformed_outgoing_webhook: &W,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let current_time = date_time::now();
+    let delivery = state
+        .store
+        .insert_webhook_delivery(storage::WebhookDeliveryNew {
+            delivery_id: utils::generate_id(consts::ID_LENGTH, WEBHOOK_DELIVERY_ID_PREFIX),
+            event_id: webhook.event_id.clone(),
+            event_type: webhook.event_type,
+            endpoint_id: webhook_endpoint.endpoint_id.clone(),
+            merchant_id: webhook_endpoint.merchant_id.clone(),
+            status: enums::WebhookDeliveryStatus::Pending,
+            response_status_code: None,
+            error_message: None,
+            created_at: current_time,
+            modified_at: current_time,
+        })
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryCreationFailed)?;
+
+    let merchant_id_tag = [metrics::KeyValue::new(
+        MERCHANT_ID,
+        webhook_endpoint.merchant_id.clone(),
+    )];
+    let response = match build_webhook_request(webhook_endpoint, transformed_outgoing_webhook) {
+        Ok(request) => {
+            metrics::WEBHOOK_OUTGOING_COUNT.add(&metrics::CONTEXT, 1, &merchant_id_tag);
+            state
+                .api_client
+                .send_request(state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
+                .await
+                .map_err(|error| error.current_context().to_string())
+        }
+        Err(error) => Err(error.current_context().to_string()),
+    };
+    logger::debug!(outgoing_webhook_response=?response);
+
+    let (delivery_update, result) = match response {
+        Ok(response) => {
+            let response_status_code = i16::try_from(response.status().as_u16()).ok();
+            if response.status().is_success() {
+                metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &merchant_id_tag,
+                );
+                (
+                    storage::WebhookDeliveryUpdate::Delivered {
+                        response_status_code: response_status_code.unwrap_or_default(),
+                    },
+                    Ok(()),
+                )
+            } else {
+                metrics::WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &merchant_id_tag,
+                );
+                (
+                    storage::WebhookDeliveryUpdate::Failed {
+                        response_status_code,
+                        error_message: errors::WebhooksFlowError::NotReceivedByMerchant.to_string(),
+                    },
+                    Err(errors::WebhooksFlowError::NotReceivedByMerchant),
+                )
+            }
+        }
+        Err(error_message) => (
+            storage::WebhookDeliveryUpdate::Failed {
+                response_status_code: None,
+                error_message,
+            },
+            Err(errors::WebhooksFlowError::CallToMerchantFailed),
+        ),
+    };
+
+    state
+        .store
+        .update_webhook_delivery(delivery, delivery_update)
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookDeliveryUpdationFailed)?;
+
+    result.into_report()
+}
+
+/// Sends the webhook to every webhook endpoint, tracking the delivery to each endpoint
+/// separately. The event is marked as notified once every endpoint has received the webhook.
+pub async fn trigger_webhook_to_endpoints<W: OutgoingWebhookType>(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    webhook_endpoints: Vec<storage::WebhookEndpoint>,
+    webhook: api::OutgoingWebhook,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let transformed_outgoing_webhook = W::from(webhook.clone());
+
+    let delivery_results =
+        futures::future::join_all(webhook_endpoints.iter().map(|webhook_endpoint| {
+            trigger_webhook_to_endpoint(
+                state,
+                webhook_endpoint,
+                &webhook,
+                &transformed_outgoing_webhook,
+            )
+        }))
+        .await;
+
+    let mut all_delivered = true;
+    for error in delivery_results.into_iter().filter_map(Result::err) {
+        logger::error!(merchant_id = %business_profile.merchant_id, webhook_delivery_failure = ?error);
+        all_delivered = false;
+    }
+
+    if !all_delivered {
+        // [#217]: Schedule webhook for retry.
+        Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?;
+    }
+
+    state
+        .store
+        .update_event(
+            webhook.event_id,
+            storage::EventUpdate::UpdateWebhookNotified {
+                is_webhook_notified: Some(true),
+            },
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    fn webhook_endpoint(
+        enabled_events: Option<Vec<enums::EventType>>,
+        disabled: bool,
+    ) -> storage::WebhookEndpoint {
+        let current_time = date_time::now();
+        storage::WebhookEndpoint {
+            endpoint_id: "whe_test".to_string(),
+            merchant_id: "merchant_test".to_string(),
+            profile_id: "pro_test".to_string(),
+            url: "https://merchant.com/webhooks".to_string(),
+            description: None,
+            enabled_events: enabled_events
+                .map(|enabled_events| encode_enabled_events(enabled_events).expect("Events")),
+            headers: Some(
+                encode_headers(HashMap::from([(
+                    "x-service-token".to_string(),
+                    Secret::new("token".to_string()),
+                )]))
+                .expect("Headers"),
+            ),
+            secret: Secret::new(signing::generate_hmac_secret()),
+            disabled,
+            created_at: current_time,
+            modified_at: current_time,
+        }
+    }
+
+    #[test]
+    fn test_only_enabled_events_are_sent_to_endpoint() {
+        let refunds_endpoint = webhook_endpoint(
+            Some(vec![
+                enums::EventType::RefundSucceeded,
+                enums::EventType::RefundFailed,
+            ]),
+            false,
+        );
+        assert!(is_event_enabled(
+            &refunds_endpoint,
+            enums::EventType::RefundFailed
+        ));
+        assert!(!is_event_enabled(
+            &refunds_endpoint,
+            enums::EventType::PaymentSucceeded
+        ));
+
+        let all_events_endpoint = webhook_endpoint(None, false);
+        assert!(is_event_enabled(
+            &all_events_endpoint,
+            enums::EventType::DisputeOpened
+        ));
+
+        let disabled_endpoint = webhook_endpoint(None, true);
+        assert!(!is_event_enabled(
+            &disabled_endpoint,
+            enums::EventType::DisputeOpened
+        ));
+    }
+
+    #[test]
+    fn test_reserved_and_invalid_headers_are_rejected() {
+        for name in ["Content-Type", "x-webhook-signature-512", "invalid header"] {
+            assert!(validate_headers(&HashMap::from([(
+                name.to_string(),
+                Secret::new("value".to_string()),
+            )]))
+            .is_err());
+        }
+        assert!(encode_enabled_events(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_webhook_request_is_signed_with_endpoint_secret() {
+        let endpoint = webhook_endpoint(None, false);
+        let webhook = api::OutgoingWebhook {
+            merchant_id: "merchant_test".to_string(),
+            event_id: "pay_test_payment_succeeded".to_string(),
+            event_type: enums::EventType::PaymentSucceeded,
+            content: api::OutgoingWebhookContent::PaymentDetails(Default::default()),
+            timestamp: date_time::now(),
+        };
+
+        let request = build_webhook_request(&endpoint, &webhook).expect("Webhook request");
+        let body = request.payload.clone().expect("Webhook body");
+        let header_value = |name: &str| {
+            request
+                .headers
+                .iter()
+                .find(|(header, _)| header == name)
+                .map(|(_, value)| value.clone().into_inner())
+                .expect("Header")
+        };
+
+        assert_eq!(header_value("x-service-token"), "token");
+        assert!(request
+            .headers
+            .iter()
+            .any(|(header, _)| header == headers::X_WEBHOOK_SIGNATURE));
+        assert!(webhook_signature::verify_webhook_signature(
+            &header_value(webhook_signature::SIGNATURE_HEADER),
+            body.peek().as_bytes(),
+            &[(
+                WebhookSignatureAlgorithm::HmacSha256,
+                endpoint.secret.peek().as_bytes(),
+            )],
+            webhook_signature::DEFAULT_TIMESTAMP_TOLERANCE_SECS,
+        )
+        .is_ok());
+    }
+}
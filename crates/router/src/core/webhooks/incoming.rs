@@ -0,0 +1,664 @@
+//! Stores incoming webhooks with their processing outcome, so that webhooks which could not be
+//! processed can be inspected, and reprocessed once the underlying issue is fixed.
+//!
+//! Webhooks are stored once their source has been verified, along with the outcome of the
+//! verification. Webhooks rejected by the verification or by the replay protection are not
+//! stored. The number of webhooks stored per merchant is limited per minute, and stored webhooks
+//! are deleted by a scheduler task after the retention period.
+//!
+//! The headers and the body of the webhook are stored with their sensitive values masked, for
+//! browsing. Bodies which are neither JSON nor form encoded can't be masked field by field, and
+//! are stored as text. The request as received is stored encrypted with the merchant key, and is
+//! used to reprocess the webhook.
+
+use api_models::webhooks::{self as webhook_types, WebhookResponseTracker};
+use base64::Engine;
+use error_stack::{report, IntoReport, Report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::types::OutgoingWebhookType;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::PaymentMethodRetrieve,
+    },
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        api,
+        domain::{self, types as domain_types},
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+const INCOMING_WEBHOOK_ID_PREFIX: &str = "whin";
+const LOWER_LIMIT: i64 = 1;
+const UPPER_LIMIT: i64 = 100;
+const DEFAULT_LIMIT: i64 = 10;
+const ERROR_MESSAGE_MAX_LENGTH: usize = 1024;
+const STORAGE_RATE_LIMIT_WINDOW_SECS: i64 = 60;
+const RETENTION_RUN_INTERVAL_DAYS: i64 = 1;
+pub const INCOMING_WEBHOOK_RETENTION_WORKFLOW: &str = "INCOMING_WEBHOOK_RETENTION_WORKFLOW";
+
+/// Headers whose values are stored unmasked
+const UNMASKED_HEADERS: [&str; 4] = ["content-type", "content-length", "user-agent", "host"];
+
+/// Fields of the webhook body whose values are masked, along with everything nested in them
+const MASKED_BODY_FIELDS: [&str; 22] = [
+    "card_number",
+    "number",
+    "cvc",
+    "cvv",
+    "card_cvc",
+    "security_code",
+    "expiry_month",
+    "expiry_year",
+    "exp_month",
+    "exp_year",
+    "password",
+    "api_key",
+    "secret",
+    "token",
+    "email",
+    "phone",
+    "phone_number",
+    "iban",
+    "account_number",
+    "routing_number",
+    "billing_address",
+    "shipping_address",
+];
+
+/// The incoming webhook request as received, from which the webhook is reprocessed
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredWebhookRequest {
+    method: String,
+    uri: String,
+    query_params: String,
+    headers: Vec<(String, String)>,
+    /// Base64 encoded body of the webhook
+    body: String,
+}
+
+/// Stores the incoming webhook before it is processed, `received_body` being the body before it
+/// was decoded. Failures are only logged, as the webhook is processed even if it could not be
+/// stored.
+pub(super) async fn store_incoming_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    received_body: &[u8],
+    source_verification_status: enums::WebhookSourceVerificationStatus,
+) -> Option<storage::IncomingWebhook> {
+    if !is_within_storage_limit(state, &merchant_account.merchant_id).await {
+        logger::warn!("Incoming webhook is not stored, as the storage limit has been reached");
+        return None;
+    }
+
+    let result: RouterResult<storage::IncomingWebhook> = async {
+        let connector_name =
+            get_connector_name(state, merchant_account, key_store, connector_name_or_mca_id).await;
+
+        let stored_request = StoredWebhookRequest {
+            method: request_details.method.to_string(),
+            uri: request_details.uri.to_string(),
+            query_params: request_details.query_params.clone(),
+            headers: request_details
+                .headers
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                    )
+                })
+                .collect(),
+            body: consts::BASE64_ENGINE.encode(received_body),
+        };
+        let raw_request = utils::Encode::<StoredWebhookRequest>::encode_to_value(&stored_request)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize incoming webhook request")?;
+
+        let now = common_utils::date_time::now();
+        let new_webhook = storage::IncomingWebhookNew {
+            webhook_id: utils::generate_id(consts::ID_LENGTH, INCOMING_WEBHOOK_ID_PREFIX),
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector_name_or_mca_id: connector_name_or_mca_id.to_string(),
+            connector_name,
+            headers: mask_headers(&stored_request.headers),
+            body: get_masked_body(&stored_request.headers, request_details.body),
+            raw_request: domain_types::encrypt(
+                Secret::<_, masking::WithType>::new(raw_request),
+                key_store.key.peek(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt incoming webhook request")?
+            .into(),
+            source_verification_status,
+            status: enums::IncomingWebhookStatus::Received,
+            error_message: None,
+            payment_id: None,
+            refund_id: None,
+            dispute_id: None,
+            created_at: now,
+            modified_at: now,
+        };
+
+        state
+            .store
+            .insert_incoming_webhook(new_webhook)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert incoming webhook")
+    }
+    .await;
+
+    result
+        .map_err(|error| logger::error!(?error, "Failed to store incoming webhook"))
+        .ok()
+}
+
+/// Whether the webhook can be stored without going over the number of webhooks stored for the
+/// merchant in the current window. The webhook is stored if the count could not be checked.
+async fn is_within_storage_limit(state: &AppState, merchant_id: &str) -> bool {
+    let key = format!("incoming_webhook_storage_count_{merchant_id}");
+    let stored_count = match state.store.get_redis_conn() {
+        Ok(redis) => redis
+            .increment_key_with_expiry(&key, STORAGE_RATE_LIMIT_WINDOW_SECS)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to count stored incoming webhooks"))
+            .ok(),
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            None
+        }
+    };
+
+    // Scheduling the retention task once per window spares a database write for every webhook
+    if stored_count == Some(1) {
+        schedule_retention_task(state, merchant_id).await;
+    }
+
+    stored_count.map_or(true, |stored_count| {
+        stored_count <= state.conf.webhooks.incoming_storage.max_stored_per_minute
+    })
+}
+
+pub fn get_next_retention_run_time() -> time::PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::days(RETENTION_RUN_INTERVAL_DAYS))
+}
+
+/// Schedules the task deleting the stored webhooks of the merchant, unless it is already scheduled
+async fn schedule_retention_task(state: &AppState, merchant_id: &str) {
+    let runner = INCOMING_WEBHOOK_RETENTION_WORKFLOW;
+    let task = "DELETE_INCOMING_WEBHOOKS";
+    let tracking_data = storage::IncomingWebhookRetentionTrackingData {
+        merchant_id: merchant_id.to_string(),
+    };
+    let tracking_data = match serde_json::to_value(tracking_data) {
+        Ok(tracking_data) => tracking_data,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to serialize incoming webhook retention tracking data"
+            );
+            return;
+        }
+    };
+    let current_time = common_utils::date_time::now();
+    let process_tracker_entry = storage::ProcessTrackerNew {
+        id: format!("{runner}_{task}_{merchant_id}"),
+        name: Some(String::from(task)),
+        tag: vec![String::from("WEBHOOK")],
+        runner: Some(String::from(runner)),
+        retry_count: 0,
+        schedule_time: Some(get_next_retention_run_time()),
+        rule: String::new(),
+        tracking_data,
+        business_status: String::from("Pending"),
+        status: enums::ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: current_time,
+        updated_at: current_time,
+    };
+
+    if let Err(error) = state.store.insert_process(process_tracker_entry).await {
+        if !error.current_context().is_db_unique_violation() {
+            logger::error!(?error, "Failed to schedule incoming webhook retention task");
+        }
+    }
+}
+
+/// Records the outcome of processing the incoming webhook, and the payment, refund or dispute
+/// which it was processed for
+pub(super) async fn update_incoming_webhook_outcome(
+    state: &AppState,
+    incoming_webhook: storage::IncomingWebhook,
+    webhook_tracker: Result<&WebhookResponseTracker, &Report<errors::ApiErrorResponse>>,
+) -> Option<storage::IncomingWebhook> {
+    let webhook_update = match webhook_tracker {
+        Ok(webhook_tracker) => {
+            let (refund_id, dispute_id) = match webhook_tracker {
+                WebhookResponseTracker::Refund { refund_id, .. } => (Some(refund_id.clone()), None),
+                WebhookResponseTracker::Dispute { dispute_id, .. } => {
+                    (None, Some(dispute_id.clone()))
+                }
+                WebhookResponseTracker::Payment { .. }
+                | WebhookResponseTracker::Mandate { .. }
+                | WebhookResponseTracker::Payout { .. }
+                | WebhookResponseTracker::NoEffect => (None, None),
+            };
+            storage::IncomingWebhookUpdate::Processed {
+                payment_id: webhook_tracker.get_payment_id(),
+                refund_id,
+                dispute_id,
+            }
+        }
+        Err(error) => storage::IncomingWebhookUpdate::Failed {
+            error_message: error
+                .current_context()
+                .to_string()
+                .chars()
+                .take(ERROR_MESSAGE_MAX_LENGTH)
+                .collect(),
+        },
+    };
+
+    state
+        .store
+        .update_incoming_webhook(incoming_webhook, webhook_update)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to update incoming webhook outcome"))
+        .ok()
+}
+
+#[instrument(skip_all)]
+pub async fn list_incoming_webhooks(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: webhook_types::IncomingWebhookListConstraints,
+) -> RouterResponse<webhook_types::IncomingWebhookListResponse> {
+    let limit = match constraints.limit {
+        Some(limit) if !(LOWER_LIMIT..=UPPER_LIMIT).contains(&limit) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between {LOWER_LIMIT} and {UPPER_LIMIT}"),
+            })
+            .into_report()?
+        }
+        Some(limit) => limit,
+        None => DEFAULT_LIMIT,
+    };
+
+    let data = state
+        .store
+        .list_incoming_webhooks_by_merchant_id(
+            &merchant_account.merchant_id,
+            limit,
+            constraints.offset.unwrap_or_default(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list incoming webhooks")?
+        .into_iter()
+        .map(foreign_into_response)
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        webhook_types::IncomingWebhookListResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_incoming_webhook(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    webhook_id: String,
+) -> RouterResponse<webhook_types::IncomingWebhookResponse> {
+    let incoming_webhook = find_incoming_webhook(&state, &merchant_account, &webhook_id).await?;
+
+    Ok(ApplicationResponse::Json(foreign_into_response(
+        incoming_webhook,
+    )))
+}
+
+/// Processes a stored incoming webhook which has failed or not been processed again through the
+/// incoming webhooks flow. Replay protection is skipped, as the webhook has been received before.
+#[instrument(skip_all)]
+pub async fn reprocess_incoming_webhook<Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_id: String,
+) -> RouterResponse<webhook_types::IncomingWebhookResponse> {
+    let incoming_webhook = find_incoming_webhook(&state, &merchant_account, &webhook_id).await?;
+
+    // The resource in a processed webhook may be outdated by now, applying it again could move the
+    // resource back to an older state
+    utils::when(
+        !matches!(
+            incoming_webhook.status,
+            enums::IncomingWebhookStatus::Failed | enums::IncomingWebhookStatus::Received
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only webhooks which have failed or not been processed can be reprocessed"
+                    .to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let stored_request = domain_types::decrypt::<serde_json::Value, masking::WithType>(
+        Some(incoming_webhook.raw_request.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt incoming webhook request")?
+    .get_required_value("raw_request")
+    .change_context(errors::ApiErrorResponse::InternalServerError)?
+    .into_inner()
+    .expose()
+    .parse_value::<StoredWebhookRequest>("StoredWebhookRequest")
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let headers = stored_request
+        .headers
+        .iter()
+        .try_fold(
+            actix_web::http::header::HeaderMap::new(),
+            |mut headers, (name, value)| {
+                let name = actix_web::http::header::HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|error| error.to_string())?;
+                let value = actix_web::http::header::HeaderValue::from_str(value)
+                    .map_err(|error| error.to_string())?;
+                headers.append(name, value);
+                Ok::<_, String>(headers)
+            },
+        )
+        .map_err(|error| {
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "Failed to construct the headers of the incoming webhook: {error}"
+            ))
+        })?;
+    let body = consts::BASE64_ENGINE
+        .decode(stored_request.body)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode the body of the incoming webhook")?;
+
+    let request_details = api::IncomingWebhookRequestDetails {
+        method: actix_web::http::Method::from_bytes(stored_request.method.as_bytes())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        uri: stored_request
+            .uri
+            .parse()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        headers: &headers,
+        body: &body,
+        query_params: stored_request.query_params,
+    };
+
+    let connector_name_or_mca_id = incoming_webhook.connector_name_or_mca_id.clone();
+    // The format of the outgoing webhooks is decided by the business profile
+    let webhooks_result = reprocess::<webhook_types::OutgoingWebhook, Ctx>(
+        state.clone(),
+        request_details,
+        merchant_account,
+        key_store,
+        &connector_name_or_mca_id,
+    )
+    .await;
+
+    let incoming_webhook = update_incoming_webhook_outcome(
+        &state,
+        incoming_webhook,
+        webhooks_result
+            .as_ref()
+            .map(|(_, webhook_tracker)| webhook_tracker),
+    )
+    .await
+    .ok_or(errors::ApiErrorResponse::InternalServerError)
+    .into_report()
+    .attach_printable("Failed to update incoming webhook outcome")?;
+
+    Ok(ApplicationResponse::Json(foreign_into_response(
+        incoming_webhook,
+    )))
+}
+
+async fn reprocess<W: OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
+    state: AppState,
+    request_details: api::IncomingWebhookRequestDetails<'_>,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+) -> RouterResult<(
+    ApplicationResponse<serde_json::Value>,
+    WebhookResponseTracker,
+)> {
+    super::webhooks_core::<W, Ctx>(
+        state,
+        request_details,
+        merchant_account,
+        key_store,
+        connector_name_or_mca_id,
+        true,
+    )
+    .await
+}
+
+async fn find_incoming_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    webhook_id: &str,
+) -> RouterResult<storage::IncomingWebhook> {
+    state
+        .store
+        .find_incoming_webhook_by_merchant_id_webhook_id(&merchant_account.merchant_id, webhook_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Incoming webhook with id {webhook_id} not found"),
+        })
+}
+
+/// The connector name of the merchant connector account, when the webhook was received on a
+/// merchant connector account id
+async fn get_connector_name(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_name_or_mca_id: &str,
+) -> Option<String> {
+    if !connector_name_or_mca_id.starts_with("mca_") {
+        return Some(connector_name_or_mca_id.to_string());
+    }
+
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &merchant_account.merchant_id,
+            connector_name_or_mca_id,
+            key_store,
+        )
+        .await
+        .map(|merchant_connector_account| merchant_connector_account.connector_name)
+        .map_err(|error| logger::error!(?error, "Failed to find merchant connector account"))
+        .ok()
+}
+
+fn foreign_into_response(
+    incoming_webhook: storage::IncomingWebhook,
+) -> webhook_types::IncomingWebhookResponse {
+    webhook_types::IncomingWebhookResponse {
+        webhook_id: incoming_webhook.webhook_id,
+        connector_name_or_mca_id: incoming_webhook.connector_name_or_mca_id,
+        connector_name: incoming_webhook.connector_name,
+        headers: incoming_webhook.headers,
+        body: incoming_webhook.body,
+        source_verification_status: incoming_webhook.source_verification_status,
+        status: incoming_webhook.status,
+        error_message: incoming_webhook.error_message,
+        payment_id: incoming_webhook.payment_id,
+        refund_id: incoming_webhook.refund_id,
+        dispute_id: incoming_webhook.dispute_id,
+        created_at: incoming_webhook.created_at,
+        modified_at: incoming_webhook.modified_at,
+    }
+}
+
+fn masked_value(value: serde_json::Value) -> serde_json::Value {
+    serde_json::Value::String(format!("{:?}", Secret::<_>::new(value)))
+}
+
+fn mask_headers(headers: &[(String, String)]) -> serde_json::Value {
+    serde_json::Value::Object(
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = serde_json::Value::String(value.clone());
+                let is_unmasked = UNMASKED_HEADERS
+                    .iter()
+                    .any(|header| header.eq_ignore_ascii_case(name));
+                if is_unmasked {
+                    (name.clone(), value)
+                } else {
+                    (name.clone(), masked_value(value))
+                }
+            })
+            .collect(),
+    )
+}
+
+/// The body of the webhook for browsing, with the sensitive fields of JSON and form encoded bodies
+/// masked
+fn get_masked_body(headers: &[(String, String)], body: &[u8]) -> Option<serde_json::Value> {
+    if body.is_empty() {
+        return None;
+    }
+
+    let is_form_encoded = headers.iter().any(|(name, value)| {
+        name.eq_ignore_ascii_case("content-type")
+            && value.starts_with("application/x-www-form-urlencoded")
+    });
+
+    let masked_body = serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .or_else(|| {
+            is_form_encoded
+                .then(|| serde_urlencoded::from_bytes::<Vec<(String, String)>>(body).ok())
+                .flatten()
+                .map(|fields| {
+                    serde_json::Value::Object(
+                        fields
+                            .into_iter()
+                            .map(|(key, value)| (key, serde_json::Value::String(value)))
+                            .collect(),
+                    )
+                })
+        })
+        .map(mask_body)
+        .unwrap_or_else(|| serde_json::Value::String(String::from_utf8_lossy(body).into_owned()));
+
+    Some(masked_body)
+}
+
+fn mask_body(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let is_masked = MASKED_BODY_FIELDS
+                        .iter()
+                        .any(|field| field.eq_ignore_ascii_case(&key));
+                    if is_masked {
+                        (key, masked_value(value))
+                    } else {
+                        (key, mask_body(value))
+                    }
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.into_iter().map(mask_body).collect())
+        }
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_mask_body_masks_nested_sensitive_fields() {
+        let body = serde_json::json!({
+            "id": "evt_123",
+            "data": {
+                "object": {
+                    "amount": 100,
+                    "card": {"number": "4242424242424242", "brand": "visa"},
+                    "billing_address": {"line1": "1467 Harrison Street"},
+                },
+            },
+        });
+
+        let masked = mask_body(body);
+
+        assert_eq!(masked["id"], "evt_123");
+        assert_eq!(masked["data"]["object"]["amount"], 100);
+        assert_eq!(masked["data"]["object"]["card"]["brand"], "visa");
+        assert_ne!(
+            masked["data"]["object"]["card"]["number"],
+            "4242424242424242"
+        );
+        assert!(masked["data"]["object"]["billing_address"].is_string());
+    }
+
+    #[test]
+    fn test_masked_body_of_non_json_webhooks() {
+        let form_headers = vec![(
+            "Content-Type".to_string(),
+            "application/x-www-form-urlencoded".to_string(),
+        )];
+
+        let form_body = get_masked_body(&form_headers, b"id=evt_123&email=jane%40example.com")
+            .expect("Form body");
+        assert_eq!(form_body["id"], "evt_123");
+        assert_ne!(form_body["email"], "jane@example.com");
+
+        assert_eq!(
+            get_masked_body(&[], b"<notification id=\"evt_123\"/>"),
+            Some(serde_json::Value::String(
+                "<notification id=\"evt_123\"/>".to_string()
+            ))
+        );
+        assert_eq!(get_masked_body(&[], b""), None);
+    }
+
+    #[test]
+    fn test_mask_headers_keeps_only_allowed_values() {
+        let headers = vec![
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Stripe-Signature".to_string(), "t=1,v1=abc".to_string()),
+        ];
+
+        let masked = mask_headers(&headers);
+
+        assert_eq!(masked["Content-Type"], "application/json");
+        assert_ne!(masked["Stripe-Signature"], "t=1,v1=abc");
+    }
+}
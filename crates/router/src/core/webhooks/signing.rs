@@ -0,0 +1,407 @@
+use api_models::admin as admin_types;
+use common_utils::{
+    crypto::{self, Encryptable},
+    date_time,
+    ext_traits::{Encode, ValueExt},
+    webhook_signature::{WebhookSignatureAlgorithm, WebhookSignatureHeader},
+};
+use diesel_models::business_profile::{
+    BusinessProfile, BusinessProfileUpdateInternal, WebhookSigningKey,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::ApplicationResponse,
+    types::domain,
+    utils,
+};
+
+/// Time for which the current key keeps signing webhooks after a rotation, unless specified
+const DEFAULT_PREVIOUS_KEY_EXPIRY_SECS: u32 = 86400;
+/// Number of keys signing webhooks during a rotation, the current key and the new key
+const MAX_ACTIVE_SIGNING_KEYS: usize = 2;
+const SIGNING_KEY_ID_PREFIX: &str = "whk";
+const HMAC_SECRET_PREFIX: &str = "whsec_";
+const HMAC_SECRET_LENGTH: usize = 32;
+
+async fn get_merchant_key_store(
+    state: &AppState,
+    merchant_id: &str,
+) -> RouterResult<domain::MerchantKeyStore> {
+    let db = &*state.store;
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+async fn get_signing_keys(
+    business_profile: &BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Vec<WebhookSigningKey>> {
+    let signing_keys: Option<Encryptable<Secret<serde_json::Value>>> = domain::types::decrypt(
+        business_profile.webhook_signing_keys.clone(),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt webhook signing keys of the business profile")?;
+
+    signing_keys
+        .map(|signing_keys| {
+            signing_keys
+                .into_inner()
+                .expose()
+                .parse_value("Vec<WebhookSigningKey>")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook signing keys of the business profile")
+        .map(Option::unwrap_or_default)
+}
+
+fn get_signing_key_bytes(
+    signing_key: &WebhookSigningKey,
+) -> CustomResult<Vec<u8>, errors::ParsingError> {
+    match signing_key.algorithm {
+        WebhookSignatureAlgorithm::HmacSha256 => {
+            Ok(signing_key.signing_key.peek().as_bytes().to_vec())
+        }
+        WebhookSignatureAlgorithm::Ed25519 => hex::decode(signing_key.signing_key.peek())
+            .into_report()
+            .change_context(errors::ParsingError::StructParseFailure("Ed25519 key pair"))
+            .attach_printable("Ed25519 key pair is not hex encoded"),
+    }
+}
+
+/// Computes the versioned signature header of an outgoing webhook, using every active signing
+/// key of the business profile. Returns `None` when the business profile has no signing keys.
+pub async fn get_webhook_signature_header(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    payload: &str,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    if business_profile.webhook_signing_keys.is_none() {
+        return Ok(None);
+    }
+
+    let key_store = get_merchant_key_store(state, &business_profile.merchant_id)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+    let signing_keys = get_signing_keys(business_profile, &key_store)
+        .await
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+
+    sign_webhook_payload(&signing_keys, payload)
+}
+
+fn sign_webhook_payload(
+    signing_keys: &[WebhookSigningKey],
+    payload: &str,
+) -> CustomResult<Option<String>, errors::WebhooksFlowError> {
+    let current_time = date_time::now();
+    let signing_keys = signing_keys
+        .iter()
+        .filter(|signing_key| signing_key.is_active(current_time))
+        .map(|signing_key| {
+            get_signing_key_bytes(signing_key).map(|key_bytes| (signing_key.algorithm, key_bytes))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)?;
+
+    if signing_keys.is_empty() {
+        return Ok(None);
+    }
+
+    WebhookSignatureHeader::sign(
+        payload.as_bytes(),
+        date_time::now_unix_timestamp(),
+        signing_keys
+            .iter()
+            .map(|(algorithm, key_bytes)| (*algorithm, key_bytes.as_slice())),
+    )
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+    .attach_printable("Failed to sign the outgoing webhook")
+    .map(|signature_header| Some(signature_header.to_string()))
+}
+
+pub(super) fn generate_hmac_secret() -> String {
+    format!(
+        "{HMAC_SECRET_PREFIX}{}",
+        crypto::generate_cryptographically_secure_random_string(HMAC_SECRET_LENGTH)
+    )
+}
+
+fn generate_signing_key(
+    algorithm: WebhookSignatureAlgorithm,
+    created_at: time::PrimitiveDateTime,
+) -> RouterResult<WebhookSigningKey> {
+    let signing_key = match algorithm {
+        WebhookSignatureAlgorithm::HmacSha256 => generate_hmac_secret(),
+        WebhookSignatureAlgorithm::Ed25519 => crypto::Ed25519::generate_key_pair()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate Ed25519 key pair")
+            .map(|(key_pair, _)| hex::encode(key_pair))?,
+    };
+
+    Ok(WebhookSigningKey {
+        key_id: utils::generate_id(consts::ID_LENGTH, SIGNING_KEY_ID_PREFIX),
+        algorithm,
+        signing_key: Secret::new(signing_key),
+        created_at,
+        expires_at: None,
+    })
+}
+
+fn get_signing_key_response(
+    signing_key: &WebhookSigningKey,
+    include_secret: bool,
+) -> RouterResult<admin_types::WebhookSigningKeyResponse> {
+    let (secret, public_key) = match signing_key.algorithm {
+        WebhookSignatureAlgorithm::HmacSha256 => (
+            include_secret.then(|| signing_key.signing_key.clone()),
+            None,
+        ),
+        WebhookSignatureAlgorithm::Ed25519 => {
+            let key_pair = get_signing_key_bytes(signing_key)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            let public_key = crypto::Ed25519::get_public_key(&key_pair)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get the public key of the Ed25519 key pair")?;
+            (None, Some(hex::encode(public_key)))
+        }
+    };
+
+    Ok(admin_types::WebhookSigningKeyResponse {
+        key_id: signing_key.key_id.clone(),
+        algorithm: signing_key.algorithm,
+        secret,
+        public_key,
+        created_at: signing_key.created_at,
+        expires_at: signing_key.expires_at,
+    })
+}
+
+pub(super) async fn find_business_profile(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+) -> RouterResult<BusinessProfile> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    Ok(business_profile)
+}
+
+async fn update_signing_keys(
+    state: &AppState,
+    business_profile: BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    signing_keys: &[WebhookSigningKey],
+) -> RouterResult<BusinessProfile> {
+    let profile_id = business_profile.profile_id.clone();
+    let webhook_signing_keys = Encode::<&[WebhookSigningKey]>::encode_to_value(&signing_keys)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode webhook signing keys")?;
+    let webhook_signing_keys = domain::types::encrypt(
+        Secret::<_, masking::WithType>::new(webhook_signing_keys),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt webhook signing keys")?;
+
+    state
+        .store
+        .update_business_profile_by_profile_id(
+            business_profile,
+            BusinessProfileUpdateInternal {
+                modified_at: Some(date_time::now()),
+                webhook_signing_keys: Some(webhook_signing_keys.into()),
+                ..Default::default()
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound { id: profile_id })
+}
+
+/// Adds a new signing key to the business profile. The keys signing webhooks until now keep
+/// doing so until they expire, so that merchants can move to the new key without rejecting
+/// webhooks. Only the newest key is kept along with the new one.
+pub async fn rotate_webhook_signing_key(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    request: admin_types::WebhookSigningKeyRotateRequest,
+) -> RouterResponse<admin_types::WebhookSigningKeyResponse> {
+    let business_profile = find_business_profile(&state, merchant_id, profile_id).await?;
+    let key_store = get_merchant_key_store(&state, merchant_id).await?;
+    let current_time = date_time::now();
+    let previous_key_expires_at = current_time.saturating_add(time::Duration::seconds(i64::from(
+        request
+            .previous_key_expiry_in_secs
+            .unwrap_or(DEFAULT_PREVIOUS_KEY_EXPIRY_SECS),
+    )));
+
+    let mut signing_keys = get_signing_keys(&business_profile, &key_store)
+        .await?
+        .into_iter()
+        .map(|signing_key| WebhookSigningKey {
+            expires_at: Some(
+                signing_key
+                    .expires_at
+                    .map_or(previous_key_expires_at, |expires_at| {
+                        expires_at.min(previous_key_expires_at)
+                    }),
+            ),
+            ..signing_key
+        })
+        .filter(|signing_key| signing_key.is_active(current_time))
+        .collect::<Vec<_>>();
+
+    signing_keys.sort_by_key(|signing_key| signing_key.created_at);
+    let excess_keys = (signing_keys.len() + 1).saturating_sub(MAX_ACTIVE_SIGNING_KEYS);
+    signing_keys.drain(..excess_keys);
+
+    let new_signing_key = generate_signing_key(request.algorithm, current_time)?;
+    let response = get_signing_key_response(&new_signing_key, true)?;
+    signing_keys.push(new_signing_key);
+
+    update_signing_keys(&state, business_profile, &key_store, &signing_keys).await?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn list_webhook_signing_keys(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: String,
+) -> RouterResponse<Vec<admin_types::WebhookSigningKeyResponse>> {
+    let business_profile = find_business_profile(&state, merchant_id, &profile_id).await?;
+    let key_store = get_merchant_key_store(&state, merchant_id).await?;
+    let current_time = date_time::now();
+
+    get_signing_keys(&business_profile, &key_store)
+        .await?
+        .iter()
+        .filter(|signing_key| signing_key.is_active(current_time))
+        .map(|signing_key| get_signing_key_response(signing_key, false))
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+/// Removes the signing key, webhooks are no longer signed with it
+pub async fn revoke_webhook_signing_key(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    key_id: String,
+) -> RouterResponse<Vec<admin_types::WebhookSigningKeyResponse>> {
+    let business_profile = find_business_profile(&state, merchant_id, profile_id).await?;
+    let key_store = get_merchant_key_store(&state, merchant_id).await?;
+    let current_time = date_time::now();
+
+    let (revoked_keys, signing_keys): (Vec<_>, Vec<_>) =
+        get_signing_keys(&business_profile, &key_store)
+            .await?
+            .into_iter()
+            .filter(|signing_key| signing_key.is_active(current_time))
+            .partition(|signing_key| signing_key.key_id == key_id);
+
+    if revoked_keys.is_empty() {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook signing key {key_id} does not exist"),
+        })?
+    }
+
+    update_signing_keys(&state, business_profile, &key_store, &signing_keys).await?;
+
+    signing_keys
+        .iter()
+        .map(|signing_key| get_signing_key_response(signing_key, false))
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use common_utils::webhook_signature;
+
+    use super::*;
+
+    #[test]
+    fn test_webhook_is_signed_with_active_keys_only() {
+        let current_time = date_time::now();
+        let hmac_key = generate_signing_key(WebhookSignatureAlgorithm::HmacSha256, current_time)
+            .expect("HMAC key");
+        let ed25519_key = generate_signing_key(WebhookSignatureAlgorithm::Ed25519, current_time)
+            .expect("Ed25519 key");
+        let expired_key = WebhookSigningKey {
+            expires_at: Some(current_time - time::Duration::seconds(1)),
+            ..generate_signing_key(WebhookSignatureAlgorithm::HmacSha256, current_time)
+                .expect("Expired key")
+        };
+        let public_key = get_signing_key_response(&ed25519_key, false)
+            .expect("Ed25519 key response")
+            .public_key
+            .map(|public_key| hex::decode(public_key).expect("Hex encoded public key"))
+            .expect("Public key");
+
+        let payload = r#"{"event_type":"payment_succeeded"}"#;
+        let header = sign_webhook_payload(
+            &[hmac_key.clone(), ed25519_key, expired_key.clone()],
+            payload,
+        )
+        .expect("Signature header")
+        .expect("Signed webhook");
+
+        for verification_key in [
+            (
+                WebhookSignatureAlgorithm::HmacSha256,
+                hmac_key.signing_key.peek().as_bytes(),
+            ),
+            (WebhookSignatureAlgorithm::Ed25519, public_key.as_slice()),
+        ] {
+            assert!(webhook_signature::verify_webhook_signature(
+                &header,
+                payload.as_bytes(),
+                &[verification_key],
+                webhook_signature::DEFAULT_TIMESTAMP_TOLERANCE_SECS,
+            )
+            .is_ok());
+        }
+        assert!(webhook_signature::verify_webhook_signature(
+            &header,
+            payload.as_bytes(),
+            &[(
+                WebhookSignatureAlgorithm::HmacSha256,
+                expired_key.signing_key.peek().as_bytes(),
+            )],
+            webhook_signature::DEFAULT_TIMESTAMP_TOLERANCE_SECS,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_webhook_is_not_signed_without_keys() {
+        assert_eq!(
+            sign_webhook_payload(&[], "{}").expect("Signature header"),
+            None
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Verifies the source of incoming webhooks and protects their processing against replays.
+//!
+//! The outcome of the source verification is recorded as a metric and stored with the events
+//! created from the webhook. Merchants can enforce verification per merchant connector account,
+//! in which case webhooks that could not be verified are rejected. When replay protection is
+//! enabled, webhooks whose signature timestamp is outside the configured tolerance are rejected,
+//! and webhooks that have already been processed are acknowledged without being processed again.
+//! Only verified webhooks are recorded as processed.
+//!
+//! A connector supports source verification when it implements
+//! `get_webhook_source_verification_algorithm` or `verify_webhook_source`, or when it is listed in
+//! `connectors_with_webhook_source_verification_call`. As nothing is recorded for the webhooks of
+//! the other connectors, replay protection is a no-op for them. The timestamp tolerance only
+//! applies to connectors implementing `get_webhook_signature_timestamp`, and connectors which
+//! don't implement `get_webhook_event_id` are deduplicated on the digest of the body, so that a
+//! redelivery with a different body is processed again.
+
+use api_models::admin::MerchantConnectorWebhookDetails;
+use common_utils::{date_time, errors::ReportSwitchExt};
+use error_stack::ResultExt;
+use redis_interface::SetnxReply;
+use router_env::logger;
+
+use super::MERCHANT_ID;
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics,
+    },
+    routes::AppState,
+    types::{api, domain, storage::enums},
+    utils::ValueExt,
+};
+
+/// Outcome of checking an incoming webhook against the webhooks that were already processed
+pub(super) enum WebhookReplayCheck {
+    /// The webhook is processed, and the key is released if the processing fails so that the
+    /// connector can redeliver it
+    Fresh(Option<String>),
+    /// The webhook has already been processed
+    Duplicate,
+    /// The signature timestamp of the webhook is outside the configured tolerance
+    Stale,
+}
+
+pub(super) async fn verify_webhook_source(
+    state: &AppState,
+    connector: &(dyn api::Connector + Sync),
+    connector_enum: api_models::enums::Connector,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    connector_name: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> RouterResult<enums::WebhookSourceVerificationStatus> {
+    let source_verified = if state
+        .conf
+        .webhook_source_verification_call
+        .connectors_with_webhook_source_verification_call
+        .contains(&connector_enum)
+    {
+        connector
+            .verify_webhook_source_verification_call(
+                state,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+                request_details,
+            )
+            .await
+    } else {
+        connector
+            .verify_webhook_source(
+                request_details,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+            )
+            .await
+    };
+
+    let status = match source_verified {
+        Ok(true) => enums::WebhookSourceVerificationStatus::Verified,
+        Ok(false) => enums::WebhookSourceVerificationStatus::Failed,
+        Err(error) => match error.current_context() {
+            errors::ConnectorError::WebhookSourceVerificationNotSupported => {
+                enums::WebhookSourceVerificationStatus::NotSupported
+            }
+            errors::ConnectorError::WebhookSourceVerificationFailed => {
+                logger::error!(?error, "Source Verification Failed");
+                enums::WebhookSourceVerificationStatus::Failed
+            }
+            _ => Err(error)
+                .switch()
+                .attach_printable("There was an issue in incoming webhook source verification")?,
+        },
+    };
+
+    if status.is_verified() {
+        metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::KeyValue::new(
+                MERCHANT_ID,
+                merchant_account.merchant_id.clone(),
+            )],
+        );
+    }
+    metrics::WEBHOOK_SOURCE_VERIFICATION_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new(MERCHANT_ID, merchant_account.merchant_id.clone()),
+            metrics::KeyValue::new("connector", connector_name.to_string()),
+            metrics::KeyValue::new("status", status.to_string()),
+        ],
+    );
+
+    Ok(status)
+}
+
+/// Whether the merchant rejects the webhooks of the merchant connector account that could not be
+/// verified
+pub(super) fn is_source_verification_enforced(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<bool> {
+    merchant_connector_account
+        .connector_webhook_details
+        .clone()
+        .map(|webhook_details| {
+            webhook_details
+                .parse_value::<MerchantConnectorWebhookDetails>("MerchantConnectorWebhookDetails")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize merchant connector webhook details")
+        })
+        .transpose()
+        .map(|webhook_details| {
+            webhook_details
+                .and_then(|details| details.enforce_source_verification)
+                .unwrap_or(false)
+        })
+}
+
+pub(super) async fn check_webhook_replay(
+    state: &AppState,
+    connector: &(dyn api::Connector + Sync),
+    merchant_id: &str,
+    connector_name: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+    source_verification_status: enums::WebhookSourceVerificationStatus,
+) -> RouterResult<WebhookReplayCheck> {
+    let config = &state.conf.webhooks.replay_protection;
+    if !config.enabled {
+        return Ok(WebhookReplayCheck::Fresh(None));
+    }
+
+    // The timestamp can only be trusted when it is covered by a verified signature
+    if source_verification_status.is_verified() {
+        let signature_timestamp = connector
+            .get_webhook_signature_timestamp(request_details)
+            .switch()
+            .attach_printable("Could not find signature timestamp in incoming webhook")?;
+
+        if let Some(signature_timestamp) = signature_timestamp {
+            let now = date_time::now_unix_timestamp();
+            if (now - signature_timestamp).abs() > config.timestamp_tolerance_secs {
+                logger::error!(
+                    signature_timestamp,
+                    "Incoming webhook signature timestamp is outside the tolerance"
+                );
+                metrics::WEBHOOK_INCOMING_STALE_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[
+                        metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string()),
+                        metrics::KeyValue::new("connector", connector_name.to_string()),
+                    ],
+                );
+                return Ok(WebhookReplayCheck::Stale);
+            }
+        }
+    }
+
+    let event_id = connector
+        .get_webhook_event_id(request_details)
+        .switch()
+        .attach_printable("Could not find event id in incoming webhook")?;
+    let key = get_replay_protection_key(
+        merchant_id,
+        connector_name,
+        event_id.as_deref(),
+        request_details.body,
+    );
+
+    let redis = match state.store.get_redis_conn() {
+        Ok(redis) => redis,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return Ok(WebhookReplayCheck::Fresh(None));
+        }
+    };
+
+    // Only a verified webhook records its key, so that a forged webhook carrying the event id of a
+    // genuine one can't get the genuine webhook dropped as a duplicate. Webhooks which could not
+    // be verified are still acknowledged if the genuine webhook has already been processed.
+    let replay_check = if source_verification_status.is_verified() {
+        redis
+            .set_key_if_not_exists_with_expiry(
+                &key,
+                "processed",
+                Some(config.deduplication_ttl_secs),
+            )
+            .await
+            .map(|reply| match reply {
+                SetnxReply::KeySet => WebhookReplayCheck::Fresh(Some(key)),
+                SetnxReply::KeyNotSet => WebhookReplayCheck::Duplicate,
+            })
+    } else {
+        redis.exists::<Vec<u8>>(&key).await.map(|is_processed| {
+            if is_processed {
+                WebhookReplayCheck::Duplicate
+            } else {
+                WebhookReplayCheck::Fresh(None)
+            }
+        })
+    };
+
+    match replay_check {
+        Ok(WebhookReplayCheck::Duplicate) => {
+            logger::info!(?event_id, "Incoming webhook has already been processed");
+            metrics::WEBHOOK_INCOMING_DUPLICATE_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::KeyValue::new(MERCHANT_ID, merchant_id.to_string()),
+                    metrics::KeyValue::new("connector", connector_name.to_string()),
+                ],
+            );
+            Ok(WebhookReplayCheck::Duplicate)
+        }
+        Ok(replay_check) => Ok(replay_check),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to check incoming webhook for replay protection"
+            );
+            Ok(WebhookReplayCheck::Fresh(None))
+        }
+    }
+}
+
+/// Releases the replay protection key of a webhook whose processing failed, so that the
+/// connector can redeliver it
+pub(super) async fn release_replay_protection_key(state: &AppState, key: &str) {
+    match state.store.get_redis_conn() {
+        Ok(redis) => {
+            if let Err(error) = redis.delete_key(key).await {
+                logger::error!(
+                    ?error,
+                    "Failed to release incoming webhook replay protection key"
+                );
+            }
+        }
+        Err(error) => logger::error!(?error, "Failed to get redis connection"),
+    }
+}
+
+/// Webhooks are identified by the id assigned by the connector, or by the digest of the body for
+/// connectors that do not assign one
+fn get_replay_protection_key(
+    merchant_id: &str,
+    connector_name: &str,
+    event_id: Option<&str>,
+    body: &[u8],
+) -> String {
+    let webhook_id = event_id
+        .map(ToString::to_string)
+        .unwrap_or_else(|| hex::encode(ring::digest::digest(&ring::digest::SHA256, body).as_ref()));
+
+    format!("webhook_replay_{merchant_id}_{connector_name}_{webhook_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_protection_key_uses_event_id() {
+        let key = get_replay_protection_key("merchant_1", "stripe", Some("evt_123"), b"{}");
+        assert_eq!(key, "webhook_replay_merchant_1_stripe_evt_123");
+    }
+
+    #[test]
+    fn test_replay_protection_key_falls_back_to_body_digest() {
+        let key = get_replay_protection_key("merchant_1", "bitpay", None, b"{}");
+        let other_key = get_replay_protection_key("merchant_1", "bitpay", None, b"{\"a\":1}");
+
+        assert_eq!(
+            key,
+            "webhook_replay_merchant_1_bitpay_44136fa355b3678a1146ad16f7e8649e94fb4fc21fe77e8310c060f61caaff8a"
+        );
+        assert_ne!(key, other_key);
+    }
+}
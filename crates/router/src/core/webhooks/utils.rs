@@ -113,6 +113,7 @@ pub async fn construct_webhook_router_data<'a>(
         connector_http_status_code: None,
         external_latency: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
     };
     Ok(router_data)
 }
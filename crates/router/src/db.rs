@@ -12,6 +12,7 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod incoming_webhook;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -22,10 +23,12 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
+pub mod recon;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod routing_algorithm;
 pub mod user;
+pub mod webhook_endpoint;
 
 use data_models::payments::{
     payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
@@ -59,6 +62,7 @@ pub trait StorageInterface:
     + events::EventInterface
     + file::FileMetadataInterface
     + fraud_check::FraudCheckInterface
+    + incoming_webhook::IncomingWebhookInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -70,6 +74,7 @@ pub trait StorageInterface:
     + scheduler::SchedulerInterface
     + payout_attempt::PayoutAttemptInterface
     + payouts::PayoutsInterface
+    + recon::ReconInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
@@ -81,6 +86,7 @@ pub trait StorageInterface:
     + business_profile::BusinessProfileInterface
     + organization::OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + user::UserAccountInterface
     + 'static
 {
@@ -51,6 +51,15 @@ pub trait ConfigInterface {
         config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError>;
 
+    /// Updates the config only if it still holds `current_config`, returning `None` when it was
+    /// changed in the meantime
+    async fn update_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: &str,
+        config_update: storage::ConfigUpdate,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError>;
+
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError>;
 }
 
@@ -89,6 +98,22 @@ impl ConfigInterface for Store {
         .await
     }
 
+    async fn update_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: &str,
+        config_update: storage::ConfigUpdate,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        cache::publish_and_redact(self, CacheKind::Config(key.into()), || async {
+            let conn = connection::pg_connection_write(self).await?;
+            storage::Config::update_by_key_if_unchanged(&conn, key, current_config, config_update)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        })
+        .await
+    }
+
     async fn find_config_by_key_from_db(
         &self,
         key: &str,
@@ -226,6 +251,25 @@ impl ConfigInterface for MockDb {
         result
     }
 
+    async fn update_config_by_key_if_unchanged(
+        &self,
+        key: &str,
+        current_config: &str,
+        config_update: storage::ConfigUpdate,
+    ) -> CustomResult<Option<storage::Config>, errors::StorageError> {
+        let mut configs = self.configs.lock().await;
+        let config = configs.iter_mut().find(|c| c.key == key).ok_or_else(|| {
+            errors::StorageError::ValueNotFound("cannot find config to update".to_string())
+        })?;
+
+        if config.config != current_config {
+            return Ok(None);
+        }
+
+        *config = ConfigUpdateInternal::from(config_update).create_config(config.clone());
+        Ok(Some(config.clone()))
+    }
+
     async fn delete_config_by_key(&self, key: &str) -> CustomResult<bool, errors::StorageError> {
         let mut configs = self.configs.lock().await;
         let result = configs
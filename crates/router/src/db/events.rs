@@ -65,6 +65,7 @@ impl EventInterface for MockDb {
             primary_object_id: event.primary_object_id,
             primary_object_type: event.primary_object_type,
             created_at: now,
+            source_verification_status: event.source_verification_status,
         };
 
         locked_events.push(stored_event.clone());
@@ -122,6 +123,7 @@ mod tests {
                 intent_reference_id: Some("test".into()),
                 primary_object_id: "primary_object_tet".into(),
                 primary_object_type: enums::EventObjectType::PaymentDetails,
+                source_verification_status: None,
             })
             .await
             .unwrap();
@@ -0,0 +1,157 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookInterface {
+    async fn insert_incoming_webhook(
+        &self,
+        webhook: storage::IncomingWebhookNew,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError>;
+
+    async fn find_incoming_webhook_by_merchant_id_webhook_id(
+        &self,
+        merchant_id: &str,
+        webhook_id: &str,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError>;
+
+    async fn list_incoming_webhooks_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::IncomingWebhook>, errors::StorageError>;
+
+    async fn update_incoming_webhook(
+        &self,
+        this: storage::IncomingWebhook,
+        webhook: storage::IncomingWebhookUpdate,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError>;
+
+    async fn delete_incoming_webhooks_by_merchant_id_created_before(
+        &self,
+        merchant_id: &str,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookInterface for Store {
+    async fn insert_incoming_webhook(
+        &self,
+        webhook: storage::IncomingWebhookNew,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_incoming_webhook_by_merchant_id_webhook_id(
+        &self,
+        merchant_id: &str,
+        webhook_id: &str,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhook::find_by_merchant_id_webhook_id(&conn, merchant_id, webhook_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_incoming_webhooks_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::IncomingWebhook>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhook::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_incoming_webhook(
+        &self,
+        this: storage::IncomingWebhook,
+        webhook: storage::IncomingWebhookUpdate,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, webhook)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_incoming_webhooks_by_merchant_id_created_before(
+        &self,
+        merchant_id: &str,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::IncomingWebhook::delete_by_merchant_id_created_before(
+            &conn,
+            merchant_id,
+            created_before,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookInterface for MockDb {
+    async fn insert_incoming_webhook(
+        &self,
+        _webhook: storage::IncomingWebhookNew,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_incoming_webhook_by_merchant_id_webhook_id(
+        &self,
+        _merchant_id: &str,
+        _webhook_id: &str,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_incoming_webhooks_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::IncomingWebhook>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_incoming_webhook(
+        &self,
+        _this: storage::IncomingWebhook,
+        _webhook: storage::IncomingWebhookUpdate,
+    ) -> CustomResult<storage::IncomingWebhook, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_incoming_webhooks_by_merchant_id_created_before(
+        &self,
+        _merchant_id: &str,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
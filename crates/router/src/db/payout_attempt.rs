@@ -15,6 +15,12 @@ pub trait PayoutAttemptInterface {
         _payout_id: &str,
     ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError>;
+
     async fn update_payout_attempt_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
@@ -42,6 +48,22 @@ impl PayoutAttemptInterface for Store {
             .into_report()
     }
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        merchant_id: &str,
+        connector_payout_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutAttempt::find_by_merchant_id_connector_payout_id(
+            &conn,
+            merchant_id,
+            connector_payout_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn update_payout_attempt_by_merchant_id_payout_id(
         &self,
         merchant_id: &str,
@@ -80,6 +102,15 @@ impl PayoutAttemptInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_payout_attempt_by_merchant_id_connector_payout_id(
+        &self,
+        _merchant_id: &str,
+        _connector_payout_id: &str,
+    ) -> CustomResult<storage::PayoutAttempt, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_payout_attempt_by_merchant_id_payout_id(
         &self,
         _merchant_id: &str,
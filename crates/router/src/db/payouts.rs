@@ -26,6 +26,29 @@ pub trait PayoutsInterface {
         &self,
         _payout: storage::PayoutsNew,
     ) -> CustomResult<storage::Payouts, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _payout_details: &api_models::payouts::PayoutListRequest,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::refunds::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError>;
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_payouts(
+        &self,
+        _merchant_id: &str,
+        _payout_details: &api_models::payouts::PayoutListRequest,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -62,6 +85,61 @@ impl PayoutsInterface for Store {
         let conn = connection::pg_connection_write(self).await?;
         payout.insert(&conn).await.map_err(Into::into).into_report()
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_constraints(
+        &self,
+        merchant_id: &str,
+        payout_details: &api_models::payouts::PayoutListRequest,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::filter_by_constraints(
+            &conn,
+            merchant_id,
+            payout_details,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        merchant_id: &str,
+        time_range: &api_models::refunds::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::filter_by_meta_constraints(
+            &conn,
+            merchant_id,
+            time_range,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_payouts(
+        &self,
+        merchant_id: &str,
+        payout_details: &api_models::payouts::PayoutListRequest,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        <storage::Payouts as storage::PayoutsDbExt>::get_payouts_count(
+            &conn,
+            merchant_id,
+            payout_details,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,4 +170,36 @@ impl PayoutsInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_constraints(
+        &self,
+        _merchant_id: &str,
+        _payout_details: &api_models::payouts::PayoutListRequest,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<(storage::Payouts, storage::PayoutAttempt)>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn filter_payouts_by_meta_constraints(
+        &self,
+        _merchant_id: &str,
+        _time_range: &api_models::refunds::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "olap")]
+    async fn get_total_count_of_payouts(
+        &self,
+        _merchant_id: &str,
+        _payout_details: &api_models::payouts::PayoutListRequest,
+    ) -> CustomResult<i64, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
@@ -0,0 +1,336 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ReconInterface {
+    async fn insert_recon_report(
+        &self,
+        report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError>;
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError>;
+
+    /// Inserts all the records of a report, or none of them if any batch fails
+    async fn insert_recon_records(
+        &self,
+        records: Vec<storage::ReconRecord>,
+        batch_size: usize,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    async fn filter_recon_records_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        outcome: Option<enums::ReconOutcome>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError>;
+
+    /// Settled transactions have long been written to the database by the time they show up in a
+    /// settlement report, so the lookups below read from the database directly
+    async fn find_payment_attempts_by_merchant_id_connector_txn_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_txn_ids: Vec<String>,
+        connector: &str,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError>;
+
+    async fn find_payment_attempts_by_merchant_id_connector_statuses_time_range(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError>;
+
+    async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for Store {
+    async fn insert_recon_report(
+        &self,
+        report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconReport::find_by_merchant_id_report_id(&conn, merchant_id, report_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconReport::list_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_recon_report(
+        &self,
+        this: storage::ReconReport,
+        report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, report)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_recon_records(
+        &self,
+        records: Vec<storage::ReconRecord>,
+        batch_size: usize,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReconRecord::insert_in_batches(&conn, records, batch_size)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn filter_recon_records_by_merchant_id_report_id(
+        &self,
+        merchant_id: &str,
+        report_id: &str,
+        outcome: Option<enums::ReconOutcome>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReconRecord::filter_by_merchant_id_report_id(
+            &conn,
+            merchant_id,
+            report_id,
+            outcome,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_payment_attempts_by_merchant_id_connector_txn_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_txn_ids: Vec<String>,
+        connector: &str,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentAttempt::find_by_merchant_id_connector_txn_ids_connector(
+            &conn,
+            merchant_id,
+            connector_txn_ids,
+            connector,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_payment_attempts_by_merchant_id_connector_statuses_time_range(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        statuses: Vec<enums::AttemptStatus>,
+        start_time: PrimitiveDateTime,
+        end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::PaymentAttempt::find_by_merchant_id_connector_statuses_time_range(
+            &conn,
+            merchant_id,
+            connector,
+            statuses,
+            start_time,
+            end_time,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+        &self,
+        merchant_id: &str,
+        connector_refund_ids: Vec<String>,
+        connector: &str,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Refund::find_by_merchant_id_connector_refund_ids_connector(
+            &conn,
+            merchant_id,
+            connector_refund_ids,
+            connector,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &str,
+        payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReconInterface for MockDb {
+    async fn insert_recon_report(
+        &self,
+        _report: storage::ReconReportNew,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_recon_report_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_recon_reports_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ReconReport>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_recon_report(
+        &self,
+        _this: storage::ReconReport,
+        _report: storage::ReconReportUpdate,
+    ) -> CustomResult<storage::ReconReport, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_recon_records(
+        &self,
+        _records: Vec<storage::ReconRecord>,
+        _batch_size: usize,
+    ) -> CustomResult<usize, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn filter_recon_records_by_merchant_id_report_id(
+        &self,
+        _merchant_id: &str,
+        _report_id: &str,
+        _outcome: Option<enums::ReconOutcome>,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ReconRecord>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_attempts_by_merchant_id_connector_txn_ids_connector(
+        &self,
+        _merchant_id: &str,
+        _connector_txn_ids: Vec<String>,
+        _connector: &str,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_attempts_by_merchant_id_connector_statuses_time_range(
+        &self,
+        _merchant_id: &str,
+        _connector: &str,
+        _statuses: Vec<enums::AttemptStatus>,
+        _start_time: PrimitiveDateTime,
+        _end_time: PrimitiveDateTime,
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refunds_by_merchant_id_connector_refund_ids_connector(
+        &self,
+        _merchant_id: &str,
+        _connector_refund_ids: Vec<String>,
+        _connector: &str,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        _merchant_id: &str,
+        _payment_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
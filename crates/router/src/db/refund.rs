@@ -369,6 +369,13 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        processing_fee: None,
+                        interchange_fee: None,
+                        fx_markup: None,
+                        net_amount: None,
+                        settlement_currency: None,
+                        refund_method: None,
+                        payout_id: None,
                     };
 
                     let field = format!(
@@ -798,6 +805,13 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            processing_fee: None,
+            interchange_fee: None,
+            fx_markup: None,
+            net_amount: None,
+            settlement_currency: None,
+            refund_method: None,
+            payout_id: None,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -48,6 +48,25 @@ pub trait RoutingAlgorithmInterface {
         limit: i64,
         offset: i64,
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>>;
+
+    async fn list_routing_algorithm_metadata_by_profile_id_name(
+        &self,
+        profile_id: &str,
+        name: &str,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmMetadata>>;
+
+    async fn insert_routing_activation_audit(
+        &self,
+        audit: routing_storage::RoutingActivationAuditNew,
+    ) -> StorageResult<routing_storage::RoutingActivationAudit>;
+
+    async fn list_routing_activation_audit_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingActivationAudit>>;
 }
 
 #[async_trait::async_trait]
@@ -144,6 +163,46 @@ impl RoutingAlgorithmInterface for Store {
         .map_err(Into::into)
         .into_report()
     }
+
+    async fn list_routing_algorithm_metadata_by_profile_id_name(
+        &self,
+        profile_id: &str,
+        name: &str,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmMetadata>> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_storage::RoutingAlgorithm::list_metadata_by_profile_id_name(&conn, profile_id, name)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_routing_activation_audit(
+        &self,
+        audit: routing_storage::RoutingActivationAuditNew,
+    ) -> StorageResult<routing_storage::RoutingActivationAudit> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn list_routing_activation_audit_by_merchant_id(
+        &self,
+        merchant_id: &str,
+        profile_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingActivationAudit>> {
+        let conn = connection::pg_connection_write(self).await?;
+        routing_storage::RoutingActivationAudit::list_by_merchant_id(
+            &conn,
+            merchant_id,
+            profile_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -196,4 +255,29 @@ impl RoutingAlgorithmInterface for MockDb {
     ) -> StorageResult<Vec<routing_storage::RoutingProfileMetadata>> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn list_routing_algorithm_metadata_by_profile_id_name(
+        &self,
+        _profile_id: &str,
+        _name: &str,
+    ) -> StorageResult<Vec<routing_storage::RoutingAlgorithmMetadata>> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_routing_activation_audit(
+        &self,
+        _audit: routing_storage::RoutingActivationAuditNew,
+    ) -> StorageResult<routing_storage::RoutingActivationAudit> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_routing_activation_audit_by_merchant_id(
+        &self,
+        _merchant_id: &str,
+        _profile_id: Option<&str>,
+        _limit: i64,
+        _offset: i64,
+    ) -> StorageResult<Vec<routing_storage::RoutingActivationAudit>> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
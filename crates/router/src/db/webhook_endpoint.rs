@@ -0,0 +1,229 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface {
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint(
+        &self,
+        this: storage::WebhookEndpoint,
+        endpoint: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_webhook_delivery(
+        &self,
+        delivery: storage::WebhookDeliveryNew,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError>;
+
+    async fn list_webhook_deliveries_by_endpoint_id(
+        &self,
+        endpoint_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::WebhookDelivery>, errors::StorageError>;
+
+    async fn update_webhook_delivery(
+        &self,
+        this: storage::WebhookDelivery,
+        delivery: storage::WebhookDeliveryUpdate,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    async fn insert_webhook_endpoint(
+        &self,
+        endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        endpoint
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_webhook_endpoint(
+        &self,
+        this: storage::WebhookEndpoint,
+        endpoint: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, endpoint)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &str,
+        endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_webhook_delivery(
+        &self,
+        delivery: storage::WebhookDeliveryNew,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        delivery
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn list_webhook_deliveries_by_endpoint_id(
+        &self,
+        endpoint_id: &str,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::WebhookDelivery>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookDelivery::list_by_endpoint_id(&conn, endpoint_id, limit, offset)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_webhook_delivery(
+        &self,
+        this: storage::WebhookDelivery,
+        delivery: storage::WebhookDeliveryUpdate,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, delivery)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        _endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        _profile_id: &str,
+        _endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        _profile_id: &str,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_webhook_endpoint(
+        &self,
+        _this: storage::WebhookEndpoint,
+        _endpoint: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        _profile_id: &str,
+        _endpoint_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_webhook_delivery(
+        &self,
+        _delivery: storage::WebhookDeliveryNew,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_webhook_deliveries_by_endpoint_id(
+        &self,
+        _endpoint_id: &str,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::WebhookDelivery>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_webhook_delivery(
+        &self,
+        _this: storage::WebhookDelivery,
+        _delivery: storage::WebhookDeliveryUpdate,
+    ) -> CustomResult<storage::WebhookDelivery, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
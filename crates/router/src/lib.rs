@@ -1,7 +1,7 @@
 #![forbid(unsafe_code)]
 #![recursion_limit = "256"]
 
-#[cfg(feature = "stripe")]
+#[cfg(any(feature = "stripe", feature = "adyen"))]
 pub mod compatibility;
 pub mod configs;
 pub mod connection;
@@ -142,7 +142,10 @@ pub fn mk_app(
             .service(routes::ApiKeys::server(state.clone()))
             .service(routes::Files::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
+            .service(routes::IncomingWebhooks::server(state.clone()))
             .service(routes::Routing::server(state.clone()))
+            .service(routes::Recon::server(state.clone()))
+            .service(routes::ConnectorHealth::server(state.clone()))
     }
 
     #[cfg(all(feature = "olap", feature = "kms"))]
@@ -159,6 +162,11 @@ pub fn mk_app(
     {
         server_app = server_app.service(routes::StripeApis::server(state.clone()));
     }
+
+    #[cfg(feature = "adyen")]
+    {
+        server_app = server_app.service(routes::AdyenApis::server(state.clone()));
+    }
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
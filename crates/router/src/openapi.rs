@@ -61,6 +61,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Disputes", description = "Manage disputes"),
         // (name = "API Key", description = "Create and manage API Keys"),
         (name = "Payouts", description = "Create and manage payouts"),
+        (name = "Recon", description = "Reconcile connector settlement reports with payments, refunds and disputes"),
         (name = "payment link", description = "Create payment link"),
     ),
     paths(
@@ -114,6 +115,12 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::payouts::payouts_fulfill,
         crate::routes::payouts::payouts_retrieve,
         crate::routes::payouts::payouts_update,
+        crate::routes::payouts::payouts_list,
+        crate::routes::payouts::payouts_filter,
+        crate::routes::recon::recon_report_create,
+        crate::routes::recon::recon_report_retrieve,
+        crate::routes::recon::recon_report_list,
+        crate::routes::recon::recon_record_list,
         crate::routes::payment_link::payment_link_retrieve
     ),
     components(schemas(
@@ -121,6 +128,7 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::refunds::RefundType,
         crate::types::api::refunds::RefundResponse,
         crate::types::api::refunds::RefundStatus,
+        api_models::enums::RefundMethod,
         crate::types::api::refunds::RefundUpdateRequest,
         crate::types::api::admin::MerchantAccountCreate,
         crate::types::api::admin::MerchantAccountUpdate,
@@ -167,9 +175,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
         api_models::enums::RetryAction,
+        api_models::enums::OutgoingWebhookFormat,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
+        api_models::enums::ReconReportStatus,
+        api_models::enums::ReconTransactionType,
+        api_models::enums::ReconOutcome,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
@@ -180,9 +192,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
         api_models::admin::MerchantConnectorWebhookDetails,
+        api_models::admin::ConnectorFeeSchedule,
+        api_models::admin::ConnectorFeeRule,
+        api_models::admin::ConnectorFeeRegion,
         api_models::admin::PaymentLinkConfig,
         api_models::admin::PaymentLinkColorSchema,
         api_models::disputes::DisputeResponse,
+        api_models::recon::ReconReportCreateRequest,
+        api_models::recon::ReconReportResponse,
+        api_models::recon::SettlementBatchSummary,
+        api_models::recon::ReconReportListResponse,
+        api_models::recon::ReconRecordResponse,
+        api_models::recon::ReconMismatch,
+        api_models::recon::ReconRecordListResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::payments::AddressDetails,
         api_models::payments::BankDebitData,
@@ -300,6 +322,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::ConnectorFeeDetails,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payment_methods::RequiredFieldInfo,
@@ -319,7 +342,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::AchBankTransfer,
         api_models::payouts::BacsBankTransfer,
         api_models::payouts::SepaBankTransfer,
+        api_models::payouts::Wallet,
+        api_models::payouts::Paypal,
+        api_models::payouts::Venmo,
+        api_models::payouts::InstantTransfer,
         api_models::payouts::PayoutCreateResponse,
+        api_models::payouts::PayoutListRequest,
+        api_models::payouts::PayoutListResponse,
+        api_models::payouts::PayoutListFilters,
         api_models::payouts::PayoutRetrieveBody,
         api_models::payouts::PayoutRetrieveRequest,
         api_models::payouts::PayoutActionRequest,
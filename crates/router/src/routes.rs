@@ -4,6 +4,8 @@ pub mod app;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+#[cfg(feature = "olap")]
+pub mod connector_health;
 pub mod customers;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
@@ -19,6 +21,8 @@ pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+#[cfg(feature = "olap")]
+pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
@@ -30,14 +34,16 @@ pub mod webhooks;
 pub use self::app::DummyConnector;
 #[cfg(feature = "payouts")]
 pub use self::app::Payouts;
-#[cfg(feature = "olap")]
-pub use self::app::Routing;
 #[cfg(all(feature = "olap", feature = "kms"))]
 pub use self::app::Verify;
 pub use self::app::{
     ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, Customers, Disputes, EphemeralKey,
-    Files, Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
-    PaymentMethods, Payments, Refunds, UserAccount, Webhooks,
+    Files, Health, IncomingWebhooks, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Refunds, UserAccount, Webhooks,
 };
+#[cfg(feature = "olap")]
+pub use self::app::{ConnectorHealth, Recon, Routing};
+#[cfg(feature = "adyen")]
+pub use super::compatibility::adyen::AdyenApis;
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{admin::*, api_locking, webhooks},
     services::{api, authentication as auth},
     types::api::admin,
 };
@@ -346,7 +346,8 @@ pub async fn payment_connector_list(
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsList;
     let merchant_id = path.into_inner();
-    let auth: Box<dyn auth::AuthenticateAndFetch<auth::AuthenticationData, _>> = Box::new(auth::JWTAuth);
+    let auth: Box<dyn auth::AuthenticateAndFetch<auth::AuthenticationData, _>> =
+        Box::new(auth::JWTAuth);
     api::server_wrap(
         flow,
         state,
@@ -571,6 +572,230 @@ pub async fn business_profiles_list(
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyRotate))]
+pub async fn webhook_signing_key_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::WebhookSigningKeyRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            webhooks::signing::rotate_webhook_signing_key(state, &merchant_id, &profile_id, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyList))]
+pub async fn webhook_signing_keys_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id| {
+            webhooks::signing::list_webhook_signing_keys(state, &merchant_id, profile_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookSigningKeyRevoke))]
+pub async fn webhook_signing_key_revoke(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookSigningKeyRevoke;
+    let (merchant_id, profile_id, key_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        key_id,
+        |state, _, key_id| {
+            webhooks::signing::revoke_webhook_signing_key(state, &merchant_id, &profile_id, key_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn webhook_endpoint_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::webhook_endpoints::WebhookEndpointCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            webhooks::endpoints::create_webhook_endpoint(state, &merchant_id, &profile_id, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn webhook_endpoints_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id,
+        |state, _, profile_id| {
+            webhooks::endpoints::list_webhook_endpoints(state, &merchant_id, profile_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn webhook_endpoint_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        endpoint_id,
+        |state, _, endpoint_id| {
+            webhooks::endpoints::retrieve_webhook_endpoint(
+                state,
+                &merchant_id,
+                &profile_id,
+                endpoint_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn webhook_endpoint_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+    json_payload: web::Json<api_models::webhook_endpoints::WebhookEndpointUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            webhooks::endpoints::update_webhook_endpoint(
+                state,
+                &merchant_id,
+                &profile_id,
+                &endpoint_id,
+                req,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn webhook_endpoint_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        endpoint_id,
+        |state, _, endpoint_id| {
+            webhooks::endpoints::delete_webhook_endpoint(
+                state,
+                &merchant_id,
+                &profile_id,
+                endpoint_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::WebhookDeliveryList))]
+pub async fn webhook_deliveries_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>,
+    query_payload: web::Query<api_models::webhook_endpoints::WebhookDeliveryListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::WebhookDeliveryList;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, _, constraints| {
+            webhooks::endpoints::list_webhook_deliveries(
+                state,
+                &merchant_id,
+                &profile_id,
+                &endpoint_id,
+                constraints,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
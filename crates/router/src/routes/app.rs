@@ -10,15 +10,23 @@ use scheduler::SchedulerInterface;
 use storage_impl::MockDb;
 use tokio::sync::oneshot;
 
+#[cfg(feature = "olap")]
+use super::connector_health;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 #[cfg(feature = "payouts")]
 use super::payouts::*;
 #[cfg(feature = "olap")]
+use super::recon::*;
+#[cfg(feature = "olap")]
 use super::routing as cloud_routing;
 #[cfg(all(feature = "olap", feature = "kms"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
+use super::webhooks::{
+    list_incoming_webhooks, reprocess_incoming_webhook, retrieve_incoming_webhook,
+};
+#[cfg(feature = "olap")]
 use super::{admin::*, api_keys::*, disputes::*, files::*};
 use super::{cache::*, health::*, payment_link::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -195,6 +203,35 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(
+                web::resource("/{payment_id}/capture")
+                    .route(web::post().to(dummy_connector_capture)),
+            )
+            .service(
+                web::resource("/{payment_id}/void").route(web::post().to(dummy_connector_void)),
+            )
+            .service(
+                web::resource("/{payment_id}/dispute")
+                    .route(web::post().to(dummy_connector_dispute)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}")
+                    .route(web::get().to(dummy_connector_dispute_data)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}/resolve")
+                    .route(web::post().to(dummy_connector_dispute_resolve)),
+            )
+            .service(web::resource("/payouts").route(web::post().to(dummy_connector_payout)))
+            .service(
+                web::resource("/payouts/{payout_id}")
+                    .route(web::get().to(dummy_connector_payout_data)),
+            )
+            .service(
+                web::resource("/config")
+                    .route(web::get().to(dummy_connector_retrieve_merchant_config))
+                    .route(web::put().to(dummy_connector_update_merchant_config)),
             );
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
@@ -226,6 +263,14 @@ impl Payments {
                         .route(web::post().to(payments_list_by_filter)),
                 )
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
+                .service(
+                    web::resource("/fees/aggregate")
+                        .route(web::post().to(get_connector_fee_aggregates)),
+                )
+                .service(
+                    web::resource("/{payment_id}/routing_trace")
+                        .route(web::get().to(payments_routing_trace)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -304,6 +349,39 @@ impl Routing {
                 web::resource("/deactivate")
                     .route(web::post().to(cloud_routing::routing_unlink_config)),
             )
+            .service(
+                web::resource("/rollback")
+                    .route(web::post().to(cloud_routing::routing_rollback_config)),
+            )
+            .service(
+                web::resource("/audit")
+                    .route(web::get().to(cloud_routing::routing_retrieve_activation_audit)),
+            )
+            .service(
+                web::resource("/canary")
+                    .route(web::get().to(cloud_routing::routing_retrieve_canary)),
+            )
+            .service(
+                web::resource("/canary/promote")
+                    .route(web::post().to(cloud_routing::routing_promote_canary)),
+            )
+            .service(
+                web::resource("/canary/abort")
+                    .route(web::post().to(cloud_routing::routing_abort_canary)),
+            )
+            .service(
+                web::resource("/timezone")
+                    .route(web::get().to(cloud_routing::routing_retrieve_timezone_config))
+                    .route(web::post().to(cloud_routing::routing_update_timezone_config)),
+            )
+            .service(
+                web::resource("/decision/surcharge")
+                    .route(web::put().to(cloud_routing::upsert_surcharge_decision_manager_config))
+                    .route(web::get().to(cloud_routing::retrieve_surcharge_decision_manager_config))
+                    .route(
+                        web::delete().to(cloud_routing::delete_surcharge_decision_manager_config),
+                    ),
+            )
             .service(
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(cloud_routing::routing_retrieve_config)),
@@ -312,6 +390,22 @@ impl Routing {
                 web::resource("/{algorithm_id}/activate")
                     .route(web::post().to(cloud_routing::routing_link_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/activate/schedule")
+                    .route(web::post().to(cloud_routing::routing_schedule_activation)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/canary")
+                    .route(web::post().to(cloud_routing::routing_start_canary)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/versions")
+                    .route(web::get().to(cloud_routing::routing_retrieve_versions)),
+            )
+            .service(
+                web::resource("/{algorithm_id}/diff/{other_algorithm_id}")
+                    .route(web::get().to(cloud_routing::routing_diff_config)),
+            )
     }
 }
 
@@ -390,8 +484,15 @@ pub struct Payouts;
 #[cfg(feature = "payouts")]
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
-        let route = web::scope("/payouts").app_data(web::Data::new(state));
-        route
+        let mut route = web::scope("/payouts").app_data(web::Data::new(state));
+
+        #[cfg(feature = "olap")]
+        {
+            route = route
+                .service(web::resource("/list").route(web::post().to(payouts_list)))
+                .service(web::resource("/filter").route(web::post().to(payouts_filter)));
+        }
+        route = route
             .service(web::resource("/create").route(web::post().to(payouts_create)))
             .service(web::resource("/{payout_id}/cancel").route(web::post().to(payouts_cancel)))
             .service(web::resource("/{payout_id}/fulfill").route(web::post().to(payouts_fulfill)))
@@ -399,7 +500,8 @@ impl Payouts {
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
                     .route(web::put().to(payouts_update)),
-            )
+            );
+        route
     }
 }
 
@@ -610,6 +712,22 @@ impl Disputes {
     }
 }
 
+pub struct IncomingWebhooks;
+
+#[cfg(feature = "olap")]
+impl IncomingWebhooks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/incoming_webhooks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(list_incoming_webhooks)))
+            .service(web::resource("/{webhook_id}").route(web::get().to(retrieve_incoming_webhook)))
+            .service(
+                web::resource("/{webhook_id}/reprocess")
+                    .route(web::post().to(reprocess_incoming_webhook)),
+            )
+    }
+}
+
 pub struct Cards;
 
 impl Cards {
@@ -636,6 +754,43 @@ impl Files {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct Recon;
+
+#[cfg(feature = "olap")]
+impl Recon {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/recon")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/reports")
+                    .route(web::post().to(recon_report_create))
+                    .route(web::get().to(recon_report_list)),
+            )
+            .service(
+                web::resource("/reports/{report_id}").route(web::get().to(recon_report_retrieve)),
+            )
+            .service(
+                web::resource("/reports/{report_id}/records")
+                    .route(web::get().to(recon_record_list)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ConnectorHealth;
+
+#[cfg(feature = "olap")]
+impl ConnectorHealth {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_health")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::get().to(connector_health::retrieve_connector_health)),
+            )
+    }
+}
+
 pub struct Cache;
 
 impl Cache {
@@ -680,6 +835,33 @@ impl BusinessProfile {
                     .route(web::post().to(business_profile_update))
                     .route(web::delete().to(business_profile_delete)),
             )
+            .service(
+                web::resource("/{profile_id}/webhook_signing_keys")
+                    .route(web::get().to(webhook_signing_keys_list)),
+            )
+            .service(
+                web::resource("/{profile_id}/webhook_signing_keys/rotate")
+                    .route(web::post().to(webhook_signing_key_rotate)),
+            )
+            .service(
+                web::resource("/{profile_id}/webhook_signing_keys/{key_id}")
+                    .route(web::delete().to(webhook_signing_key_revoke)),
+            )
+            .service(
+                web::resource("/{profile_id}/webhook_endpoints")
+                    .route(web::post().to(webhook_endpoint_create))
+                    .route(web::get().to(webhook_endpoints_list)),
+            )
+            .service(
+                web::resource("/{profile_id}/webhook_endpoints/{endpoint_id}")
+                    .route(web::get().to(webhook_endpoint_retrieve))
+                    .route(web::post().to(webhook_endpoint_update))
+                    .route(web::delete().to(webhook_endpoint_delete)),
+            )
+            .service(
+                web::resource("/{profile_id}/webhook_endpoints/{endpoint_id}/deliveries")
+                    .route(web::get().to(webhook_deliveries_list)),
+            )
     }
 }
 
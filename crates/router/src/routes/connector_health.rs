@@ -0,0 +1,29 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_health as health_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, connector_health},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorHealthRetrieve))]
+pub async fn retrieve_connector_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<health_types::ConnectorHealthQuery>,
+) -> impl Responder {
+    let flow = Flow::ConnectorHealthRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, query| connector_health::retrieve_connector_health(state, query),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -70,8 +70,8 @@ pub async fn dummy_connector_payment(
         state,
         &req,
         payload,
-        |state, _, req| core::payment(state, req),
-        &auth::NoAuth,
+        core::payment,
+        &utils::DummyConnectorAuth,
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -90,8 +90,8 @@ pub async fn dummy_connector_payment_data(
         state,
         &req,
         payload,
-        |state, _, req| core::payment_data(state, req),
-        &auth::NoAuth,
+        core::payment_data,
+        &utils::DummyConnectorAuth,
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -111,8 +111,8 @@ pub async fn dummy_connector_refund(
         state,
         &req,
         payload,
-        |state, _, req| core::refund_payment(state, req),
-        &auth::NoAuth,
+        core::refund_payment,
+        &utils::DummyConnectorAuth,
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -131,9 +131,189 @@ pub async fn dummy_connector_refund_data(
         state,
         &req,
         payload,
-        |state, _, req| core::refund_data(state, req),
+        core::refund_data,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCapture))]
+pub async fn dummy_connector_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorCaptureRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentCapture;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        core::payment_capture,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentVoid))]
+pub async fn dummy_connector_void(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPaymentVoid;
+    let payment_id = path.into_inner();
+    let payload = types::DummyConnectorVoidRequest { payment_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        core::payment_void,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeCreate))]
+pub async fn dummy_connector_dispute(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorDisputeRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeCreate;
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = Some(path.into_inner());
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        core::dispute_payment,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeRetrieve))]
+pub async fn dummy_connector_dispute_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeRetrieve;
+    let dispute_id = path.into_inner();
+    let payload = types::DummyConnectorDisputeRetrieveRequest { dispute_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| core::dispute_data(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeResolve))]
+pub async fn dummy_connector_dispute_resolve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorDisputeResolveBody>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeResolve;
+    let payload = types::DummyConnectorDisputeResolveRequest {
+        dispute_id: path.into_inner(),
+        status: json_payload.into_inner().status,
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| core::dispute_resolve(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCreate))]
+pub async fn dummy_connector_payout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPayoutRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutCreate;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        core::payout,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutRetrieve))]
+pub async fn dummy_connector_payout_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutRetrieve;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutRetrieveRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| core::payout_data(state, req),
         &auth::NoAuth,
         api_locking::LockAction::NotApplicable,
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyMerchantConfigUpdate))]
+pub async fn dummy_connector_update_merchant_config(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorMerchantConfig>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyMerchantConfigUpdate;
+    let payload = json_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        core::merchant_config_update,
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyMerchantConfigRetrieve))]
+pub async fn dummy_connector_retrieve_merchant_config(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyMerchantConfigRetrieve;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, merchant, _| core::merchant_config_retrieve(state, merchant),
+        &utils::DummyConnectorAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -1,4 +1,12 @@
 pub const PAYMENT_ID_PREFIX: &str = "dummy_pay";
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dispute";
+pub const PAYOUT_ID_PREFIX: &str = "dummy_payout";
+pub const MANDATE_ID_PREFIX: &str = "dummy_mandate";
+pub const WEBHOOK_ID_PREFIX: &str = "dummy_evt";
+pub const MERCHANT_CONFIG_KEY_PREFIX: &str = "dummy_connector_config_";
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Dummy-Connector-Signature";
+/// Days a merchant has to respond to a dispute
+pub const DISPUTE_CHALLENGE_DAYS: i64 = 7;
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
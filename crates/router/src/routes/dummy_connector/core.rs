@@ -1,9 +1,10 @@
 use app::AppState;
 use common_utils::generate_id_with_default_len;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 
 use super::{errors, types, utils};
 use crate::{
+    logger,
     routes::{app, dummy_connector::consts},
     services::api,
     utils::OptionExt,
@@ -11,6 +12,7 @@ use crate::{
 
 pub async fn payment(
     state: AppState,
+    merchant: types::DummyConnectorMerchant,
     req: types::DummyConnectorPaymentRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
     utils::tokio_mock_sleep(
@@ -18,10 +20,24 @@ pub async fn payment(
         state.conf.dummy_connector.payment_tolerance,
     )
     .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Payment,
+    )
+    .await?;
 
-    let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
+    if let types::DummyConnectorPaymentMethodData::Mandate(mandate) = &req.payment_method_data {
+        utils::get_mandate_data(&state, &mandate.mandate_id).await?;
+    }
+
+    let payment_attempt = types::DummyConnectorPaymentAttempt {
+        merchant_key: merchant.merchant_key,
+        ..req.into()
+    };
+    let mut payment_data =
         types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
+    utils::setup_mandate_if_required(&state, &mut payment_data).await?;
 
     utils::store_data_in_redis(
         &state,
@@ -37,11 +53,13 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+    send_payment_webhook(&state, &payment_data).await;
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
 pub async fn payment_data(
     state: AppState,
+    merchant: types::DummyConnectorMerchant,
     req: types::DummyConnectorPaymentRetrieveRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
     utils::tokio_mock_sleep(
@@ -49,6 +67,12 @@ pub async fn payment_data(
         state.conf.dummy_connector.payment_retrieve_tolerance,
     )
     .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::PaymentRetrieve,
+    )
+    .await?;
 
     let payment_data = utils::get_payment_data_from_payment_id(&state, req.payment_id).await?;
     Ok(api::ApplicationResponse::Json(payment_data.into()))
@@ -109,11 +133,14 @@ pub async fn payment_complete(
     let _ = redis_conn.delete_key(req.attempt_id.as_str()).await;
 
     if let Ok(payment_data) = payment_data {
-        let updated_payment_data = types::DummyConnectorPaymentData {
-            status: payment_status,
+        let mut updated_payment_data = types::DummyConnectorPaymentData {
+            status: payment_data
+                .capture_method
+                .get_payment_status(payment_status),
             next_action: None,
             ..payment_data
         };
+        utils::setup_mandate_if_required(&state, &mut updated_payment_data).await?;
         utils::store_data_in_redis(
             &state,
             updated_payment_data.payment_id.clone(),
@@ -121,6 +148,7 @@ pub async fn payment_complete(
             state.conf.dummy_connector.payment_ttl,
         )
         .await?;
+        send_payment_webhook(&state, &updated_payment_data).await;
         return Ok(api::ApplicationResponse::JsonForRedirection(
             api_models::payments::RedirectionResponse {
                 return_url: String::new(),
@@ -144,8 +172,90 @@ pub async fn payment_complete(
     ))
 }
 
+pub async fn payment_capture(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+    req: types::DummyConnectorCaptureRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Capture,
+    )
+    .await?;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id).await?;
+
+    let amount_to_capture = req.amount.unwrap_or(payment_data.amount);
+    payment_data.is_eligible_for_capture(amount_to_capture)?;
+
+    let updated_payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Succeeded,
+        amount_captured: Some(amount_to_capture),
+        eligible_amount: amount_to_capture,
+        ..payment_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        updated_payment_data.payment_id.clone(),
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    send_payment_webhook(&state, &updated_payment_data).await;
+    Ok(api::ApplicationResponse::Json(updated_payment_data.into()))
+}
+
+pub async fn payment_void(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+    req: types::DummyConnectorVoidRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Void,
+    )
+    .await?;
+
+    let payment_data = utils::get_payment_data_from_payment_id(&state, req.payment_id).await?;
+    payment_data.is_eligible_for_void()?;
+
+    let updated_payment_data = types::DummyConnectorPaymentData {
+        status: types::DummyConnectorStatus::Cancelled,
+        eligible_amount: 0,
+        ..payment_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        updated_payment_data.payment_id.clone(),
+        updated_payment_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    send_payment_webhook(&state, &updated_payment_data).await;
+    Ok(api::ApplicationResponse::Json(updated_payment_data.into()))
+}
+
 pub async fn refund_payment(
     state: AppState,
+    merchant: types::DummyConnectorMerchant,
     req: types::DummyConnectorRefundRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
     utils::tokio_mock_sleep(
@@ -153,6 +263,12 @@ pub async fn refund_payment(
         state.conf.dummy_connector.refund_tolerance,
     )
     .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Refund,
+    )
+    .await?;
 
     let payment_id = req
         .payment_id
@@ -193,11 +309,19 @@ pub async fn refund_payment(
         state.conf.dummy_connector.refund_ttl,
     )
     .await?;
+    utils::send_webhook(
+        &state,
+        payment_data.merchant_key.as_deref(),
+        types::DummyConnectorWebhookEventType::RefundSucceeded,
+        types::DummyConnectorWebhookData::Refund(refund_data.clone()),
+    )
+    .await;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
 
 pub async fn refund_data(
     state: AppState,
+    merchant: types::DummyConnectorMerchant,
     req: types::DummyConnectorRefundRetrieveRequest,
 ) -> types::DummyConnectorResponse<types::DummyConnectorRefundResponse> {
     let refund_id = req.refund_id;
@@ -206,18 +330,264 @@ pub async fn refund_data(
         state.conf.dummy_connector.refund_retrieve_tolerance,
     )
     .await;
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::RefundRetrieve,
+    )
+    .await?;
 
-    let redis_conn = state
-        .store
-        .get_redis_conn()
-        .change_context(errors::DummyConnectorErrors::InternalServerError)
-        .attach_printable("Failed to get redis connection")?;
-    let refund_data = redis_conn
-        .get_and_deserialize_key::<types::DummyConnectorRefundResponse>(
-            refund_id.as_str(),
-            "DummyConnectorRefundResponse",
-        )
-        .await
-        .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
+    let refund_data = utils::get_refund_data(&state, refund_id.as_str()).await?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+pub async fn dispute_payment(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+    req: types::DummyConnectorDisputeRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Dispute,
+    )
+    .await?;
+
+    let payment_id = req
+        .payment_id
+        .get_required_value("payment_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+    let payment_data = utils::get_payment_data_from_payment_id(&state, payment_id).await?;
+
+    let dispute_amount = req.amount.unwrap_or(payment_data.eligible_amount);
+    payment_data.is_eligible_for_dispute(dispute_amount)?;
+
+    let created = common_utils::date_time::now();
+    let dispute_data = types::DummyConnectorDisputeData {
+        dispute_id: generate_id_with_default_len(consts::DISPUTE_ID_PREFIX),
+        payment_id: payment_data.payment_id,
+        status: types::DummyConnectorDisputeStatus::Opened,
+        amount: dispute_amount,
+        currency: payment_data.currency,
+        reason: req.reason,
+        created,
+        updated: created,
+        challenge_required_by: created + time::Duration::days(consts::DISPUTE_CHALLENGE_DAYS),
+        merchant_key: payment_data.merchant_key,
+    };
+    utils::store_data_in_redis(
+        &state,
+        dispute_data.dispute_id.clone(),
+        dispute_data.clone(),
+        state.conf.dummy_connector.dispute_ttl,
+    )
+    .await?;
+    send_dispute_webhook(&state, &dispute_data).await;
+    Ok(api::ApplicationResponse::Json(dispute_data.into()))
+}
+
+pub async fn dispute_data(
+    state: AppState,
+    req: types::DummyConnectorDisputeRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let dispute_data = utils::get_dispute_data(&state, req.dispute_id.as_str()).await?;
+    Ok(api::ApplicationResponse::Json(dispute_data.into()))
+}
+
+pub async fn dispute_resolve(
+    state: AppState,
+    req: types::DummyConnectorDisputeResolveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    if req.status == types::DummyConnectorDisputeStatus::Opened {
+        return Err(report!(errors::DummyConnectorErrors::InvalidRequest {
+            message: "A dispute can only be resolved as won or lost",
+        }));
+    }
+
+    let dispute_data = utils::get_dispute_data(&state, req.dispute_id.as_str()).await?;
+    if dispute_data.status != types::DummyConnectorDisputeStatus::Opened {
+        return Err(report!(errors::DummyConnectorErrors::InvalidRequest {
+            message: "The dispute is already resolved",
+        }));
+    }
+
+    let updated_dispute_data = types::DummyConnectorDisputeData {
+        status: req.status,
+        updated: common_utils::date_time::now(),
+        ..dispute_data
+    };
+    utils::store_data_in_redis(
+        &state,
+        updated_dispute_data.dispute_id.clone(),
+        updated_dispute_data.clone(),
+        state.conf.dummy_connector.dispute_ttl,
+    )
+    .await?;
+    send_dispute_webhook(&state, &updated_dispute_data).await;
+    Ok(api::ApplicationResponse::Json(updated_dispute_data.into()))
+}
+
+/// Creates a payout, which is settled asynchronously once the configured payout duration has
+/// passed. The settlement fails at the error rate the merchant configured for payouts, and its
+/// outcome is notified with a webhook.
+pub async fn payout(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+    req: types::DummyConnectorPayoutRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::simulate_faults(
+        &state,
+        merchant.merchant_key.as_deref(),
+        types::DummyConnectorFaultFlow::Payout,
+    )
+    .await?;
+
+    if req.amount <= 0 {
+        return Err(report!(errors::DummyConnectorErrors::InvalidRequest {
+            message: "Payout amount must be greater than zero",
+        }));
+    }
+
+    let payout_data = types::DummyConnectorPayoutData {
+        payout_id: generate_id_with_default_len(consts::PAYOUT_ID_PREFIX),
+        status: types::DummyConnectorStatus::Processing,
+        amount: req.amount,
+        currency: req.currency,
+        connector: req.connector,
+        created: common_utils::date_time::now(),
+        merchant_key: merchant.merchant_key,
+    };
+    utils::store_data_in_redis(
+        &state,
+        payout_data.payout_id.clone(),
+        payout_data.clone(),
+        state.conf.dummy_connector.payout_ttl,
+    )
+    .await?;
+
+    let settlement_state = state.clone();
+    let payout_id = payout_data.payout_id.clone();
+    tokio::spawn(async move {
+        utils::tokio_mock_sleep(
+            settlement_state.conf.dummy_connector.payout_duration,
+            settlement_state.conf.dummy_connector.payout_tolerance,
+        )
+        .await;
+        if let Err(error) = settle_payout(&settlement_state, payout_id.as_str()).await {
+            logger::error!(dummy_connector_payout_settlement_error=?error);
+        }
+    });
+
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
+
+pub async fn payout_data(
+    state: AppState,
+    req: types::DummyConnectorPayoutRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    let payout_data = utils::get_payout_data(&state, req.payout_id.as_str()).await?;
+    Ok(api::ApplicationResponse::Json(payout_data.into()))
+}
+
+pub async fn merchant_config_update(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+    req: types::DummyConnectorMerchantConfig,
+) -> types::DummyConnectorResponse<types::DummyConnectorMerchantConfig> {
+    let merchant_key = merchant
+        .merchant_key
+        .get_required_value("authorization")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "authorization",
+        })?;
+    req.validate()?;
+
+    utils::store_merchant_config(&state, merchant_key.as_str(), &req).await?;
+    Ok(api::ApplicationResponse::Json(req))
+}
+
+pub async fn merchant_config_retrieve(
+    state: AppState,
+    merchant: types::DummyConnectorMerchant,
+) -> types::DummyConnectorResponse<types::DummyConnectorMerchantConfig> {
+    let merchant_key = merchant
+        .merchant_key
+        .get_required_value("authorization")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "authorization",
+        })?;
+
+    let merchant_config = utils::get_merchant_config(&state, Some(merchant_key.as_str()))
+        .await?
+        .unwrap_or_default();
+    Ok(api::ApplicationResponse::Json(merchant_config))
+}
+
+async fn settle_payout(state: &AppState, payout_id: &str) -> types::DummyConnectorResult<()> {
+    let payout_data = utils::get_payout_data(state, payout_id).await?;
+    let is_failed = utils::get_merchant_config(state, payout_data.merchant_key.as_deref())
+        .await?
+        .filter(|merchant_config| {
+            merchant_config.applies_to(&types::DummyConnectorFaultFlow::Payout)
+        })
+        .and_then(|merchant_config| merchant_config.get_fault(rand::random::<f64>()))
+        .is_some();
+
+    let (status, event_type) = if is_failed {
+        (
+            types::DummyConnectorStatus::Failed,
+            types::DummyConnectorWebhookEventType::PayoutFailed,
+        )
+    } else {
+        (
+            types::DummyConnectorStatus::Succeeded,
+            types::DummyConnectorWebhookEventType::PayoutSucceeded,
+        )
+    };
+    let updated_payout_data = types::DummyConnectorPayoutData {
+        status,
+        ..payout_data
+    };
+    utils::store_data_in_redis(
+        state,
+        updated_payout_data.payout_id.clone(),
+        updated_payout_data.clone(),
+        state.conf.dummy_connector.payout_ttl,
+    )
+    .await?;
+    let merchant_key = updated_payout_data.merchant_key.clone();
+    utils::send_webhook(
+        state,
+        merchant_key.as_deref(),
+        event_type,
+        types::DummyConnectorWebhookData::Payout(updated_payout_data.into()),
+    )
+    .await;
+    Ok(())
+}
+
+async fn send_payment_webhook(state: &AppState, payment_data: &types::DummyConnectorPaymentData) {
+    if let Some(event_type) =
+        types::DummyConnectorWebhookEventType::from_payment_status(&payment_data.status)
+    {
+        utils::send_webhook(
+            state,
+            payment_data.merchant_key.as_deref(),
+            event_type,
+            types::DummyConnectorWebhookData::Payment(payment_data.clone().into()),
+        )
+        .await;
+    }
+}
+
+async fn send_dispute_webhook(state: &AppState, dispute_data: &types::DummyConnectorDisputeData) {
+    utils::send_webhook(
+        state,
+        dispute_data.merchant_key.as_deref(),
+        types::DummyConnectorWebhookEventType::from_dispute_status(&dispute_data.status),
+        types::DummyConnectorWebhookData::Dispute(dispute_data.clone().into()),
+    )
+    .await;
+}
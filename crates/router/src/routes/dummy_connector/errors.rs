@@ -37,6 +37,30 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Invalid request: {message}")]
+    InvalidRequest { message: &'static str },
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_10", message = "Simulated failure injected for the request")]
+    SimulatedFailure,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_11", message = "Simulated timeout injected for the request")]
+    SimulatedTimeout,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_12", message = "Payment is not authorized")]
+    PaymentNotAuthorized,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_13", message = "Capture amount exceeds the authorized amount")]
+    CaptureAmountExceedsPaymentAmount,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_14", message = "Dispute does not exist in our records")]
+    DisputeNotFound,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_15", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_16", message = "Mandate does not exist in our records")]
+    MandateNotFound,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +107,31 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::InvalidRequest { message: _ } => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::SimulatedFailure => {
+                AER::InternalServerError(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::SimulatedTimeout => AER::ConnectorError(
+                ApiError::new("DC", 11, self.error_message(), None),
+                reqwest::StatusCode::GATEWAY_TIMEOUT,
+            ),
+            Self::PaymentNotAuthorized => {
+                AER::BadRequest(ApiError::new("DC", 12, self.error_message(), None))
+            }
+            Self::CaptureAmountExceedsPaymentAmount => {
+                AER::BadRequest(ApiError::new("DC", 13, self.error_message(), None))
+            }
+            Self::DisputeNotFound => {
+                AER::NotFound(ApiError::new("DC", 14, self.error_message(), None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("DC", 15, self.error_message(), None))
+            }
+            Self::MandateNotFound => {
+                AER::NotFound(ApiError::new("DC", 16, self.error_message(), None))
+            }
         }
     }
 }
@@ -16,8 +16,17 @@ pub enum Flow {
     DummyPaymentRetrieve,
     DummyPaymentAuthorize,
     DummyPaymentComplete,
+    DummyPaymentCapture,
+    DummyPaymentVoid,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyDisputeCreate,
+    DummyDisputeRetrieve,
+    DummyDisputeResolve,
+    DummyPayoutCreate,
+    DummyPayoutRetrieve,
+    DummyMerchantConfigUpdate,
+    DummyMerchantConfigRetrieve,
 }
 
 impl FlowMetric for Flow {}
@@ -64,6 +73,29 @@ pub enum DummyConnectorStatus {
     #[default]
     Processing,
     Failed,
+    Authorized,
+    Cancelled,
+}
+
+#[derive(
+    Default, serde::Serialize, serde::Deserialize, strum::Display, Clone, PartialEq, Debug, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorCaptureMethod {
+    #[default]
+    Automatic,
+    Manual,
+}
+
+impl DummyConnectorCaptureMethod {
+    /// Successful payments captured manually are only authorized until they are captured
+    pub fn get_payment_status(&self, status: DummyConnectorStatus) -> DummyConnectorStatus {
+        match (status, self) {
+            (DummyConnectorStatus::Succeeded, Self::Manual) => DummyConnectorStatus::Authorized,
+            (status, _) => status,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -72,6 +104,7 @@ pub struct DummyConnectorPaymentAttempt {
     pub attempt_id: String,
     pub payment_id: String,
     pub payment_request: DummyConnectorPaymentRequest,
+    pub merchant_key: Option<String>,
 }
 
 impl From<DummyConnectorPaymentRequest> for DummyConnectorPaymentAttempt {
@@ -84,6 +117,7 @@ impl From<DummyConnectorPaymentRequest> for DummyConnectorPaymentAttempt {
             attempt_id,
             payment_id,
             payment_request,
+            merchant_key: None,
         }
     }
 }
@@ -95,18 +129,31 @@ impl DummyConnectorPaymentAttempt {
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
     ) -> DummyConnectorPaymentData {
+        let status = self
+            .payment_request
+            .capture_method
+            .get_payment_status(status);
+        let mandate_id = match &self.payment_request.payment_method_data {
+            DummyConnectorPaymentMethodData::Mandate(mandate) => Some(mandate.mandate_id.clone()),
+            _ => None,
+        };
         DummyConnectorPaymentData {
             attempt_id: self.attempt_id,
             payment_id: self.payment_id,
             status,
             amount: self.payment_request.amount,
             eligible_amount: self.payment_request.amount,
+            amount_captured: None,
             connector: self.payment_request.connector,
             created: self.timestamp,
             currency: self.payment_request.currency,
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            capture_method: self.payment_request.capture_method,
+            setup_mandate: self.payment_request.setup_mandate,
+            mandate_id,
+            merchant_key: self.merchant_key,
         }
     }
 }
@@ -118,6 +165,11 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    #[serde(default)]
+    pub capture_method: DummyConnectorCaptureMethod,
+    /// Whether a mandate should be set up with the payment, for charging it again later
+    #[serde(default)]
+    pub setup_mandate: bool,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -131,6 +183,7 @@ pub enum DummyConnectorPaymentMethodData {
     Card(DummyConnectorCard),
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    Mandate(DummyConnectorMandate),
 }
 
 #[derive(
@@ -142,6 +195,7 @@ pub enum DummyConnectorPaymentMethodType {
     Card,
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    Mandate,
 }
 
 impl From<DummyConnectorPaymentMethodData> for DummyConnectorPaymentMethodType {
@@ -150,6 +204,7 @@ impl From<DummyConnectorPaymentMethodData> for DummyConnectorPaymentMethodType {
             DummyConnectorPaymentMethodData::Card(_) => Self::Card,
             DummyConnectorPaymentMethodData::Wallet(wallet) => Self::Wallet(wallet),
             DummyConnectorPaymentMethodData::PayLater(pay_later) => Self::PayLater(pay_later),
+            DummyConnectorPaymentMethodData::Mandate(_) => Self::Mandate,
         }
     }
 }
@@ -160,12 +215,13 @@ impl GetPaymentMethodDetails for DummyConnectorPaymentMethodType {
             Self::Card => "3D Secure",
             Self::Wallet(wallet) => wallet.get_name(),
             Self::PayLater(pay_later) => pay_later.get_name(),
+            Self::Mandate => "Mandate",
         }
     }
 
     fn get_image_link(&self, base_url: &str) -> String {
         match self {
-            Self::Card => format!("{}{}", base_url, "CARD.svg"),
+            Self::Card | Self::Mandate => format!("{}{}", base_url, "CARD.svg"),
             Self::Wallet(wallet) => wallet.get_image_link(base_url),
             Self::PayLater(pay_later) => pay_later.get_image_link(base_url),
         }
@@ -181,6 +237,20 @@ pub struct DummyConnectorCard {
     pub cvc: Secret<String>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandate {
+    pub mandate_id: String,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandateData {
+    pub mandate_id: String,
+    pub payment_method_type: DummyConnectorPaymentMethodType,
+    pub currency: Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
 pub enum DummyConnectorCardFlow {
     NoThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
     ThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
@@ -252,6 +322,7 @@ pub struct DummyConnectorPaymentData {
     pub status: DummyConnectorStatus,
     pub amount: i64,
     pub eligible_amount: i64,
+    pub amount_captured: Option<i64>,
     pub currency: Currency,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub created: PrimitiveDateTime,
@@ -259,6 +330,12 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    #[serde(default)]
+    pub capture_method: DummyConnectorCaptureMethod,
+    #[serde(default)]
+    pub setup_mandate: bool,
+    pub mandate_id: Option<String>,
+    pub merchant_key: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
@@ -275,6 +352,41 @@ impl DummyConnectorPaymentData {
         }
         Ok(())
     }
+
+    pub fn is_eligible_for_capture(&self, capture_amount: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the capture"));
+        }
+        if capture_amount <= 0 || capture_amount > self.amount {
+            return Err(
+                report!(DummyConnectorErrors::CaptureAmountExceedsPaymentAmount)
+                    .attach_printable("Capture amount is not within the authorized amount"),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn is_eligible_for_void(&self) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Authorized {
+            return Err(report!(DummyConnectorErrors::PaymentNotAuthorized)
+                .attach_printable("Payment is not authorized to process the void"));
+        }
+        Ok(())
+    }
+
+    pub fn is_eligible_for_dispute(&self, dispute_amount: i64) -> DummyConnectorResult<()> {
+        if self.status != DummyConnectorStatus::Succeeded {
+            return Err(report!(DummyConnectorErrors::PaymentNotSuccessful)
+                .attach_printable("Payment is not successful to open a dispute"));
+        }
+        if dispute_amount <= 0 || dispute_amount > self.eligible_amount {
+            return Err(report!(DummyConnectorErrors::InvalidRequest {
+                message: "Dispute amount exceeds the captured amount of the payment",
+            }));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -293,6 +405,8 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub amount_captured: Option<i64>,
+    pub mandate_id: Option<String>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -305,6 +419,8 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            amount_captured: value.amount_captured,
+            mandate_id: value.mandate_id,
         }
     }
 }
@@ -330,6 +446,18 @@ pub struct DummyConnectorPaymentCompleteBody {
     pub confirm: bool,
 }
 
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorCaptureRequest {
+    /// Amount to capture, the whole authorized amount when not given
+    pub amount: Option<i64>,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorVoidRequest {
+    pub payment_id: String,
+}
+
 #[derive(Default, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
@@ -372,7 +500,484 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeRequest {
+    /// Disputed amount, the whole captured amount when not given
+    pub amount: Option<i64>,
+    pub reason: Option<String>,
+    pub payment_id: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+    Won,
+    Lost,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorDisputeData {
+    pub dispute_id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorDisputeStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub challenge_required_by: PrimitiveDateTime,
+    pub merchant_key: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorDisputeResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorDisputeStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub challenge_required_by: PrimitiveDateTime,
+}
+
+impl From<DummyConnectorDisputeData> for DummyConnectorDisputeResponse {
+    fn from(value: DummyConnectorDisputeData) -> Self {
+        Self {
+            id: value.dispute_id,
+            payment_id: value.payment_id,
+            status: value.status,
+            amount: value.amount,
+            currency: value.currency,
+            reason: value.reason,
+            created: value.created,
+            updated: value.updated,
+            challenge_required_by: value.challenge_required_by,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeRetrieveRequest {
+    pub dispute_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeResolveBody {
+    pub status: DummyConnectorDisputeStatus,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeResolveRequest {
+    pub dispute_id: String,
+    pub status: DummyConnectorDisputeStatus,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub connector: DummyConnectors,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorPayoutData {
+    pub payout_id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub connector: DummyConnectors,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub merchant_key: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorPayoutResponse {
+    pub id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    pub connector: DummyConnectors,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+impl From<DummyConnectorPayoutData> for DummyConnectorPayoutResponse {
+    fn from(value: DummyConnectorPayoutData) -> Self {
+        Self {
+            id: value.payout_id,
+            status: value.status,
+            amount: value.amount,
+            currency: value.currency,
+            connector: value.connector,
+            created: value.created,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRetrieveRequest {
+    pub payout_id: String,
+}
+
+/// The merchant a request to the dummy connector is made for, identified by a digest of the api
+/// key sent by the connector integration
+#[derive(Default, Debug, Clone)]
+pub struct DummyConnectorMerchant {
+    pub merchant_key: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorFaultFlow {
+    Payment,
+    PaymentRetrieve,
+    Capture,
+    Void,
+    Refund,
+    RefundRetrieve,
+    Dispute,
+    Payout,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DummyConnectorFault {
+    Failure,
+    Timeout,
+}
+
+/// Behaviour of the dummy connector for the requests of a merchant
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct DummyConnectorMerchantConfig {
+    /// Latency added to the responses, in milliseconds
+    #[serde(default)]
+    pub latency: u64,
+    /// Maximum deviation of the added latency, in milliseconds
+    #[serde(default)]
+    pub latency_tolerance: u64,
+    /// Fraction of the requests, between 0 and 1, that fail with an internal error
+    #[serde(default)]
+    pub error_rate: f64,
+    /// Fraction of the requests, between 0 and 1, that are held until the caller times out
+    #[serde(default)]
+    pub timeout_rate: f64,
+    /// Flows the latency and the faults apply to, every flow when empty
+    #[serde(default)]
+    pub flows: Vec<DummyConnectorFaultFlow>,
+    /// Url the webhooks for the payments, refunds, disputes and payouts are sent to
+    pub webhook_url: Option<String>,
+    /// Secret the webhooks are signed with
+    pub webhook_secret: Option<Secret<String>>,
+    /// Delay before a webhook is sent, in milliseconds
+    #[serde(default)]
+    pub webhook_delay: u64,
+}
+
+impl DummyConnectorMerchantConfig {
+    pub fn validate(&self) -> DummyConnectorResult<()> {
+        if !(0.0..=1.0).contains(&self.error_rate) || !(0.0..=1.0).contains(&self.timeout_rate) {
+            return Err(report!(DummyConnectorErrors::InvalidRequest {
+                message: "error_rate and timeout_rate must be between 0 and 1",
+            }));
+        }
+        if self.error_rate + self.timeout_rate > 1.0 {
+            return Err(report!(DummyConnectorErrors::InvalidRequest {
+                message: "error_rate and timeout_rate must not add up to more than 1",
+            }));
+        }
+        if self.latency_tolerance > self.latency {
+            return Err(report!(DummyConnectorErrors::InvalidRequest {
+                message: "latency_tolerance must not exceed latency",
+            }));
+        }
+        if self.webhook_url.is_some() && self.webhook_secret.is_none() {
+            return Err(report!(DummyConnectorErrors::MissingRequiredField {
+                field_name: "webhook_secret",
+            }));
+        }
+        Ok(())
+    }
+
+    pub fn applies_to(&self, flow: &DummyConnectorFaultFlow) -> bool {
+        self.flows.is_empty() || self.flows.contains(flow)
+    }
+
+    /// Picks the fault to inject for a sample drawn uniformly from `[0, 1)`
+    pub fn get_fault(&self, sample: f64) -> Option<DummyConnectorFault> {
+        if sample < self.timeout_rate {
+            Some(DummyConnectorFault::Timeout)
+        } else if sample < self.timeout_rate + self.error_rate {
+            Some(DummyConnectorFault::Failure)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentFailed,
+    PaymentAuthorized,
+    PaymentCancelled,
+    RefundSucceeded,
+    DisputeOpened,
+    DisputeWon,
+    DisputeLost,
+    PayoutSucceeded,
+    PayoutFailed,
+}
+
+impl DummyConnectorWebhookEventType {
+    pub fn from_payment_status(status: &DummyConnectorStatus) -> Option<Self> {
+        match status {
+            DummyConnectorStatus::Succeeded => Some(Self::PaymentSucceeded),
+            DummyConnectorStatus::Failed => Some(Self::PaymentFailed),
+            DummyConnectorStatus::Authorized => Some(Self::PaymentAuthorized),
+            DummyConnectorStatus::Cancelled => Some(Self::PaymentCancelled),
+            DummyConnectorStatus::Processing => None,
+        }
+    }
+
+    pub fn from_dispute_status(status: &DummyConnectorDisputeStatus) -> Self {
+        match status {
+            DummyConnectorDisputeStatus::Opened => Self::DisputeOpened,
+            DummyConnectorDisputeStatus::Won => Self::DisputeWon,
+            DummyConnectorDisputeStatus::Lost => Self::DisputeLost,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(untagged)]
+pub enum DummyConnectorWebhookData {
+    Payment(DummyConnectorPaymentResponse),
+    Refund(DummyConnectorRefundResponse),
+    Dispute(DummyConnectorDisputeResponse),
+    Payout(DummyConnectorPayoutResponse),
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DummyConnectorWebhook {
+    pub id: String,
+    pub event_type: DummyConnectorWebhookEventType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub data: DummyConnectorWebhookData,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
 pub type DummyConnectorResult<T> = CustomResult<T, DummyConnectorErrors>;
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use actix_web::http::{header, Method, Uri};
+    use api_models::webhooks::{
+        ConnectorWebhookSecrets, IncomingWebhookEvent, ObjectReferenceId, PayoutIdType,
+    };
+    use common_utils::crypto::{self, VerifySignature};
+
+    use super::*;
+    use crate::{
+        connector::DummyConnector,
+        routes::dummy_connector::utils,
+        types::api::{self, IncomingWebhook},
+    };
+
+    fn payment_data(status: DummyConnectorStatus) -> DummyConnectorPaymentData {
+        DummyConnectorPaymentData {
+            attempt_id: "dummy_attempt_1".to_string(),
+            payment_id: "dummy_pay_1".to_string(),
+            status,
+            amount: 1000,
+            eligible_amount: 1000,
+            amount_captured: None,
+            currency: Currency::USD,
+            created: common_utils::date_time::now(),
+            payment_method_type: DummyConnectorPaymentMethodType::Card,
+            connector: DummyConnectors::PhonyPay,
+            next_action: None,
+            return_url: None,
+            capture_method: DummyConnectorCaptureMethod::Manual,
+            setup_mandate: false,
+            mandate_id: None,
+            merchant_key: None,
+        }
+    }
+
+    #[test]
+    fn test_faults_are_picked_by_configured_rates() {
+        let merchant_config = DummyConnectorMerchantConfig {
+            timeout_rate: 0.25,
+            error_rate: 0.5,
+            ..Default::default()
+        };
+        assert!(merchant_config.validate().is_ok());
+        assert_eq!(
+            merchant_config.get_fault(0.1),
+            Some(DummyConnectorFault::Timeout)
+        );
+        assert_eq!(
+            merchant_config.get_fault(0.5),
+            Some(DummyConnectorFault::Failure)
+        );
+        assert_eq!(merchant_config.get_fault(0.75), None);
+        assert!(merchant_config.applies_to(&DummyConnectorFaultFlow::Refund));
+
+        let merchant_config = DummyConnectorMerchantConfig {
+            flows: vec![DummyConnectorFaultFlow::Capture],
+            ..merchant_config
+        };
+        assert!(!merchant_config.applies_to(&DummyConnectorFaultFlow::Refund));
+
+        let merchant_config = DummyConnectorMerchantConfig {
+            error_rate: 0.8,
+            ..merchant_config
+        };
+        assert!(merchant_config.validate().is_err());
+    }
+
+    #[test]
+    fn test_manually_captured_payment_lifecycle() {
+        assert_eq!(
+            DummyConnectorCaptureMethod::Manual.get_payment_status(DummyConnectorStatus::Succeeded),
+            DummyConnectorStatus::Authorized
+        );
+        assert_eq!(
+            DummyConnectorCaptureMethod::Manual.get_payment_status(DummyConnectorStatus::Failed),
+            DummyConnectorStatus::Failed
+        );
+
+        let authorized_payment = payment_data(DummyConnectorStatus::Authorized);
+        assert!(authorized_payment.is_eligible_for_capture(600).is_ok());
+        assert!(authorized_payment.is_eligible_for_capture(1200).is_err());
+        assert!(authorized_payment.is_eligible_for_void().is_ok());
+        assert!(authorized_payment.is_eligible_for_refund(100).is_err());
+
+        let captured_payment = DummyConnectorPaymentData {
+            amount_captured: Some(600),
+            eligible_amount: 600,
+            ..payment_data(DummyConnectorStatus::Succeeded)
+        };
+        assert!(captured_payment.is_eligible_for_capture(600).is_err());
+        assert!(captured_payment.is_eligible_for_void().is_err());
+        assert!(captured_payment.is_eligible_for_dispute(600).is_ok());
+        assert!(captured_payment.is_eligible_for_dispute(700).is_err());
+    }
+
+    #[test]
+    fn test_webhooks_are_verified_and_parsed_by_the_connector() {
+        let webhook_secret = "dummy_webhook_secret";
+        let connector = DummyConnector::<1>;
+        let connector_webhook_secrets = ConnectorWebhookSecrets {
+            secret: webhook_secret.as_bytes().to_vec(),
+            additional_secret: None,
+        };
+        let now = common_utils::date_time::now();
+        let payout_webhook = DummyConnectorWebhook {
+            id: "dummy_whevt_1".to_string(),
+            event_type: DummyConnectorWebhookEventType::PayoutFailed,
+            created: now,
+            data: DummyConnectorWebhookData::Payout(DummyConnectorPayoutResponse {
+                id: "dummy_po_1".to_string(),
+                status: DummyConnectorStatus::Failed,
+                amount: 1000,
+                currency: Currency::USD,
+                connector: DummyConnectors::PhonyPay,
+                created: now,
+            }),
+        };
+        let dispute_webhook = DummyConnectorWebhook {
+            id: "dummy_whevt_2".to_string(),
+            event_type: DummyConnectorWebhookEventType::DisputeOpened,
+            created: now,
+            data: DummyConnectorWebhookData::Dispute(DummyConnectorDisputeResponse {
+                id: "dummy_dp_1".to_string(),
+                payment_id: "dummy_pay_1".to_string(),
+                status: DummyConnectorDisputeStatus::Opened,
+                amount: 600,
+                currency: Currency::USD,
+                reason: None,
+                created: now,
+                updated: now,
+                challenge_required_by: now,
+            }),
+        };
+
+        for webhook in [payout_webhook, dispute_webhook] {
+            let body = serde_json::to_string(&webhook).unwrap();
+            let mut headers = header::HeaderMap::new();
+            headers.insert(
+                header::HeaderName::from_static("x-dummy-connector-signature"),
+                header::HeaderValue::from_str(
+                    &utils::get_webhook_signature(webhook_secret, &body).unwrap(),
+                )
+                .unwrap(),
+            );
+            let request = api::IncomingWebhookRequestDetails {
+                method: Method::POST,
+                uri: Uri::from_static("/webhooks/merchant_1/phonypay"),
+                headers: &headers,
+                body: body.as_bytes(),
+                query_params: String::new(),
+            };
+
+            let signature = connector
+                .get_webhook_source_verification_signature(&request, &connector_webhook_secrets)
+                .unwrap();
+            let message = connector
+                .get_webhook_source_verification_message(
+                    &request,
+                    "merchant_1",
+                    &connector_webhook_secrets,
+                )
+                .unwrap();
+            assert!(crypto::HmacSha256
+                .verify_signature(webhook_secret.as_bytes(), &signature, &message)
+                .unwrap());
+            assert!(!crypto::HmacSha256
+                .verify_signature(b"another_webhook_secret", &signature, &message)
+                .unwrap());
+
+            let event_type = connector.get_webhook_event_type(&request).unwrap();
+            let object_reference_id = connector.get_webhook_object_reference_id(&request).unwrap();
+            match webhook.data {
+                DummyConnectorWebhookData::Payout(_) => {
+                    assert_eq!(event_type, IncomingWebhookEvent::PayoutFailure);
+                    assert!(matches!(
+                        object_reference_id,
+                        ObjectReferenceId::PayoutId(PayoutIdType::ConnectorPayoutId(id))
+                            if id == "dummy_po_1"
+                    ));
+                }
+                _ => {
+                    assert_eq!(event_type, IncomingWebhookEvent::DisputeOpened);
+                    assert!(matches!(
+                        object_reference_id,
+                        ObjectReferenceId::PaymentId(
+                            api_models::payments::PaymentIdType::ConnectorTransactionId(id)
+                        ) if id == "dummy_pay_1"
+                    ));
+                    let dispute_details = connector.get_dispute_details(&request).unwrap();
+                    assert_eq!(dispute_details.connector_dispute_id, "dummy_dp_1");
+                    assert_eq!(dispute_details.amount, "600");
+                }
+            }
+        }
+    }
+}
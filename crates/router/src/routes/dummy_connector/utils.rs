@@ -1,6 +1,11 @@
 use std::fmt::Debug;
 
-use common_utils::ext_traits::AsyncExt;
+use actix_web::http::header::HeaderMap;
+use common_utils::{
+    crypto::{self, GenerateDigest, SignMessage},
+    ext_traits::{AsyncExt, Encode},
+    generate_id_with_default_len,
+};
 use error_stack::{report, IntoReport, ResultExt};
 use masking::PeekInterface;
 use maud::html;
@@ -11,18 +16,25 @@ use super::{
     consts, errors,
     types::{self, GetPaymentMethodDetails},
 };
-use crate::{configs::settings, routes::AppState};
+use crate::{
+    configs::settings,
+    core::errors::{ApiErrorResponse, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services::{self, authentication as auth},
+    types::RequestBody,
+};
 
 pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
-    let mut rng = rand::thread_rng();
-    // TODO: change this to `Uniform::try_from`
-    // this would require changing the fn signature
-    // to return a Result
-    let effective_delay = Uniform::from((delay - tolerance)..(delay + tolerance));
-    tokio::sleep(tokio::Duration::from_millis(
-        effective_delay.sample(&mut rng),
-    ))
-    .await
+    // The rng is not held across the await, so that the sleep can be spawned
+    let effective_delay = {
+        let mut rng = rand::thread_rng();
+        // TODO: change this to `Uniform::try_from`
+        // this would require changing the fn signature
+        // to return a Result
+        Uniform::from((delay - tolerance)..(delay + tolerance)).sample(&mut rng)
+    };
+    tokio::sleep(tokio::Duration::from_millis(effective_delay)).await
 }
 
 pub async fn store_data_in_redis(
@@ -89,6 +101,318 @@ pub async fn get_payment_data_by_attempt_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn get_refund_data(
+    state: &AppState,
+    refund_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorRefundResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorRefundResponse>(
+            refund_id,
+            "DummyConnectorRefundResponse",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::RefundNotFound)
+}
+
+pub async fn get_dispute_data(
+    state: &AppState,
+    dispute_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorDisputeData> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorDisputeData>(
+            dispute_id,
+            "DummyConnectorDisputeData",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::DisputeNotFound)
+}
+
+pub async fn get_payout_data(
+    state: &AppState,
+    payout_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorPayoutData> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorPayoutData>(
+            payout_id,
+            "DummyConnectorPayoutData",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::PayoutNotFound)
+}
+
+pub async fn get_mandate_data(
+    state: &AppState,
+    mandate_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorMandateData> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorMandateData>(
+            mandate_id,
+            "DummyConnectorMandateData",
+        )
+        .await
+        .change_context(errors::DummyConnectorErrors::MandateNotFound)
+}
+
+/// Sets up the mandate asked for with a payment once the payment is successful
+pub async fn setup_mandate_if_required(
+    state: &AppState,
+    payment_data: &mut types::DummyConnectorPaymentData,
+) -> types::DummyConnectorResult<()> {
+    let is_successful = matches!(
+        payment_data.status,
+        types::DummyConnectorStatus::Succeeded | types::DummyConnectorStatus::Authorized
+    );
+    if !payment_data.setup_mandate || !is_successful || payment_data.mandate_id.is_some() {
+        return Ok(());
+    }
+
+    let mandate_data = types::DummyConnectorMandateData {
+        mandate_id: generate_id_with_default_len(consts::MANDATE_ID_PREFIX),
+        payment_method_type: payment_data.payment_method_type.clone(),
+        currency: payment_data.currency,
+        created: common_utils::date_time::now(),
+    };
+    store_data_in_redis(
+        state,
+        mandate_data.mandate_id.clone(),
+        mandate_data.clone(),
+        state.conf.dummy_connector.mandate_ttl,
+    )
+    .await?;
+    payment_data.mandate_id = Some(mandate_data.mandate_id);
+    Ok(())
+}
+
+/// Authenticates the requests made by the connector integration, which sends the api key of the
+/// merchant in the authorization header. Requests without the header are not attributed to any
+/// merchant, so no merchant config applies to them.
+pub struct DummyConnectorAuth;
+
+#[async_trait::async_trait]
+impl auth::AuthenticateAndFetch<types::DummyConnectorMerchant, AppState> for DummyConnectorAuth {
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        _state: &AppState,
+    ) -> RouterResult<(types::DummyConnectorMerchant, auth::AuthenticationType)> {
+        let merchant_key =
+            auth::get_header_value_by_key(headers::AUTHORIZATION.to_string(), request_headers)?
+                .map(|api_key| {
+                    crypto::Sha256
+                        .generate_digest(api_key.as_bytes())
+                        .map(hex::encode)
+                        .change_context(ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to generate the dummy connector merchant key")
+                })
+                .transpose()?;
+        Ok((
+            types::DummyConnectorMerchant { merchant_key },
+            auth::AuthenticationType::NoAuth,
+        ))
+    }
+}
+
+fn get_merchant_config_key(merchant_key: &str) -> String {
+    format!("{}{}", consts::MERCHANT_CONFIG_KEY_PREFIX, merchant_key)
+}
+
+pub async fn store_merchant_config(
+    state: &AppState,
+    merchant_key: &str,
+    merchant_config: &types::DummyConnectorMerchantConfig,
+) -> types::DummyConnectorResult<()> {
+    store_data_in_redis(
+        state,
+        get_merchant_config_key(merchant_key),
+        merchant_config,
+        state.conf.dummy_connector.merchant_config_ttl,
+    )
+    .await
+}
+
+pub async fn get_merchant_config(
+    state: &AppState,
+    merchant_key: Option<&str>,
+) -> types::DummyConnectorResult<Option<types::DummyConnectorMerchantConfig>> {
+    let merchant_key = match merchant_key {
+        Some(merchant_key) => merchant_key,
+        None => return Ok(None),
+    };
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .get_and_deserialize_key::<types::DummyConnectorMerchantConfig>(
+            get_merchant_config_key(merchant_key).as_str(),
+            "DummyConnectorMerchantConfig",
+        )
+        .await
+    {
+        Ok(merchant_config) => Ok(Some(merchant_config)),
+        Err(error)
+            if matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(error) => Err(error)
+            .change_context(errors::DummyConnectorErrors::InternalServerError)
+            .attach_printable("Failed to fetch the dummy connector merchant config"),
+    }
+}
+
+/// Adds the latency and injects the faults the merchant configured for the flow
+pub async fn simulate_faults(
+    state: &AppState,
+    merchant_key: Option<&str>,
+    flow: types::DummyConnectorFaultFlow,
+) -> types::DummyConnectorResult<()> {
+    let merchant_config = match get_merchant_config(state, merchant_key).await? {
+        Some(merchant_config) if merchant_config.applies_to(&flow) => merchant_config,
+        _ => return Ok(()),
+    };
+
+    if merchant_config.latency_tolerance > 0 {
+        tokio_mock_sleep(merchant_config.latency, merchant_config.latency_tolerance).await;
+    } else if merchant_config.latency > 0 {
+        tokio::sleep(tokio::Duration::from_millis(merchant_config.latency)).await;
+    }
+
+    match merchant_config.get_fault(rand::random::<f64>()) {
+        Some(types::DummyConnectorFault::Timeout) => {
+            tokio::sleep(tokio::Duration::from_millis(
+                state.conf.dummy_connector.timeout_duration,
+            ))
+            .await;
+            Err(report!(errors::DummyConnectorErrors::SimulatedTimeout))
+                .attach_printable(format!("Simulated timeout for the {flow:?} flow"))
+        }
+        Some(types::DummyConnectorFault::Failure) => {
+            Err(report!(errors::DummyConnectorErrors::SimulatedFailure))
+                .attach_printable(format!("Simulated failure for the {flow:?} flow"))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Sends a webhook for the event to the url configured by the merchant, once the configured delay
+/// has passed. Failures are only logged, as they should not affect the flow the event is for.
+pub async fn send_webhook(
+    state: &AppState,
+    merchant_key: Option<&str>,
+    event_type: types::DummyConnectorWebhookEventType,
+    data: types::DummyConnectorWebhookData,
+) {
+    if let Err(error) = trigger_webhook(state, merchant_key, event_type, data).await {
+        logger::error!(dummy_connector_webhook_error=?error);
+    }
+}
+
+/// Signs the body of a webhook with the webhook secret of the merchant, the signature is sent hex
+/// encoded in the signature header
+pub fn get_webhook_signature(
+    webhook_secret: &str,
+    webhook_body: &str,
+) -> types::DummyConnectorResult<String> {
+    crypto::HmacSha256
+        .sign_message(webhook_secret.as_bytes(), webhook_body.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to sign the dummy connector webhook")
+}
+
+async fn trigger_webhook(
+    state: &AppState,
+    merchant_key: Option<&str>,
+    event_type: types::DummyConnectorWebhookEventType,
+    data: types::DummyConnectorWebhookData,
+) -> types::DummyConnectorResult<()> {
+    let (webhook_url, webhook_secret, webhook_delay) =
+        match get_merchant_config(state, merchant_key).await? {
+            Some(types::DummyConnectorMerchantConfig {
+                webhook_url: Some(webhook_url),
+                webhook_secret: Some(webhook_secret),
+                webhook_delay,
+                ..
+            }) => (webhook_url, webhook_secret, webhook_delay),
+            _ => return Ok(()),
+        };
+
+    let webhook = types::DummyConnectorWebhook {
+        id: generate_id_with_default_len(consts::WEBHOOK_ID_PREFIX),
+        event_type,
+        created: common_utils::date_time::now(),
+        data,
+    };
+    let webhook_body = RequestBody::log_and_get_request_body(
+        &webhook,
+        Encode::<types::DummyConnectorWebhook>::encode_to_string_of_json,
+    )
+    .change_context(errors::DummyConnectorErrors::InternalServerError)
+    .attach_printable("Failed to encode the dummy connector webhook")?;
+    let signature = get_webhook_signature(
+        webhook_secret.peek(),
+        RequestBody::get_inner_value(webhook_body.clone()).peek(),
+    )?;
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(&webhook_url)
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                consts::WEBHOOK_SIGNATURE_HEADER.to_string(),
+                signature.into(),
+            ),
+        ])
+        .body(Some(webhook_body))
+        .build();
+
+    let state = state.clone();
+    ::tokio::spawn(async move {
+        tokio::sleep(tokio::Duration::from_millis(webhook_delay)).await;
+        let response = state
+            .api_client
+            .send_request(&state, request, None, false)
+            .await;
+        logger::debug!(dummy_connector_webhook_response=?response);
+    });
+    Ok(())
+}
+
 pub fn get_authorize_page(
     payment_data: types::DummyConnectorPaymentData,
     return_url: String,
@@ -314,6 +638,16 @@ impl ProcessPaymentAttempt for types::DummyConnectorPayLater {
     }
 }
 
+impl ProcessPaymentAttempt for types::DummyConnectorMandate {
+    fn build_payment_data_from_payment_attempt(
+        self,
+        payment_attempt: types::DummyConnectorPaymentAttempt,
+        _redirect_url: String,
+    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+        Ok(payment_attempt.build_payment_data(types::DummyConnectorStatus::Succeeded, None, None))
+    }
+}
+
 impl ProcessPaymentAttempt for types::DummyConnectorPaymentMethodData {
     fn build_payment_data_from_payment_attempt(
         self,
@@ -330,6 +664,9 @@ impl ProcessPaymentAttempt for types::DummyConnectorPaymentMethodData {
             Self::PayLater(pay_later) => {
                 pay_later.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
             }
+            Self::Mandate(mandate) => {
+                mandate.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
+            }
         }
     }
 }
@@ -70,14 +70,19 @@ pub async fn get_create_file_request(
             .into_report()
             .attach_printable("Missing / Invalid file in the request")?
     }
-    // Get file mime type using 'infer'
-    let kind = infer::get(&file).ok_or(errors::ApiErrorResponse::MissingFileContentType)?;
-    let file_type = kind
-        .mime_type()
-        .parse::<mime::Mime>()
-        .into_report()
-        .change_context(errors::ApiErrorResponse::MissingFileContentType)
-        .attach_printable("File content type error")?;
+    // Get file mime type using 'infer', settlement reports are plain CSV which has no magic bytes
+    let file_type = match (infer::get(&file), &purpose) {
+        (Some(kind), _) => kind
+            .mime_type()
+            .parse::<mime::Mime>()
+            .into_report()
+            .change_context(errors::ApiErrorResponse::MissingFileContentType)
+            .attach_printable("File content type error")?,
+        (None, files::FilePurpose::SettlementReport) => mime::TEXT_CSV,
+        (None, files::FilePurpose::DisputeEvidence) => {
+            Err(errors::ApiErrorResponse::MissingFileContentType)?
+        }
+    };
     Ok(CreateFileRequest {
         file,
         file_name,
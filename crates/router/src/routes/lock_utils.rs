@@ -24,6 +24,8 @@ pub enum ApiIdentifier {
     ApiKeys,
     PaymentLink,
     Routing,
+    Recon,
+    ConnectorHealth,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -45,7 +47,21 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingRetrieveDictionary
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
-            | Flow::RoutingDeleteConfig => Self::Routing,
+            | Flow::RoutingDeleteConfig
+            | Flow::RoutingRetrieveTimezoneConfig
+            | Flow::RoutingUpdateTimezoneConfig
+            | Flow::RoutingDiffConfig
+            | Flow::RoutingRetrieveVersions
+            | Flow::RoutingRollbackConfig
+            | Flow::RoutingScheduleActivation
+            | Flow::RoutingRetrieveActivationAudit
+            | Flow::RoutingStartCanary
+            | Flow::RoutingPromoteCanary
+            | Flow::RoutingAbortCanary
+            | Flow::RoutingRetrieveCanary
+            | Flow::SurchargeDecisionManagerUpsertConfig
+            | Flow::SurchargeDecisionManagerRetrieveConfig
+            | Flow::SurchargeDecisionManagerDeleteConfig => Self::Routing,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
@@ -88,6 +104,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::PaymentsRoutingTrace
+            | Flow::PaymentsConnectorFeeAggregate
             | Flow::PaymentsRedirect => Self::Payments,
 
             Flow::PayoutsCreate
@@ -95,6 +113,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsList
+            | Flow::PayoutsFilter
             | Flow::PayoutsAccounts => Self::Payouts,
 
             Flow::RefundsCreate
@@ -102,7 +122,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsUpdate
             | Flow::RefundsList => Self::Refunds,
 
-            Flow::IncomingWebhookReceive => Self::Webhooks,
+            Flow::IncomingWebhookReceive
+            | Flow::IncomingWebhookList
+            | Flow::IncomingWebhookRetrieve
+            | Flow::IncomingWebhookReprocess => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -126,11 +149,27 @@ impl From<Flow> for ApiIdentifier {
             | Flow::BusinessProfileUpdate
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
-            | Flow::BusinessProfileList => Self::Business,
+            | Flow::BusinessProfileList
+            | Flow::WebhookSigningKeyRotate
+            | Flow::WebhookSigningKeyList
+            | Flow::WebhookSigningKeyRevoke
+            | Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookDeliveryList => Self::Business,
 
             Flow::Verification => Self::Verification,
 
             Flow::PaymentLinkInitiate | Flow::PaymentLinkRetrieve => Self::PaymentLink,
+
+            Flow::ReconReportCreate
+            | Flow::ReconReportRetrieve
+            | Flow::ReconReportList
+            | Flow::ReconRecordList => Self::Recon,
+
+            Flow::ConnectorHealthRetrieve => Self::ConnectorHealth,
         }
     }
 }
@@ -13,6 +13,7 @@ counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
 histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
+counter_metric!(EXTERNAL_REQUEST_RETRY_COUNT, GLOBAL_METER); // No. of retried idempotent requests
 
 // Operation Level Metrics
 counter_metric!(PAYMENT_OPS_COUNT, GLOBAL_METER);
@@ -67,6 +68,10 @@ counter_metric!(RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
 counter_metric!(CONNECTOR_ERROR_RESPONSE_COUNT, GLOBAL_METER);
 counter_metric!(REQUEST_TIMEOUT_COUNT, GLOBAL_METER);
 
+// Connector health and circuit breaker metrics
+counter_metric!(CONNECTOR_CIRCUIT_STATE_CHANGE, GLOBAL_METER); // Attributes needed
+counter_metric!(CONNECTOR_CIRCUIT_OPEN_SKIP_COUNT, GLOBAL_METER); // No. of times routing skipped a connector with an open circuit
+
 counter_metric!(EXECUTE_PRETASK_COUNT, GLOBAL_METER);
 counter_metric!(CONNECTOR_PAYMENT_METHOD_TOKENIZATION, GLOBAL_METER);
 counter_metric!(PREPROCESSING_STEPS_COUNT, GLOBAL_METER);
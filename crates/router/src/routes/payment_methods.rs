@@ -130,8 +130,8 @@ pub async fn list_customer_payment_method_api(
 ) -> HttpResponse {
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+    let (auth, auth_flow) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+        Ok((auth, auth_flow)) => (auth, auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
     let customer_id = customer_id.into_inner().0;
@@ -147,6 +147,7 @@ pub async fn list_customer_payment_method_api(
                 auth.key_store,
                 Some(req),
                 Some(&customer_id),
+                auth_flow,
             )
         },
         &*auth,
@@ -187,8 +188,8 @@ pub async fn list_customer_payment_method_api_client(
 ) -> HttpResponse {
     let flow = Flow::CustomerPaymentMethodsList;
     let payload = query_payload.into_inner();
-    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+    let (auth, auth_flow) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+        Ok((auth, auth_flow)) => (auth, auth_flow),
         Err(e) => return api::log_and_return_error_response(e),
     };
     api::server_wrap(
@@ -203,6 +204,7 @@ pub async fn list_customer_payment_method_api_client(
                 auth.key_store,
                 Some(req),
                 None,
+                auth_flow,
             )
         },
         &*auth,
@@ -244,7 +246,7 @@ pub async fn payment_method_retrieve_api(
         state,
         &req,
         payload,
-        |state, _auth, pm| cards::retrieve_payment_method(state, pm),
+        |state, auth, pm| cards::retrieve_payment_method(state, auth.merchant_account, pm),
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     )
@@ -873,6 +873,54 @@ pub async fn get_filters_for_payments(
     )
     .await
 }
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsConnectorFeeAggregate))]
+#[cfg(feature = "olap")]
+pub async fn get_connector_fee_aggregates(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<payment_types::TimeRange>,
+) -> impl Responder {
+    let flow = Flow::PaymentsConnectorFeeAggregate;
+    let payload = payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::get_connector_fee_aggregates(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRoutingTrace))]
+#[cfg(feature = "olap")]
+// #[get("/{payment_id}/routing_trace")]
+pub async fn payments_routing_trace(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<payment_types::PaymentsRoutingTraceRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRoutingTrace;
+    let payment_id = path.into_inner();
+    let payload = query_payload.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            payments::get_routing_trace(state, auth.merchant_account, payment_id.clone(), req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 async fn authorize_verify_select<Op, Ctx>(
     operation: Op,
     state: app::AppState,
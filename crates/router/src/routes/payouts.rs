@@ -200,6 +200,72 @@ pub async fn payouts_fulfill(
     )
     .await
 }
+/// Payouts - List
+///
+/// To list the payouts associated with the merchant, filtered by the given constraints
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[utoipa::path(
+    post,
+    path = "/payouts/list",
+    request_body=PayoutListRequest,
+    responses(
+        (status = 200, description = "List of payouts", body = PayoutListResponse),
+    ),
+    tag = "Payouts",
+    operation_id = "List all Payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
+pub async fn payouts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutListRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| payouts_list_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+/// Payouts - Filter
+///
+/// To list the available payout filters (connectors, currencies, statuses and payout types) within a time range
+#[cfg(all(feature = "olap", feature = "payouts"))]
+#[utoipa::path(
+    post,
+    path = "/payouts/filter",
+    request_body=TimeRange,
+    responses(
+        (status = 200, description = "List of filters", body = PayoutListFilters),
+    ),
+    tag = "Payouts",
+    operation_id = "List all filters for Payouts",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsFilter))]
+pub async fn payouts_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::refunds::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsFilter;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| payouts_filter_core(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsAccounts))]
 // #[get("/accounts")]
 pub async fn payouts_accounts() -> impl Responder {
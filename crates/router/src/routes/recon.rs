@@ -0,0 +1,164 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::recon as recon_models;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, recon},
+    services::{api, authentication as auth},
+};
+
+/// Recon - Create Report
+///
+/// To reconcile a settlement report uploaded through the files API against the stored payments,
+/// refunds and disputes
+#[utoipa::path(
+    post,
+    path = "/recon/reports",
+    request_body = ReconReportCreateRequest,
+    responses(
+        (status = 200, description = "Recon report created", body = ReconReportResponse),
+        (status = 400, description = "Invalid settlement report")
+    ),
+    tag = "Recon",
+    operation_id = "Create a Recon Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportCreate))]
+pub async fn recon_report_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_models::ReconReportCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconReportCreate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            recon::recon_report_create_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Recon - Retrieve Report
+///
+/// To retrieve a recon report along with the matched and unmatched counts of every settlement batch
+#[utoipa::path(
+    get,
+    path = "/recon/reports/{report_id}",
+    params(
+        ("report_id" = String, Path, description = "The identifier for recon report")
+    ),
+    responses(
+        (status = 200, description = "Recon report retrieved", body = ReconReportResponse),
+        (status = 404, description = "Recon report does not exist in our records")
+    ),
+    tag = "Recon",
+    operation_id = "Retrieve a Recon Report",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportRetrieve))]
+pub async fn recon_report_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReconReportRetrieve;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth, report_id| {
+            recon::recon_report_retrieve_core(state, auth.merchant_account, report_id)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Recon - List Reports
+///
+/// To list the recon reports of a merchant
+#[utoipa::path(
+    get,
+    path = "/recon/reports",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of recon reports to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of recon reports to skip"),
+    ),
+    responses(
+        (status = 200, description = "Recon reports listed", body = ReconReportListResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Recon",
+    operation_id = "List Recon Reports",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconReportList))]
+pub async fn recon_report_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<recon_models::ReconReportListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconReportList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req| recon::recon_report_list_core(state, auth.merchant_account, req),
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Recon - List Records
+///
+/// To list the reconciled settlement report lines of a recon report
+#[utoipa::path(
+    get,
+    path = "/recon/reports/{report_id}/records",
+    params(
+        ("report_id" = String, Path, description = "The identifier for recon report"),
+        ("outcome" = Option<ReconOutcome>, Query, description = "Only list the records with this outcome"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of recon records to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of recon records to skip"),
+    ),
+    responses(
+        (status = 200, description = "Recon records listed", body = ReconRecordListResponse),
+        (status = 404, description = "Recon report does not exist in our records")
+    ),
+    tag = "Recon",
+    operation_id = "List Recon Records",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ReconRecordList))]
+pub async fn recon_record_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    payload: web::Query<recon_models::ReconRecordListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconRecordList;
+    let report_id = path.into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req| {
+            recon::recon_record_list_core(state, auth.merchant_account, report_id.clone(), req)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
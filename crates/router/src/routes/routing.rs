@@ -3,16 +3,16 @@
 //! Functions that are used to perform the api level configuration, retrieval, updation
 //! of Routing configs.
 use actix_web::{web, HttpRequest, Responder};
-use api_models::routing as routing_types;
 #[cfg(feature = "business_profile_routing")]
 use api_models::routing::{RoutingRetrieveLinkQuery, RoutingRetrieveQuery};
+use api_models::{routing as routing_types, surcharge_decision_configs};
 use router_env::{
     tracing::{self, instrument},
     Flow,
 };
 
 use crate::{
-    core::{api_locking, routing},
+    core::{api_locking, routing, surcharge_decision_config},
     routes::AppState,
     services::{api as oss_api, authentication as oss_auth, authentication as auth},
 };
@@ -201,6 +201,268 @@ pub async fn routing_unlink_config(
     }
 }
 
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_rollback_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingRollbackRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingRollbackConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, payload| {
+            routing::rollback_routing_config(
+                state,
+                auth.merchant_account,
+                #[cfg(not(feature = "business_profile_routing"))]
+                auth.key_store,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_schedule_activation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<routing_types::RoutingActivationScheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingScheduleActivation;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (path.into_inner(), json_payload.into_inner()),
+        |state, auth: oss_auth::AuthenticationData, (algorithm_id, payload)| {
+            routing::schedule_routing_config_activation(
+                state,
+                auth.merchant_account,
+                algorithm_id,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_start_canary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<routing_types::RoutingCanaryStartRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingStartCanary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (path.into_inner(), json_payload.into_inner()),
+        |state, auth: oss_auth::AuthenticationData, (algorithm_id, payload)| {
+            routing::start_routing_canary(
+                state,
+                auth.merchant_account,
+                #[cfg(not(feature = "business_profile_routing"))]
+                auth.key_store,
+                algorithm_id,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_promote_canary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingCanaryActionRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingPromoteCanary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, payload| {
+            routing::promote_routing_canary(
+                state,
+                auth.merchant_account,
+                #[cfg(not(feature = "business_profile_routing"))]
+                auth.key_store,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_abort_canary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingCanaryActionRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingAbortCanary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, payload| {
+            routing::abort_routing_canary(
+                state,
+                auth.merchant_account,
+                #[cfg(not(feature = "business_profile_routing"))]
+                auth.key_store,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_canary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingCanaryQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveCanary;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, query_params| {
+            routing::retrieve_routing_canary(state, auth.merchant_account, query_params)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_versions(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveVersions;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, algorithm_id| {
+            routing::list_routing_config_versions(state, auth.merchant_account, algorithm_id)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_diff_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::RoutingDiffConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, (algorithm_id, other_algorithm_id)| {
+            routing::diff_routing_configs(
+                state,
+                auth.merchant_account,
+                algorithm_id,
+                other_algorithm_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_activation_audit(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<routing_types::RoutingActivationAuditQuery>,
+) -> impl Responder {
+    let flow = Flow::RoutingRetrieveActivationAudit;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, query_params| {
+            routing::retrieve_routing_activation_audit(state, auth.merchant_account, query_params)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn routing_update_default_config(
@@ -248,6 +510,53 @@ pub async fn routing_retrieve_default_config(
     .await
 }
 
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_timezone_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    oss_api::server_wrap(
+        Flow::RoutingRetrieveTimezoneConfig,
+        state,
+        &req,
+        (),
+        |state, auth: oss_auth::AuthenticationData, _| {
+            routing::retrieve_routing_timezone_config(state, auth.merchant_account)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn routing_update_timezone_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<routing_types::RoutingTimezoneConfig>,
+) -> impl Responder {
+    oss_api::server_wrap(
+        Flow::RoutingUpdateTimezoneConfig,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, payload| {
+            routing::update_routing_timezone_config(state, auth.merchant_account, payload)
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn routing_retrieve_linked_config(
@@ -296,3 +605,88 @@ pub async fn routing_retrieve_linked_config(
         .await
     }
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn upsert_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<surcharge_decision_configs::SurchargeDecisionManagerRequest>,
+) -> impl Responder {
+    let flow = Flow::SurchargeDecisionManagerUpsertConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: oss_auth::AuthenticationData, payload| {
+            surcharge_decision_config::upsert_surcharge_decision_config(
+                state,
+                auth.key_store,
+                auth.merchant_account,
+                payload,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn retrieve_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SurchargeDecisionManagerRetrieveConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: oss_auth::AuthenticationData, _| {
+            surcharge_decision_config::retrieve_surcharge_decision_config(
+                state,
+                auth.merchant_account,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn delete_surcharge_decision_manager_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SurchargeDecisionManagerDeleteConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: oss_auth::AuthenticationData, _| {
+            surcharge_decision_config::delete_surcharge_decision_config(
+                state,
+                auth.key_store,
+                auth.merchant_account,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(&oss_auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
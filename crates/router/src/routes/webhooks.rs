@@ -1,4 +1,6 @@
 use actix_web::{web, HttpRequest, Responder};
+#[cfg(feature = "olap")]
+use api_models::webhooks::IncomingWebhookListConstraints;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
@@ -41,3 +43,77 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     )
     .await
 }
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookList))]
+pub async fn list_incoming_webhooks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<IncomingWebhookListConstraints>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookList;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth, constraints| {
+            webhooks::incoming::list_incoming_webhooks(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookRetrieve))]
+pub async fn retrieve_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth, webhook_id| {
+            webhooks::incoming::retrieve_incoming_webhook(state, auth.merchant_account, webhook_id)
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReprocess))]
+pub async fn reprocess_incoming_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookReprocess;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth, webhook_id| {
+            webhooks::incoming::reprocess_incoming_webhook::<Oss>(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                webhook_id,
+            )
+        },
+        auth::auth_type(&auth::ApiKeyAuth, &auth::JWTAuth, req.headers()),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -12,6 +12,8 @@ use std::{
 use actix_web::{body, web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError};
 use api_models::enums::{AttemptStatus, CaptureMethod};
 pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
+#[cfg(feature = "record_replay")]
+pub use client::{RecordReplayApiClient, RecordReplayMode};
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
 use common_utils::{
     consts::X_HS_LATENCY,
@@ -256,6 +258,14 @@ pub enum CaptureSyncMethod {
     Bulk,
 }
 
+/// Flow of a connector integration
+pub trait ConnectorFlow {
+    /// Whether the flow only reads the state of a payment or refund at the connector, so that its
+    /// requests can be sent again whatever their http method is, as some connectors look them up
+    /// with a POST
+    const IS_IDEMPOTENT: bool = false;
+}
+
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
@@ -274,7 +284,7 @@ pub async fn execute_connector_processing_step<
     connector_request: Option<Request>,
 ) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
 where
-    T: Clone + Debug,
+    T: Clone + Debug + ConnectorFlow,
     // BoxedConnectorIntegration<T, Req, Resp>: 'b,
 {
     // If needed add an error stack as follows
@@ -348,7 +358,11 @@ where
                 })?);
 
             match connector_request {
-                Some(request) => {
+                Some(mut request) => {
+                    request.set_connector(req.connector.clone());
+                    if T::IS_IDEMPOTENT {
+                        request.allow_retries();
+                    }
                     logger::debug!(connector_request=?request);
                     let current_time = Instant::now();
                     let response = call_connector_api(state, request).await;
@@ -494,21 +508,52 @@ pub async fn send_request(
         || proxy_bypass_urls(&state.conf.locker).contains(&url.to_string());
     #[cfg(not(feature = "dummy_connector"))]
     let should_bypass_proxy = proxy_bypass_urls(&state.conf.locker).contains(&url.to_string());
+    let (http_client_config, has_connector_override) = state
+        .conf
+        .connectors
+        .http_client
+        .get_config(request.connector.as_deref());
     let client = client::create_client(
         &state.conf.proxy,
         should_bypass_proxy,
         request.certificate,
         request.certificate_key,
+        request
+            .connector
+            .as_deref()
+            .filter(|_| has_connector_override),
+        &http_client_config,
     )?;
 
     let headers = request.headers.construct_header_map()?;
-    let metrics_tag = router_env::opentelemetry::KeyValue {
+    let mut metrics_tags = vec![router_env::opentelemetry::KeyValue {
         key: consts::METRICS_HOST_TAG_NAME.into(),
         value: url.host_str().unwrap_or_default().to_string().into(),
+    }];
+    if let Some(connector) = request.connector.as_ref() {
+        metrics_tags.push(metrics::request::add_attributes(
+            consts::METRICS_CONNECTOR_TAG_NAME,
+            connector.to_owned(),
+        ));
+    }
+
+    // Only requests without side effects are safe to be sent again, which the caller decides
+    let max_retries = if request.retry_allowed {
+        http_client_config.max_retries.unwrap_or_default()
+    } else {
+        0
     };
+    let retry_backoff = Duration::from_millis(
+        http_client_config
+            .retry_backoff_ms
+            .unwrap_or(consts::DEFAULT_CONNECTOR_RETRY_BACKOFF_MS),
+    );
+    let request_timeout = option_timeout_secs
+        .or(http_client_config.request_timeout)
+        .unwrap_or(crate::consts::REQUEST_TIME_OUT);
 
     let send_request = async {
-        match request.method {
+        let request_builder = match request.method {
             Method::Get => client.get(url),
             Method::Post => {
                 let client = client.post(url);
@@ -552,34 +597,83 @@ pub async fn send_request(
             Method::Delete => client.delete(url),
         }
         .add_headers(headers)
-        .timeout(Duration::from_secs(
-            option_timeout_secs.unwrap_or(crate::consts::REQUEST_TIME_OUT),
-        ))
-        .send()
-        .await
-        .map_err(|error| match error {
-            error if error.is_timeout() => {
-                metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                errors::ApiClientError::RequestTimeoutReceived
-            }
-            error if is_connection_closed(&error) => {
-                metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                errors::ApiClientError::ConnectionClosed
-            }
-            _ => errors::ApiClientError::RequestNotSent(error.to_string()),
-        })
-        .into_report()
-        .attach_printable("Unable to send request to connector")
+        .timeout(Duration::from_secs(request_timeout));
+
+        send_with_retries(request_builder, max_retries, retry_backoff, &metrics_tags)
+            .await
+            .map_err(|error| match error {
+                error if error.is_timeout() => {
+                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+                    errors::ApiClientError::RequestTimeoutReceived
+                }
+                error if is_connection_closed(&error) => {
+                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+                    errors::ApiClientError::ConnectionClosed
+                }
+                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+            })
+            .into_report()
+            .attach_printable("Unable to send request to connector")
     };
 
     metrics_request::record_operation_time(
         send_request,
         &metrics::EXTERNAL_REQUEST_TIME,
-        &[metrics_tag],
+        &metrics_tags,
     )
     .await
 }
 
+/// Sends the request, retrying it up to `max_retries` times on connection failures, timeouts
+/// and gateway errors. The caller must ensure that the request is idempotent.
+async fn send_with_retries(
+    request: reqwest::RequestBuilder,
+    max_retries: u8,
+    retry_backoff: Duration,
+    metrics_tags: &[router_env::opentelemetry::KeyValue],
+) -> Result<reqwest::Response, reqwest::Error> {
+    let mut request = request;
+    let mut backoff = retry_backoff;
+
+    for _ in 0..max_retries {
+        // Requests with streaming bodies cannot be cloned and are sent only once
+        let retry_request = match request.try_clone() {
+            Some(retry_request) => retry_request,
+            None => break,
+        };
+
+        match request.send().await {
+            Ok(response) if is_retryable_status(response.status()) => {
+                logger::warn!(status = ?response.status(), "Retrying request to connector");
+            }
+            Err(error) if is_retryable_error(&error) => {
+                logger::warn!(?error, "Retrying request to connector");
+            }
+            result => return result,
+        }
+
+        metrics::EXTERNAL_REQUEST_RETRY_COUNT.add(&metrics::CONTEXT, 1, metrics_tags);
+        tokio::time::sleep(backoff).await;
+        backoff = backoff.saturating_mul(2);
+        request = retry_request;
+    }
+
+    request.send().await
+}
+
+fn is_retryable_status(status: http::StatusCode) -> bool {
+    matches!(
+        status,
+        http::StatusCode::BAD_GATEWAY
+            | http::StatusCode::SERVICE_UNAVAILABLE
+            | http::StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout() || is_connection_closed(error)
+}
+
 fn is_connection_closed(error: &reqwest::Error) -> bool {
     let mut source = error.source();
     while let Some(err) = source {
@@ -1425,6 +1519,38 @@ mod tests {
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_only_gateway_errors_are_retried() {
+        assert!(super::is_retryable_status(http::StatusCode::BAD_GATEWAY));
+        assert!(super::is_retryable_status(
+            http::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert!(super::is_retryable_status(
+            http::StatusCode::GATEWAY_TIMEOUT
+        ));
+        assert!(!super::is_retryable_status(
+            http::StatusCode::INTERNAL_SERVER_ERROR
+        ));
+        assert!(!super::is_retryable_status(
+            http::StatusCode::TOO_MANY_REQUESTS
+        ));
+    }
+
+    #[test]
+    fn test_only_sync_flows_are_retried() {
+        use super::ConnectorFlow;
+        use crate::types::api;
+
+        let idempotency = [
+            api::PSync::IS_IDEMPOTENT,
+            api::RSync::IS_IDEMPOTENT,
+            api::Authorize::IS_IDEMPOTENT,
+            api::Capture::IS_IDEMPOTENT,
+            api::Execute::IS_IDEMPOTENT,
+        ];
+        assert_eq!(idempotency, [true, true, false, false, false]);
+    }
 }
 
 pub fn build_payment_link_html(
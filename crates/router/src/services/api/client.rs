@@ -1,15 +1,20 @@
-use std::time::Duration;
+#[cfg(feature = "record_replay")]
+pub mod record_replay;
+
+use std::{collections::HashMap, sync::RwLock, time::Duration};
 
 use error_stack::{IntoReport, ResultExt};
 use http::{HeaderValue, Method};
 use masking::PeekInterface;
-use once_cell::sync::OnceCell;
+use once_cell::sync::Lazy;
 use reqwest::multipart::Form;
 use router_env::tracing_actix_web::RequestId;
 
+#[cfg(feature = "record_replay")]
+pub use self::record_replay::{RecordReplayApiClient, RecordReplayMode};
 use super::{request::Maskable, Request};
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{ConnectorHttpClientConfig, Locker, Proxy},
     core::{
         errors::{ApiClientError, CustomResult},
         payments,
@@ -17,12 +22,29 @@ use crate::{
     routes::AppState,
 };
 
-static NON_PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
-static PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+/// Upper bound on the number of cached clients, after which the clients built with client
+/// certificates are dropped and rebuilt on demand
+const MAX_CACHED_CLIENTS: usize = 1024;
+
+/// Clients are reused across requests so that connections are pooled, the shared clients are
+/// keyed only by whether they go through the proxy, while connectors with their own http client
+/// settings and merchant connector accounts using client certificates get dedicated clients
+static CLIENTS: Lazy<RwLock<HashMap<ClientCacheKey, reqwest::Client>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ClientCacheKey {
+    is_proxied: bool,
+    /// Present only for connectors having an override in the http client settings
+    connector: Option<String>,
+    /// SHA-256 fingerprint of the client certificate and key used for mTLS
+    certificate_fingerprint: Option<String>,
+}
 
 fn get_client_builder(
     proxy_config: &Proxy,
     should_bypass_proxy: bool,
+    http_client_config: &ConnectorHttpClientConfig,
 ) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
     let mut client_builder = reqwest::Client::builder()
         .redirect(reqwest::redirect::Policy::none())
@@ -32,6 +54,14 @@ fn get_client_builder(
                 .unwrap_or_default(),
         ));
 
+    if let Some(connect_timeout) = http_client_config.connect_timeout {
+        client_builder = client_builder.connect_timeout(Duration::from_secs(connect_timeout));
+    }
+
+    if let Some(pool_max_idle_per_host) = http_client_config.pool_max_idle_per_host {
+        client_builder = client_builder.pool_max_idle_per_host(pool_max_idle_per_host);
+    }
+
     if should_bypass_proxy {
         return Ok(client_builder);
     }
@@ -59,52 +89,102 @@ fn get_client_builder(
     Ok(client_builder)
 }
 
-fn get_base_client(
-    proxy_config: &Proxy,
-    should_bypass_proxy: bool,
+fn get_certificate_fingerprint(certificate: &str, certificate_key: &str) -> String {
+    let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+    context.update(certificate.as_bytes());
+    context.update(certificate_key.as_bytes());
+    hex::encode(context.finish())
+}
+
+fn get_or_create_client(
+    key: ClientCacheKey,
+    create_client: impl FnOnce() -> CustomResult<reqwest::Client, ApiClientError>,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
-    Ok(if should_bypass_proxy
-        || (proxy_config.http_url.is_none() && proxy_config.https_url.is_none())
+    if let Some(client) = CLIENTS
+        .read()
+        .map_err(|_| ApiClientError::ClientConstructionFailed)
+        .into_report()
+        .attach_printable("Http client cache lock is poisoned")?
+        .get(&key)
     {
-        &NON_PROXIED_CLIENT
-    } else {
-        &PROXIED_CLIENT
+        return Ok(client.clone());
     }
-    .get_or_try_init(|| {
-        get_client_builder(proxy_config, should_bypass_proxy)?
-            .build()
-            .into_report()
-            .change_context(ApiClientError::ClientConstructionFailed)
-            .attach_printable("Failed to construct base client")
-    })?
-    .clone())
+
+    let client = create_client()?;
+    let mut clients = CLIENTS
+        .write()
+        .map_err(|_| ApiClientError::ClientConstructionFailed)
+        .into_report()
+        .attach_printable("Http client cache lock is poisoned")?;
+
+    if clients.len() >= MAX_CACHED_CLIENTS {
+        clients.retain(|key, _| key.certificate_fingerprint.is_none());
+    }
+
+    // Another request may have created the client in the meantime, prefer the cached one
+    Ok(clients.entry(key).or_insert(client).clone())
 }
 
 // We may need to use outbound proxy to connect to external world.
 // Precedence will be the environment variables, followed by the config.
+// `connector` is expected only when the connector has its own http client settings, so that
+// the remaining connectors share the same pool of connections.
 pub(super) fn create_client(
     proxy_config: &Proxy,
     should_bypass_proxy: bool,
     client_certificate: Option<String>,
     client_certificate_key: Option<String>,
+    connector: Option<&str>,
+    http_client_config: &ConnectorHttpClientConfig,
 ) -> CustomResult<reqwest::Client, ApiClientError> {
+    let is_proxied = !(should_bypass_proxy
+        || (proxy_config.http_url.is_none() && proxy_config.https_url.is_none()));
+
     match (client_certificate, client_certificate_key) {
         (Some(encoded_certificate), Some(encoded_certificate_key)) => {
-            let client_builder = get_client_builder(proxy_config, should_bypass_proxy)?;
+            let key = ClientCacheKey {
+                is_proxied,
+                connector: connector.map(ToOwned::to_owned),
+                certificate_fingerprint: Some(get_certificate_fingerprint(
+                    &encoded_certificate,
+                    &encoded_certificate_key,
+                )),
+            };
+
+            get_or_create_client(key, || {
+                let client_builder =
+                    get_client_builder(proxy_config, should_bypass_proxy, http_client_config)?;
 
-            let identity = payments::helpers::create_identity_from_certificate_and_key(
-                encoded_certificate,
-                encoded_certificate_key,
-            )?;
+                let identity = payments::helpers::create_identity_from_certificate_and_key(
+                    encoded_certificate,
+                    encoded_certificate_key,
+                )?;
 
-            client_builder
-                .identity(identity)
-                .build()
-                .into_report()
-                .change_context(ApiClientError::ClientConstructionFailed)
-                .attach_printable("Failed to construct client with certificate and certificate key")
+                client_builder
+                    .identity(identity)
+                    .build()
+                    .into_report()
+                    .change_context(ApiClientError::ClientConstructionFailed)
+                    .attach_printable(
+                        "Failed to construct client with certificate and certificate key",
+                    )
+            })
+        }
+        _ => {
+            let key = ClientCacheKey {
+                is_proxied,
+                connector: connector.map(ToOwned::to_owned),
+                certificate_fingerprint: None,
+            };
+
+            get_or_create_client(key, || {
+                get_client_builder(proxy_config, should_bypass_proxy, http_client_config)?
+                    .build()
+                    .into_report()
+                    .change_context(ApiClientError::ClientConstructionFailed)
+                    .attach_printable("Failed to construct base client")
+            })
         }
-        _ => get_base_client(proxy_config, should_bypass_proxy),
     }
 }
 
@@ -417,3 +497,82 @@ impl ApiClient for MockApiClient {
 
     fn add_flow_name(&mut self, _flow_name: String) {}
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use crate::configs::settings::ConnectorHttpClientSettings;
+
+    #[test]
+    fn test_certificate_fingerprint_identifies_certificate_and_key() {
+        let fingerprint = get_certificate_fingerprint("certificate", "key");
+
+        assert_eq!(
+            fingerprint,
+            get_certificate_fingerprint("certificate", "key")
+        );
+        assert_ne!(
+            fingerprint,
+            get_certificate_fingerprint("certificate", "rotated_key")
+        );
+        assert_ne!(
+            fingerprint,
+            get_certificate_fingerprint("rotated_certificate", "key")
+        );
+    }
+
+    #[test]
+    fn test_connector_override_falls_back_to_default() {
+        let default = ConnectorHttpClientConfig {
+            connect_timeout: Some(10),
+            request_timeout: Some(30),
+            pool_max_idle_per_host: Some(32),
+            max_retries: Some(1),
+            retry_backoff_ms: None,
+        };
+        let settings = ConnectorHttpClientSettings {
+            default: default.clone(),
+            overrides: HashMap::from([(
+                "adyen".to_string(),
+                ConnectorHttpClientConfig {
+                    request_timeout: Some(60),
+                    max_retries: Some(0),
+                    ..Default::default()
+                },
+            )]),
+        };
+
+        let (adyen_config, has_override) = settings.get_config(Some("adyen"));
+        assert!(has_override);
+        assert_eq!(adyen_config.request_timeout, Some(60));
+        assert_eq!(adyen_config.max_retries, Some(0));
+        assert_eq!(adyen_config.connect_timeout, Some(10));
+
+        assert_eq!(
+            settings.get_config(Some("stripe")),
+            (default.clone(), false)
+        );
+        assert_eq!(settings.get_config(None), (default, false));
+    }
+
+    #[test]
+    fn test_clients_are_reused_per_cache_key() {
+        let key = ClientCacheKey {
+            is_proxied: false,
+            connector: Some("test_connector_client_reuse".to_string()),
+            certificate_fingerprint: Some(get_certificate_fingerprint("certificate", "key")),
+        };
+        let mut builds = 0;
+
+        for _ in 0..2 {
+            get_or_create_client(key.clone(), || {
+                builds += 1;
+                Ok(reqwest::Client::new())
+            })
+            .unwrap();
+        }
+
+        assert_eq!(builds, 1);
+    }
+}
@@ -0,0 +1,634 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use error_stack::{IntoReport, ResultExt};
+use http::Method as HttpMethod;
+use masking::{Maskable, PeekInterface, Secret};
+use once_cell::sync::Lazy;
+use router_env::tracing_actix_web::RequestId;
+use serde::{Deserialize, Serialize};
+
+use super::{ApiClient, RequestBuilder};
+use crate::{
+    core::errors::{ApiClientError, CustomResult},
+    logger,
+    routes::AppState,
+    services::{ContentType, Method, Request},
+};
+
+/// Fields of request bodies and query strings which are masked in the cassette, and not compared
+/// when matching requests against recorded ones
+const DEFAULT_MASKED_FIELDS: [&str; 14] = [
+    "card_number",
+    "number",
+    "cvc",
+    "cvv",
+    "card_cvc",
+    "security_code",
+    "expiry_month",
+    "expiry_year",
+    "exp_month",
+    "exp_year",
+    "password",
+    "api_key",
+    "secret",
+    "token",
+];
+
+/// Cassettes shared by every client which records to or replays from the same file, as the
+/// connector tests create a new client for each call made during a test
+static CASSETTES: Lazy<Mutex<HashMap<PathBuf, Arc<Mutex<Cassette>>>>> = Lazy::new(Default::default);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RecordReplayMode {
+    /// Requests are sent to the connector, and the responses are recorded to the cassette
+    Record,
+    /// Requests are answered with the responses recorded in the cassette
+    Replay,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+    #[serde(skip)]
+    replayed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: Method,
+    pub url: String,
+    /// Headers of the request, the values of sensitive headers are masked. Headers are not
+    /// compared when matching requests, as they usually carry credentials
+    pub headers: BTreeMap<String, String>,
+    /// Body of the request. JSON and form bodies are stored with their sensitive fields masked,
+    /// bodies in any other format are replaced by their SHA-256 digest
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status_code: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Cassette {
+    /// Finds the response recorded for the request. Identical requests are answered in the order
+    /// they were recorded, and the last of them is repeated once all were replayed.
+    pub fn replay(&mut self, request: &RecordedRequest) -> Option<RecordedResponse> {
+        let position = self
+            .interactions
+            .iter()
+            .position(|interaction| !interaction.replayed && interaction.request.matches(request))
+            .or_else(|| {
+                self.interactions
+                    .iter()
+                    .rposition(|interaction| interaction.request.matches(request))
+            })?;
+
+        let interaction = self.interactions.get_mut(position)?;
+        interaction.replayed = true;
+        Some(interaction.response.clone())
+    }
+}
+
+impl RecordedRequest {
+    fn new(request: &Request, masked_fields: &[String]) -> Self {
+        let headers: BTreeMap<String, String> = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    Maskable::Masked(secret) => format!("{secret:?}"),
+                    Maskable::Normal(value) => value.clone(),
+                };
+                (name.to_lowercase(), value)
+            })
+            .collect();
+
+        // Most connectors only set the content type through the header
+        let is_form_body = matches!(request.content_type, Some(ContentType::FormUrlEncoded))
+            || headers
+                .get(http::header::CONTENT_TYPE.as_str())
+                .map_or(false, |content_type| {
+                    content_type.starts_with(mime::APPLICATION_WWW_FORM_URLENCODED.as_ref())
+                });
+
+        let body = request.payload.as_ref().map(|payload| {
+            let payload = payload.peek();
+            if is_form_body {
+                serde_json::Value::String(mask_form_fields(payload, masked_fields))
+            } else {
+                // Secrets can't be told apart in bodies of other formats, so only their digest is
+                // stored, which is still enough to match requests
+                serde_json::from_str(payload).map_or_else(
+                    |_| serde_json::Value::String(get_body_digest(payload)),
+                    |body| mask_fields(body, masked_fields),
+                )
+            }
+        });
+
+        Self {
+            method: request.method,
+            url: mask_query_fields(&request.url, masked_fields),
+            headers,
+            body,
+        }
+    }
+
+    fn matches(&self, other: &Self) -> bool {
+        self.method == other.method && self.url == other.url && self.body == other.body
+    }
+}
+
+impl RecordedResponse {
+    async fn record(response: reqwest::Response) -> CustomResult<Self, ApiClientError> {
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect();
+        let body = response
+            .bytes()
+            .await
+            .into_report()
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable("Error while reading the response to record")?;
+
+        Ok(Self {
+            status_code,
+            headers,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        })
+    }
+
+    fn to_response(&self) -> CustomResult<reqwest::Response, ApiClientError> {
+        // The recorded body is already decoded, so its encoding and length headers no longer apply
+        self.headers
+            .iter()
+            .filter(|(name, _)| {
+                !name.eq_ignore_ascii_case(http::header::CONTENT_ENCODING.as_str())
+                    && !name.eq_ignore_ascii_case(http::header::CONTENT_LENGTH.as_str())
+            })
+            .fold(
+                http::Response::builder().status(self.status_code),
+                |builder, (name, value)| builder.header(name, value),
+            )
+            .body(self.body.clone())
+            .into_report()
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable("Failed to construct the recorded response")
+            .map(reqwest::Response::from)
+    }
+}
+
+fn mask_fields(value: serde_json::Value, masked_fields: &[String]) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, value)| {
+                    let is_masked = masked_fields
+                        .iter()
+                        .any(|field| field.eq_ignore_ascii_case(&key));
+                    let value = if is_masked {
+                        serde_json::Value::String(format!("{:?}", Secret::<_>::new(value)))
+                    } else {
+                        mask_fields(value, masked_fields)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(values) => serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(|value| mask_fields(value, masked_fields))
+                .collect(),
+        ),
+        value => value,
+    }
+}
+
+fn is_masked_form_key(key: &str, masked_fields: &[String]) -> bool {
+    // Nested keys such as `card[number]` are masked when any of their parts is a masked field
+    key.split(['[', ']'])
+        .filter(|part| !part.is_empty())
+        .any(|part| {
+            masked_fields
+                .iter()
+                .any(|field| field.eq_ignore_ascii_case(part))
+        })
+}
+
+fn mask_form_fields(form: &str, masked_fields: &[String]) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            url::form_urlencoded::parse(form.as_bytes()).map(|(key, value)| {
+                let value = if is_masked_form_key(&key, masked_fields) {
+                    format!("{:?}", Secret::<_>::new(value)).into()
+                } else {
+                    value
+                };
+                (key, value)
+            }),
+        )
+        .finish()
+}
+
+fn mask_query_fields(url: &str, masked_fields: &[String]) -> String {
+    match url.split_once('?') {
+        Some((path, query)) => format!("{path}?{}", mask_form_fields(query, masked_fields)),
+        None => url.to_string(),
+    }
+}
+
+fn get_body_digest(body: &str) -> String {
+    format!(
+        "sha256:{}",
+        hex::encode(ring::digest::digest(&ring::digest::SHA256, body.as_bytes()))
+    )
+}
+
+fn get_cassette(
+    path: &Path,
+    mode: RecordReplayMode,
+) -> CustomResult<Arc<Mutex<Cassette>>, ApiClientError> {
+    let mut cassettes = CASSETTES
+        .lock()
+        .map_err(|_| ApiClientError::UnexpectedState)
+        .into_report()
+        .attach_printable("The lock on the cassettes is poisoned")?;
+
+    if let Some(cassette) = cassettes.get(path) {
+        return Ok(cassette.clone());
+    }
+
+    let cassette = match mode {
+        // Recording always starts afresh, so that requests which are no longer made are dropped
+        RecordReplayMode::Record => Cassette::default(),
+        RecordReplayMode::Replay => {
+            let content = std::fs::read_to_string(path)
+                .into_report()
+                .change_context(ApiClientError::CassetteAccessFailed)
+                .attach_printable_lazy(|| format!("Unable to read cassette {}", path.display()))?;
+            serde_json::from_str(&content)
+                .into_report()
+                .change_context(ApiClientError::CassetteAccessFailed)
+                .attach_printable_lazy(|| format!("Unable to parse cassette {}", path.display()))?
+        }
+    };
+
+    let cassette = Arc::new(Mutex::new(cassette));
+    cassettes.insert(path.to_path_buf(), cassette.clone());
+    Ok(cassette)
+}
+
+///
+/// Api client which records the requests sent to connectors along with their responses into a
+/// cassette file, or replays the recorded responses, so that connector tests can run offline
+///
+#[derive(Clone)]
+pub struct RecordReplayApiClient {
+    mode: RecordReplayMode,
+    cassette_path: PathBuf,
+    cassette: Arc<Mutex<Cassette>>,
+    masked_fields: Vec<String>,
+    request_id: Option<String>,
+}
+
+impl RecordReplayApiClient {
+    pub fn new(
+        mode: RecordReplayMode,
+        cassette_path: impl Into<PathBuf>,
+    ) -> CustomResult<Self, ApiClientError> {
+        let cassette_path = cassette_path.into();
+        let cassette = get_cassette(&cassette_path, mode)?;
+
+        Ok(Self {
+            mode,
+            cassette_path,
+            cassette,
+            masked_fields: DEFAULT_MASKED_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            request_id: None,
+        })
+    }
+
+    /// Also masks these fields of request bodies, for credentials or values which change on every
+    /// run of a test, such as timestamps and nonces
+    pub fn with_masked_fields(mut self, fields: impl IntoIterator<Item = String>) -> Self {
+        self.masked_fields.extend(fields);
+        self
+    }
+
+    fn record(&self, interaction: Interaction) -> CustomResult<(), ApiClientError> {
+        let content = {
+            let mut cassette = self
+                .cassette
+                .lock()
+                .map_err(|_| ApiClientError::UnexpectedState)
+                .into_report()
+                .attach_printable("The lock on the cassette is poisoned")?;
+            cassette.interactions.push(interaction);
+            serde_json::to_string_pretty(&*cassette)
+                .into_report()
+                .change_context(ApiClientError::CassetteAccessFailed)
+                .attach_printable("Unable to serialize cassette")?
+        };
+
+        if let Some(directory) = self.cassette_path.parent() {
+            std::fs::create_dir_all(directory)
+                .into_report()
+                .change_context(ApiClientError::CassetteAccessFailed)
+                .attach_printable("Unable to create the directory of the cassette")?;
+        }
+
+        std::fs::write(&self.cassette_path, content)
+            .into_report()
+            .change_context(ApiClientError::CassetteAccessFailed)
+            .attach_printable_lazy(|| {
+                format!("Unable to write cassette {}", self.cassette_path.display())
+            })
+    }
+
+    fn replay(&self, request: &RecordedRequest) -> CustomResult<reqwest::Response, ApiClientError> {
+        let response = self
+            .cassette
+            .lock()
+            .map_err(|_| ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("The lock on the cassette is poisoned")?
+            .replay(request)
+            .ok_or(ApiClientError::RecordedResponseNotFound)
+            .into_report()
+            .attach_printable_lazy(|| {
+                format!(
+                    "No response recorded in {} for {} {}",
+                    self.cassette_path.display(),
+                    request.method,
+                    request.url
+                )
+            })?;
+
+        response.to_response()
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiClient for RecordReplayApiClient {
+    fn request(
+        &self,
+        _method: HttpMethod,
+        _url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Only requests made through send_request can be recorded")
+    }
+
+    fn request_with_certificate(
+        &self,
+        _method: HttpMethod,
+        _url: String,
+        _certificate: Option<String>,
+        _certificate_key: Option<String>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        Err(ApiClientError::UnexpectedState)
+            .into_report()
+            .attach_printable("Only requests made through send_request can be recorded")
+    }
+
+    async fn send_request(
+        &self,
+        state: &AppState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        _forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = RecordedRequest::new(&request, &self.masked_fields);
+
+        match self.mode {
+            RecordReplayMode::Replay => self.replay(&recorded_request),
+            RecordReplayMode::Record => {
+                let response =
+                    crate::services::send_request(state, request, option_timeout_secs).await?;
+                let recorded_response = RecordedResponse::record(response).await?;
+                logger::debug!(
+                    cassette = %self.cassette_path.display(),
+                    status_code = recorded_response.status_code,
+                    "Recorded connector response"
+                );
+
+                self.record(Interaction {
+                    request: recorded_request,
+                    response: recorded_response.clone(),
+                    replayed: false,
+                })?;
+                recorded_response.to_response()
+            }
+        }
+    }
+
+    fn add_request_id(&mut self, request_id: RequestId) {
+        self.request_id
+            .replace(request_id.as_hyphenated().to_string());
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.request_id.clone()
+    }
+
+    fn add_merchant_id(&mut self, _merchant_id: Option<String>) {}
+
+    fn add_flow_name(&mut self, _flow_name: String) {}
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn masked_fields() -> Vec<String> {
+        DEFAULT_MASKED_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect()
+    }
+
+    fn connector_request(body: &str) -> Request {
+        let mut request = Request::new(Method::Post, "https://sandbox.connector.com/payments");
+        request.add_header(
+            "Authorization",
+            Maskable::Masked(Secret::new("Bearer sk_test_123".to_string())),
+        );
+        request.set_body(body.to_string());
+        request
+    }
+
+    #[test]
+    fn test_secrets_are_masked_in_recorded_request() {
+        let request = connector_request(
+            r#"{"amount":100,"card":{"number":"4111111111111111","cvc":"123"},"api_key":"sk"}"#,
+        );
+        let recorded = RecordedRequest::new(&request, &masked_fields());
+        let serialized = serde_json::to_string(&recorded).expect("serialized request");
+
+        assert!(!serialized.contains("4111111111111111"));
+        assert!(!serialized.contains("sk_test_123"));
+        assert!(!serialized.contains(r#""123""#));
+        assert_eq!(
+            recorded
+                .body
+                .as_ref()
+                .and_then(|body| body.get("amount"))
+                .and_then(|amount| amount.as_i64()),
+            Some(100)
+        );
+    }
+
+    fn form_request(body: &str) -> Request {
+        let mut request = Request::new(
+            Method::Post,
+            "https://sandbox.connector.com/payments?api_key=sk_test_123&expand=charges",
+        );
+        request.add_header(
+            "Content-Type",
+            Maskable::Normal("application/x-www-form-urlencoded".to_string()),
+        );
+        request.set_body(body.to_string());
+        request
+    }
+
+    #[test]
+    fn test_secrets_are_masked_in_recorded_form_request() {
+        let recorded = RecordedRequest::new(
+            &form_request(
+                "amount=100&payment_method_data%5Bcard%5D%5Bnumber%5D=4111111111111111\
+                 &payment_method_data%5Bcard%5D%5Bcvc%5D=123",
+            ),
+            &masked_fields(),
+        );
+        let serialized = serde_json::to_string(&recorded).expect("serialized request");
+
+        assert!(!serialized.contains("4111111111111111"));
+        assert!(!serialized.contains("sk_test_123"));
+        assert!(!serialized.contains("=123"));
+        assert!(serialized.contains("amount=100"));
+        assert!(recorded.url.ends_with("&expand=charges"));
+
+        // The values of masked fields do not matter when matching
+        let other = RecordedRequest::new(
+            &form_request(
+                "amount=100&payment_method_data%5Bcard%5D%5Bnumber%5D=4242424242424242\
+                 &payment_method_data%5Bcard%5D%5Bcvc%5D=456",
+            ),
+            &masked_fields(),
+        );
+        assert!(recorded.matches(&other));
+    }
+
+    #[test]
+    fn test_xml_request_body_is_not_recorded() {
+        let body = "<payment><amount>100</amount><card><number>4111111111111111</number>\
+                    <cvc>123</cvc></card></payment>";
+        let recorded = RecordedRequest::new(&connector_request(body), &masked_fields());
+        let serialized = serde_json::to_string(&recorded).expect("serialized request");
+
+        assert!(!serialized.contains("4111111111111111"));
+        assert!(!serialized.contains("<cvc>"));
+        assert_eq!(
+            recorded.body,
+            Some(serde_json::Value::String(get_body_digest(body)))
+        );
+        assert!(recorded.matches(&RecordedRequest::new(
+            &connector_request(body),
+            &masked_fields()
+        )));
+    }
+
+    #[test]
+    fn test_replay_matches_normalized_requests_in_order() {
+        let response = |body: &str| RecordedResponse {
+            status_code: 200,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: body.to_string(),
+        };
+        let recorded =
+            |body: &str| RecordedRequest::new(&connector_request(body), &masked_fields());
+
+        let mut cassette = Cassette {
+            interactions: vec![
+                Interaction {
+                    request: recorded(r#"{"amount":100,"card":{"number":"4111111111111111"}}"#),
+                    response: response("first"),
+                    replayed: false,
+                },
+                Interaction {
+                    request: recorded(r#"{"amount":100,"card":{"number":"4111111111111111"}}"#),
+                    response: response("second"),
+                    replayed: false,
+                },
+            ],
+        };
+
+        // Key order and the values of masked fields do not matter
+        let request = recorded(r#"{"card":{"number":"4242424242424242"},"amount":100}"#);
+        let bodies = (0..3)
+            .map(|_| cassette.replay(&request).map(|response| response.body))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bodies,
+            vec![
+                Some("first".to_string()),
+                Some("second".to_string()),
+                Some("second".to_string())
+            ]
+        );
+
+        assert!(cassette.replay(&recorded(r#"{"amount":200}"#)).is_none());
+    }
+
+    #[actix_rt::test]
+    async fn test_recorded_response_is_replayed() {
+        let recorded = RecordedResponse {
+            status_code: 402,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("content-encoding".to_string(), "gzip".to_string()),
+            ],
+            body: r#"{"error":"card_declined"}"#.to_string(),
+        };
+
+        let response = recorded.to_response().expect("replayed response");
+        assert_eq!(response.status().as_u16(), 402);
+        assert!(response
+            .headers()
+            .get(http::header::CONTENT_ENCODING)
+            .is_none());
+        assert_eq!(
+            response.text().await.expect("response body"),
+            r#"{"error":"card_declined"}"#
+        );
+    }
+}
@@ -171,6 +171,9 @@ pub type PayoutRecipientType =
 #[cfg(feature = "payouts")]
 pub type PayoutQuoteType =
     dyn services::ConnectorIntegration<api::PoQuote, PayoutsData, PayoutsResponseData>;
+#[cfg(feature = "payouts")]
+pub type PayoutSyncType =
+    dyn services::ConnectorIntegration<api::PoSync, PayoutsData, PayoutsResponseData>;
 
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
@@ -292,6 +295,8 @@ pub struct RouterData<Flow, Request, Response> {
     pub external_latency: Option<u128>,
     /// Contains apple pay flow type simplified or manual
     pub apple_pay_flow: Option<storage_enums::ApplePayFlow>,
+    /// Contains the fees charged by the connector, when the connector reports them
+    pub connector_fee_details: Option<ConnectorFeeDetails>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -325,6 +330,17 @@ pub struct PaymentMethodBalance {
     pub currency: String,
 }
 
+/// Fees charged by the connector for a transaction, in the lowest denomination of the
+/// settlement currency
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorFeeDetails {
+    pub processing_fee: Option<i64>,
+    pub interchange_fee: Option<i64>,
+    pub fx_markup: Option<i64>,
+    pub net_amount: Option<i64>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PayoutsData {
@@ -1132,6 +1148,7 @@ impl<F1, F2, T1, T2> From<(&RouterData<F1, T1, PaymentsResponseData>, T2)>
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
             apple_pay_flow: data.apple_pay_flow.clone(),
+            connector_fee_details: data.connector_fee_details.clone(),
         }
     }
 }
@@ -1187,6 +1204,7 @@ impl<F1, F2>
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
             apple_pay_flow: None,
+            connector_fee_details: None,
         }
     }
 }
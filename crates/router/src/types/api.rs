@@ -27,13 +27,17 @@ use crate::{
     configs::settings::Connectors,
     connector, consts,
     core::errors::{self, CustomResult},
-    services::{request, ConnectorIntegration, ConnectorRedirectResponse, ConnectorValidation},
+    services::{
+        self, request, ConnectorIntegration, ConnectorRedirectResponse, ConnectorValidation,
+    },
     types::{self, api::enums as api_enums},
 };
 
 #[derive(Clone, Debug)]
 pub struct AccessTokenAuth;
 
+impl services::ConnectorFlow for AccessTokenAuth {}
+
 pub trait ConnectorAccessToken:
     ConnectorIntegration<AccessTokenAuth, types::AccessTokenRequestData, types::AccessToken>
 {
@@ -49,6 +53,8 @@ pub enum ConnectorCallType {
 #[derive(Clone, Debug)]
 pub struct VerifyWebhookSource;
 
+impl services::ConnectorFlow for VerifyWebhookSource {}
+
 pub trait ConnectorVerifyWebhookSource:
     ConnectorIntegration<
     VerifyWebhookSource,
@@ -261,6 +267,34 @@ impl PayoutConnectorData {
     ) -> CustomResult<BoxedConnector, errors::ApiErrorResponse> {
         match enums::PayoutConnectors::from_str(connector_name) {
             Ok(name) => match name {
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector1 => {
+                    Ok(Box::new(&connector::DummyConnector::<1>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector2 => {
+                    Ok(Box::new(&connector::DummyConnector::<2>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector3 => {
+                    Ok(Box::new(&connector::DummyConnector::<3>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector4 => {
+                    Ok(Box::new(&connector::DummyConnector::<4>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector5 => {
+                    Ok(Box::new(&connector::DummyConnector::<5>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector6 => {
+                    Ok(Box::new(&connector::DummyConnector::<6>))
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::PayoutConnectors::DummyConnector7 => {
+                    Ok(Box::new(&connector::DummyConnector::<7>))
+                }
                 enums::PayoutConnectors::Adyen => Ok(Box::new(&connector::Adyen)),
                 enums::PayoutConnectors::Wise => Ok(Box::new(&connector::Wise)),
             },
@@ -61,6 +61,8 @@ pub enum EvidenceType {
 #[derive(Debug, Clone)]
 pub struct Accept;
 
+impl services::ConnectorFlow for Accept {}
+
 pub trait AcceptDispute:
     services::ConnectorIntegration<
     Accept,
@@ -73,6 +75,8 @@ pub trait AcceptDispute:
 #[derive(Debug, Clone)]
 pub struct Evidence;
 
+impl services::ConnectorFlow for Evidence {}
+
 pub trait SubmitEvidence:
     services::ConnectorIntegration<
     Evidence,
@@ -85,6 +89,8 @@ pub trait SubmitEvidence:
 #[derive(Debug, Clone)]
 pub struct Defend;
 
+impl services::ConnectorFlow for Defend {}
+
 pub trait DefendDispute:
     services::ConnectorIntegration<
     Defend,
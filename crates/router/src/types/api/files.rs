@@ -63,11 +63,14 @@ pub struct CreateFileRequest {
 #[strum(serialize_all = "snake_case")]
 pub enum FilePurpose {
     DisputeEvidence,
+    SettlementReport,
 }
 
 #[derive(Debug, Clone)]
 pub struct Upload;
 
+impl services::ConnectorFlow for Upload {}
+
 pub trait UploadFile:
     services::ConnectorIntegration<Upload, types::UploadFileRequestData, types::UploadFileResponse>
 {
@@ -76,6 +79,8 @@ pub trait UploadFile:
 #[derive(Debug, Clone)]
 pub struct Retrieve;
 
+impl services::ConnectorFlow for Retrieve {}
+
 pub trait RetrieveFile:
     services::ConnectorIntegration<
     Retrieve,
@@ -1,13 +1,15 @@
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CryptoData, CustomerAcceptance, HeaderPayload, MandateAmountData, MandateData,
-    MandateTransactionType, MandateType, MandateValidationFields, NextActionType, OnlineMandate,
-    PayLaterData, PaymentIdType, PaymentListConstraints, PaymentListFilterConstraints,
-    PaymentListFilters, PaymentListResponse, PaymentListResponseV2, PaymentMethodData,
-    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials,
-    PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
+    ConnectorFeeAggregate, ConnectorFeeAggregateResponse, CryptoData, CustomerAcceptance,
+    HeaderPayload, MandateAmountData, MandateData, MandateTransactionType, MandateType,
+    MandateValidationFields, NextActionType, OnlineMandate, PayLaterData, PaymentIdType,
+    PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters, PaymentListResponse,
+    PaymentListResponseV2, PaymentMethodData, PaymentMethodDataResponse, PaymentOp,
+    PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsApproveRequest,
+    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
     PaymentsRedirectionResponse, PaymentsRejectRequest, PaymentsRequest, PaymentsResponse,
-    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
+    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsRoutingTraceRequest,
+    PaymentsRoutingTraceResponse, PaymentsSessionRequest, PaymentsSessionResponse,
     PaymentsStartRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken,
     TimeRange, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
@@ -81,6 +83,24 @@ pub struct SetupMandate;
 #[derive(Debug, Clone)]
 pub struct PreProcessing;
 
+impl api::ConnectorFlow for Authorize {}
+impl api::ConnectorFlow for AuthorizeSessionToken {}
+impl api::ConnectorFlow for CompleteAuthorize {}
+impl api::ConnectorFlow for Approve {}
+impl api::ConnectorFlow for Balance {}
+impl api::ConnectorFlow for InitPayment {}
+impl api::ConnectorFlow for Capture {}
+impl api::ConnectorFlow for PSync {
+    const IS_IDEMPOTENT: bool = true;
+}
+impl api::ConnectorFlow for Void {}
+impl api::ConnectorFlow for Reject {}
+impl api::ConnectorFlow for Session {}
+impl api::ConnectorFlow for PaymentMethodToken {}
+impl api::ConnectorFlow for CreateConnectorCustomer {}
+impl api::ConnectorFlow for SetupMandate {}
+impl api::ConnectorFlow for PreProcessing {}
+
 pub trait PaymentIdTypeExt {
     fn get_payment_intent_id(&self) -> errors::CustomResult<String, errors::ValidationError>;
 }
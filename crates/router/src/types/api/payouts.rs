@@ -1,7 +1,8 @@
 pub use api_models::payouts::{
-    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout, PayoutActionRequest,
-    PayoutCreateRequest, PayoutCreateResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
-    PayoutRetrieveRequest, SepaBankTransfer,
+    AchBankTransfer, BacsBankTransfer, Bank as BankPayout, Card as CardPayout, InstantTransfer,
+    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutListFilters,
+    PayoutListRequest, PayoutListResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody,
+    PayoutRetrieveRequest, Paypal, SepaBankTransfer, Venmo, Wallet,
 };
 
 #[cfg(feature = "payouts")]
@@ -13,26 +14,51 @@ use crate::{services::api, types};
 #[derive(Debug, Clone)]
 pub struct PoCancel;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoCancel {}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PoCreate;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoCreate {}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PoEligibility;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoEligibility {}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PoFulfill;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoFulfill {}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PoQuote;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoQuote {}
+
 #[cfg(feature = "payouts")]
 #[derive(Debug, Clone)]
 pub struct PoRecipient;
 
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoRecipient {}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone)]
+pub struct PoSync;
+
+#[cfg(feature = "payouts")]
+impl api::ConnectorFlow for PoSync {}
+
 #[cfg(feature = "payouts")]
 pub trait PayoutCancel:
     api::ConnectorIntegration<PoCancel, types::PayoutsData, types::PayoutsResponseData>
@@ -69,6 +95,12 @@ pub trait PayoutRecipient:
 {
 }
 
+#[cfg(feature = "payouts")]
+pub trait PayoutSync:
+    api::ConnectorIntegration<PoSync, types::PayoutsData, types::PayoutsResponseData>
+{
+}
+
 #[cfg(feature = "payouts")]
 pub trait Payouts:
     ConnectorCommon
@@ -78,6 +110,7 @@ pub trait Payouts:
     + PayoutFulfill
     + PayoutQuote
     + PayoutRecipient
+    + PayoutSync
 {
 }
 #[cfg(not(feature = "payouts"))]
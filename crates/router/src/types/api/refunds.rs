@@ -26,6 +26,11 @@ pub struct Execute;
 #[derive(Debug, Clone)]
 pub struct RSync;
 
+impl api::ConnectorFlow for Execute {}
+impl api::ConnectorFlow for RSync {
+    const IS_IDEMPOTENT: bool = true;
+}
+
 pub trait RefundExecute:
     api::ConnectorIntegration<Execute, types::RefundsData, types::RefundsResponseData>
 {
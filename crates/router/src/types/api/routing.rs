@@ -3,9 +3,11 @@ pub use api_models::routing::RoutableChoiceKind;
 pub use api_models::{
     enums as api_enums,
     routing::{
-        ConnectorVolumeSplit, DetailedConnectorChoice, RoutableConnectorChoice, RoutingAlgorithm,
-        RoutingAlgorithmKind, RoutingAlgorithmRef, RoutingConfigRequest, RoutingDictionary,
-        RoutingDictionaryRecord, StraightThroughAlgorithm,
+        ConnectorCostTrace, ConnectorEligibilityTrace, ConnectorVolumeSplit,
+        DetailedConnectorChoice, EligibilityStage, IneligibilityReason, LeastCostRoutingConfig,
+        RoutableConnectorChoice, RoutingAlgorithm, RoutingAlgorithmKind, RoutingAlgorithmRef,
+        RoutingCanaryConfig, RoutingCanaryStickiness, RoutingConfigRequest, RoutingDictionary,
+        RoutingDictionaryRecord, RoutingTrace, StraightThroughAlgorithm,
     },
 };
 
@@ -4,7 +4,7 @@ pub use api_models::webhooks::{
     OutgoingWebhook, OutgoingWebhookContent, WebhookFlow,
 };
 use common_utils::ext_traits::ValueExt;
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use masking::ExposeInterface;
 
 use super::ConnectorCommon;
@@ -73,11 +73,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
             .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
     }
 
+    /// Connectors that can verify the source of their webhooks override this method, or
+    /// `verify_webhook_source` itself. Webhooks of the other connectors are never considered
+    /// verified.
     fn get_webhook_source_verification_algorithm(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
-        Ok(Box::new(crypto::NoAlgorithm))
+        Err(errors::ConnectorError::WebhookSourceVerificationNotSupported).into_report()
     }
 
     async fn get_webhook_source_verification_merchant_secret(
@@ -213,7 +216,10 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
     ) -> CustomResult<bool, errors::ConnectorError> {
         let algorithm = self
             .get_webhook_source_verification_algorithm(request)
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+            .map_err(|error| match error.current_context() {
+                errors::ConnectorError::WebhookSourceVerificationNotSupported => error,
+                _ => error.change_context(errors::ConnectorError::WebhookSourceVerificationFailed),
+            })?;
 
         let connector_webhook_secrets = self
             .get_webhook_source_verification_merchant_secret(
@@ -256,6 +262,23 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<serde_json::Value, errors::ConnectorError>;
 
+    /// Identifier assigned to the webhook by the connector, which stays the same when the
+    /// connector redelivers the webhook
+    fn get_webhook_event_id(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// Unix timestamp (in seconds) covered by the signature of the webhook
+    fn get_webhook_signature_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     fn get_webhook_api_response(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
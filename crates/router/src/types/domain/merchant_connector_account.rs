@@ -35,6 +35,7 @@ pub struct MerchantConnectorAccount {
     pub profile_id: Option<String>,
     pub applepay_verified_domains: Option<Vec<String>>,
     pub pm_auth_config: Option<serde_json::Value>,
+    pub fee_schedule: Option<serde_json::Value>,
 }
 
 #[derive(Debug)]
@@ -54,6 +55,7 @@ pub enum MerchantConnectorAccountUpdate {
         applepay_verified_domains: Option<Vec<String>>,
         pm_auth_config: Option<serde_json::Value>,
         connector_label: Option<String>,
+        fee_schedule: Option<serde_json::Value>,
     },
 }
 
@@ -89,6 +91,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 profile_id: self.profile_id,
                 applepay_verified_domains: self.applepay_verified_domains,
                 pm_auth_config: self.pm_auth_config,
+                fee_schedule: self.fee_schedule,
             },
         )
     }
@@ -128,6 +131,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: other.profile_id,
             applepay_verified_domains: other.applepay_verified_domains,
             pm_auth_config: other.pm_auth_config,
+            fee_schedule: other.fee_schedule,
         })
     }
 
@@ -155,6 +159,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             profile_id: self.profile_id,
             applepay_verified_domains: self.applepay_verified_domains,
             pm_auth_config: self.pm_auth_config,
+            fee_schedule: self.fee_schedule,
         })
     }
 }
@@ -177,6 +182,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 applepay_verified_domains,
                 pm_auth_config,
                 connector_label,
+                fee_schedule,
             } => Self {
                 merchant_id,
                 connector_type,
@@ -194,6 +200,7 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 applepay_verified_domains,
                 pm_auth_config,
                 connector_label,
+                fee_schedule,
             },
         }
     }
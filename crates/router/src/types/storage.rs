@@ -11,6 +11,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
+pub mod incoming_webhook;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -31,21 +32,27 @@ pub mod reverse_lookup;
 pub mod payout_attempt;
 pub mod payouts;
 mod query;
+pub mod recon;
 pub mod refund;
 pub mod user;
+pub mod webhook_endpoint;
 
 pub use data_models::payments::{
-    payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
+    payment_attempt::{
+        PaymentAttempt, PaymentAttemptFeeAggregate, PaymentAttemptNew,
+        PaymentAttemptRoutingArmAggregate, PaymentAttemptUpdate,
+    },
     payment_intent::{PaymentIntentNew, PaymentIntentUpdate},
     PaymentIntent,
 };
 
 pub use self::{
     address::*, api_keys::*, capture::*, cards_info::*, configs::*, connector_response::*,
-    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, payout_attempt::*, payouts::*, process_tracker::*, refund::*,
-    reverse_lookup::*, routing_algorithm::*, user::*,
+    customers::*, dispute::*, ephemeral_key::*, events::*, file::*, incoming_webhook::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payout_attempt::*, payouts::*,
+    process_tracker::*, recon::*, refund::*, reverse_lookup::*, routing_algorithm::*, user::*,
+    webhook_endpoint::*,
 };
 use crate::types::api::routing;
 
@@ -0,0 +1,4 @@
+pub use diesel_models::incoming_webhook::{
+    IncomingWebhook, IncomingWebhookNew, IncomingWebhookRetentionTrackingData,
+    IncomingWebhookUpdate, IncomingWebhookUpdateInternal,
+};
@@ -1 +1,294 @@
-pub use diesel_models::payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::errors::CustomResult;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+pub use diesel_models::payouts::{
+    PayoutSyncWorkflow, Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal,
+};
+use diesel_models::{
+    enums::{Currency, PayoutStatus, PayoutType},
+    errors,
+    payout_attempt::PayoutAttempt,
+    query::generics::db_metrics,
+    schema::{payout_attempt::dsl as pa_dsl, payouts::dsl},
+};
+use error_stack::{IntoReport, ResultExt};
+
+use crate::{connection::PgPooledConn, logger};
+
+#[async_trait::async_trait]
+pub trait PayoutsDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_list_details: &api_models::payouts::PayoutListRequest,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError>;
+
+    async fn filter_by_meta_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::refunds::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError>;
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_list_details: &api_models::payouts::PayoutListRequest,
+    ) -> CustomResult<i64, errors::DatabaseError>;
+}
+
+/// Payout status, connector and profile live on `payout_attempt`, so those constraints are
+/// resolved to a set of payout ids before `payouts` is filtered.
+async fn filter_payout_ids_by_attempt_constraints(
+    conn: &PgPooledConn,
+    merchant_id: &str,
+    payout_list_details: &api_models::payouts::PayoutListRequest,
+) -> CustomResult<Option<Vec<String>>, errors::DatabaseError> {
+    if payout_list_details.status.is_none()
+        && payout_list_details.connector.is_none()
+        && payout_list_details.profile_id.is_none()
+    {
+        return Ok(None);
+    }
+
+    let mut filter = <PayoutAttempt as HasTable>::table()
+        .select(pa_dsl::payout_id)
+        .filter(pa_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .into_boxed();
+
+    if let Some(profile_id) = &payout_list_details.profile_id {
+        filter = filter.filter(pa_dsl::profile_id.eq(profile_id.to_owned()));
+    }
+
+    if let Some(connector) = &payout_list_details.connector {
+        let connector: Vec<String> = connector.iter().map(ToString::to_string).collect();
+        filter = filter.filter(pa_dsl::connector.eq_any(connector));
+    }
+
+    if let Some(filter_payout_status) = &payout_list_details.status {
+        filter = filter.filter(pa_dsl::status.eq_any(filter_payout_status.clone()));
+    }
+
+    logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+    filter
+        .get_results_async(conn)
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering payout attempts by predicate")
+        .map(Some)
+}
+
+#[async_trait::async_trait]
+impl PayoutsDbExt for Payouts {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_list_details: &api_models::payouts::PayoutListRequest,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<(Self, PayoutAttempt)>, errors::DatabaseError> {
+        let payout_ids =
+            filter_payout_ids_by_attempt_constraints(conn, merchant_id, payout_list_details)
+                .await?;
+
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        match &payout_list_details.payout_id {
+            Some(payout_id) => {
+                filter = filter.filter(dsl::payout_id.eq(payout_id.to_owned()));
+            }
+            None => {
+                filter = filter.limit(limit).offset(offset);
+            }
+        };
+
+        if let Some(payout_ids) = payout_ids {
+            filter = filter.filter(dsl::payout_id.eq_any(payout_ids));
+        }
+
+        if let Some(customer_id) = &payout_list_details.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+
+        if let Some(time_range) = payout_list_details.time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+
+        if let Some(filter_currency) = &payout_list_details.currency {
+            filter = filter.filter(dsl::destination_currency.eq_any(filter_currency.clone()));
+        }
+
+        if let Some(filter_payout_type) = &payout_list_details.payout_type {
+            filter = filter.filter(dsl::payout_type.eq_any(filter_payout_type.clone()));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        let payouts: Vec<Self> =
+            db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+                filter.get_results_async(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")?;
+
+        let payout_ids: Vec<String> = payouts.iter().map(|p| p.payout_id.clone()).collect();
+        let mut payout_attempts: Vec<PayoutAttempt> =
+            db_metrics::track_database_call::<<PayoutAttempt as HasTable>::Table, _, _>(
+                <PayoutAttempt as HasTable>::table()
+                    .filter(pa_dsl::merchant_id.eq(merchant_id.to_owned()))
+                    .filter(pa_dsl::payout_id.eq_any(payout_ids))
+                    .get_results_async(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error fetching payout attempts for filtered payouts")?;
+
+        Ok(payouts
+            .into_iter()
+            .filter_map(|payout| {
+                let index = payout_attempts
+                    .iter()
+                    .position(|attempt| attempt.payout_id == payout.payout_id)?;
+                Some((payout, payout_attempts.swap_remove(index)))
+            })
+            .collect())
+    }
+
+    async fn filter_by_meta_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        time_range: &api_models::refunds::TimeRange,
+    ) -> CustomResult<api_models::payouts::PayoutListFilters, errors::DatabaseError> {
+        let start_time = time_range.start_time;
+
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(start_time))
+            .filter(dsl::created_at.le(end_time));
+
+        let attempt_filter = <PayoutAttempt as HasTable>::table()
+            .filter(pa_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(pa_dsl::created_at.ge(start_time))
+            .filter(pa_dsl::created_at.le(end_time));
+
+        let filter_connector: Vec<String> = attempt_filter
+            .clone()
+            .select(pa_dsl::connector)
+            .distinct()
+            .order_by(pa_dsl::connector.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by connector")?;
+
+        let filter_status: Vec<PayoutStatus> = attempt_filter
+            .select(pa_dsl::status)
+            .distinct()
+            .order_by(pa_dsl::status.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout status")?;
+
+        let filter_currency: Vec<Currency> = filter
+            .clone()
+            .select(dsl::destination_currency)
+            .distinct()
+            .order_by(dsl::destination_currency.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by currency")?;
+
+        let filter_payout_type: Vec<PayoutType> = filter
+            .select(dsl::payout_type)
+            .distinct()
+            .order_by(dsl::payout_type.asc())
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error filtering records by payout type")?;
+
+        Ok(api_models::payouts::PayoutListFilters {
+            connector: filter_connector,
+            currency: filter_currency,
+            status: filter_status,
+            payout_type: filter_payout_type,
+        })
+    }
+
+    async fn get_payouts_count(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        payout_list_details: &api_models::payouts::PayoutListRequest,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        let payout_ids =
+            filter_payout_ids_by_attempt_constraints(conn, merchant_id, payout_list_details)
+                .await?;
+
+        let mut filter = <Self as HasTable>::table()
+            .count()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(payout_id) = &payout_list_details.payout_id {
+            filter = filter.filter(dsl::payout_id.eq(payout_id.to_owned()));
+        }
+
+        if let Some(payout_ids) = payout_ids {
+            filter = filter.filter(dsl::payout_id.eq_any(payout_ids));
+        }
+
+        if let Some(customer_id) = &payout_list_details.customer_id {
+            filter = filter.filter(dsl::customer_id.eq(customer_id.to_owned()));
+        }
+
+        if let Some(time_range) = payout_list_details.time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+
+        if let Some(filter_currency) = &payout_list_details.currency {
+            filter = filter.filter(dsl::destination_currency.eq_any(filter_currency.clone()));
+        }
+
+        if let Some(filter_payout_type) = &payout_list_details.payout_type {
+            filter = filter.filter(dsl::payout_type.eq_any(filter_payout_type.clone()));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_result_async::<i64>(conn)
+            .await
+            .into_report()
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering count of payouts")
+    }
+}
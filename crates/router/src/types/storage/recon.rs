@@ -0,0 +1,3 @@
+pub use diesel_models::recon::{
+    ReconRecord, ReconReport, ReconReportNew, ReconReportUpdate, ReconReportUpdateInternal,
+};
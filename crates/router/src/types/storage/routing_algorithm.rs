@@ -1,3 +1,4 @@
 pub use diesel_models::routing_algorithm::{
+    RoutingActivationAudit, RoutingActivationAuditNew, RoutingActivationTrackingData,
     RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
 };
@@ -0,0 +1,4 @@
+pub use diesel_models::webhook_endpoint::{
+    WebhookDelivery, WebhookDeliveryNew, WebhookDeliveryUpdate, WebhookDeliveryUpdateInternal,
+    WebhookEndpoint, WebhookEndpointNew, WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+};
@@ -428,6 +428,7 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::MobilePay
             | api_enums::PaymentMethodType::SamsungPay
             | api_enums::PaymentMethodType::Twint
+            | api_enums::PaymentMethodType::Venmo
             | api_enums::PaymentMethodType::Vipps
             | api_enums::PaymentMethodType::TouchNGo
             | api_enums::PaymentMethodType::Swish
@@ -569,6 +570,28 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PayoutStatus) -> Self {
+        match value {
+            storage_enums::PayoutStatus::Success => Some(storage_enums::EventType::PayoutSuccess),
+            storage_enums::PayoutStatus::Failed | storage_enums::PayoutStatus::Ineligible => {
+                Some(storage_enums::EventType::PayoutFailed)
+            }
+            storage_enums::PayoutStatus::Cancelled => {
+                Some(storage_enums::EventType::PayoutCancelled)
+            }
+            storage_enums::PayoutStatus::Pending => {
+                Some(storage_enums::EventType::PayoutProcessing)
+            }
+            storage_enums::PayoutStatus::Expired => Some(storage_enums::EventType::PayoutExpired),
+            storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::RequiresCreation
+            | storage_enums::PayoutStatus::RequiresPayoutMethodData
+            | storage_enums::PayoutStatus::RequiresFulfillment => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -601,6 +624,27 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::PayoutStatus {
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::PayoutSuccess => Ok(Self::Success),
+            api_models::webhooks::IncomingWebhookEvent::PayoutFailure => Ok(Self::Failed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutCancelled => Ok(Self::Cancelled),
+            api_models::webhooks::IncomingWebhookEvent::PayoutProcessing
+            | api_models::webhooks::IncomingWebhookEvent::PayoutCreated => Ok(Self::Pending),
+            api_models::webhooks::IncomingWebhookEvent::PayoutExpired => Ok(Self::Expired),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReversal => Ok(Self::Reversed),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -842,6 +886,14 @@ impl TryFrom<domain::MerchantConnectorAccount> for api_models::admin::MerchantCo
             profile_id: item.profile_id,
             applepay_verified_domains: item.applepay_verified_domains,
             pm_auth_config: item.pm_auth_config,
+            fee_schedule: item
+                .fee_schedule
+                .map(|fee_schedule| {
+                    serde_json::Value::parse_value(fee_schedule, "ConnectorFeeSchedule")
+                        .attach_printable("Unable to deserialize fee_schedule")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                })
+                .transpose()?,
         })
     }
 }
@@ -890,6 +942,49 @@ impl ForeignFrom<storage::Capture> for api_models::payments::CaptureResponse {
     }
 }
 
+impl ForeignFrom<&storage::PaymentAttempt> for Option<api_models::payments::ConnectorFeeDetails> {
+    fn foreign_from(payment_attempt: &storage::PaymentAttempt) -> Self {
+        let fee_details = api_models::payments::ConnectorFeeDetails {
+            processing_fee: payment_attempt.processing_fee,
+            interchange_fee: payment_attempt.interchange_fee,
+            fx_markup: payment_attempt.fx_markup,
+            net_amount: payment_attempt.net_amount,
+            settlement_currency: payment_attempt.settlement_currency,
+        };
+        (fee_details != api_models::payments::ConnectorFeeDetails::default()).then_some(fee_details)
+    }
+}
+
+impl ForeignFrom<&storage::Refund> for Option<api_models::payments::ConnectorFeeDetails> {
+    fn foreign_from(refund: &storage::Refund) -> Self {
+        let fee_details = api_models::payments::ConnectorFeeDetails {
+            processing_fee: refund.processing_fee,
+            interchange_fee: refund.interchange_fee,
+            fx_markup: refund.fx_markup,
+            net_amount: refund.net_amount,
+            settlement_currency: refund.settlement_currency,
+        };
+        (fee_details != api_models::payments::ConnectorFeeDetails::default()).then_some(fee_details)
+    }
+}
+
+impl ForeignFrom<storage::PaymentAttemptFeeAggregate>
+    for api_models::payments::ConnectorFeeAggregate
+{
+    fn foreign_from(fee_aggregate: storage::PaymentAttemptFeeAggregate) -> Self {
+        Self {
+            connector: fee_aggregate.connector.unwrap_or_default(),
+            date: fee_aggregate.date.to_string(),
+            settlement_currency: fee_aggregate.settlement_currency,
+            payment_count: fee_aggregate.payment_count,
+            processing_fee: fee_aggregate.processing_fee,
+            interchange_fee: fee_aggregate.interchange_fee,
+            fx_markup: fee_aggregate.fx_markup,
+            net_amount: fee_aggregate.net_amount,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     fn foreign_from(value: api_models::payouts::Bank) -> Self {
         match value {
@@ -900,11 +995,32 @@ impl ForeignFrom<api_models::payouts::Bank> for api_enums::PaymentMethodType {
     }
 }
 
+impl ForeignFrom<api_models::payouts::Wallet> for api_enums::PaymentMethodType {
+    fn foreign_from(value: api_models::payouts::Wallet) -> Self {
+        match value {
+            api_models::payouts::Wallet::Paypal(_) => Self::Paypal,
+            api_models::payouts::Wallet::Venmo(_) => Self::Venmo,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::payouts::InstantTransfer> for api_enums::PaymentMethodType {
+    fn foreign_from(value: api_models::payouts::InstantTransfer) -> Self {
+        match value {
+            api_models::payouts::InstantTransfer::SepaInstant(_) => Self::Sepa,
+            api_models::payouts::InstantTransfer::FasterPayments(_) => Self::Bacs,
+            api_models::payouts::InstantTransfer::Rtp(_) => Self::Ach,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMethod {
     fn foreign_from(value: api_models::payouts::PayoutMethodData) -> Self {
         match value {
-            api_models::payouts::PayoutMethodData::Bank(_) => Self::BankTransfer,
+            api_models::payouts::PayoutMethodData::Bank(_)
+            | api_models::payouts::PayoutMethodData::InstantTransfer(_) => Self::BankTransfer,
             api_models::payouts::PayoutMethodData::Card(_) => Self::Card,
+            api_models::payouts::PayoutMethodData::Wallet(_) => Self::Wallet,
         }
     }
 }
@@ -912,8 +1028,10 @@ impl ForeignFrom<api_models::payouts::PayoutMethodData> for api_enums::PaymentMe
 impl ForeignFrom<api_models::enums::PayoutType> for api_enums::PaymentMethod {
     fn foreign_from(value: api_models::enums::PayoutType) -> Self {
         match value {
-            api_models::enums::PayoutType::Bank => Self::BankTransfer,
+            api_models::enums::PayoutType::Bank
+            | api_models::enums::PayoutType::InstantTransfer => Self::BankTransfer,
             api_models::enums::PayoutType::Card => Self::Card,
+            api_models::enums::PayoutType::Wallet => Self::Wallet,
         }
     }
 }
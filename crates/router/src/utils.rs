@@ -327,6 +327,26 @@ pub async fn find_payment_intent_from_mandate_id_type(
     .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
 
+pub async fn find_payout_attempt_from_payout_id_type(
+    db: &dyn StorageInterface,
+    payout_id_type: webhooks::PayoutIdType,
+    merchant_account: &domain::MerchantAccount,
+) -> CustomResult<diesel_models::payout_attempt::PayoutAttempt, errors::ApiErrorResponse> {
+    match payout_id_type {
+        webhooks::PayoutIdType::PayoutId(payout_id) => db
+            .find_payout_attempt_by_merchant_id_payout_id(&merchant_account.merchant_id, &payout_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound),
+        webhooks::PayoutIdType::ConnectorPayoutId(connector_payout_id) => db
+            .find_payout_attempt_by_merchant_id_connector_payout_id(
+                &merchant_account.merchant_id,
+                &connector_payout_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound),
+    }
+}
+
 pub async fn get_profile_id_using_object_reference_id(
     db: &dyn StorageInterface,
     object_reference_id: webhooks::ObjectReferenceId,
@@ -336,31 +356,67 @@ pub async fn get_profile_id_using_object_reference_id(
     match merchant_account.default_profile.as_ref() {
         Some(profile_id) => Ok(profile_id.clone()),
         _ => {
-            let payment_intent = match object_reference_id {
+            let (business_country, business_label, profile_id) = match object_reference_id {
                 webhooks::ObjectReferenceId::PaymentId(payment_id_type) => {
-                    find_payment_intent_from_payment_id_type(db, payment_id_type, merchant_account)
-                        .await?
+                    let payment_intent = find_payment_intent_from_payment_id_type(
+                        db,
+                        payment_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
                 }
                 webhooks::ObjectReferenceId::RefundId(refund_id_type) => {
-                    find_payment_intent_from_refund_id_type(
+                    let payment_intent = find_payment_intent_from_refund_id_type(
                         db,
                         refund_id_type,
                         merchant_account,
                         connector_name,
                     )
-                    .await?
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
                 }
                 webhooks::ObjectReferenceId::MandateId(mandate_id_type) => {
-                    find_payment_intent_from_mandate_id_type(db, mandate_id_type, merchant_account)
-                        .await?
+                    let payment_intent = find_payment_intent_from_mandate_id_type(
+                        db,
+                        mandate_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payment_intent.business_country,
+                        payment_intent.business_label,
+                        payment_intent.profile_id,
+                    )
+                }
+                webhooks::ObjectReferenceId::PayoutId(payout_id_type) => {
+                    let payout_attempt = find_payout_attempt_from_payout_id_type(
+                        db,
+                        payout_id_type,
+                        merchant_account,
+                    )
+                    .await?;
+                    (
+                        payout_attempt.business_country,
+                        payout_attempt.business_label,
+                        payout_attempt.profile_id,
+                    )
                 }
             };
 
             let profile_id = utils::get_profile_id_from_business_details(
-                payment_intent.business_country,
-                payment_intent.business_label.as_ref(),
+                business_country,
+                business_label.as_ref(),
                 merchant_account,
-                payment_intent.profile_id.as_ref(),
+                profile_id.as_ref(),
                 db,
                 false,
             )
@@ -769,3 +825,70 @@ where
 
     Ok(())
 }
+
+#[cfg(feature = "payouts")]
+pub async fn trigger_payouts_webhook(
+    state: &crate::routes::AppState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &crate::core::payouts::PayoutData,
+) -> RouterResult<()> {
+    let payout_attempt = &payout_data.payout_attempt;
+    let event_type: Option<enums::EventType> =
+        types::transformers::ForeignFrom::foreign_from(payout_attempt.status);
+
+    if let Some(event_type) = event_type {
+        let profile_id = utils::get_profile_id_from_business_details(
+            payout_attempt.business_country,
+            payout_attempt.business_label.as_ref(),
+            merchant_account,
+            payout_attempt.profile_id.as_ref(),
+            &*state.store,
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payout_attempt")?;
+
+        let business_profile = state
+            .store
+            .find_business_profile_by_profile_id(&profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                id: profile_id.to_string(),
+            })?;
+
+        let payouts_response = crate::core::payouts::response_handler(
+            state,
+            merchant_account,
+            &api_models::payouts::PayoutRequest::PayoutRetrieveRequest(
+                api_models::payouts::PayoutRetrieveRequest {
+                    payout_id: payout_attempt.payout_id.clone(),
+                    force_sync: None,
+                },
+            ),
+            payout_data,
+        )
+        .await?;
+
+        if let services::ApplicationResponse::Json(payouts_response_json) = payouts_response {
+            Box::pin(
+                webhooks_core::create_event_and_trigger_appropriate_outgoing_webhook(
+                    state.clone(),
+                    merchant_account.clone(),
+                    business_profile,
+                    event_type,
+                    diesel_models::enums::EventClass::Payouts,
+                    None,
+                    payout_attempt.payout_id.clone(),
+                    diesel_models::enums::EventObjectType::PayoutDetails,
+                    webhooks::OutgoingWebhookContent::PayoutDetails(Box::new(
+                        payouts_response_json,
+                    )),
+                ),
+            )
+            .await?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,3 +1,7 @@
+pub mod incoming_webhook_retention;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_sync;
 pub mod refund_router;
+pub mod routing_activation;
 pub mod tokenized_data;
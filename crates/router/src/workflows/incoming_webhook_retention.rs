@@ -0,0 +1,58 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::workflows::ProcessTrackerWorkflow, db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{core::webhooks::incoming, errors, logger, routes::AppState, types::storage};
+
+/// Deletes the stored incoming webhooks of a merchant which are past the retention period. The
+/// task is never finished, it is scheduled again to run a day later.
+pub struct IncomingWebhookRetentionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for IncomingWebhookRetentionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let tracking_data: storage::IncomingWebhookRetentionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("IncomingWebhookRetentionTrackingData")?;
+
+        let retention_period =
+            time::Duration::days(state.conf.webhooks.incoming_storage.retention_days);
+        state
+            .store
+            .delete_incoming_webhooks_by_merchant_id_created_before(
+                &tracking_data.merchant_id,
+                common_utils::date_time::now().saturating_sub(retention_period),
+            )
+            .await?;
+
+        process
+            .reset(
+                state.get_db().as_scheduler(),
+                incoming::get_next_retention_run_time(),
+            )
+            .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        logger::error!(%process.id, ?error, "Failed to delete stored incoming webhooks");
+        process
+            .reset(
+                state.get_db().as_scheduler(),
+                incoming::get_next_retention_run_time(),
+            )
+            .await
+            .map_err(Into::into)
+    }
+}
@@ -0,0 +1,111 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::payouts::{self as payout_flows, helpers as payout_helpers},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::{api, storage},
+    workflows::payment_sync,
+};
+
+pub struct PayoutsSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PayoutsSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::PayoutSyncWorkflow = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutSyncWorkflow")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let request = api::payouts::PayoutRequest::PayoutRetrieveRequest(
+            api::payouts::PayoutRetrieveRequest {
+                payout_id: tracking_data.payout_id.clone(),
+                force_sync: Some(true),
+            },
+        );
+
+        let mut payout_data =
+            payout_flows::make_payout_data(state, &merchant_account, &key_store, &request).await?;
+
+        if payout_helpers::should_call_payout_connector_sync(&payout_data.payout_attempt) {
+            let connector_data = payout_flows::get_connector_data(
+                state,
+                &merchant_account,
+                Some(payout_data.payout_attempt.connector.clone()),
+                None,
+            )
+            .await?;
+
+            // A failed sync is retried as per the schedule of the connector
+            payout_data = match payout_flows::sync_payout(
+                state,
+                &merchant_account,
+                &key_store,
+                &request,
+                &connector_data,
+                &mut payout_data,
+            )
+            .await
+            {
+                Ok(synced_payout_data) => synced_payout_data,
+                Err(error) => {
+                    logger::error!(payout_sync_error=?error);
+                    payout_data
+                }
+            };
+        }
+
+        if payout_helpers::should_call_payout_connector_sync(&payout_data.payout_attempt) {
+            payment_sync::retry_sync_task(
+                db,
+                tracking_data.connector,
+                tracking_data.merchant_id,
+                process,
+            )
+            .await?;
+        } else {
+            let id = process.id.clone();
+            process
+                .finish_with_status(
+                    state.get_db().as_scheduler(),
+                    format!("COMPLETED_BY_PT_{id}"),
+                )
+                .await?
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,73 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    db::process_tracker::ProcessTrackerExt,
+    errors as sch_errors, SchedulerAppState,
+};
+
+use crate::{
+    core::routing,
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage::{self, enums},
+};
+
+pub struct RoutingActivationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for RoutingActivationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: storage::RoutingActivationTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("RoutingActivationTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        routing::activate_routing_algorithm(
+            db,
+            &merchant_account,
+            #[cfg(not(feature = "business_profile_routing"))]
+            &key_store,
+            tracking_data.algorithm_id,
+            enums::RoutingActivationAction::ScheduledActivate,
+            tracking_data.created_by,
+        )
+        .await?;
+
+        let id = process.id.clone();
+        process
+            .finish_with_status(
+                state.get_db().as_scheduler(),
+                format!("COMPLETED_BY_PT_{id}"),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
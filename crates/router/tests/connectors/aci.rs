@@ -93,6 +93,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         connector_api_version: None,
         connector_http_status_code: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
         external_latency: None,
     }
 }
@@ -151,6 +152,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         connector_api_version: None,
         connector_http_status_code: None,
         apple_pay_flow: None,
+        connector_fee_details: None,
         external_latency: None,
     }
 }
@@ -164,7 +166,7 @@ async fn payments_create_success() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
 
@@ -208,7 +210,7 @@ async fn payments_create_failure() {
             conf,
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            utils::get_api_client(),
         )
         .await;
         let connector = types::api::ConnectorData {
@@ -269,7 +271,7 @@ async fn refund_for_successful_payments() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
     let connector_integration: services::BoxedConnectorIntegration<
@@ -337,7 +339,7 @@ async fn refunds_create_failure() {
         conf,
         StorageImpl::PostgresqlTest,
         tx,
-        Box::new(services::MockApiClient),
+        utils::get_api_client(),
     )
     .await;
     let connector_integration: services::BoxedConnectorIntegration<
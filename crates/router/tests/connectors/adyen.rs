@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use api_models::payments::{Address, AddressDetails};
+use common_utils::pii::Email;
 use masking::Secret;
 use router::types::{self, api, storage::enums, PaymentAddress};
 
@@ -105,6 +106,12 @@ impl AdyenTest {
                         bank_city: "Amsterdam".to_string(),
                     }),
                 )),
+                enums::PayoutType::Wallet => Some(api::PayoutMethodData::Wallet(
+                    api::payouts::Wallet::Paypal(api::payouts::Paypal {
+                        email: Email::from_str("EmailUsedForPayPalAccount@example.com").ok()?,
+                    }),
+                )),
+                enums::PayoutType::InstantTransfer => None,
             },
             ..Default::default()
         })
@@ -548,6 +555,20 @@ async fn should_create_and_fulfill_sepa_payout() {
     assert_eq!(response.status.unwrap(), enums::PayoutStatus::Success);
 }
 
+// Create and fulfill PayPal payout
+#[ignore]
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_create_and_fulfill_paypal_payout() {
+    let payout_type = enums::PayoutType::Wallet;
+    let payout_info = AdyenTest::get_payout_info(payout_type);
+    let response = CONNECTOR
+        .create_and_fulfill_payout(None, payout_type, payout_info)
+        .await
+        .expect("Payout wallet creation and fulfill response");
+    assert_eq!(response.status.unwrap(), enums::PayoutStatus::Success);
+}
+
 // Verifies if card is eligible for payout
 #[ignore]
 #[cfg(feature = "payouts")]
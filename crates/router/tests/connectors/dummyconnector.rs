@@ -33,6 +33,16 @@ impl utils::Connector for DummyConnectorTest {
     fn get_name(&self) -> String {
         "dummyconnector".to_string()
     }
+
+    #[cfg(feature = "payouts")]
+    fn get_payout_data(&self) -> Option<types::api::PayoutConnectorData> {
+        use router::connector::DummyConnector;
+        Some(types::api::PayoutConnectorData {
+            connector: Box::new(&DummyConnector::<1>),
+            connector_name: types::PayoutConnectors::DummyConnector1,
+            get_token: types::api::GetToken::Connector,
+        })
+    }
 }
 
 static CONNECTOR: DummyConnectorTest = DummyConnectorTest {};
@@ -442,4 +452,63 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
 
 // Connector dependent test cases goes here
 
+// Sets up a mandate with a payment and charges the customer again with it.
+#[actix_web::test]
+async fn should_make_payment_with_mandate_set_up_by_a_previous_payment() {
+    let setup_response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                setup_mandate_details: Some(data_models::mandates::MandateData::default()),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(setup_response.status, enums::AttemptStatus::Charged);
+    let connector_mandate_id = match setup_response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            mandate_reference, ..
+        } => mandate_reference.and_then(|mandate| mandate.connector_mandate_id),
+        _ => None,
+    };
+    assert_ne!(connector_mandate_id, None, "Empty connector mandate id");
+
+    let response = CONNECTOR
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethodData::MandatePayment,
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: "mandate_1".to_string(),
+                    mandate_reference_id: Some(
+                        api_models::payments::MandateReferenceId::ConnectorMandateId(
+                            api_models::payments::ConnectorMandateReferenceId {
+                                connector_mandate_id,
+                                payment_method_id: None,
+                            },
+                        ),
+                    ),
+                }),
+                off_session: Some(true),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+// Creates a payout, which stays pending until the dummy connector settles it.
+#[cfg(feature = "payouts")]
+#[actix_web::test]
+async fn should_create_pending_payout() {
+    let response = CONNECTOR
+        .create_payout(None, enums::PayoutType::Bank, None)
+        .await
+        .expect("Payout creation response");
+    assert_eq!(response.status, Some(enums::PayoutStatus::Pending));
+    assert!(!response.connector_payout_id.is_empty());
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
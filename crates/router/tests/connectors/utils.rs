@@ -68,7 +68,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -92,7 +92,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -116,7 +116,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -144,7 +144,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         integration.execute_pretasks(&mut request, &state).await?;
@@ -510,6 +510,7 @@ pub trait ConnectorActions: Connector {
             connector_api_version: None,
             connector_http_status_code: None,
             apple_pay_flow: None,
+            connector_fee_details: None,
             external_latency: None,
         }
     }
@@ -555,7 +556,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -594,7 +595,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -634,7 +635,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -674,7 +675,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -758,7 +759,7 @@ pub trait ConnectorActions: Connector {
             Settings::new().unwrap(),
             StorageImpl::PostgresqlTest,
             tx,
-            Box::new(services::MockApiClient),
+            get_api_client(),
         )
         .await;
         connector_integration
@@ -777,7 +778,7 @@ pub trait ConnectorActions: Connector {
 }
 
 async fn call_connector<
-    T: Debug + Clone + 'static,
+    T: Debug + Clone + services::ConnectorFlow + 'static,
     Req: Debug + Clone + 'static,
     Resp: Debug + Clone + 'static,
 >(
@@ -786,13 +787,9 @@ async fn call_connector<
 ) -> Result<RouterData<T, Req, Resp>, Report<ConnectorError>> {
     let conf = Settings::new().unwrap();
     let tx: oneshot::Sender<()> = oneshot::channel().0;
-    let state = routes::AppState::with_storage(
-        conf,
-        StorageImpl::PostgresqlTest,
-        tx,
-        Box::new(services::MockApiClient),
-    )
-    .await;
+    let state =
+        routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest, tx, get_api_client())
+            .await;
     services::api::execute_connector_processing_step(
         &state,
         integration,
@@ -803,6 +800,44 @@ async fn call_connector<
     .await
 }
 
+/// Api client for the connector calls made by a test. When `CONNECTOR_HTTP_MODE` is `record` or
+/// `replay`, requests are recorded to or replayed from a cassette named after the test, in
+/// `CONNECTOR_CASSETTE_DIR` (`tests/connectors/cassettes` by default). Recording and replaying
+/// needs the `record_replay` feature.
+#[cfg(feature = "record_replay")]
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    let mode = match std::env::var("CONNECTOR_HTTP_MODE") {
+        Ok(mode) => services::RecordReplayMode::from_str(&mode)
+            .expect("CONNECTOR_HTTP_MODE must be either `record` or `replay`"),
+        Err(_) => return Box::new(services::MockApiClient),
+    };
+
+    // Tests run on a thread named after the test, such as `adyen::should_capture_payment`
+    let test_name = std::thread::current()
+        .name()
+        .expect("Test thread has no name")
+        .replace("::", "/");
+    let cassette_directory = std::env::var("CONNECTOR_CASSETTE_DIR")
+        .unwrap_or_else(|_| format!("{}/tests/connectors/cassettes", env!("CARGO_MANIFEST_DIR")));
+
+    Box::new(
+        services::RecordReplayApiClient::new(
+            mode,
+            format!("{cassette_directory}/{test_name}.json"),
+        )
+        .expect("Failed to load the cassette of the test"),
+    )
+}
+
+#[cfg(not(feature = "record_replay"))]
+pub fn get_api_client() -> Box<dyn services::ApiClient> {
+    assert!(
+        std::env::var("CONNECTOR_HTTP_MODE").is_err(),
+        "CONNECTOR_HTTP_MODE needs the `record_replay` feature"
+    );
+    Box::new(services::MockApiClient)
+}
+
 pub struct MockConfig {
     pub address: Option<String>,
     pub mocks: Vec<Mock>,
@@ -140,6 +140,10 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments routing trace flow.
+    PaymentsRoutingTrace,
+    /// Payments connector fee aggregate flow.
+    PaymentsConnectorFeeAggregate,
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
@@ -155,6 +159,12 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts fulfill flow.
     PayoutsFulfill,
+    #[cfg(feature = "payouts")]
+    /// Payouts list flow.
+    PayoutsList,
+    #[cfg(feature = "payouts")]
+    /// Payouts filter flow.
+    PayoutsFilter,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payments Redirect flow.
@@ -187,8 +197,42 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing retrieve timezone config
+    RoutingRetrieveTimezoneConfig,
+    /// Routing update timezone config
+    RoutingUpdateTimezoneConfig,
+    /// Routing diff between two configs
+    RoutingDiffConfig,
+    /// Routing retrieve config versions
+    RoutingRetrieveVersions,
+    /// Routing rollback to the previously active config
+    RoutingRollbackConfig,
+    /// Routing schedule config activation
+    RoutingScheduleActivation,
+    /// Routing retrieve activation audit trail
+    RoutingRetrieveActivationAudit,
+    /// Routing start canary rollout of a config
+    RoutingStartCanary,
+    /// Routing promote canary config
+    RoutingPromoteCanary,
+    /// Routing abort canary rollout
+    RoutingAbortCanary,
+    /// Routing retrieve canary rollout status
+    RoutingRetrieveCanary,
+    /// Surcharge decision manager upsert config
+    SurchargeDecisionManagerUpsertConfig,
+    /// Surcharge decision manager retrieve config
+    SurchargeDecisionManagerRetrieveConfig,
+    /// Surcharge decision manager delete config
+    SurchargeDecisionManagerDeleteConfig,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Incoming Webhook List flow
+    IncomingWebhookList,
+    /// Incoming Webhook Retrieve flow
+    IncomingWebhookRetrieve,
+    /// Incoming Webhook Reprocess flow
+    IncomingWebhookReprocess,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -237,8 +281,36 @@ pub enum Flow {
     BusinessProfileDelete,
     /// List all the business profiles for a merchant
     BusinessProfileList,
+    /// Rotate the key used for signing outgoing webhooks of a business profile
+    WebhookSigningKeyRotate,
+    /// List the active webhook signing keys of a business profile
+    WebhookSigningKeyList,
+    /// Revoke a webhook signing key of a business profile
+    WebhookSigningKeyRevoke,
+    /// Create a webhook endpoint for a business profile
+    WebhookEndpointCreate,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint of a business profile
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint of a business profile
+    WebhookEndpointUpdate,
+    /// Delete a webhook endpoint of a business profile
+    WebhookEndpointDelete,
+    /// List the webhook deliveries to a webhook endpoint
+    WebhookDeliveryList,
     /// Different verification flows
     Verification,
+    /// Create a reconciliation report from a settlement report
+    ReconReportCreate,
+    /// Retrieve a reconciliation report
+    ReconReportRetrieve,
+    /// List reconciliation reports
+    ReconReportList,
+    /// List the records of a reconciliation report
+    ReconRecordList,
+    /// Retrieve the health and circuit state of connectors
+    ConnectorHealthRetrieve,
 }
 
 ///
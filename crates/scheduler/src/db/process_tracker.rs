@@ -187,6 +187,11 @@ impl ProcessTrackerInterface for MockDb {
         new: storage::ProcessTrackerNew,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
         let mut processes = self.processes.lock().await;
+        if processes.iter().any(|process| process.id == new.id) {
+            Err(errors::StorageError::DatabaseError(error_stack::report!(
+                storage::errors::DatabaseError::UniqueViolation
+            )))?
+        }
         let process = storage::ProcessTracker {
             id: new.id,
             name: new.name,
@@ -217,11 +222,20 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process_tracker(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let existing = processes
+            .iter_mut()
+            .find(|existing| existing.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process tracker entry found for id = {}",
+                this.id
+            )))?;
+        *existing =
+            storage::ProcessTrackerUpdateInternal::from(process).apply_changeset(existing.clone());
+        Ok(existing.clone())
     }
 
     async fn process_tracker_update_process_status_by_ids(
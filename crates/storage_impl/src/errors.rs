@@ -264,6 +264,11 @@ pub enum ApiClientError {
     GatewayTimeoutReceived,
     #[error("Server responded with unexpected response")]
     UnexpectedServerResponse,
+
+    #[error("Failed to read or write the cassette of recorded requests")]
+    CassetteAccessFailed,
+    #[error("No recorded response matches the request")]
+    RecordedResponseNotFound,
 }
 
 impl ApiClientError {
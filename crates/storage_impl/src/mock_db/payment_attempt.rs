@@ -3,7 +3,8 @@ use common_utils::errors::CustomResult;
 use data_models::{
     errors::StorageError,
     payments::payment_attempt::{
-        PaymentAttempt, PaymentAttemptInterface, PaymentAttemptNew, PaymentAttemptUpdate,
+        PaymentAttempt, PaymentAttemptFeeAggregate, PaymentAttemptInterface, PaymentAttemptNew,
+        PaymentAttemptRoutingArmAggregate, PaymentAttemptUpdate,
     },
 };
 use diesel_models::enums as storage_enums;
@@ -47,6 +48,26 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn get_fee_aggregates_by_merchant_id_time_range(
+        &self,
+        _merchant_id: &str,
+        _start_time: time::PrimitiveDateTime,
+        _end_time: Option<time::PrimitiveDateTime>,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptFeeAggregate>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    async fn get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+        &self,
+        _merchant_id: &str,
+        _algorithm_ids: Vec<String>,
+        _start_time: time::PrimitiveDateTime,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptRoutingArmAggregate>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
         _attempt_id: &str,
@@ -144,6 +165,14 @@ impl PaymentAttemptInterface for MockDb {
             authentication_data: payment_attempt.authentication_data,
             encoded_data: payment_attempt.encoded_data,
             merchant_connector_id: payment_attempt.merchant_connector_id,
+            processing_fee: None,
+            interchange_fee: None,
+            fx_markup: None,
+            net_amount: None,
+            settlement_currency: None,
+            routing_trace: None,
+            routing_arm: None,
+            routing_algorithm_id: None,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
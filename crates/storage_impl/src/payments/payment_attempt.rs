@@ -5,8 +5,8 @@ use data_models::{
     mandates::{MandateAmountData, MandateDataType},
     payments::{
         payment_attempt::{
-            PaymentAttempt, PaymentAttemptInterface, PaymentAttemptNew, PaymentAttemptUpdate,
-            PaymentListFilters,
+            PaymentAttempt, PaymentAttemptFeeAggregate, PaymentAttemptInterface, PaymentAttemptNew,
+            PaymentAttemptRoutingArmAggregate, PaymentAttemptUpdate, PaymentListFilters,
         },
         PaymentIntent,
     },
@@ -26,6 +26,7 @@ use diesel_models::{
 use error_stack::{IntoReport, ResultExt};
 use redis_interface::HsetnxReply;
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use crate::{
     diesel_error_to_data_error,
@@ -292,6 +293,75 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             er.change_context(new_err)
         })
     }
+
+    async fn get_fee_aggregates_by_merchant_id_time_range(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: Option<PrimitiveDateTime>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptFeeAggregate>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::get_fee_aggregates_by_merchant_id_time_range(
+            &conn,
+            merchant_id,
+            start_time,
+            end_time,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|fee_aggregates| {
+            fee_aggregates
+                .into_iter()
+                .map(|fee_aggregate| PaymentAttemptFeeAggregate {
+                    connector: fee_aggregate.connector,
+                    date: fee_aggregate.date,
+                    settlement_currency: fee_aggregate.settlement_currency,
+                    payment_count: fee_aggregate.payment_count,
+                    processing_fee: fee_aggregate.processing_fee,
+                    interchange_fee: fee_aggregate.interchange_fee,
+                    fx_markup: fee_aggregate.fx_markup,
+                    net_amount: fee_aggregate.net_amount,
+                })
+                .collect()
+        })
+    }
+
+    async fn get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+        &self,
+        merchant_id: &str,
+        algorithm_ids: Vec<String>,
+        start_time: PrimitiveDateTime,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptRoutingArmAggregate>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+            &conn,
+            merchant_id,
+            algorithm_ids,
+            start_time,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|arm_aggregates| {
+            arm_aggregates
+                .into_iter()
+                .map(|arm_aggregate| PaymentAttemptRoutingArmAggregate {
+                    routing_arm: arm_aggregate.routing_arm,
+                    routing_algorithm_id: arm_aggregate.routing_algorithm_id,
+                    status: arm_aggregate.status,
+                    date: arm_aggregate.date,
+                    attempt_count: arm_aggregate.attempt_count,
+                })
+                .collect()
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -364,6 +434,14 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     authentication_data: payment_attempt.authentication_data.clone(),
                     encoded_data: payment_attempt.encoded_data.clone(),
                     merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                    processing_fee: None,
+                    interchange_fee: None,
+                    fx_markup: None,
+                    net_amount: None,
+                    settlement_currency: None,
+                    routing_trace: None,
+                    routing_arm: None,
+                    routing_algorithm_id: None,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -860,6 +938,40 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             )
             .await
     }
+
+    async fn get_fee_aggregates_by_merchant_id_time_range(
+        &self,
+        merchant_id: &str,
+        start_time: PrimitiveDateTime,
+        end_time: Option<PrimitiveDateTime>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptFeeAggregate>, errors::StorageError> {
+        self.router_store
+            .get_fee_aggregates_by_merchant_id_time_range(
+                merchant_id,
+                start_time,
+                end_time,
+                storage_scheme,
+            )
+            .await
+    }
+
+    async fn get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+        &self,
+        merchant_id: &str,
+        algorithm_ids: Vec<String>,
+        start_time: PrimitiveDateTime,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttemptRoutingArmAggregate>, errors::StorageError> {
+        self.router_store
+            .get_routing_arm_aggregates_by_merchant_id_algorithm_ids(
+                merchant_id,
+                algorithm_ids,
+                start_time,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 impl DataModelExt for MandateAmountData {
@@ -962,6 +1074,14 @@ impl DataModelExt for PaymentAttempt {
             authentication_data: self.authentication_data,
             encoded_data: self.encoded_data,
             merchant_connector_id: self.merchant_connector_id,
+            processing_fee: self.processing_fee,
+            interchange_fee: self.interchange_fee,
+            fx_markup: self.fx_markup,
+            net_amount: self.net_amount,
+            settlement_currency: self.settlement_currency,
+            routing_trace: self.routing_trace,
+            routing_arm: self.routing_arm,
+            routing_algorithm_id: self.routing_algorithm_id,
         }
     }
 
@@ -1014,6 +1134,14 @@ impl DataModelExt for PaymentAttempt {
             authentication_data: storage_model.authentication_data,
             encoded_data: storage_model.encoded_data,
             merchant_connector_id: storage_model.merchant_connector_id,
+            processing_fee: storage_model.processing_fee,
+            interchange_fee: storage_model.interchange_fee,
+            fx_markup: storage_model.fx_markup,
+            net_amount: storage_model.net_amount,
+            settlement_currency: storage_model.settlement_currency,
+            routing_trace: storage_model.routing_trace,
+            routing_arm: storage_model.routing_arm,
+            routing_algorithm_id: storage_model.routing_algorithm_id,
         }
     }
 }
@@ -1162,6 +1290,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1169,6 +1300,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1198,6 +1332,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1219,6 +1356,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             },
             Self::VoidUpdate {
                 status,
@@ -1243,6 +1383,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -1258,6 +1403,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             },
             Self::UnresolvedResponseUpdate {
@@ -1399,6 +1549,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => Self::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1406,6 +1559,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 amount_capturable,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             },
             DieselPaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1435,6 +1591,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -1456,6 +1615,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                routing_trace,
+                routing_arm,
+                routing_algorithm_id,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -1480,6 +1642,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             } => Self::ResponseUpdate {
                 status,
@@ -1495,6 +1662,11 @@ impl DataModelExt for PaymentAttemptUpdate {
                 error_reason,
                 connector_response_reference_id,
                 amount_capturable,
+                processing_fee,
+                interchange_fee,
+                fx_markup,
+                net_amount,
+                settlement_currency,
                 updated_by,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE 'payouts';
+
+ALTER TYPE "EventObjectType" ADD VALUE 'payout_details';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_success';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_failed';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_processing';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_cancelled';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_expired';
+
+ALTER TYPE "EventType" ADD VALUE 'payout_reversed';
+
+ALTER TYPE "PayoutStatus" ADD VALUE 'expired';
+
+ALTER TYPE "PayoutStatus" ADD VALUE 'reversed';
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutType" ADD VALUE 'wallet';
+
+ALTER TYPE "PayoutType" ADD VALUE 'instant_transfer';
@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE recon_report (
+    report_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    total_records INTEGER NOT NULL DEFAULT 0,
+    matched_records INTEGER NOT NULL DEFAULT 0,
+    unmatched_records INTEGER NOT NULL DEFAULT 0,
+    error_message VARCHAR(1024),
+    created_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX recon_report_merchant_id_created_at ON recon_report (merchant_id, created_at DESC);
+
+CREATE TABLE recon_record (
+    record_id VARCHAR(64) PRIMARY KEY,
+    report_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    settlement_batch_id VARCHAR(128),
+    transaction_type VARCHAR(32) NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    reference_id VARCHAR(64),
+    reported_amount BIGINT,
+    reported_currency VARCHAR(8),
+    reported_fee BIGINT,
+    reported_net_amount BIGINT,
+    reported_status VARCHAR(64),
+    expected_amount BIGINT,
+    expected_currency VARCHAR(8),
+    outcome VARCHAR(32) NOT NULL,
+    mismatch_details JSONB,
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX recon_record_merchant_id_report_id ON recon_record (merchant_id, report_id);
@@ -0,0 +1,14 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS processing_fee,
+DROP COLUMN IF EXISTS interchange_fee,
+DROP COLUMN IF EXISTS fx_markup,
+DROP COLUMN IF EXISTS net_amount,
+DROP COLUMN IF EXISTS settlement_currency;
+
+ALTER TABLE refund
+DROP COLUMN IF EXISTS processing_fee,
+DROP COLUMN IF EXISTS interchange_fee,
+DROP COLUMN IF EXISTS fx_markup,
+DROP COLUMN IF EXISTS net_amount,
+DROP COLUMN IF EXISTS settlement_currency;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS processing_fee BIGINT,
+ADD COLUMN IF NOT EXISTS interchange_fee BIGINT,
+ADD COLUMN IF NOT EXISTS fx_markup BIGINT,
+ADD COLUMN IF NOT EXISTS net_amount BIGINT,
+ADD COLUMN IF NOT EXISTS settlement_currency "Currency";
+
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS processing_fee BIGINT,
+ADD COLUMN IF NOT EXISTS interchange_fee BIGINT,
+ADD COLUMN IF NOT EXISTS fx_markup BIGINT,
+ADD COLUMN IF NOT EXISTS net_amount BIGINT,
+ADD COLUMN IF NOT EXISTS settlement_currency "Currency";
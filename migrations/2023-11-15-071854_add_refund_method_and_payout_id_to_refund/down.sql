@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund
+DROP COLUMN IF EXISTS refund_method,
+DROP COLUMN IF EXISTS payout_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS refund_method VARCHAR(32),
+ADD COLUMN IF NOT EXISTS payout_id VARCHAR(64);
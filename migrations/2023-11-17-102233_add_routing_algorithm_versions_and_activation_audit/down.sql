@@ -0,0 +1,10 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS routing_activation_audit;
+
+ALTER TABLE routing_algorithm
+DROP CONSTRAINT IF EXISTS routing_algorithm_profile_id_name_version_key;
+
+ALTER TABLE routing_algorithm
+DROP COLUMN IF EXISTS version,
+DROP COLUMN IF EXISTS created_by,
+DROP COLUMN IF EXISTS comment;
@@ -0,0 +1,31 @@
+-- Your SQL goes here
+ALTER TABLE routing_algorithm
+ADD COLUMN version INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN created_by VARCHAR(64),
+ADD COLUMN comment VARCHAR(256);
+
+-- Number the algorithms which already share a name, so that the versions are unique
+UPDATE routing_algorithm
+SET version = numbered.version
+FROM (
+    SELECT algorithm_id,
+        ROW_NUMBER() OVER (PARTITION BY profile_id, name ORDER BY created_at, algorithm_id) AS version
+    FROM routing_algorithm
+) AS numbered
+WHERE routing_algorithm.algorithm_id = numbered.algorithm_id;
+
+ALTER TABLE routing_algorithm
+ADD CONSTRAINT routing_algorithm_profile_id_name_version_key UNIQUE (profile_id, name, version);
+
+CREATE TABLE routing_activation_audit (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    algorithm_id VARCHAR(64),
+    previous_algorithm_id VARCHAR(64),
+    action VARCHAR(32) NOT NULL,
+    created_by VARCHAR(64),
+    created_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX routing_activation_audit_merchant_id_created_at ON routing_activation_audit (merchant_id, created_at DESC);
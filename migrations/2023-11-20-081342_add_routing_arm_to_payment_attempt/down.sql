@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS routing_arm,
+DROP COLUMN IF EXISTS routing_algorithm_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS routing_arm VARCHAR(16),
+ADD COLUMN IF NOT EXISTS routing_algorithm_id VARCHAR(64);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account DROP COLUMN IF EXISTS fee_schedule;
+
+-- Values cannot be removed from an enum type in postgres, `least_cost` is left in place
+SELECT 1;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS fee_schedule JSONB;
+
+ALTER TYPE "RoutingAlgorithmKind" ADD VALUE IF NOT EXISTS 'least_cost';
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE webhook_delivery;
+DROP TABLE webhook_endpoint;
@@ -0,0 +1,33 @@
+-- Your SQL goes here
+CREATE TABLE webhook_endpoint (
+    endpoint_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    url VARCHAR(2048) NOT NULL,
+    description VARCHAR(256),
+    enabled_events JSONB,
+    headers JSONB,
+    secret VARCHAR(128) NOT NULL,
+    disabled BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX webhook_endpoint_profile_id ON webhook_endpoint (profile_id);
+
+CREATE TABLE webhook_delivery (
+    delivery_id VARCHAR(64) PRIMARY KEY,
+    event_id VARCHAR(64) NOT NULL,
+    event_type "EventType" NOT NULL,
+    endpoint_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    response_status_code SMALLINT,
+    error_message VARCHAR(1024),
+    created_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX webhook_delivery_endpoint_id_created_at ON webhook_delivery (endpoint_id, created_at DESC);
+
+CREATE INDEX webhook_delivery_event_id ON webhook_delivery (event_id);
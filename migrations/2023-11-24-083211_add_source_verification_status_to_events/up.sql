@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE events ADD COLUMN IF NOT EXISTS source_verification_status VARCHAR(32);
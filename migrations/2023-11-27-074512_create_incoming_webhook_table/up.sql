@@ -0,0 +1,20 @@
+-- Your SQL goes here
+CREATE TABLE incoming_webhook (
+    webhook_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_name_or_mca_id VARCHAR(64) NOT NULL,
+    connector_name VARCHAR(64),
+    headers JSONB NOT NULL,
+    body JSONB,
+    raw_request BYTEA NOT NULL,
+    source_verification_status VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    error_message VARCHAR(1024),
+    payment_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    dispute_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL,
+    modified_at TIMESTAMP NOT NULL
+);
+
+CREATE INDEX incoming_webhook_merchant_id_created_at ON incoming_webhook (merchant_id, created_at DESC);